@@ -0,0 +1,102 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  build_response::build_comment_response,
+  context::LemmyContext,
+  notify::{NotifyData, notify_mod_action},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::source::{
+  comment::{Comment, CommentUpdateForm},
+  community::Community,
+  modlog::{Modlog, ModlogInsertForm},
+  person::Person,
+  post::Post,
+};
+use lemmy_db_schema_file::enums::ModeratorPermission;
+use lemmy_db_views_comment::api::{ApproveQuarantinedComment, CommentResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+pub async fn approve_quarantined_comment(
+  Json(data): Json<ApproveQuarantinedComment>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommentResponse>> {
+  let comment_id = data.comment_id;
+  let local_instance_id = local_user_view.person.instance_id;
+
+  // Can't use CommentView here because it hides quarantined comments from non-mods
+  let orig_comment = Comment::read(&mut context.pool(), comment_id).await?;
+  if !orig_comment.quarantined || orig_comment.removed {
+    Err(LemmyErrorType::NotQuarantined)?
+  }
+  let post = Post::read(&mut context.pool(), orig_comment.post_id).await?;
+  let community = Community::read(&mut context.pool(), post.community_id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::RemoveContent),
+    &mut context.pool(),
+  )
+  .await?;
+
+  if data.approve {
+    let comment = Comment::update(
+      &mut context.pool(),
+      comment_id,
+      &CommentUpdateForm {
+        quarantined: Some(false),
+        ..Default::default()
+      },
+    )
+    .await?;
+
+    // Send out the notifications and the activity which were held back on creation
+    let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+    let creator = Person::read(&mut context.pool(), comment.creator_id).await?;
+    NotifyData {
+      comment: Some(comment.clone()),
+      do_send_email: !local_site.disable_email_notifications,
+      ..NotifyData::new(post, creator, community)
+    }
+    .send(&context);
+
+    ActivityChannel::submit_activity(SendActivityData::CreateComment(comment), &context)?;
+  } else {
+    let reason = data.deny_reason.clone().unwrap_or_default();
+    is_valid_body_field(&reason, false)?;
+
+    // The comment stays quarantined, so the removal isn't federated either
+    let comment = Comment::update(
+      &mut context.pool(),
+      comment_id,
+      &CommentUpdateForm {
+        removed: Some(true),
+        ..Default::default()
+      },
+    )
+    .await?;
+
+    let form =
+      ModlogInsertForm::mod_remove_comment(local_user_view.person.id, &comment, true, &reason);
+    let action = Modlog::create(&mut context.pool(), &[form]).await?;
+    notify_mod_action(action, &context);
+  }
+
+  build_comment_response(
+    &context,
+    comment_id,
+    local_user_view.into(),
+    local_instance_id,
+  )
+  .await
+  .map(Json)
+}
@@ -6,6 +6,7 @@ use lemmy_api_utils::{
   utils::{check_community_mod_action, check_community_user_action},
 };
 use lemmy_db_schema::source::comment::{Comment, CommentUpdateForm};
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_comment::{
   CommentView,
   api::{CommentResponse, DistinguishComment},
@@ -46,6 +47,7 @@ pub async fn distinguish_comment(
     &local_user_view,
     &orig_comment.community,
     false,
+    Some(ModeratorPermission::RemoveContent),
     &mut context.pool(),
   )
   .await?;
@@ -0,0 +1,72 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_edit_history_visible, check_private_instance, diff_lines, is_mod_or_admin_opt},
+};
+use lemmy_db_schema::source::revision::CommentRevision;
+use lemmy_db_views_comment::{
+  CommentView,
+  api::{CommentRevisionDiff, ListCommentRevisions, ListCommentRevisionsResponse},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::SiteView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use std::iter::once;
+
+/// Lists the previous versions of a comment
+pub async fn list_comment_revisions(
+  Query(data): Query<ListCommentRevisions>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListCommentRevisionsResponse>> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  let local_site = site_view.local_site;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  // Reading the view makes sure that the comment is visible to the user
+  let local_user = local_user_view.as_ref().map(|l| l.local_user.clone());
+  let comment_view = CommentView::read(
+    &mut context.pool(),
+    data.comment_id,
+    local_user.as_ref(),
+    site_view.site.instance_id,
+  )
+  .await?;
+  let is_mod_or_admin = is_mod_or_admin_opt(
+    &mut context.pool(),
+    local_user_view.as_ref(),
+    Some(comment_view.community.id),
+  )
+  .await
+  .is_ok();
+  // The old versions of removed or deleted content are only shown to mods and admins
+  if (comment_view.comment.removed || comment_view.comment.deleted) && !is_mod_or_admin {
+    Err(LemmyErrorType::NotFound)?
+  }
+  check_edit_history_visible(
+    &local_site,
+    local_user_view.as_ref(),
+    comment_view.creator.id,
+    is_mod_or_admin,
+  )?;
+
+  let revisions = CommentRevision::list(&mut context.pool(), data.comment_id).await?;
+
+  // Each revision is compared with the version which replaced it
+  let next_versions = revisions
+    .iter()
+    .skip(1)
+    .map(|r| r.content.clone())
+    .chain(once(comment_view.comment.content))
+    .collect::<Vec<_>>();
+  let revisions = revisions
+    .into_iter()
+    .zip(next_versions)
+    .map(|(revision, content)| CommentRevisionDiff {
+      content_diff: diff_lines(&revision.content, &content),
+      revision,
+    })
+    .collect();
+
+  Ok(Json(ListCommentRevisionsResponse { revisions }))
+}
@@ -11,6 +11,7 @@ use lemmy_db_schema::source::{
   comment::Comment,
   modlog::{Modlog, ModlogInsertForm},
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_comment::{
   CommentView,
   api::{CommentResponse, LockComment},
@@ -34,6 +35,7 @@ pub async fn lock_comment(
     &local_user_view,
     &orig_comment.community,
     false,
+    Some(ModeratorPermission::RemoveContent),
     &mut context.pool(),
   )
   .await?;
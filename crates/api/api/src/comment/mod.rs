@@ -1,5 +1,8 @@
+pub mod approve_quarantined;
 pub mod distinguish;
 pub mod like;
 pub mod list_comment_likes;
+pub mod list_revisions;
 pub mod lock;
+pub mod pin;
 pub mod save;
@@ -0,0 +1,73 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, check_community_user_action},
+};
+use lemmy_db_schema::source::comment::{Comment, CommentUpdateForm};
+use lemmy_db_schema_file::enums::ModeratorPermission;
+use lemmy_db_views_comment::{
+  CommentView,
+  api::{CommentResponse, PinComment},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn pin_comment(
+  Json(data): Json<PinComment>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommentResponse>> {
+  let local_instance_id = local_user_view.person.instance_id;
+
+  let orig_comment = CommentView::read(
+    &mut context.pool(),
+    data.comment_id,
+    Some(&local_user_view.local_user),
+    local_instance_id,
+  )
+  .await?;
+
+  check_community_user_action(
+    &local_user_view,
+    &orig_comment.community,
+    &mut context.pool(),
+  )
+  .await?;
+
+  // Verify that only the creator can pin
+  if local_user_view.person.id != orig_comment.creator.id {
+    Err(LemmyErrorType::NoCommentEditAllowed)?
+  }
+
+  // Verify that only a mod or admin can pin a comment
+  check_community_mod_action(
+    &local_user_view,
+    &orig_comment.community,
+    false,
+    Some(ModeratorPermission::RemoveContent),
+    &mut context.pool(),
+  )
+  .await?;
+
+  // Update the Comment
+  let form = CommentUpdateForm {
+    pinned: Some(data.pinned),
+    ..Default::default()
+  };
+
+  let comment = Comment::update(&mut context.pool(), data.comment_id, &form).await?;
+  ActivityChannel::submit_activity(SendActivityData::UpdateComment(comment), &context)?;
+
+  let comment_view = CommentView::read(
+    &mut context.pool(),
+    data.comment_id,
+    Some(&local_user_view.local_user),
+    local_instance_id,
+  )
+  .await?;
+
+  Ok(Json(CommentResponse { comment_view }))
+}
@@ -19,6 +19,7 @@ use lemmy_db_schema::{
   },
   traits::{Bannable, Followable},
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_community::api::BanFromCommunity;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_person::{PersonView, api::PersonResponse};
@@ -37,7 +38,14 @@ pub async fn ban_from_community(
   let community = Community::read(&mut context.pool(), data.community_id).await?;
 
   // Verify that only mods or admins can ban
-  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::ManageBans),
+    &mut context.pool(),
+  )
+  .await?;
 
   LocalUser::is_higher_mod_or_admin_check(
     &mut context.pool(),
@@ -0,0 +1,68 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_action, check_community_user_action, slur_regex},
+};
+use lemmy_db_schema::source::{
+  community::{Community, CommunityActions},
+  tag::Tag,
+};
+use lemmy_db_schema_file::enums::ModeratorPermission;
+use lemmy_db_views_community::api::EditUserFlair;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{
+    slurs::check_slurs,
+    validation::{is_valid_flair_emoji, is_valid_user_flair},
+  },
+};
+
+pub async fn edit_user_flair(
+  Json(data): Json<EditUserFlair>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  let person_id = data.person_id.unwrap_or(local_user_view.person.id);
+  let is_self = person_id == local_user_view.person.id;
+
+  let (flair, needs_mod) = if let Some(tag_id) = data.tag_id {
+    let tag = Tag::read(&mut context.pool(), tag_id).await?;
+    if tag.community_id != community.id || !tag.user_flair || tag.deleted {
+      Err(LemmyErrorType::TagNotInCommunity)?
+    }
+    let flair = tag.display_name.unwrap_or(tag.name);
+    (Some((flair, tag.emoji)), tag.mod_only || !is_self)
+  } else if let Some(flair) = &data.flair {
+    // Custom flairs can only be given by mods
+    is_valid_user_flair(flair)?;
+    check_slurs(flair, &slur_regex(&context).await?)?;
+    if let Some(emoji) = &data.flair_emoji {
+      is_valid_flair_emoji(emoji)?;
+    }
+    (Some((flair.clone(), data.flair_emoji.clone())), true)
+  } else {
+    (None, !is_self)
+  };
+
+  if needs_mod {
+    check_community_mod_action(
+      &local_user_view,
+      &community,
+      false,
+      Some(ModeratorPermission::EditSettings),
+      &mut context.pool(),
+    )
+    .await?;
+  } else {
+    check_community_user_action(&local_user_view, &community, &mut context.pool()).await?;
+  }
+
+  CommunityActions::update_flair(&mut context.pool(), community.id, person_id, flair).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
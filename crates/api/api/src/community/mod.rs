@@ -18,13 +18,17 @@ use lemmy_utils::error::LemmyResult;
 pub mod add_mod;
 pub mod ban;
 pub mod block;
+pub mod flair;
 pub mod follow;
+pub mod moderator_permissions;
 pub mod multi_community_follow;
 pub mod pending_follows;
 pub mod random;
+pub mod recurring_post;
 pub mod tag;
 pub mod transfer;
 pub mod update_notifications;
+pub mod wiki;
 
 pub(super) async fn do_follow_community(
   community: Community,
@@ -0,0 +1,101 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::source::{
+  community::{Community, CommunityActions},
+  local_user::LocalUser,
+  modlog::{Modlog, ModlogInsertForm},
+};
+use lemmy_db_views_community::api::{AddModToCommunityResponse, EditModeratorPermissions};
+use lemmy_db_views_community_moderator::CommunityModeratorView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::{connection::get_conn, traits::Crud};
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_body_field};
+
+pub async fn edit_moderator_permissions(
+  Json(data): Json<EditModeratorPermissions>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<AddModToCommunityResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  // Only mods holding all permissions or admins can change the permissions of other mods
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    None,
+    &mut context.pool(),
+  )
+  .await?;
+
+  LocalUser::is_higher_mod_or_admin_check(
+    &mut context.pool(),
+    community.id,
+    local_user_view.person.id,
+    vec![data.person_id],
+  )
+  .await?;
+
+  if let Some(reason) = &data.reason {
+    is_valid_body_field(reason, false)?;
+  }
+
+  // Same as for adding mods, the change would be rejected by the home instance of a remote
+  // community if the admin isn't a moderator there.
+  if local_user_view.local_user.admin && !community.local {
+    CommunityModeratorView::check_is_community_moderator(
+      &mut context.pool(),
+      community.id,
+      local_user_view.person.id,
+    )
+    .await?;
+  }
+
+  let pool = &mut context.pool();
+  let conn = &mut get_conn(pool).await?;
+  let tx_data = data.clone();
+  let action = conn
+    .run_transaction(|conn| {
+      async move {
+        CommunityActions::update_moderator_permissions(
+          &mut conn.into(),
+          tx_data.community_id,
+          tx_data.person_id,
+          tx_data.permissions,
+        )
+        .await?;
+
+        let form = ModlogInsertForm::mod_change_moderator_permissions(
+          local_user_view.person.id,
+          tx_data.community_id,
+          tx_data.person_id,
+          tx_data.reason.as_deref(),
+        );
+        Modlog::create(&mut conn.into(), &[form]).await
+      }
+      .scope_boxed()
+    })
+    .await?;
+  notify_mod_action(action.clone(), &context);
+
+  let moderators = CommunityModeratorView::for_community(&mut context.pool(), community.id).await?;
+
+  // Adding an existing mod again federates their new permissions
+  ActivityChannel::submit_activity(
+    SendActivityData::AddModToCommunity {
+      moderator: local_user_view.person,
+      community_id: data.community_id,
+      target: data.person_id,
+      added: true,
+    },
+    &context,
+  )?;
+
+  Ok(Json(AddModToCommunityResponse { moderators }))
+}
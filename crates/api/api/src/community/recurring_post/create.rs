@@ -0,0 +1,70 @@
+use super::{check_name_template, convert_publish_time, process_body};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_mod_permission};
+use lemmy_db_schema::{
+  impls::actor_language::validate_post_language,
+  source::{
+    community::Community,
+    recurring_post::{RecurringPost, RecurringPostInsertForm},
+  },
+};
+use lemmy_db_schema_file::enums::ModeratorPermission;
+use lemmy_db_views_community::api::{CreateRecurringPost, RecurringPostResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::check_api_elements_count,
+};
+
+pub async fn create_recurring_post(
+  Json(data): Json<CreateRecurringPost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RecurringPostResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  // The posts are published in the name of the creator, so admins can't use their override
+  let admin_override = check_community_mod_permission(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
+  if admin_override {
+    Err(LemmyErrorType::NotAModerator)?
+  }
+
+  let existing = RecurringPost::list_for_community(&mut context.pool(), community.id).await?;
+  check_api_elements_count(existing.len())?;
+
+  let name_template = data.name_template.trim().to_string();
+  check_name_template(&name_template, &context).await?;
+  let body = match &data.body {
+    Some(body) => Some(process_body(body, &context).await?),
+    None => None,
+  };
+  validate_post_language(&mut context.pool(), data.language_id, community.id).await?;
+  let next_publish_at = convert_publish_time(data.first_publish_at)?;
+
+  let form = RecurringPostInsertForm {
+    body,
+    nsfw: data.nsfw,
+    language_id: data.language_id,
+    feature_latest: data.feature_latest,
+    lock_previous: data.lock_previous,
+    ..RecurringPostInsertForm::new(
+      community.id,
+      local_user_view.person.id,
+      name_template,
+      data.frequency,
+      next_publish_at,
+      next_publish_at,
+    )
+  };
+  let recurring_post = RecurringPost::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(RecurringPostResponse { recurring_post }))
+}
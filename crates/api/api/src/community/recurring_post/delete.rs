@@ -0,0 +1,31 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_mod_action};
+use lemmy_db_schema::source::{community::Community, recurring_post::RecurringPost};
+use lemmy_db_schema_file::enums::ModeratorPermission;
+use lemmy_db_views_community::api::DeleteRecurringPost;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_recurring_post(
+  Json(data): Json<DeleteRecurringPost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let recurring_post = RecurringPost::read(&mut context.pool(), data.id).await?;
+  let community = Community::read(&mut context.pool(), recurring_post.community_id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
+
+  RecurringPost::delete(&mut context.pool(), recurring_post.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,30 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_mod_action};
+use lemmy_db_schema::source::{community::Community, recurring_post::RecurringPost};
+use lemmy_db_schema_file::enums::ModeratorPermission;
+use lemmy_db_views_community::api::{ListRecurringPosts, ListRecurringPostsResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_recurring_posts(
+  Query(data): Query<ListRecurringPosts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListRecurringPostsResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
+
+  let recurring_posts =
+    RecurringPost::list_for_community(&mut context.pool(), community.id).await?;
+
+  Ok(Json(ListRecurringPostsResponse { recurring_posts }))
+}
@@ -0,0 +1,43 @@
+use chrono::{DateTime, TimeZone, Utc};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::source::recurring_post::RecurringPost;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{
+    slurs::check_slurs,
+    validation::{is_valid_body_field, is_valid_post_title},
+  },
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+fn convert_publish_time(publish_at: i64) -> LemmyResult<DateTime<Utc>> {
+  let converted = Utc
+    .timestamp_opt(publish_at, 0)
+    .single()
+    .ok_or(LemmyErrorType::InvalidUnixTime)?;
+  if converted < Utc::now() {
+    Err(LemmyErrorType::PostScheduleTimeMustBeInFuture)?;
+  }
+  Ok(converted)
+}
+
+/// Checks the title template by rendering it, as the placeholders may make it longer.
+async fn check_name_template(name_template: &str, context: &LemmyContext) -> LemmyResult<()> {
+  check_slurs(name_template, &slur_regex(context).await?)?;
+  is_valid_post_title(&RecurringPost::render_name(name_template, Utc::now()))
+}
+
+async fn process_body(body: &str, context: &LemmyContext) -> LemmyResult<String> {
+  let slur_regex = slur_regex(context).await?;
+  let url_blocklist = get_url_blocklist(context).await?;
+  let body = process_markdown(body, &slur_regex, &url_blocklist, context).await?;
+  is_valid_body_field(&body, true)?;
+  Ok(body)
+}
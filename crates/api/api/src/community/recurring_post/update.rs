@@ -0,0 +1,79 @@
+use super::{check_name_template, convert_publish_time, process_body};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_mod_action};
+use lemmy_db_schema::{
+  impls::actor_language::validate_post_language,
+  source::{
+    community::Community,
+    recurring_post::{RecurringPost, RecurringPostUpdateForm},
+  },
+};
+use lemmy_db_schema_file::enums::ModeratorPermission;
+use lemmy_db_views_community::api::{EditRecurringPost, RecurringPostResponse};
+use lemmy_db_views_community_moderator::CommunityModeratorView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn edit_recurring_post(
+  Json(data): Json<EditRecurringPost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RecurringPostResponse>> {
+  let orig = RecurringPost::read(&mut context.pool(), data.id).await?;
+  let community = Community::read(&mut context.pool(), orig.community_id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
+  // Admins may edit the template, but it stays in the name of a moderator
+  let is_mod = CommunityModeratorView::check_moderator_permission(
+    &mut context.pool(),
+    community.id,
+    local_user_view.person.id,
+    Some(ModeratorPermission::EditSettings),
+  )
+  .await
+  .is_ok();
+
+  let name_template = data.name_template.as_deref().map(str::trim);
+  if let Some(name_template) = name_template {
+    check_name_template(name_template, &context).await?;
+  }
+  // Empty means erase
+  let body = match data.body.as_deref() {
+    Some("") => Some(None),
+    Some(body) => Some(Some(process_body(body, &context).await?)),
+    None => None,
+  };
+  if data.language_id.is_some() {
+    validate_post_language(&mut context.pool(), data.language_id, community.id).await?;
+  }
+  let next_publish_at = data.next_publish_at.map(convert_publish_time).transpose()?;
+
+  let form = RecurringPostUpdateForm {
+    // The posts are published in the name of the moderator who edited the template last
+    creator_id: is_mod.then_some(local_user_view.person.id),
+    name_template: name_template.map(ToString::to_string),
+    body,
+    nsfw: data.nsfw,
+    language_id: data.language_id.map(Some),
+    frequency: data.frequency,
+    // Moving the next post starts the schedule over from there
+    schedule_start_at: next_publish_at,
+    next_publish_at,
+    feature_latest: data.feature_latest,
+    lock_previous: data.lock_previous,
+    last_post_id: None,
+    updated_at: Some(Some(Utc::now())),
+  };
+  let recurring_post = RecurringPost::update(&mut context.pool(), orig.id, &form).await?;
+
+  Ok(Json(RecurringPostResponse { recurring_post }))
+}
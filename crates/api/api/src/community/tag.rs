@@ -10,6 +10,7 @@ use lemmy_db_schema::source::{
   community::Community,
   tag::{Tag, TagInsertForm, TagUpdateForm},
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_community::{
   CommunityView,
   api::{CreateCommunityTag, DeleteCommunityTag, UpdateCommunityTag},
@@ -20,7 +21,13 @@ use lemmy_utils::{
   error::LemmyResult,
   utils::{
     slurs::check_slurs,
-    validation::{check_api_elements_count, is_valid_actor_name, summary_length_check},
+    validation::{
+      check_api_elements_count,
+      is_valid_actor_name,
+      is_valid_flair_emoji,
+      is_valid_tag_color,
+      summary_length_check,
+    },
   },
 };
 use url::Url;
@@ -37,13 +44,26 @@ pub async fn create_community_tag(
   let community = community_view.community;
 
   // Verify that only mods can create tags
-  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
-
-  check_api_elements_count(community_view.post_tags.0.len())?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
+
+  let user_flair = data.user_flair.unwrap_or_default();
+  if user_flair {
+    check_api_elements_count(community_view.user_flairs.0.len())?;
+  } else {
+    check_api_elements_count(community_view.post_tags.0.len())?;
+  }
   if let Some(desc) = &data.description {
     summary_length_check(desc)?;
     check_slurs(desc, &slur_regex(&context).await?)?;
   }
+  check_tag_style(data.color.as_deref(), data.emoji.as_deref())?;
 
   let ap_id = Url::parse(&format!("{}/tag/{}", community.ap_id, &data.name))?;
 
@@ -55,6 +75,10 @@ pub async fn create_community_tag(
     community_id: data.community_id,
     ap_id: ap_id.into(),
     deleted: Some(false),
+    color: data.color.clone().filter(|c| !c.is_empty()),
+    emoji: data.emoji.clone().filter(|e| !e.is_empty()),
+    mod_only: data.mod_only,
+    user_flair: Some(user_flair),
   };
 
   let tag = Tag::create(&mut context.pool(), &tag_form).await?;
@@ -76,22 +100,39 @@ pub async fn update_community_tag(
   let community = Community::read(&mut context.pool(), tag.community_id).await?;
 
   // Verify that only mods can update tags
-  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
 
   if let Some(desc) = &data.description {
     summary_length_check(desc)?;
     check_slurs(desc, &slur_regex(&context).await?)?;
   }
+  check_tag_style(data.color.as_deref(), data.emoji.as_deref())?;
 
   // Update the tag
   let tag_form = TagUpdateForm {
     display_name: diesel_string_update(data.display_name.as_deref()),
     description: diesel_string_update(data.description.as_deref()),
+    color: diesel_string_update(data.color.as_deref()),
+    emoji: diesel_string_update(data.emoji.as_deref()),
+    mod_only: data.mod_only,
     updated_at: Some(Some(Utc::now())),
     ..Default::default()
   };
 
   let tag = Tag::update(&mut context.pool(), data.tag_id, &tag_form).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), community),
+    &context,
+  )?;
+
   Ok(Json(tag))
 }
 
@@ -104,7 +145,14 @@ pub async fn delete_community_tag(
   let community = Community::read(&mut context.pool(), tag.community_id).await?;
 
   // Verify that only mods can delete tags
-  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
 
   // Soft delete the tag
   let tag_form = TagUpdateForm {
@@ -122,3 +170,14 @@ pub async fn delete_community_tag(
 
   Ok(Json(tag))
 }
+
+/// Empty strings are allowed, and remove the color or emoji when editing a tag.
+fn check_tag_style(color: Option<&str>, emoji: Option<&str>) -> LemmyResult<()> {
+  if let Some(color) = color.filter(|c| !c.is_empty()) {
+    is_valid_tag_color(color)?;
+  }
+  if let Some(emoji) = emoji.filter(|e| !e.is_empty()) {
+    is_valid_flair_emoji(emoji)?;
+  }
+  Ok(())
+}
@@ -4,12 +4,13 @@ use diesel_async::scoped_futures::ScopedFutureExt;
 use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
-  utils::{check_community_user_action, is_admin, is_top_mod},
+  utils::{check_admin_action, check_community_user_action, is_top_mod},
 };
 use lemmy_db_schema::source::{
   community::{Community, CommunityActions, CommunityModeratorForm},
   modlog::{Modlog, ModlogInsertForm},
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_community::{
   CommunityView,
   api::{GetCommunityResponse, TransferCommunity},
@@ -17,10 +18,7 @@ use lemmy_db_views_community::{
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_diesel_utils::{connection::get_conn, traits::Crud};
-use lemmy_utils::{
-  error::{LemmyErrorType, LemmyResult},
-  location_info,
-};
+use lemmy_utils::{error::LemmyResult, location_info};
 
 // TODO: we don't do anything for federation here, it should be updated the next time the community
 //       gets fetched. i hope we can get rid of the community creator role soon.
@@ -37,9 +35,16 @@ pub async fn transfer_community(
   check_community_user_action(&local_user_view, &community, &mut context.pool()).await?;
 
   // Make sure transferrer is either the top community mod, or an admin
-  if !(is_top_mod(&local_user_view, &community_mods).is_ok() || is_admin(&local_user_view).is_ok())
-  {
-    Err(LemmyErrorType::NotAnAdmin)?
+  if is_top_mod(&local_user_view, &community_mods).is_err() {
+    check_admin_action(
+      &local_user_view,
+      Some(AdminPermission::HandleReports),
+      "TransferCommunity",
+      Some(data.community_id.0),
+      &data,
+      &mut context.pool(),
+    )
+    .await?;
   }
 
   // You have to re-do the community_moderator table, reordering it.
@@ -0,0 +1,42 @@
+use super::{check_wiki_mod_action, process_body, process_title};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::{
+  community::Community,
+  wiki::{WikiPage, WikiPageInsertForm, WikiPageRevision, WikiPageRevisionInsertForm},
+};
+use lemmy_db_views_community::api::{CreateWikiPage, WikiPageResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_wiki_slug};
+
+pub async fn create_wiki_page(
+  Json(data): Json<CreateWikiPage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WikiPageResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_wiki_mod_action(&local_user_view, &community, &context).await?;
+
+  is_valid_wiki_slug(&data.slug)?;
+  let title = process_title(&data.title, &context).await?;
+  let body = process_body(&data.body, &context).await?;
+
+  let ap_id = WikiPage::build_ap_id(&community.ap_id, &data.slug)?;
+  let form = WikiPageInsertForm {
+    edit_permission: data.edit_permission,
+    ..WikiPageInsertForm::new(community.id, data.slug, title, body, ap_id)
+  };
+  let wiki_page = WikiPage::create(&mut context.pool(), &form).await?;
+
+  let revision_form = WikiPageRevisionInsertForm::new(
+    wiki_page.id,
+    local_user_view.person.id,
+    wiki_page.title.clone(),
+    wiki_page.body.clone(),
+  );
+  WikiPageRevision::create(&mut context.pool(), &revision_form).await?;
+
+  Ok(Json(WikiPageResponse { wiki_page }))
+}
@@ -0,0 +1,30 @@
+use super::check_wiki_mod_action;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::{
+  community::Community,
+  wiki::{WikiPage, WikiPageUpdateForm},
+};
+use lemmy_db_views_community::api::{DeleteWikiPage, WikiPageResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_wiki_page(
+  Json(data): Json<DeleteWikiPage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WikiPageResponse>> {
+  let orig = WikiPage::read(&mut context.pool(), data.id).await?;
+  let community = Community::read(&mut context.pool(), orig.community_id).await?;
+  check_wiki_mod_action(&local_user_view, &community, &context).await?;
+
+  let form = WikiPageUpdateForm {
+    deleted: Some(data.deleted),
+    ..Default::default()
+  };
+  let wiki_page = WikiPage::update(&mut context.pool(), orig.id, &form).await?;
+
+  Ok(Json(WikiPageResponse { wiki_page }))
+}
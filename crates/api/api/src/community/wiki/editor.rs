@@ -0,0 +1,40 @@
+use super::check_wiki_mod_action;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::{community::Community, wiki::WikiEditor};
+use lemmy_db_views_community::api::{AddWikiEditor, ListWikiEditors, ListWikiEditorsResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn add_wiki_editor(
+  Json(data): Json<AddWikiEditor>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_wiki_mod_action(&local_user_view, &community, &context).await?;
+
+  if data.added {
+    WikiEditor::approve(&mut context.pool(), community.id, data.person_id).await?;
+  } else {
+    WikiEditor::remove(&mut context.pool(), community.id, data.person_id).await?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+pub async fn list_wiki_editors(
+  Query(data): Query<ListWikiEditors>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListWikiEditorsResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_wiki_mod_action(&local_user_view, &community, &context).await?;
+
+  let editors = WikiEditor::list(&mut context.pool(), community.id).await?;
+
+  Ok(Json(ListWikiEditorsResponse { editors }))
+}
@@ -0,0 +1,19 @@
+use super::read_wiki_community;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::wiki::WikiPage;
+use lemmy_db_views_community::api::{ListWikiPages, ListWikiPagesResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_wiki_pages(
+  Query(data): Query<ListWikiPages>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListWikiPagesResponse>> {
+  let community = read_wiki_community(data.community_id, &local_user_view, &context).await?;
+  let wiki_pages = WikiPage::list_for_community(&mut context.pool(), community.id).await?;
+
+  Ok(Json(ListWikiPagesResponse { wiki_pages }))
+}
@@ -0,0 +1,24 @@
+use super::read_wiki_community;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::wiki::{WikiPage, WikiPageRevision};
+use lemmy_db_views_community::api::{ListWikiPageRevisions, ListWikiPageRevisionsResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn list_wiki_page_revisions(
+  Query(data): Query<ListWikiPageRevisions>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListWikiPageRevisionsResponse>> {
+  let wiki_page = WikiPage::read(&mut context.pool(), data.id).await?;
+  if wiki_page.deleted {
+    Err(LemmyErrorType::NotFound)?
+  }
+  read_wiki_community(wiki_page.community_id, &local_user_view, &context).await?;
+  let revisions = WikiPageRevision::list_for_page(&mut context.pool(), wiki_page.id).await?;
+
+  Ok(Json(ListWikiPageRevisionsResponse { revisions }))
+}
@@ -0,0 +1,132 @@
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{
+    check_community_mod_action,
+    check_community_mod_permission,
+    check_community_user_action,
+    check_private_instance,
+    get_url_blocklist,
+    is_mod_or_admin_opt,
+    process_markdown,
+    slur_regex,
+  },
+};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{community::Community, wiki::WikiEditor},
+};
+use lemmy_db_schema_file::enums::{ModeratorPermission, WikiEditPermission};
+use lemmy_db_views_community::CommunityView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::SiteView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{
+    slurs::check_slurs,
+    validation::{is_valid_body_field, is_valid_post_title},
+  },
+};
+
+pub mod create;
+pub mod delete;
+pub mod editor;
+pub mod list;
+pub mod list_revisions;
+pub mod read;
+pub mod revert;
+pub mod update;
+
+/// Reads the community of a wiki, checking that the user is allowed to view it.
+async fn read_wiki_community(
+  community_id: CommunityId,
+  local_user_view: &Option<LocalUserView>,
+  context: &LemmyContext,
+) -> LemmyResult<Community> {
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  check_private_instance(local_user_view, &local_site)?;
+
+  let is_mod_or_admin = is_mod_or_admin_opt(
+    &mut context.pool(),
+    local_user_view.as_ref(),
+    Some(community_id),
+  )
+  .await
+  .is_ok();
+  let local_user = local_user_view.as_ref().map(|u| &u.local_user);
+  let community_view = CommunityView::read(
+    &mut context.pool(),
+    community_id,
+    local_user,
+    is_mod_or_admin,
+  )
+  .await?;
+  Ok(community_view.community)
+}
+
+/// Mods can edit every page, approved editors only the pages which are open to them.
+///
+/// Pages of remote communities are read-only, as they are owned by the other instance.
+async fn check_wiki_edit_allowed(
+  local_user_view: &LocalUserView,
+  community: &Community,
+  edit_permission: WikiEditPermission,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if !community.local {
+    Err(LemmyErrorType::NoWikiEditAllowed)?
+  }
+  let is_mod = check_community_mod_permission(
+    local_user_view,
+    community,
+    false,
+    Some(ModeratorPermission::EditWiki),
+    &mut context.pool(),
+  )
+  .await
+  .is_ok();
+  if is_mod {
+    return Ok(());
+  }
+
+  check_community_user_action(local_user_view, community, &mut context.pool()).await?;
+  let is_editor =
+    WikiEditor::is_approved(&mut context.pool(), community.id, local_user_view.person.id).await?;
+  if edit_permission != WikiEditPermission::ApprovedEditors || !is_editor {
+    Err(LemmyErrorType::NoWikiEditAllowed)?
+  }
+  Ok(())
+}
+
+/// Only mods can create and delete pages, and change who may edit them.
+async fn check_wiki_mod_action(
+  local_user_view: &LocalUserView,
+  community: &Community,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if !community.local {
+    Err(LemmyErrorType::NoWikiEditAllowed)?
+  }
+  check_community_mod_action(
+    local_user_view,
+    community,
+    false,
+    Some(ModeratorPermission::EditWiki),
+    &mut context.pool(),
+  )
+  .await
+}
+
+async fn process_title(title: &str, context: &LemmyContext) -> LemmyResult<String> {
+  let title = title.trim();
+  check_slurs(title, &slur_regex(context).await?)?;
+  is_valid_post_title(title)?;
+  Ok(title.to_string())
+}
+
+async fn process_body(body: &str, context: &LemmyContext) -> LemmyResult<String> {
+  let slur_regex = slur_regex(context).await?;
+  let url_blocklist = get_url_blocklist(context).await?;
+  let body = process_markdown(body, &slur_regex, &url_blocklist, context).await?;
+  is_valid_body_field(&body, true)?;
+  Ok(body)
+}
@@ -0,0 +1,19 @@
+use super::read_wiki_community;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::wiki::WikiPage;
+use lemmy_db_views_community::api::{GetWikiPage, WikiPageResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn get_wiki_page(
+  Query(data): Query<GetWikiPage>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<WikiPageResponse>> {
+  let community = read_wiki_community(data.community_id, &local_user_view, &context).await?;
+  let wiki_page = WikiPage::read_from_slug(&mut context.pool(), community.id, &data.slug).await?;
+
+  Ok(Json(WikiPageResponse { wiki_page }))
+}
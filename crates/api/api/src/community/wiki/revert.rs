@@ -0,0 +1,49 @@
+use super::check_wiki_edit_allowed;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::{
+  community::Community,
+  wiki::{WikiPage, WikiPageRevision, WikiPageRevisionInsertForm, WikiPageUpdateForm},
+};
+use lemmy_db_views_community::api::{RevertWikiPage, WikiPageResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn revert_wiki_page(
+  Json(data): Json<RevertWikiPage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WikiPageResponse>> {
+  let orig = WikiPage::read(&mut context.pool(), data.id).await?;
+  if orig.deleted {
+    Err(LemmyErrorType::Deleted)?
+  }
+  let revision = WikiPageRevision::read(&mut context.pool(), data.revision_id).await?;
+  if revision.wiki_page_id != orig.id {
+    Err(LemmyErrorType::NotFound)?
+  }
+  let community = Community::read(&mut context.pool(), orig.community_id).await?;
+  check_wiki_edit_allowed(&local_user_view, &community, orig.edit_permission, &context).await?;
+
+  let form = WikiPageUpdateForm {
+    title: Some(revision.title.clone()),
+    body: Some(revision.body.clone()),
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  let wiki_page = WikiPage::update(&mut context.pool(), orig.id, &form).await?;
+
+  // The revert is saved as a new revision, so that the history stays complete
+  let revision_form = WikiPageRevisionInsertForm::new(
+    wiki_page.id,
+    local_user_view.person.id,
+    revision.title,
+    revision.body,
+  );
+  WikiPageRevision::create(&mut context.pool(), &revision_form).await?;
+
+  Ok(Json(WikiPageResponse { wiki_page }))
+}
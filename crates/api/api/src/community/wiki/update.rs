@@ -0,0 +1,67 @@
+use super::{check_wiki_edit_allowed, check_wiki_mod_action, process_body, process_title};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{context::LemmyContext, utils::slur_regex};
+use lemmy_db_schema::source::{
+  community::Community,
+  wiki::{WikiPage, WikiPageRevision, WikiPageRevisionInsertForm, WikiPageUpdateForm},
+};
+use lemmy_db_views_community::api::{EditWikiPage, WikiPageResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::summary_length_check},
+};
+
+pub async fn edit_wiki_page(
+  Json(data): Json<EditWikiPage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WikiPageResponse>> {
+  let orig = WikiPage::read(&mut context.pool(), data.id).await?;
+  if orig.deleted {
+    Err(LemmyErrorType::Deleted)?
+  }
+  let community = Community::read(&mut context.pool(), orig.community_id).await?;
+  check_wiki_edit_allowed(&local_user_view, &community, orig.edit_permission, &context).await?;
+  if data.edit_permission.is_some() {
+    check_wiki_mod_action(&local_user_view, &community, &context).await?;
+  }
+
+  let title = match &data.title {
+    Some(title) => process_title(title, &context).await?,
+    None => orig.title,
+  };
+  let body = match &data.body {
+    Some(body) => process_body(body, &context).await?,
+    None => orig.body,
+  };
+  if let Some(summary) = &data.summary {
+    summary_length_check(summary)?;
+    check_slurs(summary, &slur_regex(&context).await?)?;
+  }
+
+  let form = WikiPageUpdateForm {
+    title: Some(title),
+    body: Some(body),
+    edit_permission: data.edit_permission,
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  let wiki_page = WikiPage::update(&mut context.pool(), orig.id, &form).await?;
+
+  let revision_form = WikiPageRevisionInsertForm {
+    summary: data.summary,
+    ..WikiPageRevisionInsertForm::new(
+      wiki_page.id,
+      local_user_view.person.id,
+      wiki_page.title.clone(),
+      wiki_page.body.clone(),
+    )
+  };
+  WikiPageRevision::create(&mut context.pool(), &revision_form).await?;
+
+  Ok(Json(WikiPageResponse { wiki_page }))
+}
@@ -1,7 +1,7 @@
 use crate::federation::ApubPerson;
 use activitypub_federation::{
   config::Data,
-  fetch::webfinger::webfinger_resolve_actor,
+  fetch::{object_id::ObjectId, webfinger::webfinger_resolve_actor},
   traits::{Actor, Object},
 };
 use diesel::NotFound;
@@ -16,15 +16,16 @@ use lemmy_db_schema::{
 use lemmy_db_schema_file::PersonId;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
+use url::Url;
 
 /// Resolve actor identifier like `!news@example.com` to user or community object.
 ///
-/// In case the requesting user is logged in and the object was not found locally, it is attempted
-/// to fetch via webfinger from the original instance.
+/// In case `fetch_remote` is set (usually when the requesting user is logged in) and the object
+/// was not found locally, it is attempted to fetch via webfinger from the original instance.
 async fn resolve_ap_identifier<ActorType, DbActor>(
   identifier: &str,
   context: &Data<LemmyContext>,
-  local_user_view: &Option<LocalUserView>,
+  fetch_remote: bool,
   include_deleted: bool,
 ) -> LemmyResult<ActorType>
 where
@@ -50,7 +51,7 @@ where
       .flatten();
     if let Some(actor) = actor {
       Ok(actor.into())
-    } else if local_user_view.is_some() {
+    } else if fetch_remote {
       // Fetch the actor from its home instance using webfinger
       let actor: ActorType = webfinger_resolve_actor(&identifier.to_lowercase(), context).await?;
       Ok(actor)
@@ -78,9 +79,14 @@ pub(crate) async fn resolve_community_identifier(
 ) -> LemmyResult<Option<CommunityId>> {
   Ok(if let Some(name) = name {
     Some(
-      resolve_ap_identifier::<ApubCommunity, Community>(name, context, local_user_view, true)
-        .await?
-        .id,
+      resolve_ap_identifier::<ApubCommunity, Community>(
+        name,
+        context,
+        local_user_view.is_some(),
+        true,
+      )
+      .await?
+      .id,
     )
   } else {
     id
@@ -96,7 +102,7 @@ pub(crate) async fn resolve_person_identifier(
   Ok(
     if let Some(name) = username {
       Some(
-        resolve_ap_identifier::<ApubPerson, Person>(name, context, local_user_view, true)
+        resolve_ap_identifier::<ApubPerson, Person>(name, context, local_user_view.is_some(), true)
           .await?
           .id,
       )
@@ -118,7 +124,7 @@ pub(crate) async fn resolve_multi_community_identifier(
       resolve_ap_identifier::<ApubMultiCommunity, MultiCommunity>(
         name,
         context,
-        local_user_view,
+        local_user_view.is_some(),
         true,
       )
       .await?
@@ -128,3 +134,29 @@ pub(crate) async fn resolve_multi_community_identifier(
     id
   })
 }
+
+/// Resolve an actor from an import file, which is either given by its url or by an identifier like
+/// `news@example.com`. Remote actors are fetched if they are not known yet.
+pub(crate) async fn resolve_import_actor<ActorType, DbActor>(
+  identifier: &str,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<ActorType>
+where
+  ActorType: Object<DataType = LemmyContext, Error = LemmyError>
+    + Object
+    + Actor
+    + From<DbActor>
+    + Send
+    + Sync
+    + 'static,
+  for<'de2> <ActorType as Object>::Kind: serde::Deserialize<'de2>,
+  DbActor: ApubActor + Send + 'static,
+{
+  if let Ok(url) = Url::parse(identifier)
+    && (url.scheme() == "https" || url.scheme() == "http")
+  {
+    return ObjectId::<ActorType>::from(url).dereference(context).await;
+  }
+  let identifier = identifier.trim_start_matches(['!', '@']);
+  resolve_ap_identifier::<ActorType, DbActor>(identifier, context, true, false).await
+}
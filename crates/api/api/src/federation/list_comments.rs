@@ -9,11 +9,17 @@ use activitypub_federation::config::Data;
 use actix_web::web::{Json, Query};
 use lemmy_api_utils::{context::LemmyContext, utils::check_private_instance};
 use lemmy_db_schema::source::comment::Comment;
-use lemmy_db_views_comment::{CommentSlimView, CommentView, api::GetComments, impls::CommentQuery};
+use lemmy_db_views_comment::{
+  CommentSlimView,
+  CommentTreeNode,
+  CommentView,
+  api::{GetCommentTree, GetComments},
+  impls::{CommentQuery, CommentTreeQuery},
+};
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::{pagination::PagedResponse, traits::Crud};
-use lemmy_utils::error::LemmyResult;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
 
 /// A common fetcher for both the CommentView, and CommentSlimView.
 async fn list_comments_common(
@@ -70,6 +76,8 @@ async fn list_comments_common(
     parent_path,
     post_id,
     local_user,
+    reply_to_paths: None,
+    quarantined_only: data.quarantined_only,
     page_cursor: data.page_cursor,
     limit,
   }
@@ -107,3 +115,45 @@ pub async fn list_comments_slim(
 
   Ok(Json(res))
 }
+
+pub async fn list_comment_tree(
+  Query(data): Query<GetCommentTree>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<PagedResponse<CommentTreeNode>>> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  let local_site = &site_view.local_site;
+
+  check_private_instance(&local_user_view, local_site)?;
+
+  // If a parent_id is given, the tree starts at its replies
+  let (post_id, parent_path) = if let Some(parent_id) = data.parent_id {
+    let parent = Comment::read(&mut context.pool(), parent_id).await?;
+    (parent.post_id, Some(parent.path))
+  } else if let Some(post_id) = data.post_id {
+    (post_id, None)
+  } else {
+    Err(LemmyErrorType::NotFound)?
+  };
+
+  let local_user = local_user_view.as_ref().map(|u| &u.local_user);
+  let sort = Some(comment_sort_type_with_default(
+    data.sort, local_user, local_site,
+  ));
+  let limit = Some(fetch_limit_with_default(data.limit, local_user, local_site));
+
+  let tree = CommentTreeQuery {
+    post_id,
+    parent_path,
+    sort,
+    local_user,
+    max_depth: data.max_depth,
+    max_breadth: data.max_breadth,
+    page_cursor: data.page_cursor,
+    limit,
+  }
+  .list(&site_view.site, &mut context.pool())
+  .await?;
+
+  Ok(Json(tree))
+}
@@ -51,6 +51,8 @@ pub async fn list_posts(
   let show_nsfw = data.show_nsfw;
   let hide_media = data.hide_media;
   let no_comments_only = data.no_comments_only;
+  let unanswered_only = data.unanswered_only;
+  let quarantined_only = data.quarantined_only;
   let page_cursor = data.page_cursor;
 
   let local_user = local_user_view.as_ref().map(|u| &u.local_user);
@@ -90,6 +92,9 @@ pub async fn list_posts(
     show_nsfw,
     hide_media,
     no_comments_only,
+    unanswered_only,
+    quarantined_only,
+    pending_approval_only: None,
     keyword_blocks,
     page_cursor,
   }
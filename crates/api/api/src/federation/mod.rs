@@ -15,6 +15,7 @@ pub mod read_person;
 pub mod resolve_object;
 pub mod search;
 pub mod user_settings_backup;
+pub mod user_settings_import;
 
 /// Returns default listing type, depending if the query is for frontpage or community.
 fn listing_type_with_default(
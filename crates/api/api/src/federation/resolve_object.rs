@@ -76,9 +76,14 @@ pub(super) async fn resolve_object_internal(
     Left(Right(Right(c))) => {
       Community(CommunityView::read(pool, c.id, local_user.as_ref(), is_admin).await?)
     }
-    Right(multi) => {
+    Right(Left(multi)) => {
       MultiCommunity(MultiCommunityView::read(pool, multi.id, my_person_id_opt).await?)
     }
+    Right(Right(w)) => {
+      // Make sure that the user is allowed to view the community
+      CommunityView::read(pool, w.community_id, local_user.as_ref(), is_admin).await?;
+      WikiPage(w.0)
+    }
   })
 }
 
@@ -1,45 +1,23 @@
-use activitypub_federation::{config::Data, fetch::object_id::ObjectId, traits::Object};
+use crate::federation::user_settings_import::start_import;
+use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use futures::{StreamExt, future::try_join_all};
-use itertools::Itertools;
 use lemmy_api_utils::{context::LemmyContext, utils::check_local_user_valid};
-use lemmy_apub_objects::objects::{
-  comment::ApubComment,
-  community::ApubCommunity,
-  person::ApubPerson,
-  post::ApubPost,
+use lemmy_db_schema::source::{
+  actor_language::LocalUserLanguage,
+  keyword_block::LocalUserKeywordBlock,
+  language::Language,
+  local_user::{LocalUser, LocalUserUpdateForm},
+  person::{Person, PersonUpdateForm},
 };
-use lemmy_db_schema::{
-  source::{
-    actor_language::LocalUserLanguage,
-    comment::{CommentActions, CommentSavedForm},
-    community::{CommunityActions, CommunityBlockForm, CommunityFollowerForm},
-    instance::{Instance, InstanceActions, InstanceCommunitiesBlockForm, InstancePersonsBlockForm},
-    keyword_block::LocalUserKeywordBlock,
-    language::Language,
-    local_user::{LocalUser, LocalUserUpdateForm},
-    person::{Person, PersonActions, PersonBlockForm, PersonUpdateForm},
-    post::{PostActions, PostSavedForm},
-  },
-  traits::{Blockable, Followable, Saveable},
-};
-use lemmy_db_schema_file::enums::CommunityFollowerState;
+use lemmy_db_schema_file::enums::SettingsImportFormat;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::{
-  api::{SuccessResponse, UserSettingsBackup},
+  api::{UserSettingsBackup, UserSettingsImportResponse},
   impls::user_backup_list_to_user_settings_backup,
 };
 use lemmy_diesel_utils::traits::Crud;
-use lemmy_utils::{
-  error::LemmyResult,
-  spawn_try_task,
-  utils::validation::{check_api_elements_count, check_blocking_keywords_are_valid},
-};
-use serde::Deserialize;
-use std::{collections::HashMap, future::Future};
-use tracing::info;
-
-const PARALLELISM: usize = 10;
+use lemmy_utils::{error::LemmyResult, utils::validation::check_blocking_keywords_are_valid};
+use std::collections::HashMap;
 
 pub async fn export_settings(
   local_user_view: LocalUserView,
@@ -55,7 +33,7 @@ pub async fn import_settings(
   Json(data): Json<UserSettingsBackup>,
   local_user_view: LocalUserView,
   context: Data<LemmyContext>,
-) -> LemmyResult<Json<SuccessResponse>> {
+) -> LemmyResult<Json<UserSettingsImportResponse>> {
   check_local_user_valid(&local_user_view)?;
   let person_form = PersonUpdateForm {
     display_name: data.display_name.clone().map(Some),
@@ -124,173 +102,15 @@ pub async fn import_settings(
     )
     .await?;
   }
-  let url_count = data.followed_communities.len()
-    + data.blocked_communities.len()
-    + data.blocked_users.len()
-    + data.blocked_instances_communities.len()
-    + data.blocked_instances_persons.len()
-    + data.saved_posts.len()
-    + data.saved_comments.len();
-  check_api_elements_count(url_count)?;
-
-  spawn_try_task(async move {
-    let person_id = local_user_view.person.id;
-
-    info!(
-      "Starting settings import for {}",
-      local_user_view.person.name
-    );
-
-    let failed_followed_communities = fetch_and_import(
-      data
-        .followed_communities
-        .clone()
-        .into_iter()
-        .map(Into::into)
-        .collect::<Vec<ObjectId<ApubCommunity>>>(),
-      &context,
-      |(followed, context)| async move {
-        let community = followed.dereference(&context).await?;
-        let form =
-          CommunityFollowerForm::new(community.id, person_id, CommunityFollowerState::Pending);
-        CommunityActions::follow(&mut context.pool(), &form).await?;
-        LemmyResult::Ok(())
-      },
-    )
-    .await?;
-
-    let failed_saved_posts = fetch_and_import(
-      data
-        .saved_posts
-        .clone()
-        .into_iter()
-        .map(Into::into)
-        .collect::<Vec<ObjectId<ApubPost>>>(),
-      &context,
-      |(saved, context)| async move {
-        let post = saved.dereference(&context).await?;
-        let form = PostSavedForm::new(post.id, person_id);
-        PostActions::save(&mut context.pool(), &form).await?;
-        LemmyResult::Ok(())
-      },
-    )
-    .await?;
-
-    let failed_saved_comments = fetch_and_import(
-      data
-        .saved_comments
-        .clone()
-        .into_iter()
-        .map(Into::into)
-        .collect::<Vec<ObjectId<ApubComment>>>(),
-      &context,
-      |(saved, context)| async move {
-        let comment = saved.dereference(&context).await?;
-        let form = CommentSavedForm::new(person_id, comment.id);
-        CommentActions::save(&mut context.pool(), &form).await?;
-        LemmyResult::Ok(())
-      },
-    )
-    .await?;
-
-    let failed_community_blocks = fetch_and_import(
-      data
-        .blocked_communities
-        .clone()
-        .into_iter()
-        .map(Into::into)
-        .collect::<Vec<ObjectId<ApubCommunity>>>(),
-      &context,
-      |(blocked, context)| async move {
-        let community = blocked.dereference(&context).await?;
-        let form = CommunityBlockForm::new(community.id, person_id);
-        CommunityActions::block(&mut context.pool(), &form).await?;
-        LemmyResult::Ok(())
-      },
-    )
-    .await?;
 
-    let failed_user_blocks = fetch_and_import(
-      data
-        .blocked_users
-        .clone()
-        .into_iter()
-        .map(Into::into)
-        .collect::<Vec<ObjectId<ApubPerson>>>(),
-      &context,
-      |(blocked, context)| async move {
-        let target = blocked.dereference(&context).await?;
-        let form = PersonBlockForm::new(person_id, target.id);
-        PersonActions::block(&mut context.pool(), &form).await?;
-        LemmyResult::Ok(())
-      },
-    )
-    .await?;
-
-    try_join_all(
-      data
-        .blocked_instances_communities
-        .iter()
-        .map(|domain| async {
-          let instance = Instance::read_or_create(&mut context.pool(), domain).await?;
-          let form = InstanceCommunitiesBlockForm::new(person_id, instance.id);
-          InstanceActions::block_communities(&mut context.pool(), &form).await?;
-          LemmyResult::Ok(())
-        }),
-    )
-    .await?;
-
-    try_join_all(data.blocked_instances_persons.iter().map(|domain| async {
-      let instance = Instance::read_or_create(&mut context.pool(), domain).await?;
-      let form = InstancePersonsBlockForm::new(person_id, instance.id);
-      InstanceActions::block_persons(&mut context.pool(), &form).await?;
-      LemmyResult::Ok(())
-    }))
-    .await?;
-
-    info!(
-      "Settings import completed for {}, the following items failed: {failed_followed_communities}, {failed_saved_posts}, {failed_saved_comments}, {failed_community_blocks}, {failed_user_blocks}",
-      local_user_view.person.name
-    );
-
-    Ok(())
-  });
-
-  Ok(Json(Default::default()))
-}
-
-async fn fetch_and_import<Kind, Fut>(
-  objects: Vec<ObjectId<Kind>>,
-  context: &Data<LemmyContext>,
-  import_fn: impl FnMut((ObjectId<Kind>, Data<LemmyContext>)) -> Fut,
-) -> LemmyResult<String>
-where
-  Kind: Object + Send + Sync + 'static,
-  for<'de2> <Kind as Object>::Kind: Deserialize<'de2>,
-  Fut: Future<Output = LemmyResult<()>>,
-{
-  let mut failed_items = vec![];
-  futures::stream::iter(
-    objects
-      .clone()
-      .into_iter()
-      // need to reset outgoing request count to avoid running into limit
-      .map(|s| (s, context.reset_request_count()))
-      .map(import_fn),
+  let import = start_import(
+    data.into(),
+    SettingsImportFormat::Lemmy,
+    local_user_view,
+    context,
   )
-  .buffer_unordered(PARALLELISM)
-  .collect::<Vec<_>>()
-  .await
-  .into_iter()
-  .enumerate()
-  .for_each(|(i, r): (usize, LemmyResult<()>)| {
-    if r.is_err()
-      && let Some(object) = objects.get(i)
-    {
-      failed_items.push(object.inner().clone());
-    }
-  });
-  Ok(failed_items.into_iter().join(","))
+  .await?;
+  Ok(Json(UserSettingsImportResponse { import }))
 }
 
 #[cfg(test)]
@@ -305,10 +125,12 @@ pub(crate) mod tests {
     source::{
       community::{Community, CommunityActions, CommunityFollowerForm, CommunityInsertForm},
       person::Person,
+      user_settings_import::UserSettingsImport,
     },
     test_data::TestData,
     traits::Followable,
   };
+  use lemmy_db_schema_file::enums::{CommunityFollowerState, SettingsImportStatus};
   use lemmy_db_views_community_follower::CommunityFollowerView;
   use lemmy_db_views_local_user::LocalUserView;
   use lemmy_diesel_utils::traits::Crud;
@@ -364,6 +186,12 @@ pub(crate) mod tests {
     // wait for background task to finish
     sleep(Duration::from_millis(1000)).await;
 
+    let imports = UserSettingsImport::list(pool, import_user.local_user.id).await?;
+    assert_eq!(1, imports.len());
+    assert_eq!(SettingsImportStatus::Complete, imports[0].status);
+    assert_eq!(1, imports[0].processed_items);
+    assert!(imports[0].failed_items.is_empty());
+
     let import_user_updated = LocalUserView::read(pool, import_user.local_user.id).await?;
 
     assert_eq!(
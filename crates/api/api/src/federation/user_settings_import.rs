@@ -0,0 +1,536 @@
+use crate::federation::{fetcher::resolve_import_actor, user_settings_backup::import_settings};
+use activitypub_federation::{config::Data, fetch::object_id::ObjectId};
+use actix_web::web::Json;
+use futures::StreamExt;
+use itertools::Itertools;
+use lemmy_api_utils::{context::LemmyContext, utils::check_local_user_valid};
+use lemmy_apub_objects::objects::{
+  comment::ApubComment,
+  community::ApubCommunity,
+  person::ApubPerson,
+  post::ApubPost,
+};
+use lemmy_db_schema::{
+  newtypes::{LocalUserId, UserSettingsImportId},
+  source::{
+    comment::{CommentActions, CommentSavedForm},
+    community::{Community, CommunityActions, CommunityBlockForm, CommunityFollowerForm},
+    instance::{Instance, InstanceActions, InstanceCommunitiesBlockForm, InstancePersonsBlockForm},
+    keyword_block::LocalUserKeywordBlock,
+    person::{Person, PersonActions, PersonBlockForm},
+    post::{PostActions, PostSavedForm},
+    user_settings_import::UserSettingsImport,
+  },
+  traits::{Blockable, Followable, Saveable},
+};
+use lemmy_db_schema_file::{
+  PersonId,
+  enums::{CommunityFollowerState, SettingsImportFormat},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{
+  ImportSettingsFile,
+  ListUserSettingsImportsResponse,
+  UserSettingsBackup,
+  UserSettingsImportResponse,
+};
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  spawn_try_task,
+  utils::validation::{check_api_elements_count, check_blocking_keywords_are_valid},
+};
+use serde::Deserialize;
+use std::{fmt::Display, future::Future, pin::pin};
+use tracing::info;
+use url::Url;
+
+const PARALLELISM: usize = 10;
+
+/// The progress is written to the database after this many items were processed.
+const PROGRESS_BATCH_SIZE: usize = 50;
+
+/// Follows and blocks which are imported in the background. Communities and users are given either
+/// by url or by `name@domain`. Keywords are only part of Piefed exports.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct ImportItems {
+  followed_communities: Vec<String>,
+  blocked_communities: Vec<String>,
+  blocked_users: Vec<String>,
+  blocked_instances_communities: Vec<String>,
+  blocked_instances_persons: Vec<String>,
+  saved_posts: Vec<Url>,
+  saved_comments: Vec<Url>,
+  blocked_keywords: Vec<String>,
+}
+
+impl ImportItems {
+  fn len(&self) -> usize {
+    self.followed_communities.len()
+      + self.blocked_communities.len()
+      + self.blocked_users.len()
+      + self.blocked_instances_communities.len()
+      + self.blocked_instances_persons.len()
+      + self.saved_posts.len()
+      + self.saved_comments.len()
+      + self.blocked_keywords.len()
+  }
+
+  fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+}
+
+impl From<UserSettingsBackup> for ImportItems {
+  fn from(backup: UserSettingsBackup) -> Self {
+    let to_strings = |urls: Vec<Url>| urls.into_iter().map(String::from).collect();
+    ImportItems {
+      followed_communities: to_strings(backup.followed_communities),
+      blocked_communities: to_strings(backup.blocked_communities),
+      blocked_users: to_strings(backup.blocked_users),
+      blocked_instances_communities: backup.blocked_instances_communities,
+      blocked_instances_persons: backup.blocked_instances_persons,
+      saved_posts: backup.saved_posts,
+      saved_comments: backup.saved_comments,
+      // Restored along with the other settings
+      blocked_keywords: vec![],
+    }
+  }
+}
+
+/// Magazine subscriptions and blocks exported from mbin or kbin.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct MbinExport {
+  /// Domain of the exporting instance. It is added to local magazines and users, which are
+  /// listed without domain.
+  instance: Option<String>,
+  #[serde(alias = "subscribed_magazines")]
+  magazine_subscriptions: Vec<String>,
+  #[serde(alias = "magazine_blocks")]
+  blocked_magazines: Vec<String>,
+  #[serde(alias = "user_blocks")]
+  blocked_users: Vec<String>,
+  #[serde(alias = "instance_blocks")]
+  blocked_instances: Vec<String>,
+}
+
+impl From<MbinExport> for ImportItems {
+  fn from(export: MbinExport) -> Self {
+    let instance = export.instance.as_deref();
+    let qualify = |names: Vec<String>| {
+      names
+        .iter()
+        .map(|name| qualify_actor_name(name, instance))
+        .collect()
+    };
+    ImportItems {
+      followed_communities: qualify(export.magazine_subscriptions),
+      blocked_communities: qualify(export.blocked_magazines),
+      blocked_users: qualify(export.blocked_users),
+      blocked_instances_communities: export.blocked_instances.clone(),
+      blocked_instances_persons: export.blocked_instances,
+      ..Default::default()
+    }
+  }
+}
+
+/// Settings export of Piefed. It is similar to the Lemmy backup, but uses a single list of blocked
+/// instances and contains keyword filters.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct PiefedExport {
+  followed_communities: Vec<String>,
+  blocked_communities: Vec<String>,
+  blocked_users: Vec<String>,
+  #[serde(alias = "blocked_domains")]
+  blocked_instances: Vec<String>,
+  #[serde(alias = "blocking_keywords")]
+  blocked_keywords: Vec<String>,
+}
+
+impl From<PiefedExport> for ImportItems {
+  fn from(export: PiefedExport) -> Self {
+    ImportItems {
+      followed_communities: export.followed_communities,
+      blocked_communities: export.blocked_communities,
+      blocked_users: export.blocked_users,
+      blocked_instances_communities: export.blocked_instances.clone(),
+      blocked_instances_persons: export.blocked_instances,
+      blocked_keywords: export.blocked_keywords,
+      ..Default::default()
+    }
+  }
+}
+
+/// Imports follows and blocks from a file exported by Lemmy or other Fediverse software. Mastodon
+/// follows are imported as community follows, as Lemmy users can't be followed.
+pub async fn import_settings_file(
+  Json(data): Json<ImportSettingsFile>,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<UserSettingsImportResponse>> {
+  check_local_user_valid(&local_user_view)?;
+  let items: ImportItems = match data.format {
+    SettingsImportFormat::Lemmy => {
+      // Also restores the profile and settings
+      let backup: UserSettingsBackup =
+        serde_json::from_str(&data.data).with_lemmy_type(LemmyErrorType::InvalidImportFile)?;
+      return import_settings(Json(backup), local_user_view, context).await;
+    }
+    SettingsImportFormat::MastodonFollows => ImportItems {
+      followed_communities: parse_mastodon_csv(&data.data),
+      ..Default::default()
+    },
+    SettingsImportFormat::MastodonBlocks => ImportItems {
+      blocked_users: parse_mastodon_csv(&data.data),
+      ..Default::default()
+    },
+    SettingsImportFormat::MastodonDomainBlocks => {
+      let domains = parse_mastodon_csv(&data.data);
+      ImportItems {
+        blocked_instances_communities: domains.clone(),
+        blocked_instances_persons: domains,
+        ..Default::default()
+      }
+    }
+    SettingsImportFormat::Mbin => serde_json::from_str::<MbinExport>(&data.data)
+      .with_lemmy_type(LemmyErrorType::InvalidImportFile)?
+      .into(),
+    SettingsImportFormat::Piefed => serde_json::from_str::<PiefedExport>(&data.data)
+      .with_lemmy_type(LemmyErrorType::InvalidImportFile)?
+      .into(),
+  };
+  // Most likely the wrong format was selected
+  if items.is_empty() {
+    Err(LemmyErrorType::InvalidImportFile)?
+  }
+
+  let import = start_import(items, data.format, local_user_view, context).await?;
+  Ok(Json(UserSettingsImportResponse { import }))
+}
+
+pub async fn list_settings_imports(
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<ListUserSettingsImportsResponse>> {
+  let imports =
+    UserSettingsImport::list(&mut context.pool(), local_user_view.local_user.id).await?;
+  Ok(Json(ListUserSettingsImportsResponse { imports }))
+}
+
+/// Creates an import job and runs it in the background. Its progress and the items which failed
+/// can be polled with [list_settings_imports].
+pub(crate) async fn start_import(
+  items: ImportItems,
+  format: SettingsImportFormat,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<UserSettingsImport> {
+  let total_items = items.len();
+  check_api_elements_count(total_items)?;
+  let import = UserSettingsImport::create(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    format,
+    total_items.try_into()?,
+  )
+  .await?;
+  let import_id = import.id;
+
+  spawn_try_task(async move {
+    let name = local_user_view.person.name;
+    info!("Starting settings import for {name}");
+    let user_ids = (local_user_view.local_user.id, local_user_view.person.id);
+    match run_import(items, import_id, user_ids, &context).await {
+      Ok(()) => {
+        let import = UserSettingsImport::complete(&mut context.pool(), import_id).await?;
+        info!(
+          "Settings import completed for {name}, {} items failed",
+          import.failed_items.len()
+        );
+        Ok(())
+      }
+      Err(e) => {
+        UserSettingsImport::fail(&mut context.pool(), import_id).await?;
+        Err(e)
+      }
+    }
+  });
+
+  Ok(import)
+}
+
+async fn run_import(
+  items: ImportItems,
+  import_id: UserSettingsImportId,
+  (local_user_id, person_id): (LocalUserId, PersonId),
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  import_batches(
+    items.followed_communities,
+    import_id,
+    context,
+    |(followed, context)| async move {
+      let community = resolve_import_actor::<ApubCommunity, Community>(&followed, &context).await?;
+      let form =
+        CommunityFollowerForm::new(community.id, person_id, CommunityFollowerState::Pending);
+      CommunityActions::follow(&mut context.pool(), &form).await?;
+      LemmyResult::Ok(())
+    },
+  )
+  .await?;
+
+  import_batches(
+    items.saved_posts,
+    import_id,
+    context,
+    |(saved, context)| async move {
+      let post = ObjectId::<ApubPost>::from(saved)
+        .dereference(&context)
+        .await?;
+      let form = PostSavedForm::new(post.id, person_id);
+      PostActions::save(&mut context.pool(), &form).await?;
+      LemmyResult::Ok(())
+    },
+  )
+  .await?;
+
+  import_batches(
+    items.saved_comments,
+    import_id,
+    context,
+    |(saved, context)| async move {
+      let comment = ObjectId::<ApubComment>::from(saved)
+        .dereference(&context)
+        .await?;
+      let form = CommentSavedForm::new(person_id, comment.id);
+      CommentActions::save(&mut context.pool(), &form).await?;
+      LemmyResult::Ok(())
+    },
+  )
+  .await?;
+
+  import_batches(
+    items.blocked_communities,
+    import_id,
+    context,
+    |(blocked, context)| async move {
+      let community = resolve_import_actor::<ApubCommunity, Community>(&blocked, &context).await?;
+      let form = CommunityBlockForm::new(community.id, person_id);
+      CommunityActions::block(&mut context.pool(), &form).await?;
+      LemmyResult::Ok(())
+    },
+  )
+  .await?;
+
+  import_batches(
+    items.blocked_users,
+    import_id,
+    context,
+    |(blocked, context)| async move {
+      let target = resolve_import_actor::<ApubPerson, Person>(&blocked, &context).await?;
+      let form = PersonBlockForm::new(person_id, target.id);
+      PersonActions::block(&mut context.pool(), &form).await?;
+      LemmyResult::Ok(())
+    },
+  )
+  .await?;
+
+  import_batches(
+    items.blocked_instances_communities,
+    import_id,
+    context,
+    |(domain, context)| async move {
+      let instance = Instance::read_or_create(&mut context.pool(), &domain).await?;
+      let form = InstanceCommunitiesBlockForm::new(person_id, instance.id);
+      InstanceActions::block_communities(&mut context.pool(), &form).await?;
+      LemmyResult::Ok(())
+    },
+  )
+  .await?;
+
+  import_batches(
+    items.blocked_instances_persons,
+    import_id,
+    context,
+    |(domain, context)| async move {
+      let instance = Instance::read_or_create(&mut context.pool(), &domain).await?;
+      let form = InstancePersonsBlockForm::new(person_id, instance.id);
+      InstanceActions::block_persons(&mut context.pool(), &form).await?;
+      LemmyResult::Ok(())
+    },
+  )
+  .await?;
+
+  // The keywords are validated together, so either all of them fail or none
+  if !items.blocked_keywords.is_empty() {
+    let processed = items.blocked_keywords.len().try_into()?;
+    let failed = match add_keyword_blocks(&items.blocked_keywords, local_user_id, context).await {
+      Ok(()) => vec![],
+      Err(_) => items.blocked_keywords,
+    };
+    UserSettingsImport::add_progress(&mut context.pool(), import_id, processed, failed).await?;
+  }
+
+  Ok(())
+}
+
+/// Imports the items in parallel, and stores the progress along with the items which failed after
+/// every batch.
+async fn import_batches<Item, Fut>(
+  items: Vec<Item>,
+  import_id: UserSettingsImportId,
+  context: &Data<LemmyContext>,
+  mut import_fn: impl FnMut((Item, Data<LemmyContext>)) -> Fut,
+) -> LemmyResult<()>
+where
+  Item: Display,
+  Fut: Future<Output = LemmyResult<()>>,
+{
+  let mut batches = pin!(
+    futures::stream::iter(items)
+      .map(|item| {
+        let name = item.to_string();
+        // need to reset outgoing request count to avoid running into limit
+        let import = import_fn((item, context.reset_request_count()));
+        async move { (name, import.await) }
+      })
+      .buffer_unordered(PARALLELISM)
+      .chunks(PROGRESS_BATCH_SIZE)
+  );
+  while let Some(batch) = batches.next().await {
+    let processed = batch.len().try_into()?;
+    let failed = batch
+      .into_iter()
+      .filter(|(_, result)| result.is_err())
+      .map(|(name, _)| name)
+      .collect();
+    UserSettingsImport::add_progress(&mut context.pool(), import_id, processed, failed).await?;
+  }
+  Ok(())
+}
+
+/// Adds the keywords to the existing keyword blocks of the user.
+async fn add_keyword_blocks(
+  keywords: &[String],
+  local_user_id: LocalUserId,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if keywords.is_empty() {
+    return Ok(());
+  }
+  let existing = LocalUserKeywordBlock::read(&mut context.pool(), local_user_id).await?;
+  let blocking_keywords = existing
+    .into_iter()
+    .chain(keywords.iter().map(|k| k.trim().to_string()))
+    .unique()
+    .collect();
+  check_blocking_keywords_are_valid(&blocking_keywords)?;
+  LocalUserKeywordBlock::update(&mut context.pool(), blocking_keywords, local_user_id).await?;
+  Ok(())
+}
+
+/// Reads the account addresses or domains from the first column of a Mastodon csv export, skipping
+/// the header line.
+fn parse_mastodon_csv(data: &str) -> Vec<String> {
+  data
+    .lines()
+    .filter_map(|line| line.split(',').next())
+    .map(|column| column.trim().trim_start_matches('@'))
+    // Headers are `Account address` or `#domain`
+    .filter(|column| !column.is_empty() && !column.contains(' ') && !column.starts_with('#'))
+    .unique()
+    .map(ToString::to_string)
+    .collect()
+}
+
+/// Adds the domain of the exporting instance to names of local actors, so that they can be
+/// resolved with webfinger. Urls are left unchanged.
+fn qualify_actor_name(name: &str, instance: Option<&str>) -> String {
+  let name = name.trim();
+  if name.contains("://") {
+    return name.to_string();
+  }
+  let name = name.trim_start_matches(['!', '@']);
+  match instance {
+    Some(instance) if !name.contains('@') => format!("{name}@{instance}"),
+    _ => name.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_parse_mastodon_csv() {
+    let follows = "Account address,Show boosts,Notify on new posts,Languages\n\
+      technology@lemmy.world,true,false,\n\
+      @rust@programming.dev,true,false,en\n\
+      \n\
+      technology@lemmy.world,true,false,\n";
+    assert_eq!(
+      vec!["technology@lemmy.world", "rust@programming.dev"],
+      parse_mastodon_csv(follows)
+    );
+
+    let domain_blocks = "#domain\nspam.example\r\nbad.example\n";
+    assert_eq!(
+      vec!["spam.example", "bad.example"],
+      parse_mastodon_csv(domain_blocks)
+    );
+  }
+
+  #[test]
+  fn test_mbin_export() -> LemmyResult<()> {
+    let export: MbinExport = serde_json::from_str(
+      r#"{
+        "instance": "kbin.example",
+        "subscribed_magazines": ["technology", "!rust@programming.dev"],
+        "blocked_users": ["https://lemmy.world/u/spammer"],
+        "blocked_instances": ["spam.example"],
+        "unknown_field": true
+      }"#,
+    )?;
+    let items = ImportItems::from(export);
+    assert_eq!(
+      ImportItems {
+        followed_communities: vec![
+          "technology@kbin.example".to_string(),
+          "rust@programming.dev".to_string()
+        ],
+        blocked_users: vec!["https://lemmy.world/u/spammer".to_string()],
+        blocked_instances_communities: vec!["spam.example".to_string()],
+        blocked_instances_persons: vec!["spam.example".to_string()],
+        ..Default::default()
+      },
+      items
+    );
+    assert_eq!(5, items.len());
+    Ok(())
+  }
+
+  #[test]
+  fn test_piefed_export() -> LemmyResult<()> {
+    let export: PiefedExport = serde_json::from_str(
+      r#"{
+        "followed_communities": ["https://piefed.social/c/piefed_meta"],
+        "blocked_domains": ["spam.example"],
+        "blocked_keywords": ["crypto"]
+      }"#,
+    )?;
+    let items = ImportItems::from(export);
+    assert_eq!(vec!["crypto".to_string()], items.blocked_keywords);
+    assert_eq!(
+      vec!["https://piefed.social/c/piefed_meta".to_string()],
+      items.followed_communities
+    );
+    assert_eq!(
+      vec!["spam.example".to_string()],
+      items.blocked_instances_persons
+    );
+    assert_eq!(4, items.len());
+    Ok(())
+  }
+}
@@ -1,4 +1,5 @@
 use base64::{Engine, engine::general_purpose::STANDARD_NO_PAD as base64};
+use bcrypt::verify;
 use captcha::Captcha;
 use lemmy_api_utils::{context::LemmyContext, utils::is_mod_or_admin_opt};
 use lemmy_db_schema::newtypes::CommunityId;
@@ -15,6 +16,7 @@ pub mod comment;
 pub mod community;
 pub mod federation;
 pub mod local_user;
+pub mod oauth;
 pub mod post;
 pub mod reports;
 pub mod site;
@@ -97,6 +99,22 @@ pub(crate) fn check_totp_2fa_valid(
   Ok(())
 }
 
+/// Accounts which were created via OAuth don't have a password, for them it needs to be empty.
+pub(crate) fn check_current_password(
+  local_user_view: &LocalUserView,
+  password: &str,
+) -> LemmyResult<()> {
+  let valid = if let Some(password_encrypted) = &local_user_view.local_user.password_encrypted {
+    verify(password, password_encrypted).unwrap_or(false)
+  } else {
+    password.is_empty()
+  };
+  if !valid {
+    Err(LemmyErrorType::IncorrectLogin)?
+  }
+  Ok(())
+}
+
 pub(crate) fn generate_totp_2fa_secret() -> String {
   Secret::generate_secret().to_string()
 }
@@ -1,5 +1,9 @@
 use actix_web::web::{Data, Json};
-use lemmy_api_utils::{context::LemmyContext, notify::notify_mod_action, utils::is_admin};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  utils::check_admin_action,
+};
 use lemmy_db_schema::source::{
   local_user::{LocalUser, LocalUserUpdateForm},
   modlog::{Modlog, ModlogInsertForm},
@@ -19,7 +23,15 @@ pub async fn add_admin(
   let my_person_id = local_user_view.person.id;
 
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    None,
+    "AddAdmin",
+    Some(data.person_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   // If its an admin removal, also check that you're a higher admin
   if !data.added {
@@ -50,6 +62,8 @@ pub async fn add_admin(
     added_local_user.local_user.id,
     &LocalUserUpdateForm {
       admin: Some(data.added),
+      // Reset any permission scopes, so that a newly added admin holds all permissions
+      admin_permissions: Some(None),
       ..Default::default()
     },
   )
@@ -0,0 +1,65 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
+use lemmy_db_schema::source::local_user::{LocalUser, LocalUserUpdateForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::{
+  api::{AddAdminResponse, EditAdminPermissions},
+  impls::PersonQuery,
+};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn edit_admin_permissions(
+  Json(data): Json<EditAdminPermissions>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<AddAdminResponse>> {
+  let my_person_id = local_user_view.person.id;
+
+  // Only admins holding all permissions can change the permissions of other admins
+  check_admin_action(
+    &local_user_view,
+    None,
+    "EditAdminPermissions",
+    Some(data.person_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
+
+  // Otherwise the last full admin could lock everyone out of admin management
+  if data.person_id == my_person_id {
+    Err(LemmyErrorType::CantEditOwnAdminPermissions)?
+  }
+
+  LocalUser::is_higher_admin_check(&mut context.pool(), my_person_id, vec![data.person_id]).await?;
+
+  let target = LocalUserView::read_person(&mut context.pool(), data.person_id).await?;
+  if !target.local_user.admin {
+    Err(LemmyErrorType::NotAnAdmin)?
+  }
+
+  LocalUser::update(
+    &mut context.pool(),
+    target.local_user.id,
+    &LocalUserUpdateForm {
+      admin_permissions: Some(data.permissions),
+      ..Default::default()
+    },
+  )
+  .await?;
+
+  let admins = PersonQuery {
+    admins_only: Some(true),
+    ..Default::default()
+  }
+  .list(
+    Some(my_person_id),
+    local_user_view.person.instance_id,
+    &mut context.pool(),
+  )
+  .await?;
+
+  Ok(Json(AddAdminResponse {
+    admins: admins.items,
+  }))
+}
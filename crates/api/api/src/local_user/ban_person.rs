@@ -4,7 +4,7 @@ use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_expire_time, is_admin, remove_or_restore_user_data},
+  utils::{check_admin_action, check_expire_time, remove_or_restore_user_data},
 };
 use lemmy_db_schema::{
   source::{
@@ -14,6 +14,7 @@ use lemmy_db_schema::{
   },
   traits::Bannable,
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_person::{
   PersonView,
@@ -30,7 +31,15 @@ pub async fn ban_from_site(
   let my_person_id = local_user_view.person.id;
 
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::HandleReports),
+    "BanPerson",
+    Some(data.person_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Also make sure you're a higher admin than the target
   LocalUser::is_higher_admin_check(&mut context.pool(), my_person_id, vec![data.person_id]).await?;
@@ -1,8 +1,8 @@
+use crate::check_current_password;
 use actix_web::{
   HttpRequest,
   web::{Data, Json},
 };
-use bcrypt::verify;
 use lemmy_api_utils::{
   claims::Claims,
   context::LemmyContext,
@@ -28,16 +28,7 @@ pub async fn change_password(
   }
 
   // Check the old password
-  let valid: bool = if let Some(password_encrypted) = &local_user_view.local_user.password_encrypted
-  {
-    verify(&data.old_password, password_encrypted).unwrap_or(false)
-  } else {
-    data.old_password.is_empty()
-  };
-
-  if !valid {
-    Err(LemmyErrorType::IncorrectLogin)?
-  }
+  check_current_password(&local_user_view, &data.old_password)?;
 
   let local_user_id = local_user_view.local_user.id;
   let new_password = data.new_password.clone();
@@ -46,10 +37,8 @@ pub async fn change_password(
 
   LoginToken::invalidate_all(&mut context.pool(), local_user_view.local_user.id).await?;
 
-  // Return the jwt
-  Ok(Json(LoginResponse {
-    jwt: Some(Claims::generate(updated_local_user.id, data.stay_logged_in, req, &context).await?),
-    verify_email_sent: false,
-    registration_created: false,
-  }))
+  // Start a new session, because all others were logged out
+  Ok(Json(
+    Claims::generate(updated_local_user.id, data.stay_logged_in, req, &context).await?,
+  ))
 }
@@ -0,0 +1,51 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::check_expire_time};
+use lemmy_db_schema::source::content_filter::{ContentFilter, ContentFilterInsertForm};
+use lemmy_db_schema_file::enums::ContentFilterMatch;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{ContentFilterResponse, CreateContentFilter};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::{content_filter_phrase_check, content_filter_regex_check},
+};
+
+/// Every filter is checked against each post and comment in a listing.
+const MAX_CONTENT_FILTERS_PER_USER: usize = 50;
+
+pub async fn create_content_filter(
+  Json(data): Json<CreateContentFilter>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ContentFilterResponse>> {
+  let local_user_id = local_user_view.local_user.id;
+  content_filter_phrase_check(&data.phrase)?;
+  let existing = ContentFilter::list(&mut context.pool(), local_user_id).await?;
+  if existing.len() >= MAX_CONTENT_FILTERS_PER_USER {
+    Err(LemmyErrorType::TooManyContentFilters)?
+  }
+  let expires_at = check_expire_time(data.expires_at)?;
+
+  let phrase = data.phrase.trim().to_string();
+  let match_type = data.match_type.unwrap_or_default();
+  if match_type == ContentFilterMatch::Regex {
+    content_filter_regex_check(&phrase)?;
+  }
+  let pattern = ContentFilter::build_pattern(match_type, &phrase);
+  ContentFilter::check_pattern(&mut context.pool(), &pattern).await?;
+
+  let form = ContentFilterInsertForm {
+    action: data.action,
+    filter_titles: data.filter_titles,
+    filter_bodies: data.filter_bodies,
+    filter_comments: data.filter_comments,
+    filter_usernames: data.filter_usernames,
+    filter_urls: data.filter_urls,
+    community_id: data.community_id,
+    expires_at,
+    ..ContentFilterInsertForm::new(local_user_id, phrase, match_type, pattern)
+  };
+  let content_filter = ContentFilter::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(ContentFilterResponse { content_filter }))
+}
@@ -0,0 +1,17 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::content_filter::ContentFilter;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{DeleteContentFilter, SuccessResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_content_filter(
+  Json(data): Json<DeleteContentFilter>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  ContentFilter::delete_for_user(&mut context.pool(), data.id, local_user_view.local_user.id)
+    .await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,16 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::content_filter::ContentFilter;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::ListContentFiltersResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_content_filters(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListContentFiltersResponse>> {
+  let content_filters =
+    ContentFilter::list(&mut context.pool(), local_user_view.local_user.id).await?;
+
+  Ok(Json(ListContentFiltersResponse { content_filters }))
+}
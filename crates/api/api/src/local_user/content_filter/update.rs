@@ -0,0 +1,64 @@
+use actix_web::web::{Data, Json};
+use chrono::Utc;
+use lemmy_api_utils::{context::LemmyContext, utils::check_expire_time};
+use lemmy_db_schema::source::content_filter::{ContentFilter, ContentFilterUpdateForm};
+use lemmy_db_schema_file::enums::ContentFilterMatch;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{ContentFilterResponse, EditContentFilter};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::validation::{content_filter_phrase_check, content_filter_regex_check},
+};
+
+pub async fn edit_content_filter(
+  Json(data): Json<EditContentFilter>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ContentFilterResponse>> {
+  let orig =
+    ContentFilter::read_for_user(&mut context.pool(), data.id, local_user_view.local_user.id)
+      .await?;
+
+  if let Some(phrase) = &data.phrase {
+    content_filter_phrase_check(phrase)?;
+  }
+  let phrase = data
+    .phrase
+    .as_deref()
+    .map(str::trim)
+    .unwrap_or(&orig.phrase)
+    .to_string();
+  let match_type = data.match_type.unwrap_or(orig.match_type);
+  if match_type == ContentFilterMatch::Regex {
+    content_filter_regex_check(&phrase)?;
+  }
+  let pattern = ContentFilter::build_pattern(match_type, &phrase);
+  ContentFilter::check_pattern(&mut context.pool(), &pattern).await?;
+
+  // Zero means erase
+  let community_id = data.community_id.map(|id| Some(id).filter(|id| id.0 != 0));
+  let expires_at = match data.expires_at {
+    Some(0) => Some(None),
+    Some(expires_at) => Some(check_expire_time(Some(expires_at))?),
+    None => None,
+  };
+
+  let form = ContentFilterUpdateForm {
+    phrase: Some(phrase),
+    match_type: Some(match_type),
+    pattern: Some(pattern),
+    action: data.action,
+    filter_titles: data.filter_titles,
+    filter_bodies: data.filter_bodies,
+    filter_comments: data.filter_comments,
+    filter_usernames: data.filter_usernames,
+    filter_urls: data.filter_urls,
+    community_id,
+    expires_at,
+    updated_at: Some(Some(Utc::now())),
+  };
+  let content_filter = ContentFilter::update(&mut context.pool(), orig.id, &form).await?;
+
+  Ok(Json(ContentFilterResponse { content_filter }))
+}
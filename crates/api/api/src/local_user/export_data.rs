@@ -1,7 +1,25 @@
-use activitypub_federation::config::Data;
-use actix_web::web::Json;
-use lemmy_api_utils::context::LemmyContext;
+use activitypub_federation::{config::Data, protocol::context::WithContext, traits::Object};
+use actix_web::{
+  HttpResponse,
+  http::header::{ContentDisposition, ContentType},
+  web::{Json, Query},
+};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{generate_outbox_url, user_data_export_path},
+};
+use lemmy_apub_objects::objects::{
+  comment::ApubComment,
+  post::ApubPost,
+  private_message::ApubPrivateMessage,
+};
+use lemmy_db_schema::{
+  LikeType,
+  newtypes::UserDataExportId,
+  source::{private_message::PrivateMessage as DbPrivateMessage, user_data_export::UserDataExport},
+};
 use lemmy_db_views_community_moderator::CommunityModeratorView;
+use lemmy_db_views_local_image::LocalImageView;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_notification::{NotificationData, impls::NotificationQuery};
 use lemmy_db_views_person_content_combined::impls::PersonContentCombinedQuery;
@@ -9,15 +27,131 @@ use lemmy_db_views_person_liked_combined::impls::PersonLikedCombinedQuery;
 use lemmy_db_views_post::PostView;
 use lemmy_db_views_post_comment_combined::PostCommentCombinedView;
 use lemmy_db_views_site::{
-  api::{ExportDataResponse, PostOrCommentOrPrivateMessage},
+  api::{
+    DownloadUserDataExport,
+    ExportDataResponse,
+    ListUserDataExportsResponse,
+    PostOrCommentOrPrivateMessage,
+    UserDataExportResponse,
+  },
   impls::user_backup_list_to_user_settings_backup,
 };
-use lemmy_utils::{self, error::LemmyResult};
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  spawn_try_task,
+};
+use serde::Serialize;
+use serde_json::{Value, json};
+use tokio::fs;
+use tracing::info;
+use url::Url;
+
+/// The archive which can be downloaded once an export is complete.
+#[derive(Serialize)]
+struct UserDataArchive {
+  data: ExportDataResponse,
+  /// Posts, comments and sent private messages as an ActivityPub outbox, so that they can be
+  /// imported by other Fediverse software.
+  outbox: Value,
+}
 
+/// Returns all your data right away. For large accounts [start_data_export] is better, because it
+/// runs in the background and also includes an ActivityPub outbox.
 pub async fn export_data(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<ExportDataResponse>> {
+  Ok(Json(generate_export_data(local_user_view, &context).await?))
+}
+
+/// Starts generating an archive of all your data in the background. Its state can be checked with
+/// [list_data_exports].
+pub async fn start_data_export(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<UserDataExportResponse>> {
+  let export = UserDataExport::create(&mut context.pool(), local_user_view.local_user.id).await?;
+  let export_id = export.id;
+
+  spawn_try_task(async move {
+    info!("Starting data export for {}", local_user_view.person.name);
+    let res: LemmyResult<()> = async {
+      let archive = generate_archive(local_user_view, &context).await?;
+      write_archive(&archive, export_id, &context).await
+    }
+    .await;
+    match res {
+      Ok(()) => {
+        UserDataExport::complete(&mut context.pool(), export_id).await?;
+        Ok(())
+      }
+      Err(e) => {
+        UserDataExport::fail(&mut context.pool(), export_id).await?;
+        Err(e)
+      }
+    }
+  });
+
+  Ok(Json(UserDataExportResponse { export }))
+}
+
+pub async fn list_data_exports(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListUserDataExportsResponse>> {
+  let exports = UserDataExport::list(&mut context.pool(), local_user_view.local_user.id).await?;
+  Ok(Json(ListUserDataExportsResponse { exports }))
+}
+
+pub async fn download_data_export(
+  Query(data): Query<DownloadUserDataExport>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<HttpResponse> {
+  UserDataExport::read_complete(&mut context.pool(), data.id, local_user_view.local_user.id)
+    .await?;
+  let archive = fs::read(user_data_export_path(context.settings(), data.id))
+    .await
+    .with_lemmy_type(LemmyErrorType::NotFound)?;
+  let filename = format!(
+    "lemmy-export-{}-{}.json",
+    local_user_view.person.name, data.id.0
+  );
+  Ok(
+    HttpResponse::Ok()
+      .content_type(ContentType::json())
+      .insert_header(ContentDisposition::attachment(filename))
+      .body(archive),
+  )
+}
+
+/// The archive is written to a file instead of the database, because it can get quite large.
+async fn write_archive(
+  archive: &UserDataArchive,
+  export_id: UserDataExportId,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let path = user_data_export_path(context.settings(), export_id);
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir).await?;
+  }
+  fs::write(path, serde_json::to_vec(archive)?).await?;
+  Ok(())
+}
+
+async fn generate_archive(
+  local_user_view: LocalUserView,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<UserDataArchive> {
+  let data = generate_export_data(local_user_view.clone(), context).await?;
+  let outbox = generate_outbox(&data.content, &local_user_view, context).await?;
+  Ok(UserDataArchive { data, outbox })
+}
+
+async fn generate_export_data(
+  local_user_view: LocalUserView,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<ExportDataResponse> {
   use PostOrCommentOrPrivateMessage::*;
 
   let local_instance_id = local_user_view.person.instance_id;
@@ -27,7 +161,7 @@ pub async fn export_data(
 
   let pool = &mut context.pool();
 
-  let content = PersonContentCombinedQuery {
+  let mut content: Vec<_> = PersonContentCombinedQuery {
     no_limit: Some(true),
     ..PersonContentCombinedQuery::new(my_person_id)
   }
@@ -39,6 +173,12 @@ pub async fn export_data(
     PostCommentCombinedView::Comment(cv) => Comment(cv.comment),
   })
   .collect();
+  content.extend(
+    DbPrivateMessage::list_for_creator(pool, my_person_id)
+      .await?
+      .into_iter()
+      .map(PrivateMessage),
+  );
 
   let notifications = NotificationQuery {
     no_limit: Some(true),
@@ -57,21 +197,8 @@ pub async fn export_data(
   })
   .collect();
 
-  let liked = PersonLikedCombinedQuery {
-    no_limit: Some(true),
-    ..PersonLikedCombinedQuery::default()
-  }
-  .list(pool, &local_user_view)
-  .await?
-  .into_iter()
-  .map(|u| {
-    match u {
-      PostCommentCombinedView::Post(pv) => pv.post.ap_id,
-      PostCommentCombinedView::Comment(cv) => cv.comment.ap_id,
-    }
-    .into()
-  })
-  .collect();
+  let liked = list_voted(LikeType::LikedOnly, &local_user_view, context).await?;
+  let disliked = list_voted(LikeType::DislikedOnly, &local_user_view, context).await?;
 
   let read_posts = PostView::list_read(pool, my_person, None, None, Some(true))
     .await?
@@ -85,15 +212,113 @@ pub async fn export_data(
     .map(|cv| cv.community.ap_id.into())
     .collect();
 
-  let settings =
-    user_backup_list_to_user_settings_backup(local_user_view, &mut context.pool()).await?;
+  let protocol_and_hostname = context.settings().get_protocol_and_hostname();
+  let media: Vec<Url> = LocalImageView::get_all_by_person_id(pool, my_person_id)
+    .await?
+    .into_iter()
+    .map(|i| {
+      Url::parse(&format!(
+        "{protocol_and_hostname}/api/v4/image/{}",
+        i.local_image.pictrs_alias
+      ))
+    })
+    .collect::<Result<_, _>>()?;
 
-  Ok(Json(ExportDataResponse {
+  let settings = user_backup_list_to_user_settings_backup(local_user_view, pool).await?;
+
+  Ok(ExportDataResponse {
     notifications,
     content,
-    liked,
     read_posts,
+    liked,
+    disliked,
     moderates,
+    media,
     settings,
-  }))
+  })
+}
+
+async fn list_voted(
+  like_type: LikeType,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<Vec<Url>> {
+  Ok(
+    PersonLikedCombinedQuery {
+      like_type: Some(like_type),
+      no_limit: Some(true),
+      ..PersonLikedCombinedQuery::default()
+    }
+    .list(&mut context.pool(), local_user_view)
+    .await?
+    .into_iter()
+    .map(|u| {
+      match u {
+        PostCommentCombinedView::Post(pv) => pv.post.ap_id,
+        PostCommentCombinedView::Comment(cv) => cv.comment.ap_id,
+      }
+      .into()
+    })
+    .collect(),
+  )
+}
+
+/// Wraps the posts, comments and private messages into `Create` activities, like they would be
+/// served in a user outbox.
+async fn generate_outbox(
+  content: &[PostOrCommentOrPrivateMessage],
+  local_user_view: &LocalUserView,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<Value> {
+  let actor = &local_user_view.person.ap_id;
+  let mut ordered_items = vec![];
+  for item in content.iter().cloned() {
+    let (ap_id, published, object) = match item {
+      PostOrCommentOrPrivateMessage::Post(p) => (
+        p.ap_id.clone(),
+        p.published_at,
+        ApubPost(p)
+          .into_json(context)
+          .await
+          .map(serde_json::to_value),
+      ),
+      PostOrCommentOrPrivateMessage::Comment(c) => (
+        c.ap_id.clone(),
+        c.published_at,
+        ApubComment(c)
+          .into_json(context)
+          .await
+          .map(serde_json::to_value),
+      ),
+      PostOrCommentOrPrivateMessage::PrivateMessage(pm) => (
+        pm.ap_id.clone(),
+        pm.published_at,
+        ApubPrivateMessage(pm)
+          .into_json(context)
+          .await
+          .map(serde_json::to_value),
+      ),
+    };
+    // Skip items which can't be converted, for example if the community was deleted
+    let Ok(Ok(object)) = object else {
+      continue;
+    };
+    ordered_items.push(json!({
+      "id": format!("{ap_id}#create"),
+      "type": "Create",
+      "actor": actor,
+      "published": published,
+      "to": object.get("to"),
+      "cc": object.get("cc"),
+      "object": object,
+    }));
+  }
+
+  let outbox = json!({
+    "id": generate_outbox_url(actor)?,
+    "type": "OrderedCollection",
+    "totalItems": ordered_items.len(),
+    "orderedItems": ordered_items,
+  });
+  Ok(serde_json::to_value(WithContext::new_default(outbox))?)
 }
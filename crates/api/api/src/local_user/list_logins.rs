@@ -1,15 +1,28 @@
-use actix_web::web::{Data, Json};
-use lemmy_api_utils::context::LemmyContext;
+use actix_web::{
+  HttpRequest,
+  web::{Data, Json},
+};
+use lemmy_api_utils::{context::LemmyContext, utils::read_auth_token};
 use lemmy_db_schema::source::login_token::LoginToken;
 use lemmy_db_views_local_user::LocalUserView;
-use lemmy_db_views_site::api::ListLoginsResponse;
+use lemmy_db_views_site::api::{ListLoginsResponse, LoginSession};
 use lemmy_utils::error::LemmyResult;
 
 pub async fn list_logins(
+  req: HttpRequest,
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<ListLoginsResponse>> {
-  let logins = LoginToken::list(&mut context.pool(), local_user_view.local_user.id).await?;
+  let current_token = read_auth_token(&req)?;
+  let logins = LoginToken::list(&mut context.pool(), local_user_view.local_user.id)
+    .await?
+    .into_iter()
+    .map(|login_token| LoginSession {
+      device: login_token.device_label(),
+      current: current_token.as_deref() == Some(login_token.token.as_str()),
+      login_token,
+    })
+    .collect();
 
   Ok(Json(ListLoginsResponse { logins }))
 }
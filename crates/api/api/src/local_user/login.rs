@@ -1,4 +1,7 @@
-use crate::check_totp_2fa_valid;
+use crate::{
+  check_totp_2fa_valid,
+  local_user::webauthn::{check_recovery_code, check_webauthn_valid},
+};
 use actix_web::{
   HttpRequest,
   web::{Data, Json},
@@ -9,12 +12,15 @@ use lemmy_api_utils::{
   context::LemmyContext,
   utils::{check_email_verified, check_local_user_deleted, check_registration_application},
 };
+use lemmy_db_schema::source::webauthn_credential::WebauthnCredential;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::{
   SiteView,
   api::{Login, LoginResponse},
 };
+use lemmy_diesel_utils::sensitive::SensitiveString;
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use webauthn_rs::prelude::PublicKeyCredential;
 
 pub async fn login(
   Json(data): Json<Login>,
@@ -44,26 +50,63 @@ pub async fn login(
   check_registration_application(&local_user_view, &site_view.local_site, &mut context.pool())
     .await?;
 
-  // Check the totp if enabled
-  if local_user_view.local_user.totp_2fa_enabled {
-    check_totp_2fa_valid(
-      &local_user_view,
-      &data.totp_2fa_token,
-      &context.settings().hostname,
-    )?;
-  }
-
-  let jwt = Claims::generate(
-    local_user_view.local_user.id,
-    data.stay_logged_in,
-    req,
+  check_second_factor(
+    &local_user_view,
+    &data.totp_2fa_token,
+    &data.webauthn_challenge_id,
+    &data.webauthn_credential,
+    &data.recovery_code,
     &context,
   )
   .await?;
 
-  Ok(Json(LoginResponse {
-    jwt: Some(jwt.clone()),
-    verify_email_sent: false,
-    registration_created: false,
-  }))
+  Ok(Json(
+    Claims::generate(
+      local_user_view.local_user.id,
+      data.stay_logged_in,
+      req,
+      &context,
+    )
+    .await?,
+  ))
+}
+
+/// If totp or webauthn are enabled, one of them is required in addition to the password. A recovery
+/// code can be used instead if the second factor is lost.
+pub(crate) async fn check_second_factor(
+  local_user_view: &LocalUserView,
+  totp_2fa_token: &Option<String>,
+  webauthn_challenge_id: &Option<String>,
+  webauthn_credential: &Option<PublicKeyCredential>,
+  recovery_code: &Option<SensitiveString>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let local_user_id = local_user_view.local_user.id;
+  let totp_enabled = local_user_view.local_user.totp_2fa_enabled;
+  let has_webauthn = !WebauthnCredential::list(&mut context.pool(), local_user_id)
+    .await?
+    .is_empty();
+  if !totp_enabled && !has_webauthn {
+    return Ok(());
+  }
+
+  if let Some(recovery_code) = recovery_code {
+    check_recovery_code(local_user_id, recovery_code, context).await
+  } else if let (Some(challenge_id), Some(credential)) =
+    (webauthn_challenge_id, webauthn_credential)
+    && has_webauthn
+  {
+    if check_webauthn_valid(challenge_id, credential, context).await? != local_user_id {
+      Err(LemmyErrorType::IncorrectWebauthnCredential)?
+    }
+    Ok(())
+  } else if totp_enabled {
+    check_totp_2fa_valid(
+      local_user_view,
+      totp_2fa_token,
+      &context.settings().hostname,
+    )
+  } else {
+    Err(LemmyErrorType::MissingWebauthnCredential.into())
+  }
 }
@@ -1,8 +1,10 @@
 pub mod add_admin;
+pub mod admin_permissions;
 pub mod ban_person;
 pub mod block;
 pub mod change_password;
 pub mod change_password_after_reset;
+pub mod content_filter;
 pub mod donation_dialog_shown;
 pub mod export_data;
 pub mod generate_totp_secret;
@@ -17,11 +19,17 @@ pub mod login;
 pub mod logout;
 pub mod note_person;
 pub mod notifications;
+pub mod personal_access_token;
+pub mod quarantine_person;
+pub mod refresh_login;
 pub mod resend_verification_email;
 pub mod reset_password;
+pub mod revoke_login;
+pub mod revoke_other_logins;
 pub mod save_settings;
 pub mod unread_counts;
 pub mod update_totp;
 pub mod user_block_instance;
 pub mod validate_auth;
 pub mod verify_email;
+pub mod webauthn;
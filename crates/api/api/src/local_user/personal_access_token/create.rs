@@ -0,0 +1,41 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::check_expire_time};
+use lemmy_db_schema::{
+  source::personal_access_token::{PersonalAccessToken, PersonalAccessTokenInsertForm},
+  utils::hash_token,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{CreatePersonalAccessToken, CreatePersonalAccessTokenResponse};
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::validation::{access_token_name_check, check_api_elements_count},
+};
+
+pub async fn create_personal_access_token(
+  Json(data): Json<CreatePersonalAccessToken>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CreatePersonalAccessTokenResponse>> {
+  access_token_name_check(&data.name)?;
+  let moderate_community_ids = data.moderate_community_ids.unwrap_or_default();
+  check_api_elements_count(moderate_community_ids.len())?;
+  let expires_at = check_expire_time(data.expires_at)?;
+
+  let token = PersonalAccessToken::generate_token();
+  let form = PersonalAccessTokenInsertForm {
+    moderate_community_ids,
+    expires_at,
+    ..PersonalAccessTokenInsertForm::new(
+      local_user_view.local_user.id,
+      data.name.trim().to_string(),
+      hash_token(&token),
+      data.scopes,
+    )
+  };
+  let personal_access_token = PersonalAccessToken::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(CreatePersonalAccessTokenResponse {
+    personal_access_token,
+    token,
+  }))
+}
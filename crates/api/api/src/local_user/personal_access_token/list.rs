@@ -0,0 +1,18 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::personal_access_token::PersonalAccessToken;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::ListPersonalAccessTokensResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_personal_access_tokens(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListPersonalAccessTokensResponse>> {
+  let personal_access_tokens =
+    PersonalAccessToken::list(&mut context.pool(), local_user_view.local_user.id).await?;
+
+  Ok(Json(ListPersonalAccessTokensResponse {
+    personal_access_tokens,
+  }))
+}
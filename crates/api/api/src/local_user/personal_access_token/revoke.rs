@@ -0,0 +1,16 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::personal_access_token::PersonalAccessToken;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{RevokePersonalAccessToken, SuccessResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn revoke_personal_access_token(
+  Json(data): Json<RevokePersonalAccessToken>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  PersonalAccessToken::revoke(&mut context.pool(), data.id, local_user_view.local_user.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
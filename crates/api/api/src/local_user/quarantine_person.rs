@@ -0,0 +1,85 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  utils::check_admin_action,
+};
+use lemmy_db_schema::source::{
+  instance::{InstanceActions, InstanceQuarantineForm},
+  local_user::LocalUser,
+  modlog::{Modlog, ModlogInsertForm},
+  person::Person,
+};
+use lemmy_db_schema_file::enums::AdminPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::{
+  PersonView,
+  api::{PersonResponse, QuarantinePerson},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+pub async fn quarantine_person(
+  Json(data): Json<QuarantinePerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonResponse>> {
+  let local_instance_id = local_user_view.person.instance_id;
+  let my_person_id = local_user_view.person.id;
+
+  // Make sure user is an admin
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::HandleReports),
+    "QuarantinePerson",
+    Some(data.person_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
+
+  // Also make sure you're a higher admin than the target
+  LocalUser::is_higher_admin_check(&mut context.pool(), my_person_id, vec![data.person_id]).await?;
+
+  is_valid_body_field(&data.reason, false)?;
+
+  // Held back content gets federated on approval, which is only possible for local persons.
+  // Remote spammers should be banned instead.
+  let person = Person::read(&mut context.pool(), data.person_id).await?;
+  if !person.local {
+    Err(LemmyErrorType::CantQuarantineRemotePerson)?
+  }
+
+  // Quarantine is a local moderation tool, so it isn't federated
+  let form = InstanceQuarantineForm::new(data.person_id, local_instance_id);
+  if data.quarantine {
+    InstanceActions::quarantine(&mut context.pool(), &form).await?;
+  } else {
+    InstanceActions::unquarantine(&mut context.pool(), &form).await?;
+  }
+
+  // Mod tables
+  let form = ModlogInsertForm::admin_quarantine_person(
+    &local_user_view.person,
+    data.person_id,
+    data.quarantine,
+    &data.reason,
+  );
+  let action = Modlog::create(&mut context.pool(), &[form]).await?;
+  notify_mod_action(action, &context);
+
+  let person_view = PersonView::read(
+    &mut context.pool(),
+    data.person_id,
+    Some(my_person_id),
+    local_instance_id,
+    true,
+  )
+  .await?;
+
+  Ok(Json(PersonResponse { person_view }))
+}
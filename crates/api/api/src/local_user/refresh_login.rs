@@ -0,0 +1,13 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{claims::Claims, context::LemmyContext};
+use lemmy_db_views_site::api::{LoginResponse, RefreshLogin};
+use lemmy_utils::error::LemmyResult;
+
+/// Get a new jwt for an existing session. The refresh token is replaced as well, so the old one
+/// can't be used again.
+pub async fn refresh_login(
+  Json(data): Json<RefreshLogin>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<LoginResponse>> {
+  Ok(Json(Claims::refresh(&data.refresh_token, &context).await?))
+}
@@ -0,0 +1,16 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::login_token::LoginToken;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{RevokeLogin, SuccessResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn revoke_login(
+  Json(data): Json<RevokeLogin>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  LoginToken::revoke(&mut context.pool(), data.id, local_user_view.local_user.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,21 @@
+use actix_web::{
+  HttpRequest,
+  web::{Data, Json},
+};
+use lemmy_api_utils::{context::LemmyContext, utils::read_auth_token};
+use lemmy_db_schema::source::login_token::LoginToken;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Log out everywhere, except for the session which makes this request.
+pub async fn revoke_other_logins(
+  req: HttpRequest,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let jwt = read_auth_token(&req)?.ok_or(LemmyErrorType::NotLoggedIn)?;
+  LoginToken::invalidate_others(&mut context.pool(), local_user_view.local_user.id, &jwt).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
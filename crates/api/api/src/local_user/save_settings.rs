@@ -1,14 +1,22 @@
 use activitypub_federation::config::Data;
-use actix_web::web::Json;
+use actix_web::{HttpRequest, web::Json};
 use lemmy_api_utils::{
   context::LemmyContext,
-  utils::{check_local_user_valid, get_url_blocklist, process_markdown_opt, slur_regex},
+  utils::{
+    check_email_domain_allowed,
+    check_local_user_valid,
+    get_url_blocklist,
+    process_markdown_opt,
+    read_auth_token,
+    slur_regex,
+  },
 };
 use lemmy_db_schema::{
   source::{
     actor_language::LocalUserLanguage,
     keyword_block::LocalUserKeywordBlock,
     local_user::{LocalUser, LocalUserUpdateForm},
+    login_token::LoginToken,
     person::{Person, PersonUpdateForm},
   },
   utils::limit_fetch_check,
@@ -36,6 +44,7 @@ use std::ops::Deref;
 
 pub async fn save_user_settings(
   Json(data): Json<SaveUserSettings>,
+  req: HttpRequest,
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
@@ -55,11 +64,14 @@ pub async fn save_user_settings(
   let email_deref = data.email.as_deref().map(str::to_lowercase);
   let email = diesel_string_update(email_deref.as_deref());
 
+  let mut email_changed = false;
   if let Some(Some(email)) = email.clone() {
     let previous_email = local_user_view.local_user.email.clone().unwrap_or_default();
     // if email was changed, check that it is not taken and send verification mail
     if previous_email.deref() != email {
+      email_changed = true;
       LocalUser::check_is_email_taken(&mut context.pool(), &email).await?;
+      check_email_domain_allowed(&email, &site_view.local_site, &mut context.pool()).await?;
       send_verification_email(
         &site_view.local_site,
         &local_user_view,
@@ -168,6 +180,7 @@ pub async fn save_user_settings(
     collapse_bot_comments: data.collapse_bot_comments,
     auto_mark_fetched_posts_as_read: data.auto_mark_fetched_posts_as_read,
     hide_media: data.hide_media,
+    collapse_crossposts: data.collapse_crossposts,
     // Update the vote display modes
     show_score: data.show_score,
     show_upvotes: data.show_upvotes,
@@ -179,5 +192,10 @@ pub async fn save_user_settings(
 
   LocalUser::update(&mut context.pool(), local_user_id, &local_user_form).await?;
 
+  // Someone who took over the account must not stay logged in after the email was changed
+  if email_changed && let Some(jwt) = read_auth_token(&req)? {
+    LoginToken::invalidate_others(&mut context.pool(), local_user_id, &jwt).await?;
+  }
+
   Ok(Json(SuccessResponse::default()))
 }
@@ -2,8 +2,9 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_utils::{
   context::LemmyContext,
-  utils::{check_community_mod_of_any_or_admin_action, is_admin},
+  utils::{check_admin_permission, check_community_mod_of_any_or_admin_action},
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_community_follower_approval::PendingFollowerView;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_notification::NotificationView;
@@ -39,8 +40,10 @@ pub async fn get_unread_counts(
       (None, None)
     };
 
-  // Admins also get the number of unread registration applications.
-  let registration_application_count = if is_admin(&local_user_view).is_ok() {
+  // Admins who review registrations also get the number of unread registration applications.
+  let reviews_registrations =
+    check_admin_permission(&local_user_view, Some(AdminPermission::ReviewRegistrations)).is_ok();
+  let registration_application_count = if reviews_registrations {
     let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
     let verified_email_only = local_site.require_email_verification;
     Some(
@@ -0,0 +1,27 @@
+use super::check_reauthentication;
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::webauthn_credential::WebauthnCredential;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{DeleteWebauthnCredential, SuccessResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_webauthn_credential(
+  Json(data): Json<DeleteWebauthnCredential>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  check_reauthentication(
+    &local_user_view,
+    &data.password,
+    &data.totp_2fa_token,
+    &data.webauthn_challenge_id,
+    &data.webauthn_credential,
+    &data.recovery_code,
+    &context,
+  )
+  .await?;
+  WebauthnCredential::delete(&mut context.pool(), data.id, local_user_view.local_user.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,52 @@
+use super::{build_webauthn, parse_challenge_id};
+use actix_web::web::{Data, Json};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use lemmy_api_utils::{context::LemmyContext, utils::check_local_user_valid};
+use lemmy_db_schema::source::webauthn_credential::{
+  WebauthnChallenge,
+  WebauthnCredential,
+  WebauthnCredentialInsertForm,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{FinishWebauthnRegistration, WebauthnCredentialResponse};
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::webauthn_credential_name_check,
+};
+use webauthn_rs::prelude::PasskeyRegistration;
+
+/// Finish registering a passkey with the response of the authenticator. Afterwards it can be
+/// used for login.
+pub async fn finish_webauthn_registration(
+  Json(data): Json<FinishWebauthnRegistration>,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<WebauthnCredentialResponse>> {
+  check_local_user_valid(&local_user_view)?;
+  webauthn_credential_name_check(&data.name)?;
+  let local_user_id = local_user_view.local_user.id;
+
+  let challenge =
+    WebauthnChallenge::consume(&mut context.pool(), parse_challenge_id(&data.challenge_id)?)
+      .await?;
+  if challenge.local_user_id != local_user_id {
+    Err(LemmyErrorType::IncorrectWebauthnCredential)?
+  }
+  let state: PasskeyRegistration = serde_json::from_str(&challenge.state)
+    .with_lemmy_type(LemmyErrorType::IncorrectWebauthnCredential)?;
+  let passkey = build_webauthn(&context)?
+    .finish_passkey_registration(&data.credential, &state)
+    .with_lemmy_type(LemmyErrorType::IncorrectWebauthnCredential)?;
+
+  let form = WebauthnCredentialInsertForm::new(
+    local_user_id,
+    data.name.trim().to_string(),
+    URL_SAFE_NO_PAD.encode(passkey.cred_id()),
+    serde_json::to_string(&passkey)?,
+  );
+  let webauthn_credential = WebauthnCredential::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(WebauthnCredentialResponse {
+    webauthn_credential,
+  }))
+}
@@ -0,0 +1,51 @@
+use super::{check_reauthentication, hash_recovery_code};
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::check_local_user_valid};
+use lemmy_db_schema::source::recovery_code::{RecoveryCode, RecoveryCodeInsertForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{GenerateRecoveryCodes, GenerateRecoveryCodesResponse};
+use lemmy_diesel_utils::sensitive::SensitiveString;
+use lemmy_utils::error::LemmyResult;
+use uuid::Uuid;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Generate new recovery codes, which can be used to log in if the second factor is lost. This
+/// invalidates all previous codes.
+pub async fn generate_recovery_codes(
+  Json(data): Json<GenerateRecoveryCodes>,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<GenerateRecoveryCodesResponse>> {
+  check_local_user_valid(&local_user_view)?;
+  check_reauthentication(
+    &local_user_view,
+    &data.password,
+    &data.totp_2fa_token,
+    &data.webauthn_challenge_id,
+    &data.webauthn_credential,
+    &data.recovery_code,
+    &context,
+  )
+  .await?;
+  let local_user_id = local_user_view.local_user.id;
+
+  let recovery_codes: Vec<SensitiveString> = (0..RECOVERY_CODE_COUNT)
+    .map(|_| {
+      Uuid::new_v4()
+        .simple()
+        .to_string()
+        .chars()
+        .take(16)
+        .collect::<String>()
+        .into()
+    })
+    .collect();
+  let forms: Vec<_> = recovery_codes
+    .iter()
+    .map(|code| RecoveryCodeInsertForm::new(local_user_id, hash_recovery_code(code)))
+    .collect();
+  RecoveryCode::replace(&mut context.pool(), local_user_id, &forms).await?;
+
+  Ok(Json(GenerateRecoveryCodesResponse { recovery_codes }))
+}
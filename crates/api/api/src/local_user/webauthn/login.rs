@@ -0,0 +1,37 @@
+use super::check_webauthn_valid;
+use actix_web::{
+  HttpRequest,
+  web::{Data, Json},
+};
+use lemmy_api_utils::{
+  claims::Claims,
+  context::LemmyContext,
+  utils::{check_email_verified, check_local_user_deleted, check_registration_application},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::{
+  SiteView,
+  api::{LoginResponse, WebauthnLogin},
+};
+use lemmy_utils::error::LemmyResult;
+
+/// Passwordless login with a passkey. This replaces both password and second factor.
+pub async fn webauthn_login(
+  Json(data): Json<WebauthnLogin>,
+  req: HttpRequest,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<LoginResponse>> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+
+  let local_user_id = check_webauthn_valid(&data.challenge_id, &data.credential, &context).await?;
+
+  let local_user_view = LocalUserView::read(&mut context.pool(), local_user_id).await?;
+  check_local_user_deleted(&local_user_view)?;
+  check_email_verified(&local_user_view, &site_view)?;
+  check_registration_application(&local_user_view, &site_view.local_site, &mut context.pool())
+    .await?;
+
+  Ok(Json(
+    Claims::generate(local_user_id, data.stay_logged_in, req, &context).await?,
+  ))
+}
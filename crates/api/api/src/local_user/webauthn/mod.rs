@@ -0,0 +1,413 @@
+use crate::{check_current_password, local_user::login::check_second_factor};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::LocalUserId,
+  source::{
+    recovery_code::RecoveryCode,
+    webauthn_credential::{WebauthnChallenge, WebauthnChallengeInsertForm, WebauthnCredential},
+  },
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::StartWebauthnLoginResponse;
+use lemmy_diesel_utils::sensitive::SensitiveString;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use sha2::{Digest, Sha256};
+use url::Url;
+use uuid::Uuid;
+use webauthn_rs::{
+  Webauthn,
+  WebauthnBuilder,
+  prelude::{Passkey, PasskeyAuthentication, PublicKeyCredential, RequestChallengeResponse},
+};
+use webauthn_rs_proto::{AllowCredentials, UserVerificationPolicy};
+
+pub mod delete;
+pub mod finish_registration;
+pub mod generate_recovery_codes;
+pub mod list;
+pub mod login;
+pub mod rename;
+pub mod start_login;
+pub mod start_registration;
+
+/// The relying party is this instance, so credentials are bound to its hostname.
+fn build_webauthn(context: &LemmyContext) -> LemmyResult<Webauthn> {
+  let settings = context.settings();
+  let rp_id = settings.get_hostname_without_port()?;
+  let rp_origin = Url::parse(&settings.get_protocol_and_hostname())?;
+  Ok(
+    WebauthnBuilder::new(&rp_id, &rp_origin)?
+      .rp_name(&settings.hostname)
+      .build()?,
+  )
+}
+
+/// Webauthn needs a stable, opaque id for each user.
+fn user_unique_id(local_user_id: LocalUserId) -> LemmyResult<Uuid> {
+  Ok(Uuid::from_u128(u128::try_from(local_user_id.0)?))
+}
+
+fn parse_challenge_id(challenge_id: &str) -> LemmyResult<Uuid> {
+  Uuid::parse_str(challenge_id).with_lemmy_type(LemmyErrorType::IncorrectWebauthnCredential)
+}
+
+fn hash_recovery_code(code: &str) -> String {
+  URL_SAFE_NO_PAD.encode(Sha256::digest(code.trim().to_lowercase()))
+}
+
+async fn read_passkeys(
+  local_user_id: LocalUserId,
+  context: &LemmyContext,
+) -> LemmyResult<Vec<(WebauthnCredential, Passkey)>> {
+  WebauthnCredential::list(&mut context.pool(), local_user_id)
+    .await?
+    .into_iter()
+    .map(|c| {
+      let passkey = serde_json::from_str(&c.passkey)?;
+      Ok((c, passkey))
+    })
+    .collect()
+}
+
+/// Starts an authentication ceremony for all credentials of the user. Returns none if the user
+/// has no credentials.
+pub(crate) async fn start_webauthn_authentication(
+  local_user_id: LocalUserId,
+  context: &LemmyContext,
+) -> LemmyResult<Option<StartWebauthnLoginResponse>> {
+  let passkeys: Vec<_> = read_passkeys(local_user_id, context)
+    .await?
+    .into_iter()
+    .map(|(_, passkey)| passkey)
+    .collect();
+  if passkeys.is_empty() {
+    return Ok(None);
+  }
+  let (mut options, state) = build_webauthn(context)?.start_passkey_authentication(&passkeys)?;
+  normalize_request_options(&mut options);
+
+  let form = WebauthnChallengeInsertForm::new(local_user_id, serde_json::to_string(&state)?);
+  let challenge = WebauthnChallenge::create(&mut context.pool(), &form).await?;
+
+  Ok(Some(StartWebauthnLoginResponse {
+    challenge_id: challenge.id.to_string(),
+    options,
+  }))
+}
+
+/// Options for accounts which don't exist or have no credentials, so that the response doesn't
+/// reveal which accounts exist. The number of credentials and their ids are derived from the
+/// name, so that they stay the same between requests. The challenge isn't stored, so it can never
+/// be answered.
+pub(crate) fn fake_webauthn_authentication(
+  username_or_email: &str,
+  context: &LemmyContext,
+) -> LemmyResult<StartWebauthnLoginResponse> {
+  let (mut options, _) = build_webauthn(context)?.start_discoverable_authentication()?;
+  let fake_credential_id = |index: u8| {
+    Sha256::new()
+      .chain_update(context.secret().jwt_secret.as_bytes())
+      .chain_update(username_or_email.trim().to_lowercase())
+      .chain_update([index])
+      .finalize()
+  };
+  // Most accounts have a single credential, some have a backup key
+  let count = match fake_credential_id(0).first() {
+    Some(b) if *b >= 240 => 3,
+    Some(b) if *b >= 176 => 2,
+    _ => 1,
+  };
+  options.public_key.allow_credentials = (1..=count)
+    .map(|index| AllowCredentials {
+      type_: "public-key".to_string(),
+      id: fake_credential_id(index).to_vec().into(),
+      transports: None,
+    })
+    .collect();
+  normalize_request_options(&mut options);
+
+  Ok(StartWebauthnLoginResponse {
+    challenge_id: Uuid::new_v4().to_string(),
+    options,
+  })
+}
+
+/// Real and fake challenges are built by different ceremonies, so they need to be brought into
+/// the same shape. Otherwise the difference would reveal which accounts have credentials.
+fn normalize_request_options(options: &mut RequestChallengeResponse) {
+  options.mediation = None;
+  options.public_key.user_verification = UserVerificationPolicy::Required;
+  options.public_key.extensions = None;
+  for credential in &mut options.public_key.allow_credentials {
+    credential.transports = None;
+  }
+}
+
+/// Changes to the second factor need the current password, and the second factor itself if one
+/// is enabled. This way a leaked session token can't be used to take over the account.
+async fn check_reauthentication(
+  local_user_view: &LocalUserView,
+  password: &str,
+  totp_2fa_token: &Option<String>,
+  webauthn_challenge_id: &Option<String>,
+  webauthn_credential: &Option<PublicKeyCredential>,
+  recovery_code: &Option<SensitiveString>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  check_current_password(local_user_view, password)?;
+  check_second_factor(
+    local_user_view,
+    totp_2fa_token,
+    webauthn_challenge_id,
+    webauthn_credential,
+    recovery_code,
+    context,
+  )
+  .await
+}
+
+/// Checks the answer to a challenge from [start_webauthn_authentication], and stores the new
+/// signature counter of the credential which was used. Returns the user who owns the credential.
+pub(crate) async fn check_webauthn_valid(
+  challenge_id: &str,
+  credential: &PublicKeyCredential,
+  context: &LemmyContext,
+) -> LemmyResult<LocalUserId> {
+  let challenge =
+    WebauthnChallenge::consume(&mut context.pool(), parse_challenge_id(challenge_id)?).await?;
+  let state: PasskeyAuthentication = serde_json::from_str(&challenge.state)
+    .with_lemmy_type(LemmyErrorType::IncorrectWebauthnCredential)?;
+  let result = build_webauthn(context)?
+    .finish_passkey_authentication(credential, &state)
+    .with_lemmy_type(LemmyErrorType::IncorrectWebauthnCredential)?;
+
+  let (credential, mut passkey) = read_passkeys(challenge.local_user_id, context)
+    .await?
+    .into_iter()
+    .find(|(_, passkey)| passkey.cred_id() == result.cred_id())
+    .ok_or(LemmyErrorType::IncorrectWebauthnCredential)?;
+  passkey.update_credential(&result);
+  WebauthnCredential::mark_used(
+    &mut context.pool(),
+    credential.id,
+    &serde_json::to_string(&passkey)?,
+  )
+  .await?;
+
+  Ok(challenge.local_user_id)
+}
+
+/// Recovery codes can be used instead of any second factor, but only once.
+pub(crate) async fn check_recovery_code(
+  local_user_id: LocalUserId,
+  recovery_code: &str,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  RecoveryCode::consume(
+    &mut context.pool(),
+    local_user_id,
+    &hash_recovery_code(recovery_code),
+  )
+  .await
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{
+    finish_registration::finish_webauthn_registration,
+    generate_recovery_codes::generate_recovery_codes,
+    login::webauthn_login,
+    start_login::start_webauthn_login,
+    start_registration::start_webauthn_registration,
+  };
+  use crate::local_user::login::login;
+  use actix_web::{test::TestRequest, web::Json};
+  use anyhow::anyhow;
+  use lemmy_api_utils::context::LemmyContext;
+  use lemmy_db_schema::{source::person::Person, test_data::TestData};
+  use lemmy_db_views_local_user::LocalUserView;
+  use lemmy_db_views_site::api::{
+    FinishWebauthnRegistration,
+    GenerateRecoveryCodes,
+    Login,
+    StartWebauthnLogin,
+    StartWebauthnRegistration,
+    WebauthnLogin,
+  };
+  use lemmy_diesel_utils::traits::Crud;
+  use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+  use serial_test::serial;
+  use url::Url;
+  use webauthn_authenticator_rs::{WebauthnAuthenticator, softpasskey::SoftPasskey};
+
+  #[tokio::test]
+  #[serial]
+  async fn test_webauthn_login() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+    let data = TestData::create(pool).await?;
+    let user = LocalUserView::create_test_user(pool, "passkey_tester", "", true).await?;
+    let origin = Url::parse(&context.settings().get_protocol_and_hostname())?;
+    let mut authenticator = WebauthnAuthenticator::new(SoftPasskey::new(true));
+
+    // Unknown users and users without passkeys still get a challenge, which stays the same
+    // between requests
+    let start_form = StartWebauthnLogin {
+      username_or_email: user.person.name.clone().into(),
+    };
+    let res = start_webauthn_login(Json(start_form.clone()), context.clone()).await?;
+    assert!(!res.0.options.public_key.allow_credentials.is_empty());
+    let unknown_form = StartWebauthnLogin {
+      username_or_email: "passkey_tester_unknown".to_string().into(),
+    };
+    let first = start_webauthn_login(Json(unknown_form.clone()), context.clone()).await?;
+    let second = start_webauthn_login(Json(unknown_form), context.clone()).await?;
+    assert!(!first.0.options.public_key.allow_credentials.is_empty());
+    assert_eq!(
+      serde_json::to_string(&first.0.options.public_key.allow_credentials)?,
+      serde_json::to_string(&second.0.options.public_key.allow_credentials)?
+    );
+    assert_ne!(first.0.challenge_id, second.0.challenge_id);
+
+    // Registering a passkey requires the password
+    let wrong_password_form = StartWebauthnRegistration {
+      password: "wrong".to_string().into(),
+      ..Default::default()
+    };
+    let res =
+      start_webauthn_registration(Json(wrong_password_form), user.clone(), context.clone()).await;
+    assert_eq!(
+      Some(LemmyErrorType::IncorrectLogin),
+      res.err().map(|e| e.error_type)
+    );
+
+    // Register a passkey with the software authenticator
+    let registration = start_webauthn_registration(
+      Json(StartWebauthnRegistration::default()),
+      user.clone(),
+      context.clone(),
+    )
+    .await?;
+    let credential = authenticator
+      .do_registration(origin.clone(), registration.0.options)
+      .map_err(|e| anyhow!("{e:?}"))?;
+    let finish_form = FinishWebauthnRegistration {
+      challenge_id: registration.0.challenge_id,
+      name: "Software key".to_string(),
+      credential,
+    };
+    finish_webauthn_registration(Json(finish_form), user.clone(), context.clone()).await?;
+
+    // Now the password alone isn't enough
+    let login_form = Login {
+      username_or_email: user.person.name.clone().into(),
+      password: String::new().into(),
+      ..Default::default()
+    };
+    let res = login(
+      Json(login_form.clone()),
+      TestRequest::default().to_http_request(),
+      context.clone(),
+    )
+    .await;
+    assert_eq!(
+      Some(LemmyErrorType::MissingWebauthnCredential),
+      res.err().map(|e| e.error_type)
+    );
+
+    // Password with passkey as second factor
+    let challenge = start_webauthn_login(Json(start_form.clone()), context.clone()).await?;
+    let credential = authenticator
+      .do_authentication(origin.clone(), challenge.0.options)
+      .map_err(|e| anyhow!("{e:?}"))?;
+    let second_factor_form = Login {
+      webauthn_challenge_id: Some(challenge.0.challenge_id.clone()),
+      webauthn_credential: Some(credential.clone()),
+      ..login_form.clone()
+    };
+    let res = login(
+      Json(second_factor_form.clone()),
+      TestRequest::default().to_http_request(),
+      context.clone(),
+    )
+    .await?;
+    assert!(res.0.jwt.is_some());
+
+    // The same challenge can't be answered twice
+    let res = login(
+      Json(second_factor_form),
+      TestRequest::default().to_http_request(),
+      context.clone(),
+    )
+    .await;
+    assert!(res.is_err());
+
+    // Passwordless login
+    let challenge = start_webauthn_login(Json(start_form.clone()), context.clone()).await?;
+    let credential = authenticator
+      .do_authentication(origin.clone(), challenge.0.options)
+      .map_err(|e| anyhow!("{e:?}"))?;
+    let passwordless_form = WebauthnLogin {
+      challenge_id: challenge.0.challenge_id,
+      credential,
+      stay_logged_in: None,
+    };
+    let res = webauthn_login(
+      Json(passwordless_form),
+      TestRequest::default().to_http_request(),
+      context.clone(),
+    )
+    .await?;
+    assert!(res.0.jwt.is_some());
+
+    // Generating recovery codes requires the second factor once it's enabled
+    let res = generate_recovery_codes(
+      Json(GenerateRecoveryCodes::default()),
+      user.clone(),
+      context.clone(),
+    )
+    .await;
+    assert_eq!(
+      Some(LemmyErrorType::MissingWebauthnCredential),
+      res.err().map(|e| e.error_type)
+    );
+    let challenge = start_webauthn_login(Json(start_form), context.clone()).await?;
+    let credential = authenticator
+      .do_authentication(origin, challenge.0.options)
+      .map_err(|e| anyhow!("{e:?}"))?;
+    let generate_form = GenerateRecoveryCodes {
+      webauthn_challenge_id: Some(challenge.0.challenge_id),
+      webauthn_credential: Some(credential),
+      ..Default::default()
+    };
+    let codes = generate_recovery_codes(Json(generate_form), user.clone(), context.clone()).await?;
+
+    // Recovery codes work once instead of the second factor
+    let recovery_form = Login {
+      recovery_code: codes.0.recovery_codes.first().cloned(),
+      ..login_form
+    };
+    let res = login(
+      Json(recovery_form.clone()),
+      TestRequest::default().to_http_request(),
+      context.clone(),
+    )
+    .await?;
+    assert!(res.0.jwt.is_some());
+    let res = login(
+      Json(recovery_form),
+      TestRequest::default().to_http_request(),
+      context.clone(),
+    )
+    .await;
+    assert_eq!(
+      Some(LemmyErrorType::IncorrectRecoveryCode),
+      res.err().map(|e| e.error_type)
+    );
+
+    Person::delete(pool, user.person.id).await?;
+    data.delete(&mut context.pool()).await?;
+    Ok(())
+  }
+}
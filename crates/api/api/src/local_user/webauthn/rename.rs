@@ -0,0 +1,25 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::webauthn_credential::WebauthnCredential;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{RenameWebauthnCredential, WebauthnCredentialResponse};
+use lemmy_utils::{error::LemmyResult, utils::validation::webauthn_credential_name_check};
+
+pub async fn rename_webauthn_credential(
+  Json(data): Json<RenameWebauthnCredential>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WebauthnCredentialResponse>> {
+  webauthn_credential_name_check(&data.name)?;
+  let webauthn_credential = WebauthnCredential::rename(
+    &mut context.pool(),
+    data.id,
+    local_user_view.local_user.id,
+    data.name.trim(),
+  )
+  .await?;
+
+  Ok(Json(WebauthnCredentialResponse {
+    webauthn_credential,
+  }))
+}
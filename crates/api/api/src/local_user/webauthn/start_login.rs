@@ -0,0 +1,33 @@
+use super::{fake_webauthn_authentication, start_webauthn_authentication};
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{StartWebauthnLogin, StartWebauthnLoginResponse};
+use lemmy_utils::error::LemmyResult;
+
+/// Start a webauthn login. The returned options need to be passed to
+/// `navigator.credentials.get()`, and the result either to [webauthn_login] for passwordless
+/// login, or to [login] as second factor.
+///
+/// Unknown accounts and accounts without passkeys get a challenge which looks the same, so this
+/// can't be used to find out which accounts exist.
+pub async fn start_webauthn_login(
+  Json(data): Json<StartWebauthnLogin>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<StartWebauthnLoginResponse>> {
+  let local_user_view =
+    LocalUserView::find_by_email_or_name(&mut context.pool(), &data.username_or_email)
+      .await
+      .ok();
+  if let Some(local_user_view) = local_user_view
+    && let Some(res) =
+      start_webauthn_authentication(local_user_view.local_user.id, &context).await?
+  {
+    return Ok(Json(res));
+  }
+
+  Ok(Json(fake_webauthn_authentication(
+    &data.username_or_email,
+    &context,
+  )?))
+}
@@ -0,0 +1,57 @@
+use super::{build_webauthn, check_reauthentication, read_passkeys, user_unique_id};
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::check_local_user_valid};
+use lemmy_db_schema::source::webauthn_credential::{
+  WebauthnChallenge,
+  WebauthnChallengeInsertForm,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{StartWebauthnRegistration, StartWebauthnRegistrationResponse};
+use lemmy_utils::error::LemmyResult;
+
+/// Start registering a new passkey or security key. The returned options need to be passed to
+/// `navigator.credentials.create()`, and the result to [finish_webauthn_registration].
+///
+/// This is where the user has to prove their identity again. Finishing only works with a
+/// challenge from here, so it doesn't need to check again.
+pub async fn start_webauthn_registration(
+  Json(data): Json<StartWebauthnRegistration>,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<StartWebauthnRegistrationResponse>> {
+  check_local_user_valid(&local_user_view)?;
+  check_reauthentication(
+    &local_user_view,
+    &data.password,
+    &data.totp_2fa_token,
+    &data.webauthn_challenge_id,
+    &data.webauthn_credential,
+    &data.recovery_code,
+    &context,
+  )
+  .await?;
+  let local_user_id = local_user_view.local_user.id;
+
+  // Dont allow registering the same authenticator twice
+  let exclude_credentials = read_passkeys(local_user_id, &context)
+    .await?
+    .into_iter()
+    .map(|(_, passkey)| passkey.cred_id().clone())
+    .collect();
+  let name = &local_user_view.person.name;
+  let display_name = local_user_view.person.display_name.as_ref().unwrap_or(name);
+  let (options, state) = build_webauthn(&context)?.start_passkey_registration(
+    user_unique_id(local_user_id)?,
+    name,
+    display_name,
+    Some(exclude_credentials),
+  )?;
+
+  let form = WebauthnChallengeInsertForm::new(local_user_id, serde_json::to_string(&state)?);
+  let challenge = WebauthnChallenge::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(StartWebauthnRegistrationResponse {
+    challenge_id: challenge.id.to_string(),
+    options,
+  }))
+}
@@ -0,0 +1,66 @@
+use super::check_authorization_request;
+use actix_web::web::{Data, Json, Query};
+use chrono::{TimeDelta, Utc};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::{
+  source::oauth_application::{OAuthAuthorizationCode, OAuthAuthorizationCodeInsertForm},
+  utils::hash_token,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{OAuthAuthorize, OAuthAuthorizeResponse, OAuthConsentResponse};
+use lemmy_utils::error::LemmyResult;
+use url::Url;
+
+/// How long an app has to exchange the authorization code for tokens.
+const AUTHORIZATION_CODE_EXPIRES_IN: TimeDelta = TimeDelta::minutes(10);
+
+/// Returns the data which the frontend shows on the consent screen.
+pub async fn get_oauth_consent(
+  Query(data): Query<OAuthAuthorize>,
+  context: Data<LemmyContext>,
+  _local_user_view: LocalUserView,
+) -> LemmyResult<Json<OAuthConsentResponse>> {
+  let (oauth_application, scope) = check_authorization_request(&data, &context).await?;
+  let creator = LocalUserView::read(&mut context.pool(), oauth_application.local_user_id)
+    .await?
+    .person;
+
+  Ok(Json(OAuthConsentResponse {
+    oauth_application,
+    creator,
+    scopes: scope.scopes,
+    moderate_community_ids: scope.moderate_community_ids,
+  }))
+}
+
+/// Called when the user approves the app. Returns the redirect back to the app, with a code that
+/// it can exchange for tokens.
+pub async fn approve_oauth_authorization(
+  Json(data): Json<OAuthAuthorize>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<OAuthAuthorizeResponse>> {
+  let (oauth_application, _) = check_authorization_request(&data, &context).await?;
+
+  let code = OAuthAuthorizationCode::generate_code();
+  let form = OAuthAuthorizationCodeInsertForm::new(
+    hash_token(&code),
+    oauth_application.id,
+    local_user_view.local_user.id,
+    data.redirect_uri.clone(),
+    data.scope.unwrap_or_default().trim().to_string(),
+    data.code_challenge,
+    Utc::now() + AUTHORIZATION_CODE_EXPIRES_IN,
+  );
+  OAuthAuthorizationCode::create(&mut context.pool(), &form).await?;
+
+  let mut redirect_url = Url::parse(&data.redirect_uri)?;
+  redirect_url.query_pairs_mut().append_pair("code", &code);
+  if let Some(state) = &data.state {
+    redirect_url.query_pairs_mut().append_pair("state", state);
+  }
+
+  Ok(Json(OAuthAuthorizeResponse {
+    redirect_url: redirect_url.into(),
+  }))
+}
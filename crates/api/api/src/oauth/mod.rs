@@ -0,0 +1,40 @@
+//! Lemmy as OAuth 2.0 provider, so that third party apps can get restricted access to user
+//! accounts. Access tokens are short lived personal access tokens, which means that their scopes
+//! are enforced in the same way.
+
+use lemmy_api_utils::{access_token::OAuthScope, context::LemmyContext};
+use lemmy_db_schema::source::oauth_application::OAuthApplication;
+use lemmy_db_views_site::api::OAuthAuthorize;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub mod authorize;
+pub mod revoke;
+pub mod token;
+pub mod userinfo;
+
+/// Checks the parameters of an authorization request, before the user is asked for consent and
+/// again when they approve it.
+async fn check_authorization_request(
+  data: &OAuthAuthorize,
+  context: &LemmyContext,
+) -> LemmyResult<(OAuthApplication, OAuthScope)> {
+  if data.response_type != "code" {
+    Err(LemmyErrorType::UnsupportedResponseType)?
+  }
+  let application =
+    OAuthApplication::read_from_client_id(&mut context.pool(), &data.client_id).await?;
+  if !application.redirect_uris.contains(&data.redirect_uri) {
+    Err(LemmyErrorType::InvalidRedirectUri)?
+  }
+
+  // Public clients can't authenticate, so they have to use PKCE. Only S256 is supported, as the
+  // plain method doesn't protect against anything.
+  match (&data.code_challenge, data.code_challenge_method.as_deref()) {
+    (Some(_), Some("S256")) => {}
+    (None, None) if application.client_secret_hash.is_some() => {}
+    _ => Err(LemmyErrorType::InvalidCodeVerifier)?,
+  }
+
+  let scope = OAuthScope::parse(data.scope.as_deref().unwrap_or_default())?;
+  Ok((application, scope))
+}
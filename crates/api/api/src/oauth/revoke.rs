@@ -0,0 +1,27 @@
+use actix_web::web::{Data, Form, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::{
+  oauth_application::{OAuthApplication, OAuthRefreshToken},
+  personal_access_token::PersonalAccessToken,
+};
+use lemmy_db_views_site::api::{RevokeOAuthToken, SuccessResponse};
+use lemmy_utils::error::LemmyResult;
+
+/// Lets an app revoke its access or refresh token, for example when the user logs out.
+pub async fn revoke_oauth_token(
+  Form(data): Form<RevokeOAuthToken>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let pool = &mut context.pool();
+  let application = OAuthApplication::read_from_client_id(pool, &data.client_id).await?;
+  application.check_client_secret(data.client_secret.as_deref())?;
+
+  // The type hint is optional and may be wrong, so try both kinds of token. Unknown tokens are no
+  // error, as required by RFC 7009.
+  PersonalAccessToken::revoke_for_application(pool, &data.token, application.id).await?;
+  OAuthRefreshToken::consume(pool, &data.token, application.id)
+    .await
+    .ok();
+
+  Ok(Json(SuccessResponse::default()))
+}
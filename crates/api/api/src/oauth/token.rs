@@ -0,0 +1,97 @@
+use actix_web::web::{Data, Form, Json};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{TimeDelta, Utc};
+use lemmy_api_utils::{access_token::OAuthScope, context::LemmyContext};
+use lemmy_db_schema::{
+  source::{
+    oauth_application::{
+      OAuthApplication,
+      OAuthAuthorizationCode,
+      OAuthRefreshToken,
+      OAuthRefreshTokenInsertForm,
+    },
+    personal_access_token::{PersonalAccessToken, PersonalAccessTokenInsertForm},
+  },
+  utils::hash_token,
+};
+use lemmy_db_views_site::api::{OAuthToken, OAuthTokenResponse};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::check_code_verifier,
+};
+use sha2::{Digest, Sha256};
+
+/// Lifetime of access tokens in seconds. Apps use their refresh token to get a new one.
+const ACCESS_TOKEN_EXPIRES_IN: i64 = 3600;
+/// Apps which aren't used for this long need to be approved again.
+const REFRESH_TOKEN_EXPIRES_IN: TimeDelta = TimeDelta::days(30);
+
+/// The token endpoint, which exchanges an authorization code or a refresh token for a new access
+/// token and refresh token.
+pub async fn oauth_token(
+  Form(data): Form<OAuthToken>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<OAuthTokenResponse>> {
+  let pool = &mut context.pool();
+  let application = OAuthApplication::read_from_client_id(pool, &data.client_id).await?;
+  application.check_client_secret(data.client_secret.as_deref())?;
+
+  let (local_user_id, scope) = match data.grant_type.as_str() {
+    "authorization_code" => {
+      let code = data.code.ok_or(LemmyErrorType::InvalidGrant)?;
+      let code = OAuthAuthorizationCode::consume(pool, &code, application.id).await?;
+      if data.redirect_uri.as_ref() != Some(&code.redirect_uri) {
+        Err(LemmyErrorType::InvalidGrant)?
+      }
+      if let Some(code_challenge) = &code.code_challenge {
+        let code_verifier = data
+          .code_verifier
+          .ok_or(LemmyErrorType::InvalidCodeVerifier)?;
+        check_code_verifier(&code_verifier)?;
+        if URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier)) != *code_challenge {
+          Err(LemmyErrorType::InvalidGrant)?
+        }
+      }
+      (code.local_user_id, code.scope)
+    }
+    "refresh_token" => {
+      let refresh_token = data.refresh_token.ok_or(LemmyErrorType::InvalidGrant)?;
+      let refresh_token = OAuthRefreshToken::consume(pool, &refresh_token, application.id).await?;
+      (refresh_token.local_user_id, refresh_token.scope)
+    }
+    _ => Err(LemmyErrorType::UnsupportedGrantType)?,
+  };
+
+  let parsed_scope = OAuthScope::parse(&scope)?;
+  let access_token = PersonalAccessToken::generate_token();
+  let access_token_form = PersonalAccessTokenInsertForm {
+    moderate_community_ids: parsed_scope.moderate_community_ids,
+    expires_at: Some(Utc::now() + TimeDelta::seconds(ACCESS_TOKEN_EXPIRES_IN)),
+    oauth_application_id: Some(application.id),
+    ..PersonalAccessTokenInsertForm::new(
+      local_user_id,
+      application.name,
+      hash_token(&access_token),
+      parsed_scope.scopes,
+    )
+  };
+  PersonalAccessToken::create(pool, &access_token_form).await?;
+
+  let refresh_token = OAuthRefreshToken::generate_token();
+  let refresh_token_form = OAuthRefreshTokenInsertForm::new(
+    hash_token(&refresh_token),
+    application.id,
+    local_user_id,
+    scope.clone(),
+    Utc::now() + REFRESH_TOKEN_EXPIRES_IN,
+  );
+  OAuthRefreshToken::create(pool, &refresh_token_form).await?;
+
+  Ok(Json(OAuthTokenResponse {
+    access_token,
+    token_type: "Bearer".to_string(),
+    expires_in: ACCESS_TOKEN_EXPIRES_IN,
+    refresh_token,
+    scope,
+  }))
+}
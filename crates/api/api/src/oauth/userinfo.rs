@@ -0,0 +1,20 @@
+use actix_web::web::Json;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::OAuthUserInfoResponse;
+use lemmy_utils::error::LemmyResult;
+
+/// The OpenID Connect userinfo endpoint.
+#[allow(clippy::unused_async)]
+pub async fn oauth_userinfo(
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<OAuthUserInfoResponse>> {
+  let person = local_user_view.person;
+
+  Ok(Json(OAuthUserInfoResponse {
+    sub: person.ap_id.to_string(),
+    preferred_username: person.name,
+    name: person.display_name,
+    picture: person.avatar.map(|avatar| avatar.to_string()),
+    profile: person.ap_id.to_string(),
+  }))
+}
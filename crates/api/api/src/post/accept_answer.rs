@@ -0,0 +1,71 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  build_response::build_post_response,
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, check_community_user_action},
+};
+use lemmy_db_schema::source::{
+  comment::Comment,
+  post::{Post, PostUpdateForm},
+};
+use lemmy_db_schema_file::enums::ModeratorPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::{
+  PostView,
+  api::{AcceptPostAnswer, PostResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use std::ops::Deref;
+
+pub async fn accept_post_answer(
+  Json(data): Json<AcceptPostAnswer>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  let post_id = data.post_id;
+  let orig_post = PostView::read(
+    &mut context.pool(),
+    post_id,
+    Some(&local_user_view.local_user),
+    local_user_view.person.instance_id,
+    false,
+  )
+  .await?;
+  let community = orig_post.community;
+
+  check_community_user_action(&local_user_view, &community, &mut context.pool()).await?;
+
+  // The post creator can pick the answer, otherwise it needs a mod
+  if local_user_view.person.id != orig_post.creator.id {
+    check_community_mod_action(
+      &local_user_view,
+      &community,
+      false,
+      Some(ModeratorPermission::RemoveContent),
+      &mut context.pool(),
+    )
+    .await?;
+  }
+
+  if let Some(comment_id) = data.comment_id {
+    let comment = Comment::read(&mut context.pool(), comment_id).await?;
+    if comment.post_id != post_id || comment.deleted || comment.removed {
+      Err(LemmyErrorType::InvalidAcceptedAnswer)?
+    }
+  }
+
+  let form = PostUpdateForm {
+    accepted_answer_id: Some(data.comment_id),
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  let updated_post = Post::update(&mut context.pool(), post_id, &form).await?;
+
+  ActivityChannel::submit_activity(SendActivityData::UpdatePost(updated_post), &context)?;
+
+  build_post_response(context.deref(), community.id, local_user_view, post_id).await
+}
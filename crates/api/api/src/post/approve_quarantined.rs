@@ -0,0 +1,91 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  build_response::build_post_response,
+  context::LemmyContext,
+  notify::{NotifyData, notify_mod_action},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::source::{
+  community::Community,
+  modlog::{Modlog, ModlogInsertForm},
+  person::Person,
+  post::{Post, PostUpdateForm},
+};
+use lemmy_db_schema_file::enums::ModeratorPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::api::{ApproveQuarantinedPost, PostResponse};
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+pub async fn approve_quarantined_post(
+  Json(data): Json<ApproveQuarantinedPost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  let post_id = data.post_id;
+  let orig_post = Post::read(&mut context.pool(), post_id).await?;
+  if !orig_post.quarantined || orig_post.removed {
+    Err(LemmyErrorType::NotQuarantined)?
+  }
+  let community = Community::read(&mut context.pool(), orig_post.community_id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::RemoveContent),
+    &mut context.pool(),
+  )
+  .await?;
+
+  if data.approve {
+    let post = Post::update(
+      &mut context.pool(),
+      post_id,
+      &PostUpdateForm {
+        quarantined: Some(false),
+        ..Default::default()
+      },
+    )
+    .await?;
+
+    // Send out the notifications and the activity which were held back on creation. Scheduled
+    // posts are federated by the scheduled task once they are published.
+    let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+    let creator = Person::read(&mut context.pool(), post.creator_id).await?;
+    NotifyData {
+      do_send_email: !local_site.disable_email_notifications,
+      ..NotifyData::new(post.clone(), creator, community.clone())
+    }
+    .send(&context);
+
+    if post.scheduled_publish_time_at.is_none() {
+      ActivityChannel::submit_activity(SendActivityData::CreatePost(post), &context)?;
+    }
+  } else {
+    let reason = data.deny_reason.clone().unwrap_or_default();
+    is_valid_body_field(&reason, false)?;
+
+    // The post stays quarantined, so the removal isn't federated either
+    let post = Post::update(
+      &mut context.pool(),
+      post_id,
+      &PostUpdateForm {
+        removed: Some(true),
+        ..Default::default()
+      },
+    )
+    .await?;
+
+    let form = ModlogInsertForm::mod_remove_post(local_user_view.person.id, &post, true, &reason);
+    let action = Modlog::create(&mut context.pool(), &[form]).await?;
+    notify_mod_action(action, &context);
+  }
+
+  build_post_response(&context, community.id, local_user_view, post_id).await
+}
@@ -4,7 +4,7 @@ use lemmy_api_utils::{
   build_response::build_post_response,
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_mod_action, is_admin},
+  utils::{check_admin_action, check_community_mod_action},
 };
 use lemmy_db_schema::{
   PostFeatureType,
@@ -14,6 +14,7 @@ use lemmy_db_schema::{
     post::{Post, PostUpdateForm},
   },
 };
+use lemmy_db_schema_file::enums::{AdminPermission, ModeratorPermission};
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_post::api::{FeaturePost, PostResponse};
 use lemmy_diesel_utils::traits::Crud;
@@ -28,10 +29,25 @@ pub async fn feature_post(
   let orig_post = Post::read(&mut context.pool(), post_id).await?;
 
   let community = Community::read(&mut context.pool(), orig_post.community_id).await?;
-  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::RemoveContent),
+    &mut context.pool(),
+  )
+  .await?;
 
   if data.feature_type == PostFeatureType::Local {
-    is_admin(&local_user_view)?;
+    check_admin_action(
+      &local_user_view,
+      Some(AdminPermission::EditSite),
+      "FeaturePost",
+      Some(data.post_id.0),
+      &data,
+      &mut context.pool(),
+    )
+    .await?;
   }
 
   // Update the post
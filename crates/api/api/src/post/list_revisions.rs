@@ -0,0 +1,80 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_edit_history_visible, check_private_instance, diff_lines, is_mod_or_admin_opt},
+};
+use lemmy_db_schema::source::{post::Post, revision::PostRevision};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::{
+  PostView,
+  api::{ListPostRevisions, ListPostRevisionsResponse, PostRevisionDiff},
+};
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use std::iter::once;
+
+/// Lists the previous versions of a post
+pub async fn list_post_revisions(
+  Query(data): Query<ListPostRevisions>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListPostRevisionsResponse>> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  let local_site = site_view.local_site;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let post = Post::read(&mut context.pool(), data.post_id).await?;
+  let is_mod_or_admin = is_mod_or_admin_opt(
+    &mut context.pool(),
+    local_user_view.as_ref(),
+    Some(post.community_id),
+  )
+  .await
+  .is_ok();
+
+  // Make sure that the post itself is visible to the user
+  let local_user = local_user_view.as_ref().map(|l| l.local_user.clone());
+  PostView::read(
+    &mut context.pool(),
+    post.id,
+    local_user.as_ref(),
+    site_view.site.instance_id,
+    is_mod_or_admin,
+  )
+  .await?;
+  // The old versions of removed or deleted content are only shown to mods and admins
+  if (post.removed || post.deleted) && !is_mod_or_admin {
+    Err(LemmyErrorType::NotFound)?
+  }
+  check_edit_history_visible(
+    &local_site,
+    local_user_view.as_ref(),
+    post.creator_id,
+    is_mod_or_admin,
+  )?;
+
+  let revisions = PostRevision::list(&mut context.pool(), post.id).await?;
+
+  // Each revision is compared with the version which replaced it
+  let next_versions = revisions
+    .iter()
+    .skip(1)
+    .map(|r| (r.name.clone(), r.body.clone()))
+    .chain(once((post.name, post.body)))
+    .collect::<Vec<_>>();
+  let revisions = revisions
+    .into_iter()
+    .zip(next_versions)
+    .map(|(revision, (name, body))| PostRevisionDiff {
+      name_diff: diff_lines(&revision.name, &name),
+      body_diff: diff_lines(
+        revision.body.as_deref().unwrap_or_default(),
+        body.as_deref().unwrap_or_default(),
+      ),
+      revision,
+    })
+    .collect();
+
+  Ok(Json(ListPostRevisionsResponse { revisions }))
+}
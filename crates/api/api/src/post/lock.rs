@@ -11,6 +11,7 @@ use lemmy_db_schema::source::{
   modlog::{Modlog, ModlogInsertForm},
   post::{Post, PostUpdateForm},
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_post::{
   PostView,
@@ -40,6 +41,7 @@ pub async fn lock_post(
     &local_user_view,
     &orig_post.community,
     false,
+    Some(ModeratorPermission::RemoveContent),
     &mut context.pool(),
   )
   .await?;
@@ -1,11 +1,16 @@
+pub mod accept_answer;
+pub mod approve_quarantined;
 pub mod feature;
 pub mod get_link_metadata;
 pub mod hide;
 pub mod like;
 pub mod list_post_likes;
+pub mod list_revisions;
 pub mod lock;
 pub mod mark_many_read;
 pub mod mark_read;
 pub mod mod_update;
+pub mod pending;
+pub mod rsvp;
 pub mod save;
 pub mod update_notifications;
@@ -7,13 +7,14 @@ use lemmy_api_utils::{
   plugins::{plugin_hook_after, plugin_hook_before},
   send_activity::{ActivityChannel, SendActivityData},
   utils::{
+    check_community_mod_action,
     check_community_user_action,
-    check_is_mod_or_admin,
     check_nsfw_allowed,
     update_post_tags,
   },
 };
 use lemmy_db_schema::source::post::{Post, PostUpdateForm};
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_post::{
   PostView,
@@ -46,7 +47,14 @@ pub async fn mod_update_post(
   let community = orig_post.community;
 
   check_community_user_action(&local_user_view, &community, &mut context.pool()).await?;
-  check_is_mod_or_admin(&mut context.pool(), local_user_view.person.id, community.id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::RemoveContent),
+    &mut context.pool(),
+  )
+  .await?;
 
   let mut post_form = PostUpdateForm {
     nsfw: data.nsfw,
@@ -60,7 +68,7 @@ pub async fn mod_update_post(
   plugin_hook_after("local_post_after_vote", &post_form);
 
   if let Some(tags) = &data.tags {
-    update_post_tags(&updated_post, tags, &context).await?;
+    update_post_tags(&updated_post, tags, true, &context).await?;
   }
 
   ActivityChannel::submit_activity(SendActivityData::UpdatePost(updated_post.clone()), &context)?;
@@ -0,0 +1,98 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  build_response::build_post_response,
+  context::LemmyContext,
+  notify::{NotifyData, notify_mod_action},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::source::{
+  activity::PendingPostActivity,
+  community::Community,
+  modlog::{Modlog, ModlogInsertForm},
+  person::Person,
+  post::{Post, PostUpdateForm},
+};
+use lemmy_db_schema_file::enums::ModeratorPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::api::{ApprovePendingPost, PostResponse};
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+pub async fn approve_pending_post(
+  Json(data): Json<ApprovePendingPost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  let post_id = data.post_id;
+  let orig_post = Post::read(&mut context.pool(), post_id).await?;
+  if !orig_post.pending_approval || orig_post.removed {
+    Err(LemmyErrorType::NotPendingApproval)?
+  }
+  let community = Community::read(&mut context.pool(), orig_post.community_id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::RemoveContent),
+    &mut context.pool(),
+  )
+  .await?;
+
+  let reason = data.deny_reason.clone().unwrap_or_default();
+  let post = if data.approve {
+    let post = Post::update(
+      &mut context.pool(),
+      post_id,
+      &PostUpdateForm {
+        pending_approval: Some(false),
+        ..Default::default()
+      },
+    )
+    .await?;
+
+    // Send out the notifications and the activity which were held back on creation. Posts of
+    // quarantined persons still need to be approved separately.
+    if !post.quarantined {
+      let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+      let creator = Person::read(&mut context.pool(), post.creator_id).await?;
+      NotifyData {
+        do_send_email: !local_site.disable_email_notifications,
+        ..NotifyData::new(post.clone(), creator, community.clone())
+      }
+      .send(&context);
+    }
+
+    if post.scheduled_publish_time_at.is_none() {
+      ActivityChannel::submit_activity(SendActivityData::CreatePost(post.clone()), &context)?;
+    }
+    post
+  } else {
+    is_valid_body_field(&reason, false)?;
+
+    // The post stays pending, so the removal isn't federated either
+    PendingPostActivity::take(&mut context.pool(), post_id).await?;
+    Post::update(
+      &mut context.pool(),
+      post_id,
+      &PostUpdateForm {
+        removed: Some(true),
+        ..Default::default()
+      },
+    )
+    .await?
+  };
+
+  // Let the author know about the decision
+  let form =
+    ModlogInsertForm::mod_approve_post(local_user_view.person.id, &post, data.approve, &reason);
+  let action = Modlog::create(&mut context.pool(), &[form]).await?;
+  notify_mod_action(action, &context);
+
+  build_post_response(&context, community.id, local_user_view, post_id).await
+}
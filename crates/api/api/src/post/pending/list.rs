@@ -0,0 +1,48 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_of_any_or_admin_action, is_mod_or_admin},
+};
+use lemmy_db_schema_file::enums::{ListingType, PostSortType};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::{PostView, api::ListPendingPosts, impls::PostQuery};
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::pagination::PagedResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_pending_posts(
+  Query(data): Query<ListPendingPosts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<PostView>>> {
+  check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+  if let Some(community_id) = data.community_id {
+    is_mod_or_admin(&mut context.pool(), &local_user_view, community_id).await?;
+  }
+  let all_communities = (data.all_communities.unwrap_or_default() || data.community_id.is_some())
+    && local_user_view.local_user.admin;
+  let listing_type = if all_communities {
+    ListingType::All
+  } else {
+    ListingType::ModeratorView
+  };
+  let site = SiteView::read_local(&mut context.pool()).await?.site;
+
+  let posts = PostQuery {
+    local_user: Some(&local_user_view.local_user),
+    listing_type: Some(listing_type),
+    sort: Some(PostSortType::Old),
+    community_id: data.community_id,
+    show_hidden: Some(true),
+    show_read: Some(true),
+    show_nsfw: Some(true),
+    pending_approval_only: Some(true),
+    page_cursor: data.page_cursor,
+    limit: data.limit,
+    ..Default::default()
+  }
+  .list(&site, &mut context.pool())
+  .await?;
+
+  Ok(Json(posts))
+}
@@ -0,0 +1,54 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_user_action, check_local_user_valid},
+};
+use lemmy_db_schema::source::post::{PostActions, PostEventRsvpForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::{
+  PostView,
+  api::{PostResponse, RsvpPostEvent},
+};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn rsvp_post_event(
+  Json(data): Json<RsvpPostEvent>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  check_local_user_valid(&local_user_view)?;
+  let post_id = data.post_id;
+  let person_id = local_user_view.person.id;
+  let local_instance_id = local_user_view.person.instance_id;
+
+  let orig_post = PostView::read(
+    &mut context.pool(),
+    post_id,
+    Some(&local_user_view.local_user),
+    local_instance_id,
+    false,
+  )
+  .await?;
+  check_community_user_action(&local_user_view, &orig_post.community, &mut context.pool()).await?;
+  if orig_post.event.is_none() {
+    Err(LemmyErrorType::PostIsNotAnEvent)?
+  }
+
+  if let Some(rsvp) = data.rsvp {
+    let form = PostEventRsvpForm::new(post_id, person_id, rsvp);
+    PostActions::rsvp(&mut context.pool(), &form).await?;
+  } else {
+    PostActions::remove_rsvp(&mut context.pool(), post_id, person_id).await?;
+  }
+
+  let post_view = PostView::read(
+    &mut context.pool(),
+    post_id,
+    Some(&local_user_view.local_user),
+    local_instance_id,
+    false,
+  )
+  .await?;
+
+  Ok(Json(PostResponse { post_view }))
+}
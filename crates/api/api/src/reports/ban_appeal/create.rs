@@ -0,0 +1,75 @@
+use crate::check_report_reason;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_local_user_deleted, slur_regex},
+};
+use lemmy_db_schema::source::{
+  ban_appeal::{BanAppeal, BanAppealInsertForm},
+  community::Community,
+  modlog::Modlog,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{
+  ReportCombinedViewInternal,
+  api::{BanAppealResponse, CreateBanAppeal},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn create_ban_appeal(
+  Json(data): Json<CreateBanAppeal>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BanAppealResponse>> {
+  // Banned users are the only ones who can appeal, so dont use check_local_user_valid()
+  check_local_user_deleted(&local_user_view)?;
+  let reason = data.reason.trim().to_string();
+  let slur_regex = slur_regex(&context).await?;
+  check_report_reason(&reason, &slur_regex)?;
+
+  let person = &local_user_view.person;
+  let community = match data.community_id {
+    Some(community_id) => Some(Community::read(&mut context.pool(), community_id).await?),
+    None => None,
+  };
+
+  let ban = Modlog::read_active_ban(
+    &mut context.pool(),
+    person.id,
+    data.community_id,
+    person.instance_id,
+  )
+  .await?
+  .ok_or(LemmyErrorType::NotBanned)?;
+  if BanAppeal::read_for_ban(&mut context.pool(), ban.id)
+    .await?
+    .is_some()
+  {
+    Err(LemmyErrorType::BanAlreadyAppealed)?
+  }
+
+  let form = BanAppealInsertForm::new(person.id, data.community_id, ban.id, reason.clone());
+  let appeal = BanAppeal::create(&mut context.pool(), &form).await?;
+
+  let ban_appeal_view =
+    ReportCombinedViewInternal::read_ban_appeal(&mut context.pool(), appeal.id, person).await?;
+
+  // Appeals against bans in remote communities are handled by the community's instance
+  if let Some(community) = community
+    && !community.local
+  {
+    ActivityChannel::submit_activity(
+      SendActivityData::AppealBan {
+        actor: local_user_view.person,
+        community,
+        reason,
+      },
+      &context,
+    )?;
+  }
+
+  Ok(Json(BanAppealResponse { ban_appeal_view }))
+}
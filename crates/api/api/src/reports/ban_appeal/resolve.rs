@@ -0,0 +1,191 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_admin_action, check_community_mod_action, check_expire_time},
+};
+use lemmy_db_schema::{
+  source::{
+    ban_appeal::{BanAppeal, BanAppealUpdateForm},
+    community::{Community, CommunityActions, CommunityPersonBanForm},
+    instance::{InstanceActions, InstanceBanForm},
+    modlog::{Modlog, ModlogInsertForm},
+    person::Person,
+  },
+  traits::Bannable,
+};
+use lemmy_db_schema_file::enums::{
+  AdminPermission, BanAppealDecision, ModeratorPermission, ModlogKind,
+};
+use lemmy_db_views_community::api::BanFromCommunity;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{
+  ReportCombinedViewInternal,
+  api::{BanAppealResponse, ResolveBanAppeal},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+pub async fn resolve_ban_appeal(
+  Json(data): Json<ResolveBanAppeal>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BanAppealResponse>> {
+  let my_person = &local_user_view.person;
+  let appeal = BanAppeal::read(&mut context.pool(), data.appeal_id).await?;
+
+  // Community bans are reviewed by the community mods, site bans by the admins
+  let community = match appeal.community_id {
+    Some(community_id) => {
+      let community = Community::read(&mut context.pool(), community_id).await?;
+      check_community_mod_action(
+        &local_user_view,
+        &community,
+        false,
+        Some(ModeratorPermission::ManageBans),
+        &mut context.pool(),
+      )
+      .await?;
+      Some(community)
+    }
+    None => {
+      check_admin_action(
+        &local_user_view,
+        Some(AdminPermission::HandleReports),
+        "ResolveBanAppeal",
+        Some(data.appeal_id.0),
+        &data,
+        &mut context.pool(),
+      )
+      .await?;
+      None
+    }
+  };
+  if appeal.resolved {
+    Err(LemmyErrorType::BanAppealAlreadyResolved)?
+  }
+  if let Some(reason) = &data.reason {
+    is_valid_body_field(reason, false)?;
+  }
+
+  let appeal_view =
+    ReportCombinedViewInternal::read_ban_appeal(&mut context.pool(), appeal.id, my_person).await?;
+  let decision = data.decision;
+  let expires_at = if decision == BanAppealDecision::Shortened {
+    let expires_at =
+      check_expire_time(data.expires_at)?.ok_or(LemmyErrorType::ShortenedBanMustExpireEarlier)?;
+    if appeal_view.ban.expires_at.is_some_and(|e| expires_at >= e) {
+      Err(LemmyErrorType::ShortenedBanMustExpireEarlier)?
+    }
+    Some(expires_at)
+  } else {
+    None
+  };
+
+  // Apply the decision to the ban itself
+  let mut actions = vec![];
+  let banned_person = Person::read(&mut context.pool(), appeal.creator_id).await?;
+  let reason = data.reason.clone().unwrap_or_default();
+  if decision != BanAppealDecision::Upheld {
+    let ban = decision == BanAppealDecision::Shortened;
+    if let Some(community) = &community {
+      let form = CommunityPersonBanForm {
+        ban_expires_at: Some(expires_at),
+        ..CommunityPersonBanForm::new(community.id, banned_person.id)
+      };
+      if ban {
+        CommunityActions::ban(&mut context.pool(), &form).await?;
+      } else {
+        CommunityActions::unban(&mut context.pool(), &form).await?;
+        actions.push(ModlogInsertForm::mod_ban_from_community(
+          my_person.id,
+          community.id,
+          banned_person.id,
+          false,
+          None,
+          &reason,
+        ));
+      }
+
+      ActivityChannel::submit_activity(
+        SendActivityData::BanFromCommunity {
+          moderator: my_person.clone(),
+          community_id: community.id,
+          target: banned_person.clone(),
+          data: BanFromCommunity {
+            community_id: community.id,
+            person_id: banned_person.id,
+            ban,
+            remove_or_restore_data: None,
+            reason: reason.clone(),
+            expires_at: data.expires_at,
+          },
+        },
+        &context,
+      )?;
+    } else {
+      let form = InstanceBanForm::new(banned_person.id, my_person.instance_id, expires_at);
+      if ban {
+        InstanceActions::ban(&mut context.pool(), &form).await?;
+      } else {
+        InstanceActions::unban(&mut context.pool(), &form).await?;
+        actions.push(ModlogInsertForm::admin_ban(
+          my_person,
+          banned_person.id,
+          false,
+          None,
+          &reason,
+        ));
+      }
+
+      ActivityChannel::submit_activity(
+        SendActivityData::BanFromSite {
+          moderator: my_person.clone(),
+          banned_user: banned_person.clone(),
+          reason: reason.clone(),
+          remove_or_restore_data: None,
+          ban,
+          expires_at: data.expires_at,
+        },
+        &context,
+      )?;
+    }
+  }
+
+  let form = BanAppealUpdateForm {
+    resolved: Some(true),
+    resolver_id: Some(Some(my_person.id)),
+    decision: Some(Some(decision)),
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  let appeal = BanAppeal::update(&mut context.pool(), appeal.id, &form).await?;
+
+  // Mod tables
+  actions.push(ModlogInsertForm::mod_resolve_ban_appeal(
+    my_person.id,
+    &appeal,
+    decision,
+    expires_at,
+    data.reason.as_deref(),
+  ));
+  let actions = Modlog::create(&mut context.pool(), &actions).await?;
+
+  // Notify the user about the decision, but not separately about the unban which is part of it
+  let decision_action = actions
+    .into_iter()
+    .filter(|a| a.kind == ModlogKind::ModResolveBanAppeal)
+    .collect();
+  notify_mod_action(decision_action, &context);
+
+  let ban_appeal_view =
+    ReportCombinedViewInternal::read_ban_appeal(&mut context.pool(), appeal.id, my_person).await?;
+
+  Ok(Json(BanAppealResponse { ban_appeal_view }))
+}
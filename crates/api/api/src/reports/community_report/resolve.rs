@@ -4,12 +4,13 @@ use either::Either;
 use lemmy_api_utils::{
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::is_admin,
+  utils::check_admin_action,
 };
 use lemmy_db_schema::{
   source::{community_report::CommunityReport, site::Site},
   traits::Reportable,
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_report_combined::{
   ReportCombinedViewInternal,
@@ -22,7 +23,15 @@ pub async fn resolve_community_report(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<CommunityReportResponse>> {
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::HandleReports),
+    "ResolveCommunityReport",
+    Some(data.report_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let report_id = data.report_id;
   let person = &local_user_view.person;
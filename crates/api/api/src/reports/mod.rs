@@ -1,3 +1,4 @@
+pub mod ban_appeal;
 pub mod comment_report;
 pub mod community_report;
 pub mod post_report;
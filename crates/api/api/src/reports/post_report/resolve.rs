@@ -7,6 +7,7 @@ use lemmy_api_utils::{
   utils::check_community_mod_action,
 };
 use lemmy_db_schema::{source::post_report::PostReport, traits::Reportable};
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_report_combined::{
   ReportCombinedViewInternal,
@@ -30,6 +31,7 @@ pub async fn resolve_post_report(
     &local_user_view,
     &report.community,
     true,
+    Some(ModeratorPermission::HandleReports),
     &mut context.pool(),
   )
   .await?;
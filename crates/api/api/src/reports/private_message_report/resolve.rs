@@ -1,6 +1,7 @@
 use actix_web::web::{Data, Json};
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
 use lemmy_db_schema::{source::private_message_report::PrivateMessageReport, traits::Reportable};
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_report_combined::{
   ReportCombinedViewInternal,
@@ -13,7 +14,15 @@ pub async fn resolve_pm_report(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<PrivateMessageReportResponse>> {
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::HandleReports),
+    "ResolvePrivateMessageReport",
+    Some(data.report_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let report_id = data.report_id;
   let person = &local_user_view.person;
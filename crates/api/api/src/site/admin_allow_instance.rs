@@ -1,11 +1,12 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
 use lemmy_db_schema::source::{
   federation_allowlist::{FederationAllowList, FederationAllowListForm},
   instance::Instance,
   modlog::{Modlog, ModlogInsertForm},
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::{FederatedInstanceView, api::AdminAllowInstanceParams};
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
@@ -15,7 +16,15 @@ pub async fn admin_allow_instance(
   local_user_view: LocalUserView,
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<FederatedInstanceView>> {
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::ManageFederation),
+    "AdminAllowInstance",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let blocklist = Instance::blocklist(&mut context.pool()).await?;
   if !blocklist.is_empty() {
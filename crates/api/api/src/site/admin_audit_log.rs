@@ -0,0 +1,29 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_permission};
+use lemmy_db_views_local_user::{
+  AdminAuditLogView,
+  LocalUserView,
+  api::ListAdminAuditLog,
+  impls::AdminAuditLogQuery,
+};
+use lemmy_diesel_utils::pagination::PagedResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_admin_audit_log(
+  Query(data): Query<ListAdminAuditLog>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<AdminAuditLogView>>> {
+  // Only admins holding all permissions can read the audit log
+  check_admin_permission(&local_user_view, None)?;
+
+  let entries = AdminAuditLogQuery {
+    admin_id: data.admin_id,
+    page_cursor: data.page_cursor,
+    limit: data.limit,
+  }
+  .list(&mut context.pool())
+  .await?;
+
+  Ok(Json(entries))
+}
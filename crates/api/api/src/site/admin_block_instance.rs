@@ -2,13 +2,14 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_utils::{
   context::LemmyContext,
-  utils::{check_expire_time, is_admin},
+  utils::{check_admin_action, check_expire_time},
 };
 use lemmy_db_schema::source::{
   federation_blocklist::{FederationBlockList, FederationBlockListForm},
   instance::Instance,
   modlog::{Modlog, ModlogInsertForm},
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::{FederatedInstanceView, api::AdminBlockInstanceParams};
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
@@ -18,7 +19,15 @@ pub async fn admin_block_instance(
   local_user_view: LocalUserView,
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<FederatedInstanceView>> {
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::ManageFederation),
+    "AdminBlockInstance",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let expires_at = check_expire_time(data.expires_at)?;
 
@@ -1,5 +1,6 @@
 use actix_web::web::{Data, Json, Query};
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::{LocalUserView, api::AdminListUsers, impls::LocalUserQuery};
 use lemmy_diesel_utils::pagination::PagedResponse;
 use lemmy_utils::error::LemmyResult;
@@ -10,10 +11,19 @@ pub async fn admin_list_users(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<PagedResponse<LocalUserView>>> {
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::HandleReports),
+    "AdminListUsers",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let users = LocalUserQuery {
     banned_only: data.banned_only,
+    invited_by_id: data.invited_by_id,
     page_cursor: data.page_cursor,
     limit: data.limit,
   }
@@ -1,5 +1,6 @@
 use actix_web::web::{Data, Json, Query};
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_image::{LocalImageView, api::ListMedia};
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_diesel_utils::pagination::PagedResponse;
@@ -11,7 +12,15 @@ pub async fn list_all_media(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<PagedResponse<LocalImageView>>> {
   // Only let admins view all media
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::Purge),
+    "ListMedia",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let images =
     LocalImageView::get_all_paged(&mut context.pool(), data.page_cursor, data.limit).await?;
@@ -1,4 +1,5 @@
 pub mod admin_allow_instance;
+pub mod admin_audit_log;
 pub mod admin_block_instance;
 pub mod admin_list_users;
 pub mod federated_instances;
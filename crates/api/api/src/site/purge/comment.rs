@@ -3,13 +3,14 @@ use actix_web::web::Json;
 use lemmy_api_utils::{
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::is_admin,
+  utils::check_admin_action,
 };
 use lemmy_db_schema::source::{
   comment::Comment,
   local_user::LocalUser,
   modlog::{Modlog, ModlogInsertForm},
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_comment::{CommentView, api::PurgeComment};
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::api::SuccessResponse;
@@ -22,7 +23,15 @@ pub async fn purge_comment(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
   // Only let admin purge an item
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::Purge),
+    "PurgeComment",
+    Some(data.comment_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let comment_id = data.comment_id;
   let local_instance_id = local_user_view.person.instance_id;
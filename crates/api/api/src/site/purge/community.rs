@@ -3,14 +3,14 @@ use actix_web::web::Json;
 use lemmy_api_utils::{
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::is_admin,
+  utils::check_admin_action,
 };
 use lemmy_db_schema::source::{
   community::Community,
   local_user::LocalUser,
   modlog::{Modlog, ModlogInsertForm},
 };
-use lemmy_db_schema_file::PersonId;
+use lemmy_db_schema_file::{PersonId, enums::AdminPermission};
 use lemmy_db_views_community::api::PurgeCommunity;
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use lemmy_db_views_local_user::LocalUserView;
@@ -24,7 +24,15 @@ pub async fn purge_community(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
   // Only let admin purge an item
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::Purge),
+    "PurgeCommunity",
+    Some(data.community_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Read the community to get its images
   let community = Community::read(&mut context.pool(), data.community_id).await?;
@@ -3,7 +3,7 @@ use actix_web::web::Json;
 use lemmy_api_utils::{
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{is_admin, purge_user_account},
+  utils::{check_admin_action, purge_user_account},
 };
 use lemmy_db_schema::{
   source::{
@@ -14,6 +14,7 @@ use lemmy_db_schema::{
   },
   traits::Bannable,
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_person::api::PurgePerson;
 use lemmy_db_views_site::api::SuccessResponse;
@@ -28,7 +29,15 @@ pub async fn purge_person(
   let local_instance_id = local_user_view.person.instance_id;
 
   // Only let admin purge an item
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::Purge),
+    "PurgePerson",
+    Some(data.person_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Also check that you're a higher admin
   LocalUser::is_higher_admin_check(
@@ -3,13 +3,14 @@ use actix_web::web::Json;
 use lemmy_api_utils::{
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{is_admin, purge_post_images},
+  utils::{check_admin_action, purge_post_images},
 };
 use lemmy_db_schema::source::{
   local_user::LocalUser,
   modlog::{Modlog, ModlogInsertForm},
   post::Post,
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_post::api::PurgePost;
 use lemmy_db_views_site::api::SuccessResponse;
@@ -22,7 +23,15 @@ pub async fn purge_post(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
   // Only let admin purge an item
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::Purge),
+    "PurgePost",
+    Some(data.post_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Read the post to get the community_id
   let post = Post::read(&mut context.pool(), data.post_id).await?;
@@ -2,11 +2,12 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use chrono::Utc;
 use diesel_async::scoped_futures::ScopedFutureExt;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
 use lemmy_db_schema::source::{
   local_user::{LocalUser, LocalUserUpdateForm},
   registration_application::{RegistrationApplication, RegistrationApplicationUpdateForm},
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_registration_applications::{
   RegistrationApplicationView,
@@ -24,7 +25,15 @@ pub async fn approve_registration_application(
   let app_id = data.id;
 
   // Only let admins do this
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::ReviewRegistrations),
+    "ApproveRegistrationApplication",
+    Some(data.id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let pool = &mut context.pool();
   let conn = &mut get_conn(pool).await?;
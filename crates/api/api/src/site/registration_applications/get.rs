@@ -1,5 +1,6 @@
 use actix_web::web::{Data, Json, Query};
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_registration_applications::{
   RegistrationApplicationView,
@@ -14,7 +15,15 @@ pub async fn get_registration_application(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<RegistrationApplicationResponse>> {
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::ReviewRegistrations),
+    "GetRegistrationApplication",
+    Some(data.person_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Read the view
   let registration_application =
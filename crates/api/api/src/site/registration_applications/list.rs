@@ -1,6 +1,7 @@
 use activitypub_federation::config::Data;
 use actix_web::web::{Json, Query};
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_registration_applications::{
   RegistrationApplicationView,
@@ -20,7 +21,15 @@ pub async fn list_registration_applications(
   let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
 
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::ReviewRegistrations),
+    "ListRegistrationApplications",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let registration_applications = RegistrationApplicationQuery {
     unread_only: data.unread_only,
@@ -80,10 +80,8 @@ async fn signup(
 
   let local_user = LocalUser::create(pool, &local_user_insert_form, vec![]).await?;
 
-  let application_insert_form = RegistrationApplicationInsertForm {
-    local_user_id: local_user.id,
-    answer: "x".to_string(),
-  };
+  let application_insert_form =
+    RegistrationApplicationInsertForm::new(local_user.id, "x".to_string());
   let application = RegistrationApplication::create(pool, &application_insert_form).await?;
 
   Ok((local_user, application))
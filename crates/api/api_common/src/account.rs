@@ -1,26 +1,79 @@
+pub use lemmy_db_schema::source::{content_filter::ContentFilter, draft::Draft};
+pub use lemmy_db_schema_file::enums::{ContentFilterAction, ContentFilterMatch, DraftKind};
 pub use lemmy_db_views_person_content_combined::api::{ListPersonHidden, ListPersonRead};
 pub use lemmy_db_views_person_liked_combined::ListPersonLiked;
 pub use lemmy_db_views_person_saved_combined::ListPersonSaved;
 pub use lemmy_db_views_post_comment_combined::PostCommentCombinedView;
-pub use lemmy_db_views_site::api::{DeleteAccount, MyUserInfo, SaveUserSettings};
+pub use lemmy_db_views_site::api::{
+  ContentFilterResponse,
+  CreateContentFilter,
+  CreateDraft,
+  DeleteAccount,
+  DeleteContentFilter,
+  DeleteDraft,
+  DraftResponse,
+  EditContentFilter,
+  EditDraft,
+  ListContentFiltersResponse,
+  ListDraftsResponse,
+  MyUserInfo,
+  PublishDraft,
+  PublishDraftResponse,
+  SaveUserSettings,
+};
 pub mod auth {
-  pub use lemmy_db_schema::source::login_token::LoginToken;
+  pub use lemmy_db_schema::source::{
+    login_token::LoginToken,
+    personal_access_token::PersonalAccessToken,
+    user_data_export::UserDataExport,
+    user_settings_import::UserSettingsImport,
+    webauthn_credential::WebauthnCredential,
+  };
+  pub use lemmy_db_schema_file::enums::{
+    ApiTokenScope,
+    DataExportStatus,
+    SettingsImportFormat,
+    SettingsImportStatus,
+  };
   pub use lemmy_db_views_registration_applications::api::Register;
   pub use lemmy_db_views_site::api::{
     CaptchaResponse,
     ChangePassword,
+    CreatePersonalAccessToken,
+    CreatePersonalAccessTokenResponse,
+    DeleteWebauthnCredential,
+    DownloadUserDataExport,
     ExportDataResponse,
+    FinishWebauthnRegistration,
+    GenerateRecoveryCodesResponse,
     GenerateTotpSecretResponse,
     GetCaptchaResponse,
+    ImportSettingsFile,
     ListLoginsResponse,
+    ListPersonalAccessTokensResponse,
+    ListUserDataExportsResponse,
+    ListUserSettingsImportsResponse,
+    ListWebauthnCredentialsResponse,
     Login,
     LoginResponse,
+    LoginSession,
     PasswordChangeAfterReset,
     PasswordReset,
+    RefreshLogin,
+    RenameWebauthnCredential,
     ResendVerificationEmail,
+    RevokeLogin,
+    RevokePersonalAccessToken,
+    StartWebauthnLogin,
+    StartWebauthnLoginResponse,
+    StartWebauthnRegistrationResponse,
     UpdateTotp,
     UpdateTotpResponse,
+    UserDataExportResponse,
     UserSettingsBackup,
+    UserSettingsImportResponse,
     VerifyEmail,
+    WebauthnCredentialResponse,
+    WebauthnLogin,
   };
 }
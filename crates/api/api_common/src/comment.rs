@@ -1,11 +1,23 @@
 pub use lemmy_db_schema::{
   newtypes::CommentId,
-  source::comment::{Comment, CommentActions, CommentInsertForm},
+  source::{
+    comment::{Comment, CommentActions, CommentInsertForm},
+    revision::CommentRevision,
+  },
 };
 pub use lemmy_db_views_comment::{
   CommentSlimView,
+  CommentTreeNode,
   CommentView,
-  api::{CommentResponse, GetComment, GetComments},
+  api::{
+    CommentResponse,
+    CommentRevisionDiff,
+    GetComment,
+    GetCommentTree,
+    GetComments,
+    ListCommentRevisions,
+    ListCommentRevisionsResponse,
+  },
 };
 
 pub mod actions {
@@ -19,8 +31,10 @@ pub mod actions {
 
   pub mod moderation {
     pub use lemmy_db_views_comment::api::{
+      ApproveQuarantinedComment,
       DistinguishComment,
       ListCommentLikes,
+      PinComment,
       PurgeComment,
       RemoveComment,
     };
@@ -3,10 +3,17 @@ pub use lemmy_db_schema::{
   source::{
     community::{Community, CommunityActions},
     multi_community::{MultiCommunity, MultiCommunityFollow},
+    recurring_post::RecurringPost,
     tag::{Tag, TagsView},
+    wiki::{WikiPage, WikiPageRevision, WikiPageRevisionView},
   },
 };
-pub use lemmy_db_schema_file::enums::CommunityVisibility;
+pub use lemmy_db_schema_file::enums::{
+  CommunityVisibility,
+  ModeratorPermission,
+  RecurringPostFrequency,
+  WikiEditPermission,
+};
 pub use lemmy_db_views_community::{
   CommunityView,
   MultiCommunityView,
@@ -20,10 +27,16 @@ pub use lemmy_db_views_community::{
     GetMultiCommunity,
     GetMultiCommunityResponse,
     GetRandomCommunity,
+    GetWikiPage,
     ListCommunities,
     ListMultiCommunities,
+    ListWikiPageRevisions,
+    ListWikiPageRevisionsResponse,
+    ListWikiPages,
+    ListWikiPagesResponse,
     UpdateCommunityNotifications,
     UpdateMultiCommunity,
+    WikiPageResponse,
   },
 };
 pub use lemmy_db_views_community_follower_approval::PendingFollowerView;
@@ -33,8 +46,11 @@ pub mod actions {
   pub use lemmy_db_views_community::api::{
     BlockCommunity,
     CreateCommunity,
+    EditUserFlair,
+    EditWikiPage,
     FollowCommunity,
     HideCommunity,
+    RevertWikiPage,
   };
 
   pub mod moderation {
@@ -42,14 +58,26 @@ pub mod actions {
     pub use lemmy_db_views_community::api::{
       AddModToCommunity,
       AddModToCommunityResponse,
+      AddWikiEditor,
       ApproveCommunityPendingFollower,
       BanFromCommunity,
       CommunityIdQuery,
       CreateCommunityTag,
+      CreateRecurringPost,
+      CreateWikiPage,
       DeleteCommunity,
       DeleteCommunityTag,
+      DeleteRecurringPost,
+      DeleteWikiPage,
       EditCommunity,
+      EditModeratorPermissions,
+      EditRecurringPost,
+      ListRecurringPosts,
+      ListRecurringPostsResponse,
+      ListWikiEditors,
+      ListWikiEditorsResponse,
       PurgeCommunity,
+      RecurringPostResponse,
       RemoveCommunity,
       TransferCommunity,
       UpdateCommunityTag,
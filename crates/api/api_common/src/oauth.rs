@@ -1,13 +1,25 @@
 pub use lemmy_db_schema::{
-  newtypes::OAuthProviderId,
+  newtypes::{OAuthApplicationId, OAuthProviderId},
   source::{
     oauth_account::OAuthAccount,
+    oauth_application::OAuthApplication,
     oauth_provider::{AdminOAuthProvider, PublicOAuthProvider},
   },
 };
 pub use lemmy_db_views_site::api::{
   AuthenticateWithOauth,
+  CreateOAuthApplication,
+  CreateOAuthApplicationResponse,
   CreateOAuthProvider,
+  DeleteOAuthApplication,
   DeleteOAuthProvider,
   EditOAuthProvider,
+  ListOAuthApplicationsResponse,
+  OAuthAuthorize,
+  OAuthAuthorizeResponse,
+  OAuthConsentResponse,
+  OAuthToken,
+  OAuthTokenResponse,
+  OAuthUserInfoResponse,
+  RevokeOAuthToken,
 };
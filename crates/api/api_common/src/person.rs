@@ -23,7 +23,7 @@ pub mod actions {
       newtypes::RegistrationApplicationId,
       source::registration_application::RegistrationApplication,
     };
-    pub use lemmy_db_views_person::api::{BanPerson, PurgePerson};
+    pub use lemmy_db_views_person::api::{BanPerson, PurgePerson, QuarantinePerson};
     pub use lemmy_db_views_registration_applications::{
       RegistrationApplicationView,
       api::{GetRegistrationApplication, RegistrationApplicationResponse},
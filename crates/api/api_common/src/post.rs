@@ -1,9 +1,14 @@
 pub use lemmy_db_schema::{
   PostFeatureType,
   newtypes::PostId,
-  source::post::{Post, PostActions, PostInsertForm, PostLikeForm},
+  source::{
+    post::{Post, PostActions, PostInsertForm, PostLikeForm},
+    post_event::PostEvent,
+    post_gallery::{PostGalleryItem, PostGalleryItemView, PostGalleryView},
+    revision::{DiffLine, PostRevision},
+  },
 };
-pub use lemmy_db_schema_file::enums::{PostListingMode, PostNotificationsMode};
+pub use lemmy_db_schema_file::enums::{PostEventRsvp, PostListingMode, PostNotificationsMode};
 pub use lemmy_db_views_post::{
   PostView,
   api::{
@@ -11,13 +16,20 @@ pub use lemmy_db_views_post::{
     GetSiteMetadata,
     GetSiteMetadataResponse,
     LinkMetadata,
+    ListPostRevisions,
+    ListPostRevisionsResponse,
     OpenGraphData,
+    PostEventInput,
+    PostGalleryImage,
     PostResponse,
+    PostRevisionDiff,
   },
 };
 pub use lemmy_db_views_search_combined::api::{GetPost, GetPostResponse};
 pub mod actions {
   pub use lemmy_db_views_post::api::{
+    AcceptPostAnswer,
+    CreateCrosspost,
     CreatePost,
     CreatePostLike,
     DeletePost,
@@ -25,13 +37,17 @@ pub mod actions {
     HidePost,
     MarkManyPostsAsRead,
     MarkPostAsRead,
+    RsvpPostEvent,
     SavePost,
     UpdatePostNotifications,
   };
 
   pub mod moderation {
     pub use lemmy_db_views_post::api::{
+      ApprovePendingPost,
+      ApproveQuarantinedPost,
       FeaturePost,
+      ListPendingPosts,
       ListPostLikes,
       LockPost,
       ModEditPost,
@@ -1,5 +1,16 @@
-pub use lemmy_db_schema::{newtypes::PrivateMessageId, source::private_message::PrivateMessage};
-pub use lemmy_db_views_private_message::{PrivateMessageView, api::PrivateMessageResponse};
+pub use lemmy_db_schema::{
+  newtypes::PrivateMessageId,
+  source::{private_message::PrivateMessage, revision::PrivateMessageRevision},
+};
+pub use lemmy_db_views_private_message::{
+  PrivateMessageView,
+  api::{
+    ListPrivateMessageRevisions,
+    ListPrivateMessageRevisionsResponse,
+    PrivateMessageResponse,
+    PrivateMessageRevisionDiff,
+  },
+};
 
 pub mod actions {
   pub use lemmy_db_views_private_message::api::{
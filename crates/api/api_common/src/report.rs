@@ -1,22 +1,33 @@
 pub use lemmy_db_schema::{
   ReportType,
-  newtypes::{CommentReportId, CommunityReportId, PostReportId, PrivateMessageReportId},
+  newtypes::{
+    BanAppealId,
+    CommentReportId,
+    CommunityReportId,
+    PostReportId,
+    PrivateMessageReportId,
+  },
   source::{
+    ban_appeal::BanAppeal,
     comment_report::CommentReport,
     community_report::CommunityReport,
     post_report::PostReport,
     private_message_report::PrivateMessageReport,
   },
 };
+pub use lemmy_db_schema_file::enums::BanAppealDecision;
 pub use lemmy_db_views_report_combined::{
+  BanAppealView,
   CommentReportView,
   CommunityReportView,
   PostReportView,
   PrivateMessageReportView,
   ReportCombinedView,
   api::{
+    BanAppealResponse,
     CommentReportResponse,
     CommunityReportResponse,
+    CreateBanAppeal,
     CreateCommentReport,
     CreateCommunityReport,
     CreatePostReport,
@@ -24,6 +35,7 @@ pub use lemmy_db_views_report_combined::{
     ListReports,
     PostReportResponse,
     PrivateMessageReportResponse,
+    ResolveBanAppeal,
     ResolveCommentReport,
     ResolveCommunityReport,
     ResolvePostReport,
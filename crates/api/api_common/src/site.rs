@@ -2,6 +2,7 @@ pub use lemmy_db_schema::{
   newtypes::{LocalSiteId, SiteId},
   source::{
     local_site::LocalSite,
+    local_site_email_domain::LocalSiteEmailDomain,
     local_site_rate_limit::LocalSiteRateLimit,
     local_site_url_blocklist::LocalSiteUrlBlocklist,
     site::Site,
@@ -14,11 +15,54 @@ pub use lemmy_db_views_site::{
 };
 
 pub mod administration {
-  pub use lemmy_db_views_local_user::api::AdminListUsers;
-  pub use lemmy_db_views_person::api::{AddAdmin, AddAdminResponse};
+  pub use lemmy_db_schema::source::admin_audit_log::AdminAuditLog;
+  pub use lemmy_db_schema_file::enums::AdminPermission;
+  pub use lemmy_db_views_local_user::{
+    AdminAuditLogView,
+    api::{AdminListUsers, ListAdminAuditLog},
+  };
+  pub use lemmy_db_views_person::api::{AddAdmin, AddAdminResponse, EditAdminPermissions};
   pub use lemmy_db_views_registration_applications::api::{
     ApproveRegistrationApplication,
     ListRegistrationApplications,
   };
   pub use lemmy_db_views_site::api::{CreateSite, EditSite};
 }
+
+pub mod registration {
+  pub use lemmy_db_schema::{
+    newtypes::{RegistrationQuestionId, RegistrationScreeningRuleId},
+    source::{
+      registration_question::{RegistrationAnswer, RegistrationAnswersView, RegistrationQuestion},
+      registration_screening_rule::RegistrationScreeningRule,
+    },
+  };
+  pub use lemmy_db_schema_file::enums::{
+    RegistrationQuestionKind,
+    RegistrationScreeningAction,
+    RegistrationScreeningRuleKind,
+  };
+  pub use lemmy_db_views_registration_applications::api::{
+    CreateRegistrationQuestion,
+    CreateRegistrationScreeningRule,
+    DeleteRegistrationQuestion,
+    DeleteRegistrationScreeningRule,
+    EditRegistrationQuestion,
+    ListRegistrationQuestionsResponse,
+    ListRegistrationScreeningRulesResponse,
+    RegistrationQuestionAnswer,
+    RegistrationQuestionResponse,
+    RegistrationScreeningRuleResponse,
+  };
+}
+
+pub mod invite {
+  pub use lemmy_db_schema::{newtypes::InviteCodeId, source::invite_code::InviteCode};
+  pub use lemmy_db_views_site::api::{
+    CreateInviteCode,
+    DeleteInviteCode,
+    InviteCodeResponse,
+    ListInviteCodes,
+    ListInviteCodesResponse,
+  };
+}
@@ -22,6 +22,7 @@ use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
   source::{
     comment::{Comment, CommentActions, CommentInsertForm, CommentLikeForm},
+    instance::InstanceActions,
     notification::Notification,
   },
   traits::Likeable,
@@ -96,9 +97,12 @@ pub async fn create_comment(
     check_comment_depth(parent)?;
   }
 
+  let quarantined =
+    InstanceActions::is_quarantined(&mut context.pool(), my_person_id, local_instance_id).await?;
   let mut comment_form = CommentInsertForm {
     language_id: data.language_id,
     federation_pending: Some(community_use_pending(&post_view.community, &context).await),
+    quarantined: Some(quarantined),
     ..CommentInsertForm::new(my_person_id, data.post_id, content.clone())
   };
   comment_form = plugin_hook_before("local_comment_before_create", comment_form).await?;
@@ -110,16 +114,19 @@ pub async fn create_comment(
     Comment::create(&mut context.pool(), &comment_form, parent_path.as_ref()).await?;
   plugin_hook_after("local_comment_after_create", &inserted_comment);
 
-  NotifyData {
-    comment: Some(inserted_comment.clone()),
-    do_send_email: !local_site.disable_email_notifications,
-    ..NotifyData::new(
-      post.clone(),
-      local_user_view.person.clone(),
-      post_view.community,
-    )
+  // Notifications for quarantined comments are sent once they get approved
+  if !inserted_comment.quarantined {
+    NotifyData {
+      comment: Some(inserted_comment.clone()),
+      do_send_email: !local_site.disable_email_notifications,
+      ..NotifyData::new(
+        post.clone(),
+        local_user_view.person.clone(),
+        post_view.community,
+      )
+    }
+    .send(&context);
   }
-  .send(&context);
 
   // You like your own comment by default
   let like_form = CommentLikeForm::new(inserted_comment.id, my_person_id, Some(true));
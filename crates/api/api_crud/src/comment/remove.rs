@@ -16,6 +16,7 @@ use lemmy_db_schema::{
   },
   traits::Reportable,
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_comment::{
   CommentView,
   api::{CommentResponse, RemoveComment},
@@ -43,6 +44,7 @@ pub async fn remove_comment(
     &local_user_view,
     &orig_comment.community,
     false,
+    Some(ModeratorPermission::RemoveContent),
     &mut context.pool(),
   )
   .await?;
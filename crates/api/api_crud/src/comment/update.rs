@@ -11,7 +11,10 @@ use lemmy_api_utils::{
 };
 use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
-  source::comment::{Comment, CommentUpdateForm},
+  source::{
+    comment::{Comment, CommentUpdateForm},
+    revision::CommentRevision,
+  },
 };
 use lemmy_db_views_comment::{
   CommentView,
@@ -74,6 +77,8 @@ pub async fn update_comment(
   .await?;
 
   let updated_comment = Comment::update(&mut context.pool(), comment_id, &form).await?;
+  CommentRevision::create_if_changed(&mut context.pool(), &orig_comment.comment, &updated_comment)
+    .await?;
 
   plugin_hook_after("local_comment_after_update", &updated_comment);
 
@@ -4,6 +4,7 @@ use lemmy_api_utils::{
   build_response::build_community_response,
   context::LemmyContext,
   utils::{
+    check_admin_permission,
     check_local_user_valid,
     check_nsfw_allowed,
     generate_featured_url,
@@ -11,7 +12,6 @@ use lemmy_api_utils::{
     generate_inbox_url,
     generate_moderators_url,
     get_url_blocklist,
-    is_admin,
     process_markdown_opt,
     slur_regex,
   },
@@ -29,7 +29,7 @@ use lemmy_db_schema::{
   },
   traits::{ApubActor, Followable},
 };
-use lemmy_db_schema_file::enums::CommunityFollowerState;
+use lemmy_db_schema_file::enums::{AdminPermission, CommunityFollowerState};
 use lemmy_db_views_community::api::{CommunityResponse, CreateCommunity};
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::SiteView;
@@ -56,7 +56,9 @@ pub async fn create_community(
   let site_view = SiteView::read_local(&mut context.pool()).await?;
   let local_site = site_view.local_site;
 
-  if local_site.community_creation_admin_only && is_admin(&local_user_view).is_err() {
+  if local_site.community_creation_admin_only
+    && check_admin_permission(&local_user_view, Some(AdminPermission::HandleReports)).is_err()
+  {
     Err(LemmyErrorType::OnlyAdminsCanCreateCommunities)?
   }
 
@@ -101,7 +103,7 @@ pub async fn create_community(
     inbox_url: Some(generate_inbox_url()?),
     moderators_url: Some(generate_moderators_url(&community_ap_id)?),
     featured_url: Some(generate_featured_url(&community_ap_id)?),
-    posting_restricted_to_mods: data.posting_restricted_to_mods,
+    posting_mode: data.posting_mode,
     visibility: data.visibility,
     ..CommunityInsertForm::new(
       site_view.site.instance_id,
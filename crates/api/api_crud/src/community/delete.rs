@@ -24,7 +24,14 @@ pub async fn delete_community(
     CommunityModeratorView::for_community(&mut context.pool(), data.community_id).await?;
 
   let community = Community::read(&mut context.pool(), data.community_id).await?;
-  check_community_mod_action(&local_user_view, &community, true, &mut context.pool()).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    true,
+    None,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Make sure deleter is the top mod
   is_top_mod(&local_user_view, &community_mods)?;
@@ -5,7 +5,7 @@ use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_mod_action, is_admin},
+  utils::{check_admin_action, check_community_mod_action},
 };
 use lemmy_db_schema::{
   source::{
@@ -15,6 +15,7 @@ use lemmy_db_schema::{
   },
   traits::Reportable,
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_community::api::{CommunityResponse, RemoveCommunity};
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use lemmy_db_views_local_user::LocalUserView;
@@ -27,10 +28,25 @@ pub async fn remove_community(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<CommunityResponse>> {
   let community = Community::read(&mut context.pool(), data.community_id).await?;
-  check_community_mod_action(&local_user_view, &community, true, &mut context.pool()).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    true,
+    None,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Verify its an admin (only an admin can remove a community)
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::HandleReports),
+    "RemoveCommunity",
+    Some(data.community_id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Do the remove
   let community_id = data.community_id;
@@ -19,6 +19,7 @@ use lemmy_db_schema::source::{
   community::{Community, CommunityUpdateForm},
   modlog::{Modlog, ModlogInsertForm},
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_community::api::{CommunityResponse, EditCommunity};
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::SiteView;
@@ -64,7 +65,14 @@ pub async fn update_community(
   let old_community = Community::read(&mut context.pool(), data.community_id).await?;
 
   // Verify its a mod (only mods can edit it)
-  check_community_mod_action(&local_user_view, &old_community, false, &mut context.pool()).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &old_community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
 
   let community_id = data.community_id;
   if let Some(languages) = data.discussion_languages.clone() {
@@ -83,7 +91,7 @@ pub async fn update_community(
     description,
     summary,
     nsfw: data.nsfw,
-    posting_restricted_to_mods: data.posting_restricted_to_mods,
+    posting_mode: data.posting_mode,
     visibility: data.visibility,
     updated_at: Some(Some(Utc::now())),
     ..Default::default()
@@ -1,10 +1,11 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
 use lemmy_db_schema::source::{
   custom_emoji::{CustomEmoji, CustomEmojiInsertForm},
   custom_emoji_keyword::CustomEmojiKeyword,
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_custom_emoji::{
   CustomEmojiView,
   api::{CreateCustomEmoji, CustomEmojiResponse},
@@ -19,7 +20,15 @@ pub async fn create_custom_emoji(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<CustomEmojiResponse>> {
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "CreateCustomEmoji",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let emoji_form = CustomEmojiInsertForm {
     shortcode: data.shortcode.to_lowercase().trim().to_string(),
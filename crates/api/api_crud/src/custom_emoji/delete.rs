@@ -1,7 +1,8 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
 use lemmy_db_schema::source::custom_emoji::CustomEmoji;
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_custom_emoji::api::DeleteCustomEmoji;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::api::SuccessResponse;
@@ -14,7 +15,15 @@ pub async fn delete_custom_emoji(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "DeleteCustomEmoji",
+    Some(data.id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   CustomEmoji::delete(&mut context.pool(), data.id).await?;
 
@@ -0,0 +1,92 @@
+use super::check_draft_target;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::draft::{Draft, DraftInsertForm};
+use lemmy_db_schema_file::enums::DraftKind;
+use lemmy_db_views_comment::CommentView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::{
+  SiteView,
+  api::{CreateDraft, DraftResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::validation::{check_api_elements_count, draft_fields_check},
+};
+
+pub async fn create_draft(
+  Json(data): Json<CreateDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<DraftResponse>> {
+  let local_user_id = local_user_view.local_user.id;
+  let existing = Draft::list(&mut context.pool(), local_user_id).await?;
+  check_api_elements_count(existing.len())?;
+  draft_fields_check(
+    data.name.as_deref(),
+    data.url.as_deref(),
+    data.alt_text.as_deref(),
+    data.custom_thumbnail.as_deref(),
+    data.body.as_deref(),
+  )?;
+
+  let base = DraftInsertForm {
+    body: data.body,
+    ..DraftInsertForm::new(local_user_id, data.kind)
+  };
+  // Only keep the fields which are used by this kind of draft
+  let form = match data.kind {
+    DraftKind::Post => DraftInsertForm {
+      community_id: data.community_id,
+      name: data.name,
+      url: data.url,
+      alt_text: data.alt_text,
+      custom_thumbnail: data.custom_thumbnail,
+      nsfw: data.nsfw,
+      language_id: data.language_id,
+      ..base
+    },
+    DraftKind::Comment => {
+      // Replies can be drafted with only the parent comment. Reading the view makes sure that
+      // the parent is visible to the user.
+      let post_id = match data.parent_id {
+        Some(parent_id) => {
+          let instance_id = SiteView::read_local(&mut context.pool())
+            .await?
+            .site
+            .instance_id;
+          let parent = CommentView::read(
+            &mut context.pool(),
+            parent_id,
+            Some(&local_user_view.local_user),
+            instance_id,
+          )
+          .await?;
+          Some(parent.comment.post_id)
+        }
+        None => data.post_id,
+      };
+      DraftInsertForm {
+        post_id,
+        parent_comment_id: data.parent_id,
+        language_id: data.language_id,
+        ..base
+      }
+    }
+    DraftKind::PrivateMessage => DraftInsertForm {
+      recipient_id: data.recipient_id,
+      ..base
+    },
+  };
+  check_draft_target(
+    form.kind,
+    form.community_id,
+    form.post_id,
+    form.recipient_id,
+  )?;
+  let draft = Draft::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(DraftResponse { draft }))
+}
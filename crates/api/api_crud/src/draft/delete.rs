@@ -0,0 +1,17 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::draft::Draft;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{DeleteDraft, SuccessResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_draft(
+  Json(data): Json<DeleteDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  Draft::delete_for_user(&mut context.pool(), data.id, local_user_view.local_user.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
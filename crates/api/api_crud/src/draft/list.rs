@@ -0,0 +1,16 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::draft::Draft;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::ListDraftsResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_drafts(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListDraftsResponse>> {
+  let drafts = Draft::list(&mut context.pool(), local_user_view.local_user.id).await?;
+
+  Ok(Json(ListDraftsResponse { drafts }))
+}
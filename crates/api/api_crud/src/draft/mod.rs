@@ -0,0 +1,28 @@
+use lemmy_db_schema::newtypes::{CommunityId, PostId};
+use lemmy_db_schema_file::{PersonId, enums::DraftKind};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod publish;
+pub mod update;
+
+/// Drafts need to know where they will be published, so that the target can be shown while
+/// editing.
+fn check_draft_target(
+  kind: DraftKind,
+  community_id: Option<CommunityId>,
+  post_id: Option<PostId>,
+  recipient_id: Option<PersonId>,
+) -> LemmyResult<()> {
+  let has_target = match kind {
+    DraftKind::Post => community_id.is_some(),
+    DraftKind::Comment => post_id.is_some(),
+    DraftKind::PrivateMessage => recipient_id.is_some(),
+  };
+  if !has_target {
+    Err(LemmyErrorType::InvalidDraft)?
+  }
+  Ok(())
+}
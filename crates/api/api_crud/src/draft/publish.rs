@@ -0,0 +1,76 @@
+use crate::{
+  comment::create::create_comment,
+  post::create::create_post,
+  private_message::create::create_private_message,
+};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::draft::Draft;
+use lemmy_db_schema_file::enums::DraftKind;
+use lemmy_db_views_comment::api::CreateComment;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::api::CreatePost;
+use lemmy_db_views_private_message::api::CreatePrivateMessage;
+use lemmy_db_views_site::api::{PublishDraft, PublishDraftResponse};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Publishing goes through the regular create endpoints, so that drafts get exactly the same
+/// validation, plugin hooks and federation as content which is submitted directly.
+pub async fn publish_draft(
+  Json(data): Json<PublishDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PublishDraftResponse>> {
+  let local_user_id = local_user_view.local_user.id;
+  let draft = Draft::read_for_user(&mut context.pool(), data.id, local_user_id).await?;
+
+  let res = match draft.kind {
+    DraftKind::Post => {
+      let form = CreatePost {
+        name: draft.name.unwrap_or_default(),
+        community_id: draft.community_id.ok_or(LemmyErrorType::InvalidDraft)?,
+        url: draft.url,
+        alt_text: draft.alt_text,
+        custom_thumbnail: draft.custom_thumbnail,
+        body: draft.body,
+        nsfw: draft.nsfw,
+        language_id: draft.language_id,
+        ..Default::default()
+      };
+      let post = create_post(Json(form), context.clone(), local_user_view).await?;
+      PublishDraftResponse {
+        post_id: Some(post.post_view.post.id),
+        ..Default::default()
+      }
+    }
+    DraftKind::Comment => {
+      let form = CreateComment {
+        content: draft.body.unwrap_or_default(),
+        post_id: draft.post_id.ok_or(LemmyErrorType::InvalidDraft)?,
+        parent_id: draft.parent_comment_id,
+        language_id: draft.language_id,
+      };
+      let comment = create_comment(Json(form), context.clone(), local_user_view).await?;
+      PublishDraftResponse {
+        comment_id: Some(comment.comment_view.comment.id),
+        ..Default::default()
+      }
+    }
+    DraftKind::PrivateMessage => {
+      let form = CreatePrivateMessage {
+        content: draft.body.unwrap_or_default(),
+        recipient_id: draft.recipient_id.ok_or(LemmyErrorType::InvalidDraft)?,
+      };
+      let message = create_private_message(Json(form), context.clone(), local_user_view).await?;
+      PublishDraftResponse {
+        private_message_id: Some(message.private_message_view.private_message.id),
+        ..Default::default()
+      }
+    }
+  };
+
+  Draft::delete_for_user(&mut context.pool(), draft.id, local_user_id).await?;
+
+  Ok(Json(res))
+}
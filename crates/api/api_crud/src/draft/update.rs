@@ -0,0 +1,57 @@
+use super::check_draft_target;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::draft::{Draft, DraftUpdateForm};
+use lemmy_db_schema_file::enums::DraftKind;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{DraftResponse, EditDraft};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{error::LemmyResult, utils::validation::draft_fields_check};
+
+pub async fn update_draft(
+  Json(data): Json<EditDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<DraftResponse>> {
+  let orig =
+    Draft::read_for_user(&mut context.pool(), data.id, local_user_view.local_user.id).await?;
+  draft_fields_check(
+    data.name.as_deref(),
+    data.url.as_deref(),
+    data.alt_text.as_deref(),
+    data.custom_thumbnail.as_deref(),
+    data.body.as_deref(),
+  )?;
+
+  let base = DraftUpdateForm {
+    body: Some(data.body),
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  let form = match orig.kind {
+    DraftKind::Post => {
+      let community_id = data.community_id.or(orig.community_id);
+      check_draft_target(orig.kind, community_id, orig.post_id, orig.recipient_id)?;
+      DraftUpdateForm {
+        community_id: Some(community_id),
+        name: Some(data.name),
+        url: Some(data.url),
+        alt_text: Some(data.alt_text),
+        custom_thumbnail: Some(data.custom_thumbnail),
+        nsfw: Some(data.nsfw),
+        language_id: Some(data.language_id),
+        ..base
+      }
+    }
+    DraftKind::Comment => DraftUpdateForm {
+      language_id: Some(data.language_id),
+      ..base
+    },
+    DraftKind::PrivateMessage => base,
+  };
+  let draft = Draft::update(&mut context.pool(), orig.id, &form).await?;
+
+  Ok(Json(DraftResponse { draft }))
+}
@@ -0,0 +1,99 @@
+use actix_web::web::{Data, Json};
+use chrono::{TimeDelta, Utc};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{
+    check_admin_permission,
+    check_community_deleted_removed,
+    check_expire_time,
+    check_local_user_valid,
+  },
+};
+use lemmy_db_schema::source::{
+  community::Community,
+  invite_code::{InviteCode, InviteCodeInsertForm},
+  local_site::LocalSite,
+};
+use lemmy_db_schema_file::enums::AdminPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::{
+  SiteView,
+  api::{CreateInviteCode, InviteCodeResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::check_api_elements_count,
+};
+
+/// Highest `max_uses` for codes created by users who aren't admins.
+const USER_INVITE_CODE_MAX_USES: i32 = 10;
+/// How many codes with uses left a user who isn't admin can have.
+const USER_MAX_ACTIVE_INVITE_CODES: i64 = 5;
+
+pub async fn create_invite_code(
+  Json(data): Json<CreateInviteCode>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<InviteCodeResponse>> {
+  if data.max_uses.is_some_and(|max_uses| max_uses < 1) {
+    Err(LemmyErrorType::InvalidInviteCode)?
+  }
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  check_can_create_invite_codes(&local_user_view, &local_site, data.max_uses, &context).await?;
+  let expires_at = check_expire_time(data.expires_at)?;
+
+  // New users can only be made to follow communities which anyone can join
+  let community_ids = data.community_ids.unwrap_or_default();
+  check_api_elements_count(community_ids.len())?;
+  for community_id in &community_ids {
+    let community = Community::read(&mut context.pool(), *community_id).await?;
+    check_community_deleted_removed(&community)?;
+    if !community.local || !community.visibility.can_view_without_login() {
+      Err(LemmyErrorType::InvalidInviteCommunity)?
+    }
+  }
+
+  let form = InviteCodeInsertForm {
+    max_uses: data.max_uses,
+    expires_at,
+    community_ids,
+    ..InviteCodeInsertForm::new(InviteCode::generate_code(), local_user_view.local_user.id)
+  };
+  let invite_code = InviteCode::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(InviteCodeResponse { invite_code }))
+}
+
+/// Admins can always create invite codes, other users only if their account is old enough. They
+/// also can't invite an unlimited number of people, so their codes need a small `max_uses` and
+/// only a few of them can be active at once.
+async fn check_can_create_invite_codes(
+  local_user_view: &LocalUserView,
+  local_site: &LocalSite,
+  max_uses: Option<i32>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  check_local_user_valid(local_user_view)?;
+  if check_admin_permission(local_user_view, Some(AdminPermission::ReviewRegistrations)).is_ok() {
+    return Ok(());
+  }
+
+  let min_account_age_days = local_site
+    .invite_min_account_age_days
+    .ok_or(LemmyErrorType::CantCreateInviteCodes)?;
+  let account_age = Utc::now() - local_user_view.person.published_at;
+  if account_age < TimeDelta::days(min_account_age_days.into()) {
+    Err(LemmyErrorType::CantCreateInviteCodes)?
+  }
+
+  if !max_uses.is_some_and(|max_uses| max_uses <= USER_INVITE_CODE_MAX_USES) {
+    Err(LemmyErrorType::InvalidInviteCodeMaxUses)?
+  }
+  let active_codes =
+    InviteCode::count_active(&mut context.pool(), local_user_view.local_user.id).await?;
+  if active_codes >= USER_MAX_ACTIVE_INVITE_CODES {
+    Err(LemmyErrorType::TooManyInviteCodes)?
+  }
+  Ok(())
+}
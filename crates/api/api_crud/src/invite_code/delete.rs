@@ -0,0 +1,21 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_permission};
+use lemmy_db_schema::source::invite_code::InviteCode;
+use lemmy_db_schema_file::enums::AdminPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{DeleteInviteCode, SuccessResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_invite_code(
+  Json(data): Json<DeleteInviteCode>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let invite_code = InviteCode::read(&mut context.pool(), data.id).await?;
+  if invite_code.creator_id != local_user_view.local_user.id {
+    check_admin_permission(&local_user_view, Some(AdminPermission::ReviewRegistrations))?;
+  }
+  InviteCode::delete(&mut context.pool(), invite_code.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
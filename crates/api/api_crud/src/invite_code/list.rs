@@ -0,0 +1,23 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_permission};
+use lemmy_db_schema::source::invite_code::InviteCode;
+use lemmy_db_schema_file::enums::AdminPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{ListInviteCodes, ListInviteCodesResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_invite_codes(
+  Query(data): Query<ListInviteCodes>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListInviteCodesResponse>> {
+  let creator_id = if data.all_users.unwrap_or_default() {
+    check_admin_permission(&local_user_view, Some(AdminPermission::ReviewRegistrations))?;
+    None
+  } else {
+    Some(local_user_view.local_user.id)
+  };
+  let invite_codes = InviteCode::list(&mut context.pool(), creator_id).await?;
+
+  Ok(Json(ListInviteCodesResponse { invite_codes }))
+}
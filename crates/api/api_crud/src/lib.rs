@@ -4,10 +4,15 @@ use lemmy_db_schema::source::community::{Community, CommunityActions};
 pub mod comment;
 pub mod community;
 pub mod custom_emoji;
+pub mod draft;
+pub mod invite_code;
 pub mod multi_community;
+pub mod oauth_application;
 pub mod oauth_provider;
 pub mod post;
 pub mod private_message;
+pub mod registration_question;
+pub mod registration_screening_rule;
 pub mod site;
 pub mod tagline;
 pub mod user;
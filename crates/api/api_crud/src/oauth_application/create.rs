@@ -0,0 +1,54 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::{
+  source::oauth_application::{OAuthApplication, OAuthApplicationInsertForm},
+  utils::hash_token,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{CreateOAuthApplication, CreateOAuthApplicationResponse};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::{
+    check_api_elements_count,
+    is_valid_body_field,
+    oauth_application_name_check,
+    oauth_redirect_uri_check,
+  },
+};
+
+pub async fn create_oauth_application(
+  Json(data): Json<CreateOAuthApplication>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CreateOAuthApplicationResponse>> {
+  oauth_application_name_check(&data.name)?;
+  if let Some(description) = &data.description {
+    is_valid_body_field(description, false)?;
+  }
+  if data.redirect_uris.is_empty() {
+    Err(LemmyErrorType::InvalidRedirectUri)?
+  }
+  check_api_elements_count(data.redirect_uris.len())?;
+  for redirect_uri in &data.redirect_uris {
+    oauth_redirect_uri_check(redirect_uri)?;
+  }
+
+  let client_secret =
+    (!data.public_client.unwrap_or_default()).then(OAuthApplication::generate_client_secret);
+  let form = OAuthApplicationInsertForm {
+    description: data.description,
+    client_secret_hash: client_secret.as_deref().map(hash_token),
+    ..OAuthApplicationInsertForm::new(
+      local_user_view.local_user.id,
+      data.name.trim().to_string(),
+      OAuthApplication::generate_client_id(),
+      data.redirect_uris,
+    )
+  };
+  let oauth_application = OAuthApplication::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(CreateOAuthApplicationResponse {
+    oauth_application,
+    client_secret,
+  }))
+}
@@ -0,0 +1,16 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::oauth_application::OAuthApplication;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{DeleteOAuthApplication, SuccessResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_oauth_application(
+  Json(data): Json<DeleteOAuthApplication>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  OAuthApplication::delete(&mut context.pool(), data.id, local_user_view.local_user.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,16 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::oauth_application::OAuthApplication;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::ListOAuthApplicationsResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_oauth_applications(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListOAuthApplicationsResponse>> {
+  let oauth_applications =
+    OAuthApplication::list(&mut context.pool(), local_user_view.local_user.id).await?;
+
+  Ok(Json(ListOAuthApplicationsResponse { oauth_applications }))
+}
@@ -1,7 +1,8 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
 use lemmy_db_schema::source::oauth_provider::{AdminOAuthProvider, OAuthProviderInsertForm};
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::api::CreateOAuthProvider;
 use lemmy_diesel_utils::traits::Crud;
@@ -14,7 +15,18 @@ pub async fn create_oauth_provider(
   local_user_view: LocalUserView,
 ) -> Result<Json<AdminOAuthProvider>, LemmyError> {
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "CreateOAuthProvider",
+    None,
+    &CreateOAuthProvider {
+      client_secret: String::new(),
+      ..data.clone()
+    },
+    &mut context.pool(),
+  )
+  .await?;
 
   let cloned_data = data.clone();
   let oauth_provider_form = OAuthProviderInsertForm {
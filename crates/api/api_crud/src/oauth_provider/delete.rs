@@ -1,7 +1,8 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
 use lemmy_db_schema::source::oauth_provider::AdminOAuthProvider;
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::api::{DeleteOAuthProvider, SuccessResponse};
 use lemmy_diesel_utils::traits::Crud;
@@ -13,7 +14,15 @@ pub async fn delete_oauth_provider(
   local_user_view: LocalUserView,
 ) -> Result<Json<SuccessResponse>, LemmyError> {
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "DeleteOAuthProvider",
+    Some(data.id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   AdminOAuthProvider::delete(&mut context.pool(), data.id).await?;
 
@@ -1,8 +1,9 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use chrono::Utc;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
 use lemmy_db_schema::source::oauth_provider::{AdminOAuthProvider, OAuthProviderUpdateForm};
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::api::EditOAuthProvider;
 use lemmy_diesel_utils::{
@@ -17,7 +18,18 @@ pub async fn update_oauth_provider(
   local_user_view: LocalUserView,
 ) -> Result<Json<AdminOAuthProvider>, LemmyError> {
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "EditOAuthProvider",
+    Some(data.id.0),
+    &EditOAuthProvider {
+      client_secret: None,
+      ..data.clone()
+    },
+    &mut context.pool(),
+  )
+  .await?;
 
   let cloned_data = data.clone();
   let oauth_provider_form = OAuthProviderUpdateForm {
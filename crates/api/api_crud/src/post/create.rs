@@ -11,20 +11,31 @@ use lemmy_api_utils::{
   send_activity::SendActivityData,
   utils::{
     check_community_user_action,
+    check_is_mod_or_admin,
     check_nsfw_allowed,
+    check_post_event,
+    check_post_gallery,
     get_url_blocklist,
     honeypot_check,
+    post_requires_approval,
     process_markdown_opt,
     send_webmention,
     slur_regex,
+    update_post_event,
+    update_post_gallery,
     update_post_tags,
   },
 };
 use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
-  source::post::{Post, PostActions, PostInsertForm, PostLikeForm},
+  newtypes::PostId,
+  source::{
+    instance::InstanceActions,
+    post::{Post, PostActions, PostInsertForm, PostLikeForm},
+  },
   traits::Likeable,
 };
+use lemmy_db_schema_file::enums::CommunityPostingMode;
 use lemmy_db_views_community::CommunityView;
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use lemmy_db_views_local_user::LocalUserView;
@@ -46,7 +57,17 @@ use lemmy_utils::{
 };
 
 pub async fn create_post(
+  data: Json<CreatePost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  create_post_with_original(data, None, context, local_user_view).await
+}
+
+/// Creates the post as a crosspost of `original_post_id`, if it is given.
+pub(crate) async fn create_post_with_original(
   Json(data): Json<CreatePost>,
+  original_post_id: Option<PostId>,
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<PostResponse>> {
@@ -58,7 +79,17 @@ pub async fn create_post(
   let url_blocklist = get_url_blocklist(&context).await?;
 
   let body = process_markdown_opt(&data.body, &slur_regex, &url_blocklist, &context).await?;
-  let url = diesel_url_create(data.url.as_deref())?;
+  let gallery = data.gallery.clone().unwrap_or_default();
+  let gallery_urls = check_post_gallery(&gallery, &url_blocklist)?;
+  if let Some(event) = &data.event {
+    check_post_event(event, &slur_regex, &url_blocklist)?;
+  }
+  // Gallery posts use the first image as url, so that they still show up as image posts on
+  // clients and instances which don't support galleries
+  let (url, alt_text) = match (diesel_url_create(data.url.as_deref())?, gallery.first()) {
+    (None, Some(first)) => (gallery_urls.first().cloned(), first.alt_text.clone()),
+    (url, _) => (url, data.alt_text.clone()),
+  };
   let custom_thumbnail = diesel_url_create(data.custom_thumbnail.as_deref())?;
   check_nsfw_allowed(data.nsfw, Some(&local_site))?;
 
@@ -73,7 +104,7 @@ pub async fn create_post(
     is_valid_url(custom_thumbnail)?;
   }
 
-  if let Some(alt_text) = &data.alt_text {
+  if let Some(alt_text) = &alt_text {
     is_valid_alt_text_field(alt_text)?;
   }
 
@@ -98,7 +129,7 @@ pub async fn create_post(
     data.nsfw
   };
 
-  if community.posting_restricted_to_mods {
+  if community.posting_mode == CommunityPostingMode::ModsOnly {
     let community_id = data.community_id;
     CommunityModeratorView::check_is_community_moderator(
       &mut context.pool(),
@@ -110,14 +141,25 @@ pub async fn create_post(
 
   let scheduled_publish_time_at =
     convert_published_time(data.scheduled_publish_time_at, &local_user_view, &context).await?;
+  let quarantined = InstanceActions::is_quarantined(
+    &mut context.pool(),
+    local_user_view.person.id,
+    local_user_view.person.instance_id,
+  )
+  .await?;
+  let pending_approval =
+    post_requires_approval(&mut context.pool(), community, local_user_view.person.id).await;
   let mut post_form = PostInsertForm {
     url,
     body,
-    alt_text: data.alt_text.clone(),
+    alt_text,
     nsfw,
     language_id: data.language_id,
     federation_pending: Some(community_use_pending(community, &context).await),
     scheduled_publish_time_at,
+    quarantined: Some(quarantined),
+    pending_approval: Some(pending_approval),
+    original_post_id,
     ..PostInsertForm::new(
       data.name.trim().to_string(),
       local_user_view.person.id,
@@ -138,11 +180,31 @@ pub async fn create_post(
   plugin_hook_after("local_post_after_create", &inserted_post);
 
   if let Some(tags) = &data.tags {
-    update_post_tags(&inserted_post, tags, &context).await?;
+    let is_mod = check_is_mod_or_admin(
+      &mut context.pool(),
+      local_user_view.person.id,
+      data.community_id,
+    )
+    .await
+    .is_ok();
+    update_post_tags(&inserted_post, tags, is_mod, &context).await?;
+  }
+
+  if !gallery.is_empty() {
+    update_post_gallery(&inserted_post, &gallery, &context).await?;
+  }
+
+  if let Some(event) = &data.event {
+    update_post_event(&inserted_post, event, &context).await?;
   }
 
   let community_id = community.id;
-  let federate_post = if scheduled_publish_time_at.is_none() {
+  // Posts of quarantined persons, or posts waiting for mod approval, are federated once they get
+  // approved
+  let federate_post = if scheduled_publish_time_at.is_none()
+    && !inserted_post.quarantined
+    && !inserted_post.pending_approval
+  {
     send_webmention(inserted_post.clone(), community);
     |post| Some(SendActivityData::CreatePost(post))
   } else {
@@ -163,15 +225,18 @@ pub async fn create_post(
 
   PostActions::like(&mut context.pool(), &like_form).await?;
 
-  NotifyData {
-    do_send_email: !local_site.disable_email_notifications,
-    ..NotifyData::new(
-      inserted_post.clone(),
-      local_user_view.person.clone(),
-      community.clone(),
-    )
+  // Notifications for quarantined or pending posts are sent once they get approved
+  if !inserted_post.quarantined && !inserted_post.pending_approval {
+    NotifyData {
+      do_send_email: !local_site.disable_email_notifications,
+      ..NotifyData::new(
+        inserted_post.clone(),
+        local_user_view.person.clone(),
+        community.clone(),
+      )
+    }
+    .send(&context);
   }
-  .send(&context);
 
   PostActions::mark_as_read(&mut context.pool(), person_id, &[post_id]).await?;
 
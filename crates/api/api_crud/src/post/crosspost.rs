@@ -0,0 +1,54 @@
+use super::create::create_post_with_original;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::{
+  PostView,
+  api::{CreateCrosspost, CreatePost, PostGalleryImage, PostResponse},
+};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// The copy is created through the regular create endpoint, so that it gets the same checks,
+/// plugin hooks and federation as any other post in the target community.
+pub async fn create_crosspost(
+  Json(data): Json<CreateCrosspost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  let original = PostView::read(
+    &mut context.pool(),
+    data.post_id,
+    Some(&local_user_view.local_user),
+    local_user_view.person.instance_id,
+    false,
+  )
+  .await?;
+  if original.community.id == data.community_id {
+    Err(LemmyErrorType::CannotCrosspostToSameCommunity)?
+  }
+
+  let gallery = original
+    .gallery
+    .0
+    .into_iter()
+    .map(|i| PostGalleryImage {
+      url: i.item.url.to_string(),
+      alt_text: i.item.alt_text,
+    })
+    .collect::<Vec<_>>();
+  let form = CreatePost {
+    name: original.post.name,
+    community_id: data.community_id,
+    url: original.post.url.map(|u| u.to_string()),
+    body: original.post.body,
+    alt_text: original.post.alt_text,
+    nsfw: Some(original.post.nsfw),
+    language_id: Some(original.post.language_id),
+    gallery: (!gallery.is_empty()).then_some(gallery),
+    ..Default::default()
+  };
+  // Crossposts of crossposts point to the first post, so that all copies are linked to each other
+  let original_post_id = original.post.original_post_id.unwrap_or(original.post.id);
+  create_post_with_original(Json(form), Some(original_post_id), context, local_user_view).await
+}
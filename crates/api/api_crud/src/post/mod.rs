@@ -5,6 +5,7 @@ use lemmy_db_views_local_user::LocalUserView;
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
 
 pub mod create;
+pub mod crosspost;
 pub mod delete;
 pub mod read;
 pub mod remove;
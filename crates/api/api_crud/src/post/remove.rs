@@ -17,6 +17,7 @@ use lemmy_db_schema::{
   },
   traits::Reportable,
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_post::api::{PostResponse, RemovePost};
 use lemmy_diesel_utils::traits::Crud;
@@ -35,7 +36,14 @@ pub async fn remove_post(
   let orig_post = Post::read(&mut context.pool(), post_id).await?;
   let community = Community::read(&mut context.pool(), orig_post.community_id).await?;
 
-  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::RemoveContent),
+    &mut context.pool(),
+  )
+  .await?;
 
   LocalUser::is_higher_mod_or_admin_check(
     &mut context.pool(),
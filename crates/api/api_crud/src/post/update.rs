@@ -12,10 +12,14 @@ use lemmy_api_utils::{
   utils::{
     check_community_user_action,
     check_nsfw_allowed,
+    check_post_event,
+    check_post_gallery,
     get_url_blocklist,
     process_markdown_opt,
     send_webmention,
     slur_regex,
+    update_post_event,
+    update_post_gallery,
     update_post_tags,
   },
 };
@@ -24,6 +28,8 @@ use lemmy_db_schema::{
   source::{
     community::Community,
     post::{Post, PostUpdateForm},
+    post_event::PostEvent,
+    revision::PostRevision,
   },
 };
 use lemmy_db_views_local_user::LocalUserView;
@@ -58,14 +64,32 @@ pub async fn update_post(
 ) -> LemmyResult<Json<PostResponse>> {
   let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
   let local_instance_id = local_user_view.person.instance_id;
-  let url = diesel_url_update(data.url.as_deref())?;
-
   let custom_thumbnail = diesel_url_update(data.custom_thumbnail.as_deref())?;
 
   let url_blocklist = get_url_blocklist(&context).await?;
 
+  let gallery_urls = data
+    .gallery
+    .as_ref()
+    .map(|gallery| check_post_gallery(gallery, &url_blocklist))
+    .transpose()?
+    .unwrap_or_default();
+  let first_image = data.gallery.as_ref().and_then(|g| g.first());
+  // If the gallery changes and no new url is given, the first image becomes the post url
+  let (url, alt_text) = match (data.url.as_deref(), gallery_urls.first(), first_image) {
+    (None, Some(url), Some(image)) => (Some(Some(url.clone())), Some(image.alt_text.clone())),
+    (url, _, _) => (
+      diesel_url_update(url)?,
+      diesel_string_update(data.alt_text.as_deref()),
+    ),
+  };
+
   let slur_regex = slur_regex(&context).await?;
 
+  if let Some(event) = &data.event {
+    check_post_event(event, &slur_regex, &url_blocklist)?;
+  }
+
   let body = diesel_string_update(
     process_markdown_opt(&data.body, &slur_regex, &url_blocklist, &context)
       .await?
@@ -74,8 +98,6 @@ pub async fn update_post(
 
   check_nsfw_allowed(data.nsfw, Some(&local_site))?;
 
-  let alt_text = diesel_string_update(data.alt_text.as_deref());
-
   if let Some(name) = &data.name {
     is_valid_post_title(name)?;
     check_slurs(name, &slur_regex)?;
@@ -158,9 +180,20 @@ pub async fn update_post(
   let post_id = data.post_id;
   let updated_post = Post::update(&mut context.pool(), post_id, &post_form).await?;
   plugin_hook_after("local_post_after_update", &post_form);
+  PostRevision::create_if_changed(&mut context.pool(), &orig_post.post, &updated_post).await?;
 
   if let Some(tags) = &data.tags {
-    update_post_tags(&orig_post.post, tags, &context).await?;
+    update_post_tags(&orig_post.post, tags, orig_post.can_mod, &context).await?;
+  }
+
+  if let Some(gallery) = &data.gallery {
+    update_post_gallery(&updated_post, gallery, &context).await?;
+  }
+
+  if let Some(event) = &data.event {
+    update_post_event(&updated_post, event, &context).await?;
+  } else if data.remove_event == Some(true) {
+    PostEvent::delete(&mut context.pool(), post_id).await?;
   }
 
   NotifyData::new(
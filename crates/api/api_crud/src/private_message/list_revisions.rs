@@ -0,0 +1,49 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::diff_lines};
+use lemmy_db_schema::source::{private_message::PrivateMessage, revision::PrivateMessageRevision};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_private_message::api::{
+  ListPrivateMessageRevisions,
+  ListPrivateMessageRevisionsResponse,
+  PrivateMessageRevisionDiff,
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use std::iter::once;
+
+/// Lists the previous versions of a private message. Only visible to the sender, the recipient
+/// and admins.
+pub async fn list_private_message_revisions(
+  Query(data): Query<ListPrivateMessageRevisions>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListPrivateMessageRevisionsResponse>> {
+  let private_message = PrivateMessage::read(&mut context.pool(), data.private_message_id).await?;
+  let person_id = local_user_view.person.id;
+  if person_id != private_message.creator_id
+    && person_id != private_message.recipient_id
+    && !local_user_view.local_user.admin
+  {
+    Err(LemmyErrorType::NotFound)?
+  }
+
+  let revisions = PrivateMessageRevision::list(&mut context.pool(), private_message.id).await?;
+
+  // Each revision is compared with the version which replaced it
+  let next_versions = revisions
+    .iter()
+    .skip(1)
+    .map(|r| r.content.clone())
+    .chain(once(private_message.content))
+    .collect::<Vec<_>>();
+  let revisions = revisions
+    .into_iter()
+    .zip(next_versions)
+    .map(|(revision, content)| PrivateMessageRevisionDiff {
+      content_diff: diff_lines(&revision.content, &content),
+      revision,
+    })
+    .collect();
+
+  Ok(Json(ListPrivateMessageRevisionsResponse { revisions }))
+}
@@ -8,7 +8,10 @@ use lemmy_api_utils::{
   send_activity::{ActivityChannel, SendActivityData},
   utils::{check_local_user_valid, get_url_blocklist, process_markdown, slur_regex},
 };
-use lemmy_db_schema::source::private_message::{PrivateMessage, PrivateMessageUpdateForm};
+use lemmy_db_schema::source::{
+  private_message::{PrivateMessage, PrivateMessageUpdateForm},
+  revision::PrivateMessageRevision,
+};
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_private_message::{
   PrivateMessageView,
@@ -49,6 +52,12 @@ pub async fn update_private_message(
   let private_message =
     PrivateMessage::update(&mut context.pool(), private_message_id, &form).await?;
   plugin_hook_after("local_private_message_after_update", &private_message);
+  PrivateMessageRevision::create_if_changed(
+    &mut context.pool(),
+    &orig_private_message,
+    &private_message,
+  )
+  .await?;
 
   let view = PrivateMessageView::read(&mut context.pool(), private_message_id).await?;
 
@@ -0,0 +1,52 @@
+use super::registration_question_check;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
+use lemmy_db_schema::source::registration_question::{
+  RegistrationQuestion,
+  RegistrationQuestionInsertForm,
+};
+use lemmy_db_schema_file::enums::AdminPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_registration_applications::api::{
+  CreateRegistrationQuestion,
+  RegistrationQuestionResponse,
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn create_registration_question(
+  Json(data): Json<CreateRegistrationQuestion>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RegistrationQuestionResponse>> {
+  // Make sure user is an admin
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::ReviewRegistrations),
+    "CreateRegistrationQuestion",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
+
+  registration_question_check(
+    &data.question,
+    data.kind.unwrap_or_default(),
+    data.choices.as_deref().unwrap_or_default(),
+  )?;
+
+  let form = RegistrationQuestionInsertForm {
+    kind: data.kind,
+    choices: data.choices,
+    required: data.required,
+    display_order: data.display_order,
+    ..RegistrationQuestionInsertForm::new(data.question.trim().to_string())
+  };
+  let registration_question = RegistrationQuestion::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(RegistrationQuestionResponse {
+    registration_question,
+  }))
+}
@@ -0,0 +1,31 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
+use lemmy_db_schema::source::registration_question::RegistrationQuestion;
+use lemmy_db_schema_file::enums::AdminPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_registration_applications::api::DeleteRegistrationQuestion;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_registration_question(
+  Json(data): Json<DeleteRegistrationQuestion>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  // Make sure user is an admin
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::ReviewRegistrations),
+    "DeleteRegistrationQuestion",
+    Some(data.id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
+
+  RegistrationQuestion::delete(&mut context.pool(), data.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,15 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::registration_question::RegistrationQuestion;
+use lemmy_db_views_registration_applications::api::ListRegistrationQuestionsResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_registration_questions(
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<ListRegistrationQuestionsResponse>> {
+  let registration_questions = RegistrationQuestion::list(&mut context.pool()).await?;
+
+  Ok(Json(ListRegistrationQuestionsResponse {
+    registration_questions,
+  }))
+}
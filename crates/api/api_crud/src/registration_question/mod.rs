@@ -0,0 +1,26 @@
+use lemmy_db_schema_file::enums::RegistrationQuestionKind;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::check_api_elements_count,
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// Checks that the question has a text, and that choice questions have something to choose from.
+fn registration_question_check(
+  question: &str,
+  kind: RegistrationQuestionKind,
+  choices: &[String],
+) -> LemmyResult<()> {
+  check_api_elements_count(choices.len())?;
+  if question.trim().is_empty() || choices.iter().any(|c| c.trim().is_empty()) {
+    Err(LemmyErrorType::InvalidRegistrationQuestion)?
+  }
+  if kind == RegistrationQuestionKind::Choice && choices.is_empty() {
+    Err(LemmyErrorType::InvalidRegistrationQuestion)?
+  }
+  Ok(())
+}
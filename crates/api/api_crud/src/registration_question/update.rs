@@ -0,0 +1,57 @@
+use super::registration_question_check;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
+use lemmy_db_schema::source::registration_question::{
+  RegistrationQuestion,
+  RegistrationQuestionUpdateForm,
+};
+use lemmy_db_schema_file::enums::AdminPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_registration_applications::api::{
+  EditRegistrationQuestion,
+  RegistrationQuestionResponse,
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn update_registration_question(
+  Json(data): Json<EditRegistrationQuestion>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RegistrationQuestionResponse>> {
+  // Make sure user is an admin
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::ReviewRegistrations),
+    "UpdateRegistrationQuestion",
+    Some(data.id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
+
+  // Check the question as it will be after the edit
+  let current = RegistrationQuestion::read(&mut context.pool(), data.id).await?;
+  registration_question_check(
+    data.question.as_deref().unwrap_or(&current.question),
+    data.kind.unwrap_or(current.kind),
+    data.choices.as_deref().unwrap_or(&current.choices),
+  )?;
+
+  let form = RegistrationQuestionUpdateForm {
+    question: data.question.map(|q| q.trim().to_string()),
+    kind: data.kind,
+    choices: data.choices,
+    required: data.required,
+    display_order: data.display_order,
+    updated_at: Some(Some(Utc::now())),
+  };
+  let registration_question =
+    RegistrationQuestion::update(&mut context.pool(), data.id, &form).await?;
+
+  Ok(Json(RegistrationQuestionResponse {
+    registration_question,
+  }))
+}
@@ -0,0 +1,50 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
+use lemmy_db_schema::source::registration_screening_rule::{
+  RegistrationScreeningRule,
+  RegistrationScreeningRuleInsertForm,
+};
+use lemmy_db_schema_file::enums::AdminPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_registration_applications::api::{
+  CreateRegistrationScreeningRule,
+  RegistrationScreeningRuleResponse,
+};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn create_registration_screening_rule(
+  Json(data): Json<CreateRegistrationScreeningRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RegistrationScreeningRuleResponse>> {
+  // Make sure user is an admin
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::ReviewRegistrations),
+    "CreateRegistrationScreeningRule",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
+
+  if !RegistrationScreeningRule::is_valid(data.kind, &data.value) {
+    Err(LemmyErrorType::InvalidScreeningRule)?
+  }
+
+  let form = RegistrationScreeningRuleInsertForm {
+    reason: data.reason,
+    ..RegistrationScreeningRuleInsertForm::new(
+      data.kind,
+      data.value.trim().to_string(),
+      data.action,
+    )
+  };
+  let registration_screening_rule =
+    RegistrationScreeningRule::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(RegistrationScreeningRuleResponse {
+    registration_screening_rule,
+  }))
+}
@@ -0,0 +1,30 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_action};
+use lemmy_db_schema::source::registration_screening_rule::RegistrationScreeningRule;
+use lemmy_db_schema_file::enums::AdminPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_registration_applications::api::DeleteRegistrationScreeningRule;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_registration_screening_rule(
+  Json(data): Json<DeleteRegistrationScreeningRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  // Make sure user is an admin
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::ReviewRegistrations),
+    "DeleteRegistrationScreeningRule",
+    Some(data.id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
+
+  RegistrationScreeningRule::delete(&mut context.pool(), data.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,21 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_permission};
+use lemmy_db_schema::source::registration_screening_rule::RegistrationScreeningRule;
+use lemmy_db_schema_file::enums::AdminPermission;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_registration_applications::api::ListRegistrationScreeningRulesResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_registration_screening_rules(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListRegistrationScreeningRulesResponse>> {
+  // The rules are only visible to admins, so they can't be worked around
+  check_admin_permission(&local_user_view, Some(AdminPermission::ReviewRegistrations))?;
+
+  let registration_screening_rules = RegistrationScreeningRule::list(&mut context.pool()).await?;
+
+  Ok(Json(ListRegistrationScreeningRulesResponse {
+    registration_screening_rules,
+  }))
+}
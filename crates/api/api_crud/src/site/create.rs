@@ -6,12 +6,8 @@ use chrono::Utc;
 use lemmy_api_utils::{
   context::LemmyContext,
   utils::{
-    generate_inbox_url,
-    get_url_blocklist,
-    is_admin,
-    local_site_rate_limit_to_rate_limit_config,
-    process_markdown_opt,
-    slur_regex,
+    check_admin_action, generate_inbox_url, get_url_blocklist,
+    local_site_rate_limit_to_rate_limit_config, process_markdown_opt, slur_regex,
   },
 };
 use lemmy_db_schema::source::{
@@ -19,12 +15,17 @@ use lemmy_db_schema::source::{
   local_site_rate_limit::{LocalSiteRateLimit, LocalSiteRateLimitUpdateForm},
   site::{Site, SiteUpdateForm},
 };
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::{
   SiteView,
   api::{CreateSite, SiteResponse},
 };
-use lemmy_diesel_utils::{dburl::DbUrl, traits::Crud, utils::diesel_string_update};
+use lemmy_diesel_utils::{
+  dburl::DbUrl,
+  traits::Crud,
+  utils::{diesel_opt_number_update, diesel_string_update},
+};
 use lemmy_utils::{
   error::{LemmyErrorType, LemmyResult},
   utils::{
@@ -47,7 +48,15 @@ pub async fn create_site(
   let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
 
   // Make sure user is an admin; other types of users should not create site data...
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "CreateSite",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   validate_create_payload(&local_site, &data)?;
 
@@ -91,6 +100,13 @@ pub async fn create_site(
     default_comment_sort_type: data.default_comment_sort_type,
     legal_information: diesel_string_update(data.legal_information.as_deref()),
     application_email_admins: data.application_email_admins,
+    session_idle_timeout_seconds: diesel_opt_number_update(data.session_idle_timeout_seconds),
+    invite_min_account_age_days: diesel_opt_number_update(data.invite_min_account_age_days),
+    account_deletion_grace_period_days: diesel_opt_number_update(
+      data.account_deletion_grace_period_days,
+    ),
+    edit_history_visibility: data.edit_history_visibility,
+    edit_history_retention_days: diesel_opt_number_update(data.edit_history_retention_days),
     updated_at: Some(Some(Utc::now())),
     slur_filter_regex: diesel_string_update(data.slur_filter_regex.as_deref()),
     federation_enabled: data.federation_enabled,
@@ -104,6 +120,8 @@ pub async fn create_site(
     disallow_nsfw_content: data.disallow_nsfw_content,
     disable_email_notifications: data.disable_email_notifications,
     suggested_communities: data.suggested_communities,
+    email_domain_allowlist_only: data.email_domain_allowlist_only,
+    block_disposable_email_domains: data.block_disposable_email_domains,
     ..Default::default()
   };
 
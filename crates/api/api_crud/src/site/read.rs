@@ -3,9 +3,11 @@ use lemmy_api_utils::{context::LemmyContext, plugins::plugin_metadata};
 use lemmy_db_schema::source::{
   actor_language::SiteLanguage,
   language::Language,
+  local_site_email_domain::LocalSiteEmailDomain,
   local_site_url_blocklist::LocalSiteUrlBlocklist,
   oauth_provider::AdminOAuthProvider,
   registration_application::RegistrationApplication,
+  registration_question::RegistrationQuestion,
   tagline::Tagline,
 };
 use lemmy_db_views_local_user::LocalUserView;
@@ -24,12 +26,13 @@ pub async fn get_site(
     .await
     .map_err(|e| anyhow::anyhow!("Failed to construct site response: {e}"))?;
 
-  // filter oauth_providers for public access
+  // filter oauth_providers and blocked email domains for public access
   if !local_user_view
     .map(|l| l.local_user.admin)
     .unwrap_or_default()
   {
     site_response.admin_oauth_providers = vec![];
+    site_response.email_domains.retain(|d| d.allowed);
   }
 
   Ok(Json(site_response))
@@ -47,6 +50,7 @@ async fn read_site(context: &LemmyContext) -> LemmyResult<GetSiteResponse> {
   let all_languages = Language::read_all(&mut context.pool()).await?;
   let discussion_languages = SiteLanguage::read_local_raw(&mut context.pool()).await?;
   let blocked_urls = LocalSiteUrlBlocklist::get_all(&mut context.pool()).await?;
+  let email_domains = LocalSiteEmailDomain::get_all(&mut context.pool()).await?;
   let tagline = Tagline::get_random(&mut context.pool()).await.ok();
   let admin_oauth_providers = AdminOAuthProvider::get_all(&mut context.pool()).await?;
   let oauth_providers =
@@ -56,6 +60,7 @@ async fn read_site(context: &LemmyContext) -> LemmyResult<GetSiteResponse> {
       .await
       .ok()
       .and_then(|u| u.updated_published_duration());
+  let registration_questions = RegistrationQuestion::list(&mut context.pool()).await?;
 
   Ok(GetSiteResponse {
     site_view,
@@ -64,11 +69,13 @@ async fn read_site(context: &LemmyContext) -> LemmyResult<GetSiteResponse> {
     all_languages,
     discussion_languages,
     blocked_urls,
+    email_domains,
     tagline,
     oauth_providers,
     admin_oauth_providers,
     image_upload_disabled: context.settings().pictrs()?.image_upload_disabled,
     active_plugins: plugin_metadata(),
     last_application_duration_seconds,
+    registration_questions,
   })
 }
@@ -6,22 +6,20 @@ use chrono::Utc;
 use lemmy_api_utils::{
   context::LemmyContext,
   utils::{
-    get_url_blocklist,
-    is_admin,
-    local_site_rate_limit_to_rate_limit_config,
-    process_markdown_opt,
-    slur_regex,
+    check_admin_action, get_url_blocklist, local_site_rate_limit_to_rate_limit_config,
+    process_markdown_opt, slur_regex,
   },
 };
 use lemmy_db_schema::source::{
   actor_language::SiteLanguage,
   local_site::{LocalSite, LocalSiteUpdateForm},
+  local_site_email_domain::LocalSiteEmailDomain,
   local_site_rate_limit::{LocalSiteRateLimit, LocalSiteRateLimitUpdateForm},
   local_site_url_blocklist::LocalSiteUrlBlocklist,
   local_user::LocalUser,
   site::{Site, SiteUpdateForm},
 };
-use lemmy_db_schema_file::enums::RegistrationMode;
+use lemmy_db_schema_file::enums::{AdminPermission, RegistrationMode};
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::{
   SiteView,
@@ -34,6 +32,7 @@ use lemmy_diesel_utils::{
 use lemmy_utils::{
   error::LemmyResult,
   utils::{
+    email_domain::check_email_domains_are_valid,
     slurs::check_slurs_opt,
     validation::{
       build_and_check_regex,
@@ -55,7 +54,15 @@ pub async fn update_site(
   let site = site_view.site;
 
   // Make sure user is an admin; other types of users should not update site data...
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "EditSite",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   validate_update_payload(&local_site, &data)?;
 
@@ -100,6 +107,13 @@ pub async fn update_site(
     default_post_sort_type: data.default_post_sort_type,
     default_post_time_range_seconds,
     default_items_per_page,
+    session_idle_timeout_seconds: diesel_opt_number_update(data.session_idle_timeout_seconds),
+    invite_min_account_age_days: diesel_opt_number_update(data.invite_min_account_age_days),
+    account_deletion_grace_period_days: diesel_opt_number_update(
+      data.account_deletion_grace_period_days,
+    ),
+    edit_history_visibility: data.edit_history_visibility,
+    edit_history_retention_days: diesel_opt_number_update(data.edit_history_retention_days),
     default_comment_sort_type: data.default_comment_sort_type,
     legal_information: diesel_string_update(data.legal_information.as_deref()),
     application_email_admins: data.application_email_admins,
@@ -118,6 +132,8 @@ pub async fn update_site(
     disallow_nsfw_content: data.disallow_nsfw_content,
     disable_email_notifications: data.disable_email_notifications,
     suggested_communities: data.suggested_communities,
+    email_domain_allowlist_only: data.email_domain_allowlist_only,
+    block_disposable_email_domains: data.block_disposable_email_domains,
     ..Default::default()
   };
 
@@ -156,6 +172,16 @@ pub async fn update_site(
     LocalSiteUrlBlocklist::replace(&mut context.pool(), parsed_urls).await?;
   }
 
+  if let Some(allowed_email_domains) = &data.allowed_email_domains {
+    let domains = check_email_domains_are_valid(allowed_email_domains)?;
+    LocalSiteEmailDomain::replace(&mut context.pool(), true, domains).await?;
+  }
+
+  if let Some(blocked_email_domains) = &data.blocked_email_domains {
+    let domains = check_email_domains_are_valid(blocked_email_domains)?;
+    LocalSiteEmailDomain::replace(&mut context.pool(), false, domains).await?;
+  }
+
   // TODO can't think of a better way to do this.
   // If the server suddenly requires email verification, or required applications, no old users
   // will be able to log in. It really only wants this to be a requirement for NEW signups.
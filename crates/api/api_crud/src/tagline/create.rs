@@ -2,9 +2,10 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_utils::{
   context::LemmyContext,
-  utils::{get_url_blocklist, is_admin, process_markdown, slur_regex},
+  utils::{check_admin_action, get_url_blocklist, process_markdown, slur_regex},
 };
 use lemmy_db_schema::source::tagline::{Tagline, TaglineInsertForm};
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::api::{CreateTagline, TaglineResponse};
 use lemmy_diesel_utils::traits::Crud;
@@ -16,7 +17,15 @@ pub async fn create_tagline(
   local_user_view: LocalUserView,
 ) -> Result<Json<TaglineResponse>, LemmyError> {
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "CreateTagline",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let slur_regex = slur_regex(&context).await?;
   let url_blocklist = get_url_blocklist(&context).await?;
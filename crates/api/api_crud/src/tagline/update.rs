@@ -3,9 +3,10 @@ use actix_web::web::Json;
 use chrono::Utc;
 use lemmy_api_utils::{
   context::LemmyContext,
-  utils::{get_url_blocklist, is_admin, process_markdown, slur_regex},
+  utils::{check_admin_action, get_url_blocklist, process_markdown, slur_regex},
 };
 use lemmy_db_schema::source::tagline::{Tagline, TaglineUpdateForm};
+use lemmy_db_schema_file::enums::AdminPermission;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::api::{TaglineResponse, UpdateTagline};
 use lemmy_diesel_utils::traits::Crud;
@@ -17,7 +18,15 @@ pub async fn update_tagline(
   local_user_view: LocalUserView,
 ) -> Result<Json<TaglineResponse>, LemmyError> {
   // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "UpdateTagline",
+    Some(data.id.0),
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   let slur_regex = slur_regex(&context).await?;
   let url_blocklist = get_url_blocklist(&context).await?;
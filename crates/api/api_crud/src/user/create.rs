@@ -9,6 +9,7 @@ use lemmy_api_utils::{
   claims::Claims,
   context::LemmyContext,
   utils::{
+    check_email_domain_allowed,
     check_email_verified,
     check_local_user_valid,
     check_registration_application,
@@ -27,7 +28,14 @@ use lemmy_db_schema::{
   source::{
     actor_language::SiteLanguage,
     captcha_answer::{CaptchaAnswer, CheckCaptchaAnswer},
-    community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
+    community::{
+      Community,
+      CommunityActions,
+      CommunityFollowerForm,
+      CommunityInsertForm,
+      CommunityModeratorForm,
+    },
+    invite_code::InviteCode,
     language::Language,
     local_site::LocalSite,
     local_user::{LocalUser, LocalUserInsertForm},
@@ -36,19 +44,37 @@ use lemmy_db_schema::{
     person::{Person, PersonInsertForm},
     post::{Post, PostActions, PostInsertForm, PostLikeForm},
     registration_application::{RegistrationApplication, RegistrationApplicationInsertForm},
+    registration_question::{
+      RegistrationAnswer,
+      RegistrationAnswerInsertForm,
+      RegistrationQuestion,
+    },
+    registration_screening_rule::RegistrationScreeningRule,
+  },
+  traits::{ApubActor, Followable, Likeable},
+};
+use lemmy_db_schema_file::{
+  PersonId,
+  enums::{
+    CommunityFollowerState,
+    RegistrationMode,
+    RegistrationQuestionKind,
+    RegistrationScreeningAction,
   },
-  traits::{ApubActor, Likeable},
 };
-use lemmy_db_schema_file::enums::RegistrationMode;
 use lemmy_db_views_community::CommunityView;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_person::impls::PersonQuery;
-use lemmy_db_views_registration_applications::api::Register;
+use lemmy_db_views_registration_applications::api::{Register, RegistrationQuestionAnswer};
 use lemmy_db_views_site::{
   SiteView,
   api::{AuthenticateWithOauth, LoginResponse},
 };
-use lemmy_diesel_utils::{connection::get_conn, pagination::PagedResponse, traits::Crud};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  pagination::PagedResponse,
+  traits::Crud,
+};
 use lemmy_email::{
   account::send_verification_email_if_required,
   admin::send_new_applicant_email_to_admins,
@@ -59,13 +85,16 @@ use lemmy_utils::{
   spawn_try_task,
   utils::{
     slurs::{check_slurs, check_slurs_opt},
-    validation::is_valid_actor_name,
+    validation::{check_api_elements_count, check_code_verifier, is_valid_actor_name},
   },
 };
-use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
-use std::{collections::HashSet, sync::LazyLock, time::Duration};
+use std::{
+  collections::HashSet,
+  net::{IpAddr, SocketAddr},
+  time::Duration,
+};
 use tracing::info;
 
 #[skip_serializing_none]
@@ -101,9 +130,17 @@ pub async fn register(
     Err(LemmyErrorType::EmailRequired)?
   }
 
-  // make sure the registration answer is provided when the registration application is required
+  // make sure the registration answers are provided when the registration application is required
+  let mut answers = vec![];
   if local_site.site_setup {
-    validate_registration_answer(require_registration_application, &data.answer)?;
+    answers = validate_registration_answers(
+      pool,
+      require_registration_application,
+      &data.answer,
+      &data.answers,
+    )
+    .await?;
+    validate_invite_code(&local_site, &data.invite_code)?;
   }
 
   // Make sure passwords match
@@ -126,16 +163,31 @@ pub async fn register(
   let slur_regex = slur_regex(&context).await?;
   check_slurs(&data.username, &slur_regex)?;
   check_slurs_opt(&data.answer, &slur_regex)?;
+  for (_, answer) in &answers {
+    check_slurs(answer, &slur_regex)?;
+  }
 
   Person::check_username_taken(pool, &data.username).await?;
 
   if let Some(email) = &data.email {
     LocalUser::check_is_email_taken(pool, email).await?;
+    if local_site.site_setup {
+      check_email_domain_allowed(email, &local_site, pool).await?;
+    }
   }
 
+  let (screening_action, screening_reasons) =
+    if local_site.site_setup && require_registration_application {
+      screen_registration(pool, data.email.as_deref(), &req, &data.answer, &answers).await?
+    } else {
+      (None, vec![])
+    };
+  let application_pending = require_registration_application
+    && screening_action != Some(RegistrationScreeningAction::Approve);
+
   // Automatically set their application as accepted, if they created this with open registration.
   // Also fixes a bug which allows users to log in when registrations are changed to closed.
-  let accepted_application = Some(!require_registration_application);
+  let accepted_application = Some(!application_pending);
 
   // Show nsfw content if param is true, or if content_warning exists
   let show_nsfw = data
@@ -152,6 +204,8 @@ pub async fn register(
   let user = conn
     .run_transaction(|conn| {
       async move {
+        let invite_code = use_invite_code(conn, tx_data.invite_code.as_deref()).await?;
+
         // We have to create both a person, and local_user
         let person = create_person(tx_data.username.clone(), &site_view, &tx_context, conn).await?;
 
@@ -160,6 +214,7 @@ pub async fn register(
           email: tx_data.email.as_deref().map(str::to_lowercase),
           show_nsfw: Some(show_nsfw),
           accepted_application,
+          invited_by_id: invite_code.as_ref().map(|i| i.creator_id),
           ..LocalUserInsertForm::new(person.id, Some(tx_data.password.to_string()))
         };
 
@@ -172,17 +227,22 @@ pub async fn register(
         )
         .await?;
 
-        if site_view.local_site.site_setup
-          && require_registration_application
-          && let Some(answer) = tx_data.answer.clone()
-        {
+        if let Some(invite_code) = &invite_code {
+          follow_invite_communities(conn, invite_code, person.id).await?;
+        }
+
+        if site_view.local_site.site_setup && require_registration_application {
           // Create the registration application
           let form = RegistrationApplicationInsertForm {
-            local_user_id: local_user.id,
-            answer,
+            screening_action,
+            screening_reasons,
+            ..RegistrationApplicationInsertForm::new(
+              local_user.id,
+              tx_data.answer.clone().unwrap_or_default(),
+            )
           };
 
-          RegistrationApplication::create(&mut conn.into(), &form).await?;
+          create_registration_application(conn, &form, answers).await?;
         }
 
         Ok(LocalUserView {
@@ -203,17 +263,16 @@ pub async fn register(
 
   let mut login_response = LoginResponse {
     jwt: None,
+    refresh_token: None,
     registration_created: false,
     verify_email_sent: false,
   };
 
   // Log the user in directly if the site is not setup, or email verification and application aren't
   // required
-  if !local_site.site_setup
-    || (!require_registration_application && !local_site.require_email_verification)
-  {
-    let jwt = Claims::generate(user.local_user.id, data.stay_logged_in, req, &context).await?;
-    login_response.jwt = Some(jwt);
+  if !local_site.site_setup || (!application_pending && !local_site.require_email_verification) {
+    login_response =
+      Claims::generate(user.local_user.id, data.stay_logged_in, req, &context).await?;
   } else {
     login_response.verify_email_sent = send_verification_email_if_required(
       &local_site,
@@ -223,7 +282,7 @@ pub async fn register(
     )
     .await?;
 
-    if require_registration_application {
+    if application_pending {
       login_response.registration_created = true;
     }
   }
@@ -300,6 +359,7 @@ pub async fn authenticate_with_oauth(
 
   let mut login_response = LoginResponse {
     jwt: None,
+    refresh_token: None,
     registration_created: false,
     verify_email_sent: false,
   };
@@ -369,8 +429,27 @@ pub async fn authenticate_with_oauth(
     } else {
       // No user was found by email => Register as new user
 
-      // make sure the registration answer is provided when the registration application is required
-      validate_registration_answer(require_registration_application, &data.answer)?;
+      // make sure the registration answers are provided when the registration application is
+      // required
+      let answers = validate_registration_answers(
+        pool,
+        require_registration_application,
+        &data.answer,
+        &data.answers,
+      )
+      .await?;
+      validate_invite_code(&local_site, &data.invite_code)?;
+      check_email_domain_allowed(&email, &local_site, pool).await?;
+
+      let (screening_action, screening_reasons) = if require_registration_application {
+        screen_registration(pool, Some(email.as_str()), &req, &data.answer, &answers).await?
+      } else {
+        (None, vec![])
+      };
+      let application_pending = require_registration_application
+        && screening_action != Some(RegistrationScreeningAction::Approve);
+      // prevent sign in until application is accepted
+      login_response.registration_created = application_pending;
 
       let slur_regex = slur_regex(&context).await?;
 
@@ -390,9 +469,14 @@ pub async fn authenticate_with_oauth(
 
             check_slurs(username, &slur_regex)?;
             check_slurs_opt(&tx_data.answer, &slur_regex)?;
+            for (_, answer) in &answers {
+              check_slurs(answer, &slur_regex)?;
+            }
 
             Person::check_username_taken(&mut conn.into(), username).await?;
 
+            let invite_code = use_invite_code(conn, tx_data.invite_code.as_deref()).await?;
+
             // We have to create a person, a local_user, and an oauth_account
             let person = create_person(username.clone(), &site_view, &tx_context, conn).await?;
 
@@ -400,8 +484,9 @@ pub async fn authenticate_with_oauth(
             let local_user_form = LocalUserInsertForm {
               email: Some(str::to_lowercase(&email)),
               show_nsfw: Some(show_nsfw),
-              accepted_application: Some(!require_registration_application),
+              accepted_application: Some(!application_pending),
               email_verified: Some(oauth_provider.auto_verify_email),
+              invited_by_id: invite_code.as_ref().map(|i| i.creator_id),
               ..LocalUserInsertForm::new(person.id, None)
             };
 
@@ -414,27 +499,28 @@ pub async fn authenticate_with_oauth(
             )
             .await?;
 
+            if let Some(invite_code) = &invite_code {
+              follow_invite_communities(conn, invite_code, person.id).await?;
+            }
+
             // Create the oauth account
             let oauth_account_form =
               OAuthAccountInsertForm::new(local_user.id, oauth_provider.id, oauth_user_id);
 
             OAuthAccount::create(&mut conn.into(), &oauth_account_form).await?;
 
-            // prevent sign in until application is accepted
-            if login_response.registration_created {
+            if require_registration_application {
               // Create the registration application
-              RegistrationApplication::create(
-                &mut conn.into(),
-                &RegistrationApplicationInsertForm {
-                  local_user_id: local_user.id,
-                  // We already check earlier that this Some, however using `ok_or` is cleaner
-                  // than unwrap or expect (which also requires clippy allow).
-                  answer: data
-                    .answer
-                    .ok_or(LemmyErrorType::RegistrationApplicationAnswerRequired)?,
-                },
-              )
-              .await?;
+              let form = RegistrationApplicationInsertForm {
+                screening_action,
+                screening_reasons,
+                ..RegistrationApplicationInsertForm::new(
+                  local_user.id,
+                  tx_data.answer.clone().unwrap_or_default(),
+                )
+              };
+
+              create_registration_application(conn, &form, answers).await?;
             }
             Ok(LocalUserView {
               person,
@@ -460,8 +546,7 @@ pub async fn authenticate_with_oauth(
   };
 
   if !login_response.registration_created && !login_response.verify_email_sent {
-    let jwt = Claims::generate(local_user.id, data.stay_logged_in, req, &context).await?;
-    login_response.jwt = Some(jwt);
+    login_response = Claims::generate(local_user.id, data.stay_logged_in, req, &context).await?;
   }
 
   Ok(Json(login_response))
@@ -553,12 +638,148 @@ async fn create_local_user(
   Ok(inserted_local_user)
 }
 
-fn validate_registration_answer(
+/// Checks the answers to the registration questions, and returns them with their questions. If the
+/// site has no questions, the single answer field is required instead.
+async fn validate_registration_answers(
+  pool: &mut DbPool<'_>,
   require_registration_application: bool,
   answer: &Option<String>,
+  answers: &Option<Vec<RegistrationQuestionAnswer>>,
+) -> LemmyResult<Vec<(RegistrationQuestion, String)>> {
+  if !require_registration_application {
+    return Ok(vec![]);
+  }
+
+  let questions = RegistrationQuestion::list(pool).await?;
+  if questions.is_empty() {
+    if answer.is_none() {
+      Err(LemmyErrorType::RegistrationApplicationAnswerRequired)?
+    }
+    return Ok(vec![]);
+  }
+
+  let answers = answers.as_deref().unwrap_or_default();
+  check_api_elements_count(answers.len())?;
+  let mut validated = vec![];
+  for question in questions {
+    let answer = answers
+      .iter()
+      .find(|a| a.question_id == question.id)
+      .map(|a| a.answer.trim())
+      .filter(|a| !a.is_empty());
+    match answer {
+      Some(answer) => {
+        if question.kind == RegistrationQuestionKind::Choice
+          && !question.choices.iter().any(|c| c == answer)
+        {
+          Err(LemmyErrorType::InvalidRegistrationAnswer)?
+        }
+        validated.push((question, answer.to_string()));
+      }
+      None if question.required => Err(LemmyErrorType::RegistrationApplicationAnswerRequired)?,
+      None => {}
+    }
+  }
+
+  Ok(validated)
+}
+
+/// Checks the registration against the screening rules of the site.
+async fn screen_registration(
+  pool: &mut DbPool<'_>,
+  email: Option<&str>,
+  req: &HttpRequest,
+  answer: &Option<String>,
+  answers: &[(RegistrationQuestion, String)],
+) -> LemmyResult<(Option<RegistrationScreeningAction>, Vec<String>)> {
+  let rules = RegistrationScreeningRule::list(pool).await?;
+  let ip = req
+    .connection_info()
+    .realip_remote_addr()
+    .and_then(parse_ip);
+  let answers: Vec<&str> = answer
+    .iter()
+    .map(String::as_str)
+    .chain(answers.iter().map(|(_, answer)| answer.as_str()))
+    .collect();
+
+  Ok(RegistrationScreeningRule::screen(
+    &rules, email, ip, &answers,
+  ))
+}
+
+/// The client address may include a port, depending on the proxy setup.
+fn parse_ip(addr: &str) -> Option<IpAddr> {
+  addr
+    .parse::<IpAddr>()
+    .ok()
+    .or_else(|| addr.parse::<SocketAddr>().ok().as_ref().map(SocketAddr::ip))
+}
+
+/// Creates the registration application together with the answers to the questionnaire.
+async fn create_registration_application(
+  conn: &mut AsyncPgConnection,
+  form: &RegistrationApplicationInsertForm,
+  answers: Vec<(RegistrationQuestion, String)>,
 ) -> LemmyResult<()> {
-  if require_registration_application && answer.is_none() {
-    Err(LemmyErrorType::RegistrationApplicationAnswerRequired)?
+  let application = RegistrationApplication::create(&mut conn.into(), form).await?;
+  let answer_forms: Vec<_> = answers
+    .into_iter()
+    .map(|(question, answer)| {
+      RegistrationAnswerInsertForm::new(
+        application.id,
+        Some(question.id),
+        question.question,
+        answer,
+      )
+    })
+    .collect();
+  if !answer_forms.is_empty() {
+    RegistrationAnswer::create_many(&mut conn.into(), &answer_forms).await?;
+  }
+
+  Ok(())
+}
+
+fn validate_invite_code(local_site: &LocalSite, invite_code: &Option<String>) -> LemmyResult<()> {
+  if local_site.registration_mode == RegistrationMode::InviteOnly && invite_code.is_none() {
+    Err(LemmyErrorType::InviteCodeRequired)?
+  }
+
+  Ok(())
+}
+
+/// Counts a use of the invite code, if one was given.
+async fn use_invite_code(
+  conn: &mut AsyncPgConnection,
+  invite_code: Option<&str>,
+) -> LemmyResult<Option<InviteCode>> {
+  match invite_code {
+    Some(code) => Ok(Some(InviteCode::use_code(&mut conn.into(), code).await?)),
+    None => Ok(None),
+  }
+}
+
+/// Makes the new user follow the communities of their invite code. Communities which were deleted
+/// or made non-public since the code was created are skipped.
+async fn follow_invite_communities(
+  conn: &mut AsyncPgConnection,
+  invite_code: &InviteCode,
+  person_id: PersonId,
+) -> LemmyResult<()> {
+  for community_id in &invite_code.community_ids {
+    let Ok(community) = Community::read(&mut conn.into(), *community_id).await else {
+      continue;
+    };
+    if community.local
+      && !community.deleted
+      && !community.removed
+      && community.visibility.can_view_without_login()
+    {
+      let form =
+        CommunityFollowerForm::new(community.id, person_id, CommunityFollowerState::Accepted);
+      CommunityActions::follow(&mut conn.into(), &form).await?;
+    }
   }
 
   Ok(())
@@ -639,20 +860,6 @@ fn read_user_info(user_info: &serde_json::Value, key: &str) -> LemmyResult<Strin
   Err(LemmyErrorType::OauthLoginFailed)?
 }
 
-#[allow(clippy::expect_used)]
-fn check_code_verifier(code_verifier: &str) -> LemmyResult<()> {
-  static VALID_CODE_VERIFIER_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^[a-zA-Z0-9\-._~]{43,128}$").expect("compile regex"));
-
-  let check = VALID_CODE_VERIFIER_REGEX.is_match(code_verifier);
-
-  if check {
-    Ok(())
-  } else {
-    Err(LemmyErrorType::InvalidCodeVerifier.into())
-  }
-}
-
 fn fetch_community_list(context: Data<LemmyContext>) {
   // Only do this in release mode.
   if cfg!(debug_assertions) {
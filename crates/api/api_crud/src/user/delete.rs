@@ -1,19 +1,17 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use bcrypt::verify;
-use lemmy_api_utils::{
-  context::LemmyContext,
-  send_activity::{ActivityChannel, SendActivityData},
-  utils::purge_user_account,
-};
+use chrono::{Days, Utc};
+use lemmy_api_utils::{context::LemmyContext, utils::delete_local_user_account};
 use lemmy_db_schema::source::{
-  community::CommunityActions,
+  local_user::{LocalUser, LocalUserUpdateForm},
   login_token::LoginToken,
-  oauth_account::OAuthAccount,
-  person::Person,
 };
 use lemmy_db_views_local_user::LocalUserView;
-use lemmy_db_views_site::api::{DeleteAccount, SuccessResponse};
+use lemmy_db_views_site::{
+  SiteView,
+  api::{DeleteAccount, SuccessResponse},
+};
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
 
 pub async fn delete_account(
@@ -21,8 +19,6 @@ pub async fn delete_account(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
-  let local_instance_id = local_user_view.person.instance_id;
-
   // Verify the password
   let valid: bool = local_user_view
     .local_user
@@ -34,31 +30,35 @@ pub async fn delete_account(
     Err(LemmyErrorType::IncorrectLogin)?
   }
 
-  if data.delete_content {
-    purge_user_account(local_user_view.person.id, local_instance_id, &context).await?;
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  if let Some(days) = local_site.account_deletion_grace_period_days {
+    // Only mark the account for deletion, it is deleted and federated by a scheduled task once the
+    // grace period is over.
+    let form = LocalUserUpdateForm {
+      deletion_scheduled_at: Some(Some(Utc::now() + Days::new(days.try_into()?))),
+      deletion_delete_content: Some(data.delete_content),
+      ..Default::default()
+    };
+    LocalUser::update(&mut context.pool(), local_user_view.local_user.id, &form).await?;
+    LoginToken::invalidate_all(&mut context.pool(), local_user_view.local_user.id).await?;
   } else {
-    // These are already run in purge_user_account,
-    // but should be done anyway even if delete_content is false
-    OAuthAccount::delete_user_accounts(&mut context.pool(), local_user_view.local_user.id).await?;
-    CommunityActions::leave_mod_team_for_all_communities(
-      &mut context.pool(),
-      local_user_view.person.id,
-    )
-    .await?;
-    Person::delete_account(
-      &mut context.pool(),
-      local_user_view.person.id,
-      local_instance_id,
-    )
-    .await?;
+    delete_local_user_account(local_user_view, data.delete_content, &context).await?;
   }
 
-  LoginToken::invalidate_all(&mut context.pool(), local_user_view.local_user.id).await?;
+  Ok(Json(SuccessResponse::default()))
+}
 
-  ActivityChannel::submit_activity(
-    SendActivityData::DeleteUser(local_user_view.person, data.delete_content),
-    &context,
-  )?;
+/// Cancels a scheduled account deletion while the grace period is not over yet.
+pub async fn cancel_account_deletion(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let form = LocalUserUpdateForm {
+    deletion_scheduled_at: Some(None),
+    deletion_delete_content: Some(false),
+    ..Default::default()
+  };
+  LocalUser::update(&mut context.pool(), local_user_view.local_user.id, &form).await?;
 
   Ok(Json(SuccessResponse::default()))
 }
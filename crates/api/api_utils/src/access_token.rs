@@ -0,0 +1,125 @@
+//! Restrictions for requests which are authenticated with a personal access token instead of a
+//! login jwt.
+//!
+//! The session middleware makes the token available for the whole request, so that the shared
+//! moderator and admin checks can enforce its scopes without changes to every handler.
+
+use lemmy_db_schema::{newtypes::CommunityId, source::personal_access_token::PersonalAccessToken};
+use lemmy_db_schema_file::enums::{AdminPermission, ApiTokenScope, ModeratorPermission};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+tokio::task_local! {
+  static ACCESS_TOKEN: PersonalAccessToken;
+}
+
+/// The rights which an OAuth app asks for, parsed from its space separated `scope` parameter.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct OAuthScope {
+  pub scopes: Vec<ApiTokenScope>,
+  pub moderate_community_ids: Vec<CommunityId>,
+}
+
+impl OAuthScope {
+  pub fn parse(scope: &str) -> LemmyResult<Self> {
+    let mut parsed = Self::default();
+    for s in scope.split_whitespace() {
+      let token_scope = match s {
+        "read" => ApiTokenScope::Read,
+        "post" => ApiTokenScope::Post,
+        "vote" => ApiTokenScope::Vote,
+        "admin:reports" => ApiTokenScope::AdminReports,
+        // The userinfo endpoint can be used with any app token, so these don't grant anything
+        "profile" => continue,
+        _ => {
+          let community_id = s
+            .strip_prefix("moderate:")
+            .and_then(|id| id.parse().ok())
+            .ok_or(LemmyErrorType::InvalidScope)?;
+          parsed
+            .moderate_community_ids
+            .push(CommunityId(community_id));
+          continue;
+        }
+      };
+      if !parsed.scopes.contains(&token_scope) {
+        parsed.scopes.push(token_scope);
+      }
+    }
+    Ok(parsed)
+  }
+}
+
+/// Runs the future with the given access token, if any.
+pub async fn with_access_token<F: Future>(token: Option<PersonalAccessToken>, f: F) -> F::Output {
+  match token {
+    Some(token) => ACCESS_TOKEN.scope(token, f).await,
+    None => f.await,
+  }
+}
+
+/// Checks that a request made with an access token holds the given scope.
+pub fn check_access_token_scope(
+  token: &PersonalAccessToken,
+  scope: ApiTokenScope,
+) -> LemmyResult<()> {
+  if token.scopes.contains(&scope) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::MissingTokenScope)?
+  }
+}
+
+/// Moderator actions with an access token are only allowed in the communities listed on the token,
+/// and for admins resolving reports with the `AdminReports` scope.
+pub(crate) fn check_access_token_moderation(
+  local_user_view: &LocalUserView,
+  community_id: CommunityId,
+  permission: Option<ModeratorPermission>,
+) -> LemmyResult<()> {
+  ACCESS_TOKEN
+    .try_with(|token| -> LemmyResult<()> {
+      let admin_reports = local_user_view.local_user.admin
+        && permission == Some(ModeratorPermission::HandleReports)
+        && token.scopes.contains(&ApiTokenScope::AdminReports);
+      if token.moderate_community_ids.contains(&community_id) || admin_reports {
+        Ok(())
+      } else {
+        Err(LemmyErrorType::MissingTokenScope)?
+      }
+    })
+    .unwrap_or(Ok(()))
+}
+
+/// Admin actions with an access token are limited to handling reports.
+pub(crate) fn check_access_token_admin(permission: Option<AdminPermission>) -> LemmyResult<()> {
+  ACCESS_TOKEN
+    .try_with(|token| -> LemmyResult<()> {
+      if permission == Some(AdminPermission::HandleReports) {
+        check_access_token_scope(token, ApiTokenScope::AdminReports)
+      } else {
+        Err(LemmyErrorType::MissingTokenScope)?
+      }
+    })
+    .unwrap_or(Ok(()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::OAuthScope;
+  use lemmy_db_schema::newtypes::CommunityId;
+  use lemmy_db_schema_file::enums::ApiTokenScope;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_parse_oauth_scope() -> LemmyResult<()> {
+    let scope = OAuthScope::parse("profile read vote read moderate:5")?;
+    assert_eq!(vec![ApiTokenScope::Read, ApiTokenScope::Vote], scope.scopes);
+    assert_eq!(vec![CommunityId(5)], scope.moderate_community_ids);
+
+    assert!(OAuthScope::parse("read write").is_err());
+    assert!(OAuthScope::parse("moderate:abc").is_err());
+    Ok(())
+  }
+}
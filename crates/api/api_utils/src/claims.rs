@@ -5,10 +5,16 @@ use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode}
 use lemmy_db_schema::{
   newtypes::LocalUserId,
   source::login_token::{LoginToken, LoginTokenCreateForm},
+  utils::hash_token,
 };
+use lemmy_db_views_site::{SiteView, api::LoginResponse};
 use lemmy_diesel_utils::sensitive::SensitiveString;
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Access tokens expire after one hour, then clients need to use their refresh token.
+pub const ACCESS_TOKEN_LIFETIME_SECONDS: i64 = 3600;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Claims {
@@ -20,6 +26,9 @@ pub struct Claims {
   pub iat: i64,
   /// Expiration timestamp
   pub exp: i64,
+  /// Random token id, so that tokens issued in the same second are still different.
+  #[serde(default)]
+  pub jti: String,
 }
 
 impl Claims {
@@ -30,35 +39,28 @@ impl Claims {
     let claims =
       decode::<Claims>(jwt, &key, &validation).with_lemmy_type(LemmyErrorType::NotLoggedIn)?;
     let user_id = LocalUserId(claims.claims.sub.parse()?);
-    LoginToken::validate(&mut context.pool(), user_id, jwt).await?;
+    let idle_timeout = Self::idle_timeout(context).await?;
+    LoginToken::validate(&mut context.pool(), user_id, jwt, idle_timeout).await?;
     Ok(user_id)
   }
 
+  /// Starts a new session. The returned access token is short lived, and the refresh token is used
+  /// to get a new one with [Claims::refresh].
   pub async fn generate(
     user_id: LocalUserId,
     stay_logged_in: Option<bool>,
     req: HttpRequest,
     context: &LemmyContext,
-  ) -> LemmyResult<SensitiveString> {
-    let hostname = context.settings().hostname.clone();
-    let now = Utc::now();
-    let exp = if stay_logged_in.unwrap_or_default() {
-      // Login doesnt expire
-      DateTime::<Utc>::MAX_UTC
+  ) -> LemmyResult<LoginResponse> {
+    let jwt = Self::encode(user_id, Self::access_token_expiry(), context)?;
+    let refresh_token = LoginToken::generate_refresh_token();
+    let expires_at = if stay_logged_in.unwrap_or_default() {
+      // Session only ends when it is idle for too long
+      None
     } else {
-      // Login expires after one week
-      now + Duration::weeks(1)
+      // Session ends after one week
+      Some(Utc::now() + Duration::weeks(1))
     };
-    let my_claims = Claims {
-      sub: user_id.0.to_string(),
-      iss: hostname,
-      iat: now.timestamp(),
-      exp: exp.timestamp(),
-    };
-
-    let secret = &context.secret().jwt_secret;
-    let key = EncodingKey::from_secret(secret.as_ref());
-    let token: SensitiveString = encode(&Header::default(), &my_claims, &key)?.into();
     let ip = req
       .connection_info()
       .realip_remote_addr()
@@ -69,13 +71,94 @@ impl Claims {
       .and_then(|ua| ua.to_str().ok())
       .map(ToString::to_string);
     let form = LoginTokenCreateForm {
-      token: token.clone(),
+      token: jwt.clone(),
       user_id,
       ip,
       user_agent,
+      refresh_token_hash: Some(hash_token(&refresh_token)),
+      expires_at,
     };
     LoginToken::create(&mut context.pool(), form).await?;
-    Ok(token)
+    Ok(LoginResponse {
+      jwt: Some(jwt),
+      refresh_token: Some(refresh_token),
+      registration_created: false,
+      verify_email_sent: false,
+    })
+  }
+
+  /// Issues a new access token for an existing session, and rotates its refresh token. Fails if
+  /// the session expired or was idle for longer than the admin allows.
+  pub async fn refresh(refresh_token: &str, context: &LemmyContext) -> LemmyResult<LoginResponse> {
+    let session = LoginToken::read_from_refresh_token(&mut context.pool(), refresh_token).await?;
+    if session.has_ended(Self::idle_timeout(context).await?) {
+      LoginToken::invalidate(&mut context.pool(), &session.token).await?;
+      Err(LemmyErrorType::NotLoggedIn)?
+    }
+
+    let jwt = Self::encode(session.user_id, Self::access_token_expiry(), context)?;
+    let new_refresh_token = LoginToken::generate_refresh_token();
+    LoginToken::rotate(&mut context.pool(), refresh_token, &jwt, &new_refresh_token).await?;
+    Ok(LoginResponse {
+      jwt: Some(jwt),
+      refresh_token: Some(new_refresh_token),
+      registration_created: false,
+      verify_email_sent: false,
+    })
+  }
+
+  /// Api v3 clients don't know about refresh tokens, so they get an access token which is valid
+  /// as long as the session instead. It still stops working when the session expires, is idle
+  /// for too long or gets revoked, which is checked in [Claims::validate].
+  pub async fn into_long_lived(
+    res: LoginResponse,
+    context: &LemmyContext,
+  ) -> LemmyResult<LoginResponse> {
+    let Some(refresh_token) = &res.refresh_token else {
+      return Ok(res);
+    };
+    let session = LoginToken::read_from_refresh_token(&mut context.pool(), refresh_token).await?;
+    let expires_at = session.expires_at.unwrap_or(DateTime::<Utc>::MAX_UTC);
+    let jwt = Self::encode(session.user_id, expires_at, context)?;
+    let new_refresh_token = LoginToken::generate_refresh_token();
+    LoginToken::rotate(&mut context.pool(), refresh_token, &jwt, &new_refresh_token).await?;
+    Ok(LoginResponse {
+      jwt: Some(jwt),
+      refresh_token: Some(new_refresh_token),
+      ..res
+    })
+  }
+
+  async fn idle_timeout(context: &LemmyContext) -> LemmyResult<Option<Duration>> {
+    let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+    Ok(
+      local_site
+        .session_idle_timeout_seconds
+        .map(|s| Duration::seconds(s.into())),
+    )
+  }
+
+  fn access_token_expiry() -> DateTime<Utc> {
+    Utc::now() + Duration::seconds(ACCESS_TOKEN_LIFETIME_SECONDS)
+  }
+
+  fn encode(
+    user_id: LocalUserId,
+    expires_at: DateTime<Utc>,
+    context: &LemmyContext,
+  ) -> LemmyResult<SensitiveString> {
+    let now = Utc::now();
+    let my_claims = Claims {
+      sub: user_id.0.to_string(),
+      iss: context.settings().hostname.clone(),
+      iat: now.timestamp(),
+      exp: expires_at.timestamp(),
+      jti: Uuid::new_v4().simple().to_string(),
+    };
+
+    let secret = &context.secret().jwt_secret;
+    let key = EncodingKey::from_secret(secret.as_ref());
+    Ok(encode(&Header::default(), &my_claims, &key)?.into())
   }
 }
 
@@ -84,13 +167,17 @@ mod tests {
 
   use crate::{claims::Claims, context::LemmyContext};
   use actix_web::test::TestRequest;
-  use lemmy_db_schema::source::{
-    instance::Instance,
-    local_user::{LocalUser, LocalUserInsertForm},
-    person::{Person, PersonInsertForm},
+  use chrono::{DateTime, Utc};
+  use jsonwebtoken::{DecodingKey, Validation, decode};
+  use lemmy_db_schema::{
+    source::{
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+    },
+    test_data::TestData,
   };
   use lemmy_diesel_utils::traits::Crud;
-  use lemmy_utils::error::LemmyResult;
+  use lemmy_utils::error::{LemmyErrorType, LemmyResult};
   use pretty_assertions::assert_eq;
   use serial_test::serial;
 
@@ -100,9 +187,9 @@ mod tests {
     let context = LemmyContext::init_test_context().await;
     let pool = &mut context.pool();
 
-    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let data = TestData::create(pool).await?;
 
-    let new_person = PersonInsertForm::test_form(inserted_instance.id, "Gerry9812");
+    let new_person = PersonInsertForm::test_form(data.instance.id, "Gerry9812");
 
     let inserted_person = Person::create(pool, &new_person).await?;
 
@@ -111,13 +198,35 @@ mod tests {
     let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
 
     let req = TestRequest::default().to_http_request();
-    let jwt = Claims::generate(inserted_local_user.id, None, req, &context).await?;
+    let login = Claims::generate(inserted_local_user.id, None, req, &context).await?;
+    let jwt = login.jwt.ok_or(LemmyErrorType::NotLoggedIn)?;
+    let refresh_token = login.refresh_token.ok_or(LemmyErrorType::NotLoggedIn)?;
 
     let valid = Claims::validate(&jwt, &context).await;
     assert!(valid.is_ok());
 
+    // Refreshing replaces both tokens
+    let refreshed = Claims::refresh(&refresh_token, &context).await?;
+    let new_jwt = refreshed.jwt.ok_or(LemmyErrorType::NotLoggedIn)?;
+    assert!(Claims::validate(&new_jwt, &context).await.is_ok());
+    assert!(Claims::validate(&jwt, &context).await.is_err());
+    assert!(Claims::refresh(&refresh_token, &context).await.is_err());
+
+    // Api v3 logins get an access token which lasts as long as the session
+    let req = TestRequest::default().to_http_request();
+    let login = Claims::generate(inserted_local_user.id, Some(true), req, &context).await?;
+    let short_jwt = login.jwt.clone().ok_or(LemmyErrorType::NotLoggedIn)?;
+    let long_lived = Claims::into_long_lived(login, &context).await?;
+    let long_jwt = long_lived.jwt.ok_or(LemmyErrorType::NotLoggedIn)?;
+    let key = DecodingKey::from_secret(context.secret().jwt_secret.as_ref());
+    let claims = decode::<Claims>(&long_jwt, &key, &Validation::default())?.claims;
+    assert_eq!(DateTime::<Utc>::MAX_UTC.timestamp(), claims.exp);
+    assert!(Claims::validate(&long_jwt, &context).await.is_ok());
+    assert!(Claims::validate(&short_jwt, &context).await.is_err());
+
     let num_deleted = Person::delete(pool, inserted_person.id).await?;
     assert_eq!(1, num_deleted);
+    data.delete(pool).await?;
 
     Ok(())
   }
@@ -1,3 +1,4 @@
+pub mod access_token;
 pub mod build_response;
 pub mod claims;
 pub mod context;
@@ -6,14 +6,14 @@ use crate::{
 use activitypub_federation::config::Data;
 use chrono::{DateTime, Utc};
 use encoding_rs::{Encoding, UTF_8};
-use futures::StreamExt;
+use futures::{StreamExt, future::join_all};
 use lemmy_db_schema::source::{
   images::{ImageDetailsInsertForm, LocalImage, LocalImageForm},
   post::{Post, PostUpdateForm},
   site::Site,
 };
 use lemmy_db_views_post::api::{LinkMetadata, OpenGraphData};
-use lemmy_diesel_utils::traits::Crud;
+use lemmy_diesel_utils::{dburl::DbUrl, traits::Crud};
 use lemmy_utils::{
   REQWEST_TIMEOUT,
   VERSION,
@@ -265,6 +265,29 @@ pub async fn generate_post_link_metadata(
   Ok(())
 }
 
+/// Processes the images of a gallery post in the same way as the post url, so that they are
+/// proxied if enabled and their dimensions and blurhash are stored.
+pub async fn generate_post_gallery_urls(urls: Vec<DbUrl>, context: &LemmyContext) -> Vec<DbUrl> {
+  join_all(urls.into_iter().map(|url| async move {
+    let metadata = fetch_link_metadata(&url, context, false)
+      .await
+      .unwrap_or_default();
+    let is_image = metadata
+      .content_type
+      .as_ref()
+      .is_some_and(|content_type| content_type.starts_with("image"));
+    if is_image {
+      proxy_image_link(url.clone().into(), false, context)
+        .await
+        .map_err(|e| warn!("Failed to proxy gallery image: {e}"))
+        .unwrap_or(url)
+    } else {
+      url
+    }
+  }))
+  .await
+}
+
 /// Extract site metadata from HTML Opengraph attributes.
 fn extract_opengraph_data(html_bytes: &[u8], url: &Url) -> LemmyResult<OpenGraphData> {
   let html = String::from_utf8_lossy(html_bytes);
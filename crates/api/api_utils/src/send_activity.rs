@@ -108,6 +108,32 @@ pub enum SendActivityData {
     receiver: Either<Site, Community>,
   },
   UpdateMultiCommunity(MultiCommunity, Person),
+  AppealBan {
+    actor: Person,
+    community: Community,
+    reason: String,
+  },
+}
+
+impl SendActivityData {
+  /// Returns true if the activity is about a post or comment which is held back for review.
+  fn is_held_back(&self) -> bool {
+    use SendActivityData::*;
+    match self {
+      CreatePost(post)
+      | UpdatePost(post)
+      | DeletePost(post, ..)
+      | RemovePost { post, .. }
+      | LockPost(post, ..)
+      | FeaturePost(post, ..) => post.quarantined || post.pending_approval,
+      CreateComment(comment)
+      | UpdateComment(comment)
+      | DeleteComment(comment, ..)
+      | RemoveComment { comment, .. }
+      | LockComment(comment, ..) => comment.quarantined,
+      _ => false,
+    }
+  }
 }
 
 // TODO: instead of static, move this into LemmyContext. make sure that stopping the process with
@@ -135,6 +161,11 @@ impl ActivityChannel {
   }
 
   pub fn submit_activity(data: SendActivityData, _context: &Data<LemmyContext>) -> LemmyResult<()> {
+    // Content from quarantined persons and posts waiting for mod approval are never federated.
+    // They get sent once approved.
+    if data.is_held_back() {
+      return Ok(());
+    }
     // could do `ACTIVITY_CHANNEL.keepalive_sender.lock()` instead and get rid of weak_sender,
     // not sure which way is more efficient
     if let Some(sender) = ACTIVITY_CHANNEL.weak_sender.upgrade() {
@@ -1,28 +1,44 @@
 use crate::{
+  access_token::{check_access_token_admin, check_access_token_moderation},
   claims::Claims,
   context::LemmyContext,
-  request::{delete_image_alias, fetch_pictrs_proxied_image_details, purge_image_from_pictrs_url},
+  request::{
+    delete_image_alias,
+    fetch_pictrs_proxied_image_details,
+    generate_post_gallery_urls,
+    purge_image_from_pictrs_url,
+  },
+  send_activity::{ActivityChannel, SendActivityData},
 };
+use activitypub_federation::config::Data;
 use actix_web::{HttpRequest, http::header::Header};
 use actix_web_httpauth::headers::authorization::{Authorization, Bearer};
 use chrono::{DateTime, Days, Local, TimeZone, Utc};
 use enum_map::{EnumMap, enum_map};
 use lemmy_db_schema::{
-  newtypes::{CommunityId, PostId, PostOrCommentId, TagId},
+  newtypes::{CommunityId, PostId, PostOrCommentId, TagId, UserDataExportId},
   source::{
+    admin_audit_log::{AdminAuditLog, AdminAuditLogInsertForm},
     comment::{Comment, CommentActions, CommentLikeForm},
     community::{Community, CommunityActions, CommunityUpdateForm},
     images::{ImageDetails, RemoteImage},
     instance::InstanceActions,
     local_site::LocalSite,
+    local_site_email_domain::LocalSiteEmailDomain,
     local_site_rate_limit::LocalSiteRateLimit,
     local_site_url_blocklist::LocalSiteUrlBlocklist,
+    local_user::{LocalUser, LocalUserUpdateForm},
+    login_token::LoginToken,
     modlog::{Modlog, ModlogInsertForm},
     oauth_account::OAuthAccount,
     person::{Person, PersonUpdateForm},
+    personal_access_token::PersonalAccessToken,
     post::{Post, PostActions, PostLikeForm, PostReadCommentsForm},
+    post_event::{PostEvent, PostEventForm},
+    post_gallery::{PostGalleryItem, PostGalleryItemInsertForm},
     private_message::PrivateMessage,
     registration_application::RegistrationApplication,
+    revision::DiffLine,
     site::Site,
     tag::{PostTag, Tag},
   },
@@ -31,14 +47,28 @@ use lemmy_db_schema::{
 use lemmy_db_schema_file::{
   InstanceId,
   PersonId,
-  enums::{FederationMode, RegistrationMode},
+  enums::{
+    AdminPermission,
+    CommunityPostingMode,
+    EditHistoryVisibility,
+    FederationMode,
+    ModeratorPermission,
+    RegistrationMode,
+    RegistrationScreeningAction,
+  },
 };
 use lemmy_db_views_community_follower_approval::PendingFollowerView;
 use lemmy_db_views_community_moderator::{CommunityModeratorView, CommunityPersonBanView};
 use lemmy_db_views_local_image::LocalImageView;
 use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::api::{PostEventInput, PostGalleryImage};
 use lemmy_db_views_site::SiteView;
-use lemmy_diesel_utils::{connection::DbPool, dburl::DbUrl, traits::Crud};
+use lemmy_diesel_utils::{
+  connection::DbPool,
+  dburl::DbUrl,
+  traits::Crud,
+  utils::diesel_url_create,
+};
 use lemmy_utils::{
   CACHE_DURATION_FEDERATION,
   CacheLock,
@@ -52,17 +82,35 @@ use lemmy_utils::{
     UntranslatedError,
   },
   rate_limit::{ActionType, BucketConfig},
-  settings::{SETTINGS, structs::PictrsImageMode},
+  settings::{
+    SETTINGS,
+    structs::{PictrsImageMode, Settings},
+  },
   spawn_try_task,
   utils::{
+    email_domain::{domain_matches, email_domain, is_disposable_email_domain},
     markdown::{image_links::markdown_rewrite_image_links, markdown_check_for_blocked_urls},
-    slurs::remove_slurs,
-    validation::{build_and_check_regex, clean_urls_in_text},
+    slurs::{check_slurs, remove_slurs},
+    validation::{
+      build_and_check_regex,
+      clean_urls_in_text,
+      is_url_blocked,
+      is_valid_alt_text_field,
+      is_valid_event_location,
+      is_valid_event_timezone,
+      is_valid_post_gallery_size,
+      is_valid_url,
+    },
   },
 };
 use moka::future::Cache;
 use regex::{Regex, RegexSet, escape};
-use std::{collections::HashSet, sync::LazyLock};
+use serde::Serialize;
+use std::{
+  collections::HashSet,
+  path::{Path, PathBuf},
+  sync::LazyLock,
+};
 use tracing::Instrument;
 use url::{ParseError, Url};
 use urlencoding::encode;
@@ -78,9 +126,13 @@ pub async fn check_is_mod_or_admin(
   let is_mod = CommunityModeratorView::check_is_community_moderator(pool, community_id, person_id)
     .await
     .is_ok();
+  // Admins can only act as moderators if they are allowed to handle reports
   let is_admin = LocalUserView::read_person(pool, person_id)
     .await
-    .is_ok_and(|t| t.local_user.admin);
+    .is_ok_and(|t| {
+      t.local_user
+        .has_admin_permission(Some(AdminPermission::HandleReports))
+    });
 
   if is_mod || is_admin {
     Ok(())
@@ -89,6 +141,20 @@ pub async fn check_is_mod_or_admin(
   }
 }
 
+/// Returns true if a new post by this person has to wait for approval by a mod. This is only
+/// enforced for local communities, remote communities handle approval on their own instance.
+pub async fn post_requires_approval(
+  pool: &mut DbPool<'_>,
+  community: &Community,
+  person_id: PersonId,
+) -> bool {
+  community.local
+    && community.posting_mode == CommunityPostingMode::RequireApproval
+    && check_is_mod_or_admin(pool, person_id, community.id)
+      .await
+      .is_err()
+}
+
 /// Checks if a person is an admin, or moderator of any community.
 pub(crate) async fn check_is_mod_of_any_or_admin(
   pool: &mut DbPool<'_>,
@@ -97,9 +163,13 @@ pub(crate) async fn check_is_mod_of_any_or_admin(
   let is_mod_of_any = CommunityModeratorView::is_community_moderator_of_any(pool, person_id)
     .await
     .is_ok();
+  // Admins can only act as moderators if they are allowed to handle reports
   let is_admin = LocalUserView::read_person(pool, person_id)
     .await
-    .is_ok_and(|t| t.local_user.admin);
+    .is_ok_and(|t| {
+      t.local_user
+        .has_admin_permission(Some(AdminPermission::HandleReports))
+    });
 
   if is_mod_of_any || is_admin {
     Ok(())
@@ -155,6 +225,44 @@ pub fn is_admin(local_user_view: &LocalUserView) -> LemmyResult<()> {
   }
 }
 
+/// Checks that the user is an admin holding the given permission. Passing `None` requires a full
+/// admin without any restrictions.
+pub fn check_admin_permission(
+  local_user_view: &LocalUserView,
+  permission: Option<AdminPermission>,
+) -> LemmyResult<()> {
+  is_admin(local_user_view)?;
+  if !local_user_view.local_user.has_admin_permission(permission) {
+    Err(LemmyErrorType::MissingAdminPermission)?
+  }
+  check_access_token_admin(permission)
+}
+
+/// Checks the admin permission like [check_admin_permission], and writes the action to the admin
+/// audit log. The target is the id of the affected item, its type depends on the action.
+/// Parameters must not contain any secrets.
+pub async fn check_admin_action(
+  local_user_view: &LocalUserView,
+  permission: Option<AdminPermission>,
+  action: &str,
+  target_id: Option<i32>,
+  parameters: &impl Serialize,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  check_admin_permission(local_user_view, permission)?;
+  let parameters = Some(serde_json::to_value(parameters)?)
+    .filter(|p| !p.is_null())
+    .map(|p| p.to_string());
+  let form = AdminAuditLogInsertForm::new(
+    local_user_view.person.id,
+    action.to_string(),
+    target_id,
+    parameters,
+  );
+  AdminAuditLog::create(pool, &form).await?;
+  Ok(())
+}
+
 pub fn is_top_mod(
   local_user_view: &LocalUserView,
   community_mods: &[CommunityModeratorView],
@@ -217,21 +325,53 @@ pub fn check_email_verified(
   Ok(())
 }
 
+/// Checks the domain of a new email address against the email domain policy of the site.
+/// Domains which are explicitly allowed are accepted even if they belong to a disposable email
+/// provider.
+pub async fn check_email_domain_allowed(
+  email: &str,
+  local_site: &LocalSite,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  let domain = email_domain(email).ok_or(LemmyErrorType::EmailDomainNotAllowed)?;
+  let email_domains = LocalSiteEmailDomain::get_all(pool).await?;
+  let is_listed = |allowed: bool| {
+    email_domains
+      .iter()
+      .any(|d| d.allowed == allowed && domain_matches(&domain, &d.domain))
+  };
+  let is_allowed = is_listed(true);
+
+  if (local_site.email_domain_allowlist_only && !is_allowed)
+    || is_listed(false)
+    || (local_site.block_disposable_email_domains
+      && !is_allowed
+      && is_disposable_email_domain(&domain))
+  {
+    Err(LemmyErrorType::EmailDomainNotAllowed)?
+  }
+  Ok(())
+}
+
 pub async fn check_registration_application(
   local_user_view: &LocalUserView,
   local_site: &LocalSite,
   pool: &mut DbPool<'_>,
 ) -> LemmyResult<()> {
   if (local_site.registration_mode == RegistrationMode::RequireApplication
+    || local_site.registration_mode == RegistrationMode::InviteOnly
     || local_site.registration_mode == RegistrationMode::Closed)
     && !local_user_view.local_user.accepted_application
     && !local_user_view.local_user.admin
   {
     // Fetch the registration application. If no admin id is present its still pending. Otherwise it
-    // was processed (either accepted or denied).
+    // was processed (either accepted or denied). It may also have been denied by the screening
+    // rules, whose reasons are only shown to admins.
     let local_user_id = local_user_view.local_user.id;
     let registration = RegistrationApplication::find_by_local_user_id(pool, local_user_id).await?;
-    if registration.admin_id.is_some() {
+    if registration.admin_id.is_some()
+      || registration.screening_action == Some(RegistrationScreeningAction::Deny)
+    {
       Err(LemmyErrorType::RegistrationDenied(
         registration.deny_reason.unwrap_or_default(),
       ))?
@@ -270,22 +410,57 @@ pub fn check_community_deleted_removed(community: &Community) -> LemmyResult<()>
 /// Check that the given user can perform a mod action in the community.
 ///
 /// In particular it checks that they're an admin or mod, wasn't banned and the community isn't
-/// removed/deleted.
+/// removed/deleted. Moderators additionally need to hold the given permission, or all
+/// permissions if `None` is passed. Admins who aren't moderators with that permission need to be
+/// allowed to handle reports, and the override is written to the modlog.
 pub async fn check_community_mod_action(
   local_user_view: &LocalUserView,
   community: &Community,
   allow_deleted: bool,
+  permission: Option<ModeratorPermission>,
   pool: &mut DbPool<'_>,
 ) -> LemmyResult<()> {
+  let admin_override =
+    check_community_mod_permission(local_user_view, community, allow_deleted, permission, pool)
+      .await?;
+  if admin_override {
+    let form = ModlogInsertForm::admin_moderator_override(local_user_view.person.id, community.id);
+    Modlog::create(pool, &[form]).await?;
+  }
+  Ok(())
+}
+
+/// The same checks as [check_community_mod_action], but without writing to the modlog. Use this
+/// to find out if the user could act as moderator. Returns true if the user is only allowed
+/// because of the admin override.
+pub async fn check_community_mod_permission(
+  local_user_view: &LocalUserView,
+  community: &Community,
+  allow_deleted: bool,
+  permission: Option<ModeratorPermission>,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<bool> {
   check_local_user_valid(local_user_view)?;
+  check_access_token_moderation(local_user_view, community.id, permission)?;
   is_mod_or_admin(pool, local_user_view, community.id).await?;
-  CommunityPersonBanView::check(pool, local_user_view.person.id, community.id).await?;
+  let person_id = local_user_view.person.id;
+  let mod_check =
+    CommunityModeratorView::check_moderator_permission(pool, community.id, person_id, permission)
+      .await;
+  let admin_override = mod_check.is_err()
+    && local_user_view
+      .local_user
+      .has_admin_permission(Some(AdminPermission::HandleReports));
+  if !admin_override {
+    mod_check?;
+  }
+  CommunityPersonBanView::check(pool, person_id, community.id).await?;
 
   // it must be possible to restore deleted community
   if !allow_deleted {
     check_community_deleted_removed(community)?;
   }
-  Ok(())
+  Ok(admin_override)
 }
 
 /// Don't allow creating reports for removed / deleted posts
@@ -712,6 +887,50 @@ pub async fn purge_user_account(
   Ok(())
 }
 
+/// Deletes the account of a local user right away, and federates the deletion.
+pub async fn delete_local_user_account(
+  local_user_view: LocalUserView,
+  delete_content: bool,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let local_instance_id = local_user_view.person.instance_id;
+
+  if delete_content {
+    purge_user_account(local_user_view.person.id, local_instance_id, context).await?;
+  } else {
+    // These are already run in purge_user_account,
+    // but should be done anyway even if delete_content is false
+    OAuthAccount::delete_user_accounts(&mut context.pool(), local_user_view.local_user.id).await?;
+    CommunityActions::leave_mod_team_for_all_communities(
+      &mut context.pool(),
+      local_user_view.person.id,
+    )
+    .await?;
+    Person::delete_account(
+      &mut context.pool(),
+      local_user_view.person.id,
+      local_instance_id,
+    )
+    .await?;
+  }
+
+  LoginToken::invalidate_all(&mut context.pool(), local_user_view.local_user.id).await?;
+  if local_user_view.local_user.deletion_scheduled_at.is_some() {
+    let form = LocalUserUpdateForm {
+      deletion_scheduled_at: Some(None),
+      ..Default::default()
+    };
+    LocalUser::update(&mut context.pool(), local_user_view.local_user.id, &form).await?;
+  }
+
+  ActivityChannel::submit_activity(
+    SendActivityData::DeleteUser(local_user_view.person, delete_content),
+    context,
+  )?;
+
+  Ok(())
+}
+
 pub fn generate_followers_url(ap_id: &DbUrl) -> Result<DbUrl, ParseError> {
   Ok(Url::parse(&format!("{ap_id}/followers"))?.into())
 }
@@ -729,6 +948,11 @@ pub fn generate_featured_url(ap_id: &DbUrl) -> Result<DbUrl, ParseError> {
   Ok(Url::parse(&format!("{ap_id}/featured"))?.into())
 }
 
+/// Where the archive of a user data export is stored.
+pub fn user_data_export_path(settings: &Settings, id: UserDataExportId) -> PathBuf {
+  Path::new(&settings.data_export_directory).join(format!("{}.json", id.0))
+}
+
 pub fn generate_moderators_url(community_id: &DbUrl) -> LemmyResult<DbUrl> {
   Ok(Url::parse(&format!("{community_id}/moderators"))?.into())
 }
@@ -906,6 +1130,17 @@ pub async fn local_user_view_from_jwt(
   Ok(local_user_view)
 }
 
+pub async fn local_user_view_from_access_token(
+  token: &str,
+  context: &LemmyContext,
+) -> LemmyResult<(LocalUserView, PersonalAccessToken)> {
+  let token = PersonalAccessToken::validate(&mut context.pool(), token).await?;
+  let local_user_view = LocalUserView::read(&mut context.pool(), token.local_user_id).await?;
+  check_local_user_deleted(&local_user_view)?;
+
+  Ok((local_user_view, token))
+}
+
 pub fn read_auth_token(req: &HttpRequest) -> LemmyResult<Option<String>> {
   // Try reading jwt from auth header
   if let Ok(header) = Authorization::<Bearer>::parse(req) {
@@ -956,24 +1191,173 @@ pub fn check_comment_depth(comment: &Comment) -> LemmyResult<()> {
   }
 }
 
+/// Sets the tags of a post. Tags which are marked as mod-only can't be added without
+/// `allow_mod_only`, but existing ones are kept so that the post creator doesn't remove them by
+/// accident.
 pub async fn update_post_tags(
   post: &Post,
   tag_ids: &[TagId],
+  allow_mod_only: bool,
   context: &LemmyContext,
 ) -> LemmyResult<()> {
-  // validate tags
+  // validate tags, user flairs can't be used for posts
   let community_tags = Tag::read_for_community(&mut context.pool(), post.community_id)
     .await?
     .into_iter()
-    .map(|t| t.id)
-    .collect::<HashSet<_>>();
-  if !community_tags.is_superset(&tag_ids.iter().copied().collect()) {
+    .filter(|t| !t.user_flair)
+    .collect::<Vec<_>>();
+  let community_tag_ids = community_tags.iter().map(|t| t.id).collect::<HashSet<_>>();
+  let mut tag_ids = tag_ids.iter().copied().collect::<HashSet<_>>();
+  if !community_tag_ids.is_superset(&tag_ids) {
     return Err(LemmyErrorType::TagNotInCommunity.into());
   }
-  PostTag::update(&mut context.pool(), post, tag_ids).await?;
+
+  if !allow_mod_only {
+    let existing_tags = Tag::read_for_post(&mut context.pool(), post.id)
+      .await?
+      .into_iter()
+      .map(|t| t.id)
+      .collect::<HashSet<_>>();
+    for tag in community_tags.iter().filter(|t| t.mod_only) {
+      if existing_tags.contains(&tag.id) {
+        tag_ids.insert(tag.id);
+      } else if tag_ids.contains(&tag.id) {
+        return Err(LemmyErrorType::TagIsModOnly.into());
+      }
+    }
+  }
+  let tag_ids = tag_ids.into_iter().collect::<Vec<_>>();
+  PostTag::update(&mut context.pool(), post, &tag_ids).await?;
+  Ok(())
+}
+
+/// Validates the images of a gallery post, and returns their urls in the same order.
+pub fn check_post_gallery(
+  gallery: &[PostGalleryImage],
+  url_blocklist: &RegexSet,
+) -> LemmyResult<Vec<DbUrl>> {
+  is_valid_post_gallery_size(gallery.len())?;
+  gallery
+    .iter()
+    .map(|image| {
+      let url = diesel_url_create(Some(&image.url))?.ok_or(LemmyErrorType::InvalidUrl)?;
+      is_url_blocked(&url, url_blocklist)?;
+      is_valid_url(&url)?;
+      if let Some(alt_text) = &image.alt_text {
+        is_valid_alt_text_field(alt_text)?;
+      }
+      Ok(url)
+    })
+    .collect()
+}
+
+pub async fn update_post_gallery(
+  post: &Post,
+  gallery: &[PostGalleryImage],
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let url_blocklist = get_url_blocklist(context).await?;
+  let urls = check_post_gallery(gallery, &url_blocklist)?;
+  let forms = generate_post_gallery_urls(urls, context)
+    .await
+    .into_iter()
+    .zip(gallery)
+    .zip(0..)
+    .map(|((url, image), position)| {
+      PostGalleryItemInsertForm::new(post.id, url, image.alt_text.clone(), position)
+    })
+    .collect::<Vec<_>>();
+  PostGalleryItem::replace(&mut context.pool(), post.id, &forms).await?;
+  Ok(())
+}
+
+/// Validates the date, time and place of an event post.
+pub fn check_post_event(
+  event: &PostEventInput,
+  slur_regex: &Regex,
+  url_blocklist: &RegexSet,
+) -> LemmyResult<()> {
+  post_event_times(event)?;
+  if let Some(timezone) = &event.timezone {
+    is_valid_event_timezone(timezone)?;
+  }
+  if let Some(location) = &event.location {
+    is_valid_event_location(location.trim())?;
+    check_slurs(location, slur_regex)?;
+  }
+  if let Some(online_url) = diesel_url_create(event.online_url.as_deref())? {
+    is_url_blocked(&online_url, url_blocklist)?;
+    is_valid_url(&online_url)?;
+  }
+  Ok(())
+}
+
+/// Start and end of an event, which may not end before it starts.
+fn post_event_times(event: &PostEventInput) -> LemmyResult<(DateTime<Utc>, DateTime<Utc>)> {
+  let parse = |time| {
+    Utc
+      .timestamp_opt(time, 0)
+      .single()
+      .ok_or(LemmyErrorType::InvalidUnixTime)
+  };
+  let start_at = parse(event.start_at)?;
+  let end_at = event.end_at.map(parse).transpose()?.unwrap_or(start_at);
+  if end_at < start_at {
+    Err(LemmyErrorType::InvalidEventTime)?
+  }
+  Ok((start_at, end_at))
+}
+
+/// Stores the event of a post. It needs to be validated with [[check_post_event]] first.
+pub async fn update_post_event(
+  post: &Post,
+  event: &PostEventInput,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let (start_at, end_at) = post_event_times(event)?;
+  let form = PostEventForm::new(
+    post.id,
+    start_at,
+    end_at,
+    event.timezone.clone().unwrap_or_else(|| "UTC".to_string()),
+    event.location.as_ref().map(|l| l.trim().to_string()),
+    diesel_url_create(event.online_url.as_deref())?,
+  );
+  PostEvent::upsert(&mut context.pool(), &form).await?;
   Ok(())
 }
 
+/// Depending on the site settings, edit history is either public or only visible to the creator,
+/// mods and admins.
+pub fn check_edit_history_visible(
+  local_site: &LocalSite,
+  local_user_view: Option<&LocalUserView>,
+  creator_id: PersonId,
+  is_mod_or_admin: bool,
+) -> LemmyResult<()> {
+  let is_creator = local_user_view.is_some_and(|l| l.person.id == creator_id);
+  if local_site.edit_history_visibility == EditHistoryVisibility::Public
+    || is_creator
+    || is_mod_or_admin
+  {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::NotAModOrAdmin)?
+  }
+}
+
+/// Line by line diff between two versions of a text.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+  diff::lines(old, new)
+    .into_iter()
+    .map(|l| match l {
+      diff::Result::Left(l) => DiffLine::Removed(l.to_string()),
+      diff::Result::Both(l, _) => DiffLine::Unchanged(l.to_string()),
+      diff::Result::Right(l) => DiffLine::Added(l.to_string()),
+    })
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -991,6 +1375,19 @@ mod tests {
     assert!(password_length_check("looooooooooooooooooooooooooooooooooooooooooooooooooooooooooong").is_err());
   }
 
+  #[test]
+  fn test_diff_lines() {
+    assert_eq!(
+      vec![
+        DiffLine::Unchanged("first".to_string()),
+        DiffLine::Removed("second".to_string()),
+        DiffLine::Added("changed".to_string()),
+        DiffLine::Unchanged("third".to_string()),
+      ],
+      diff_lines("first\nsecond\nthird", "first\nchanged\nthird")
+    );
+  }
+
   #[test]
   fn honeypot() {
     assert!(honeypot_check(&None).is_ok());
@@ -1083,6 +1480,8 @@ mod tests {
       unresolved_report_count: 0,
       federation_pending: false,
       locked: false,
+      quarantined: false,
+      pinned: false,
     };
     assert!(check_comment_depth(&comment).is_ok());
     comment.path = Ltree("0.123.456".to_string());
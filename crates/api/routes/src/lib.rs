@@ -1,26 +1,47 @@
 use actix_web::{guard, web::*};
 use lemmy_api::{
   comment::{
+    approve_quarantined::approve_quarantined_comment,
     distinguish::distinguish_comment,
     like::like_comment,
     list_comment_likes::list_comment_likes,
+    list_revisions::list_comment_revisions,
     lock::lock_comment,
+    pin::pin_comment,
     save::save_comment,
   },
   community::{
     add_mod::add_mod_to_community,
     ban::ban_from_community,
     block::user_block_community,
+    flair::edit_user_flair,
     follow::follow_community,
+    moderator_permissions::edit_moderator_permissions,
     multi_community_follow::follow_multi_community,
     pending_follows::{approve::post_pending_follows_approve, list::get_pending_follows_list},
     random::get_random_community,
+    recurring_post::{
+      create::create_recurring_post,
+      delete::delete_recurring_post,
+      list::list_recurring_posts,
+      update::edit_recurring_post,
+    },
     tag::{create_community_tag, delete_community_tag, update_community_tag},
     transfer::transfer_community,
     update_notifications::update_community_notifications,
+    wiki::{
+      create::create_wiki_page,
+      delete::delete_wiki_page,
+      editor::{add_wiki_editor, list_wiki_editors},
+      list::list_wiki_pages,
+      list_revisions::list_wiki_page_revisions,
+      read::get_wiki_page,
+      revert::revert_wiki_page,
+      update::edit_wiki_page,
+    },
   },
   federation::{
-    list_comments::{list_comments, list_comments_slim},
+    list_comments::{list_comment_tree, list_comments, list_comments_slim},
     list_person_content::list_person_content,
     list_posts::list_posts,
     read_community::get_community,
@@ -29,15 +50,23 @@ use lemmy_api::{
     resolve_object::resolve_object,
     search::search,
     user_settings_backup::{export_settings, import_settings},
+    user_settings_import::{import_settings_file, list_settings_imports},
   },
   local_user::{
     add_admin::add_admin,
+    admin_permissions::edit_admin_permissions,
     ban_person::ban_from_site,
     block::user_block_person,
     change_password::change_password,
     change_password_after_reset::change_password_after_reset,
+    content_filter::{
+      create::create_content_filter,
+      delete::delete_content_filter,
+      list::list_content_filters,
+      update::edit_content_filter,
+    },
     donation_dialog_shown::donation_dialog_shown,
-    export_data::export_data,
+    export_data::{download_data_export, export_data, list_data_exports, start_data_export},
     generate_totp_secret::generate_totp_secret,
     get_captcha::get_captcha,
     list_hidden::list_person_hidden,
@@ -54,29 +83,60 @@ use lemmy_api::{
       mark_all_read::mark_all_notifications_read,
       mark_notification_read::mark_notification_as_read,
     },
+    personal_access_token::{
+      create::create_personal_access_token,
+      list::list_personal_access_tokens,
+      revoke::revoke_personal_access_token,
+    },
+    quarantine_person::quarantine_person,
+    refresh_login::refresh_login,
     resend_verification_email::resend_verification_email,
     reset_password::reset_password,
+    revoke_login::revoke_login,
+    revoke_other_logins::revoke_other_logins,
     save_settings::save_user_settings,
     unread_counts::get_unread_counts,
     update_totp::update_totp,
     user_block_instance::{user_block_instance_communities, user_block_instance_persons},
     validate_auth::validate_auth,
     verify_email::verify_email,
+    webauthn::{
+      delete::delete_webauthn_credential,
+      finish_registration::finish_webauthn_registration,
+      generate_recovery_codes::generate_recovery_codes,
+      list::list_webauthn_credentials,
+      login::webauthn_login,
+      rename::rename_webauthn_credential,
+      start_login::start_webauthn_login,
+      start_registration::start_webauthn_registration,
+    },
+  },
+  oauth::{
+    authorize::{approve_oauth_authorization, get_oauth_consent},
+    revoke::revoke_oauth_token,
+    token::oauth_token,
+    userinfo::oauth_userinfo,
   },
   post::{
+    accept_answer::accept_post_answer,
+    approve_quarantined::approve_quarantined_post,
     feature::feature_post,
     get_link_metadata::get_link_metadata,
     hide::hide_post,
     like::like_post,
     list_post_likes::list_post_likes,
+    list_revisions::list_post_revisions,
     lock::lock_post,
     mark_many_read::mark_posts_as_read,
     mark_read::mark_post_as_read,
     mod_update::mod_update_post,
+    pending::{approve::approve_pending_post, list::list_pending_posts},
+    rsvp::rsvp_post_event,
     save::save_post,
     update_notifications::update_post_notifications,
   },
   reports::{
+    ban_appeal::{create::create_ban_appeal, resolve::resolve_ban_appeal},
     comment_report::{create::create_comment_report, resolve::resolve_comment_report},
     community_report::{create::create_community_report, resolve::resolve_community_report},
     post_report::{create::create_post_report, resolve::resolve_post_report},
@@ -85,6 +145,7 @@ use lemmy_api::{
   },
   site::{
     admin_allow_instance::admin_allow_instance,
+    admin_audit_log::list_admin_audit_log,
     admin_block_instance::admin_block_instance,
     admin_list_users::admin_list_users,
     federated_instances::get_federated_instances,
@@ -124,6 +185,14 @@ use lemmy_api_crud::{
     list::list_custom_emojis,
     update::update_custom_emoji,
   },
+  draft::{
+    create::create_draft,
+    delete::delete_draft,
+    list::list_drafts,
+    publish::publish_draft,
+    update::update_draft,
+  },
+  invite_code::{create::create_invite_code, delete::delete_invite_code, list::list_invite_codes},
   multi_community::{
     create::create_multi_community,
     create_entry::create_multi_community_entry,
@@ -131,6 +200,11 @@ use lemmy_api_crud::{
     list::list_multi_communities,
     update::update_multi_community,
   },
+  oauth_application::{
+    create::create_oauth_application,
+    delete::delete_oauth_application,
+    list::list_oauth_applications,
+  },
   oauth_provider::{
     create::create_oauth_provider,
     delete::delete_oauth_provider,
@@ -138,6 +212,7 @@ use lemmy_api_crud::{
   },
   post::{
     create::create_post,
+    crosspost::create_crosspost,
     delete::delete_post,
     read::get_post,
     remove::remove_post,
@@ -146,8 +221,20 @@ use lemmy_api_crud::{
   private_message::{
     create::create_private_message,
     delete::delete_private_message,
+    list_revisions::list_private_message_revisions,
     update::update_private_message,
   },
+  registration_question::{
+    create::create_registration_question,
+    delete::delete_registration_question,
+    list::list_registration_questions,
+    update::update_registration_question,
+  },
+  registration_screening_rule::{
+    create::create_registration_screening_rule,
+    delete::delete_registration_screening_rule,
+    list::list_registration_screening_rules,
+  },
   site::{create::create_site, read::get_site, update::update_site},
   tagline::{
     create::create_tagline,
@@ -157,7 +244,7 @@ use lemmy_api_crud::{
   },
   user::{
     create::{authenticate_with_oauth, register},
-    delete::delete_account,
+    delete::{cancel_account_deletion, delete_account},
     my_user::get_my_user,
   },
 };
@@ -234,6 +321,7 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/transfer", post().to(transfer_community))
           .route("/ban_user", post().to(ban_from_community))
           .route("/mod", post().to(add_mod_to_community))
+          .route("/mod/permissions", put().to(edit_moderator_permissions))
           .route("/icon", post().to(upload_community_icon))
           .route("/icon", delete().to(delete_community_icon))
           .route("/banner", post().to(upload_community_banner))
@@ -241,6 +329,26 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/tag", post().to(create_community_tag))
           .route("/tag", put().to(update_community_tag))
           .route("/tag", delete().to(delete_community_tag))
+          .route("/flair", put().to(edit_user_flair))
+          .service(
+            scope("/recurring_post")
+              .route("", post().to(create_recurring_post))
+              .route("", put().to(edit_recurring_post))
+              .route("", delete().to(delete_recurring_post))
+              .route("/list", get().to(list_recurring_posts)),
+          )
+          .service(
+            scope("/wiki")
+              .route("", get().to(get_wiki_page))
+              .route("", post().to(create_wiki_page))
+              .route("", put().to(edit_wiki_page))
+              .route("/delete", post().to(delete_wiki_page))
+              .route("/list", get().to(list_wiki_pages))
+              .route("/revisions", get().to(list_wiki_page_revisions))
+              .route("/revert", post().to(revert_wiki_page))
+              .route("/editor", post().to(add_wiki_editor))
+              .route("/editor/list", get().to(list_wiki_editors)),
+          )
           .route("/notifications", post().to(update_community_notifications))
           .service(
             scope("/pending_follows")
@@ -267,6 +375,11 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .wrap(rate_limit.post())
           .route(post().to(create_post)),
       )
+      .service(
+        resource("/post/crosspost")
+          .wrap(rate_limit.post())
+          .route(post().to(create_crosspost)),
+      )
       .service(
         resource("/post/site_metadata")
           .wrap(rate_limit.search())
@@ -286,11 +399,20 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/list", get().to(list_posts))
           .route("/like", post().to(like_post))
           .route("/like/list", get().to(list_post_likes))
+          .route("/revisions", get().to(list_post_revisions))
           .route("/save", put().to(save_post))
+          .route("/rsvp", post().to(rsvp_post_event))
+          .route("/answer", post().to(accept_post_answer))
           .route("/report", post().to(create_post_report))
           .route("/report/resolve", put().to(resolve_post_report))
           .route("/notifications", post().to(update_post_notifications))
-          .route("/mod_update", put().to(mod_update_post)),
+          .route("/mod_update", put().to(mod_update_post))
+          .route("/approve_quarantined", post().to(approve_quarantined_post))
+          .service(
+            scope("/pending")
+              .route("/list", get().to(list_pending_posts))
+              .route("/approve", post().to(approve_pending_post)),
+          ),
       )
       // Comment
       .service(
@@ -307,14 +429,21 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("", delete().to(delete_comment))
           .route("/remove", post().to(remove_comment))
           .route("/distinguish", post().to(distinguish_comment))
+          .route("/pin", post().to(pin_comment))
           .route("/like", post().to(like_comment))
           .route("/like/list", get().to(list_comment_likes))
+          .route("/revisions", get().to(list_comment_revisions))
           .route("/save", put().to(save_comment))
           .route("/lock", post().to(lock_comment))
           .route("/list", get().to(list_comments))
           .route("/list/slim", get().to(list_comments_slim))
+          .route("/tree", get().to(list_comment_tree))
           .route("/report", post().to(create_comment_report))
-          .route("/report/resolve", put().to(resolve_comment_report)),
+          .route("/report/resolve", put().to(resolve_comment_report))
+          .route(
+            "/approve_quarantined",
+            post().to(approve_quarantined_comment),
+          ),
       )
       // Private Message
       .service(
@@ -322,6 +451,7 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("", post().to(create_private_message))
           .route("", put().to(update_private_message))
           .route("", delete().to(delete_private_message))
+          .route("/revisions", get().to(list_private_message_revisions))
           .route("/report", post().to(create_pm_report))
           .route("/report/resolve", put().to(resolve_pm_report)),
       )
@@ -329,7 +459,9 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
       .service(
         scope("/report")
           .wrap(rate_limit.message())
-          .route("/list", get().to(list_reports)),
+          .route("/list", get().to(list_reports))
+          .route("/ban_appeal", post().to(create_ban_appeal))
+          .route("/ban_appeal/resolve", put().to(resolve_ban_appeal)),
       )
       // User
       .service(
@@ -338,6 +470,7 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .wrap(rate_limit.register())
           .route("/register", post().to(register))
           .route("/login", post().to(login))
+          .route("/refresh", post().to(refresh_login))
           .route("/logout", post().to(logout))
           .route("/password_reset", post().to(reset_password))
           .route("/get_captcha", get().to(get_captcha))
@@ -345,6 +478,20 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/change_password", put().to(change_password))
           .route("/totp/generate", post().to(generate_totp_secret))
           .route("/totp/update", post().to(update_totp))
+          .route(
+            "/webauthn/register/start",
+            post().to(start_webauthn_registration),
+          )
+          .route(
+            "/webauthn/register/finish",
+            post().to(finish_webauthn_registration),
+          )
+          .route("/webauthn/start", post().to(start_webauthn_login))
+          .route("/webauthn/login", post().to(webauthn_login))
+          .route(
+            "/recovery_codes/generate",
+            post().to(generate_recovery_codes),
+          )
           .route("/verify_email", post().to(verify_email))
           .route(
             "/resend_verification_email",
@@ -367,7 +514,43 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
               .route("/mark_as_read", post().to(mark_notification_as_read)),
           )
           .route("", delete().to(delete_account))
+          .route("/delete/cancel", post().to(cancel_account_deletion))
+          .route("/login", delete().to(revoke_login))
           .route("/login/list", get().to(list_logins))
+          .route("/login/revoke_others", post().to(revoke_other_logins))
+          .service(
+            scope("/token")
+              .route("", post().to(create_personal_access_token))
+              .route("", delete().to(revoke_personal_access_token))
+              .route("/list", get().to(list_personal_access_tokens)),
+          )
+          .service(
+            scope("/content_filter")
+              .route("", post().to(create_content_filter))
+              .route("", put().to(edit_content_filter))
+              .route("", delete().to(delete_content_filter))
+              .route("/list", get().to(list_content_filters)),
+          )
+          // Publishing a draft creates a post, so it uses the same rate limit
+          .service(
+            resource("/draft/publish")
+              .wrap(rate_limit.post())
+              .route(post().to(publish_draft)),
+          )
+          .service(
+            scope("/draft")
+              .wrap(rate_limit.comment())
+              .route("", post().to(create_draft))
+              .route("", put().to(update_draft))
+              .route("", delete().to(delete_draft))
+              .route("/list", get().to(list_drafts)),
+          )
+          .service(
+            scope("/webauthn")
+              .route("", put().to(rename_webauthn_credential))
+              .route("", delete().to(delete_webauthn_credential))
+              .route("/list", get().to(list_webauthn_credentials)),
+          )
           .route("/validate_auth", get().to(validate_auth))
           .route("/donation_dialog_shown", post().to(donation_dialog_shown))
           .route("/avatar", post().to(upload_user_avatar))
@@ -389,18 +572,23 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/hidden", get().to(list_person_hidden))
           .route("/liked", get().to(list_person_liked))
           .route("/settings/save", put().to(save_user_settings))
+          .route("/settings/import/list", get().to(list_settings_imports))
           // Account settings import / export have a strict rate limit
           .service(
             scope("/settings")
               .wrap(rate_limit.import_user_settings())
               .route("/export", get().to(export_settings))
-              .route("/import", post().to(import_settings)),
+              .route("/import", post().to(import_settings))
+              .route("/import/file", post().to(import_settings_file)),
           )
           .service(
             resource("/data/export")
               .wrap(rate_limit.import_user_settings())
-              .route(get().to(export_data)),
-          ),
+              .route(get().to(export_data))
+              .route(post().to(start_data_export)),
+          )
+          .route("/data/export/list", get().to(list_data_exports))
+          .route("/data/export/download", get().to(download_data_export)),
       )
       // User actions
       .service(
@@ -413,12 +601,27 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
       .service(
         scope("/admin")
           .route("/add", post().to(add_admin))
+          .route("/permissions", put().to(edit_admin_permissions))
+          .route("/audit_log", get().to(list_admin_audit_log))
           .service(
             scope("/registration_application")
               .route("", get().to(get_registration_application))
               .route("/list", get().to(list_registration_applications))
               .route("/approve", put().to(approve_registration_application)),
           )
+          .service(
+            scope("/registration_question")
+              .route("", post().to(create_registration_question))
+              .route("", put().to(update_registration_question))
+              .route("", delete().to(delete_registration_question))
+              .route("/list", get().to(list_registration_questions)),
+          )
+          .service(
+            scope("/registration_screening_rule")
+              .route("", post().to(create_registration_screening_rule))
+              .route("", delete().to(delete_registration_screening_rule))
+              .route("/list", get().to(list_registration_screening_rules)),
+          )
           .service(
             scope("/purge")
               .route("/person", post().to(purge_person))
@@ -434,6 +637,7 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
               .route("/list", get().to(list_taglines)),
           )
           .route("/ban", post().to(ban_from_site))
+          .route("/quarantine", post().to(quarantine_person))
           .route("/users", get().to(admin_list_users))
           .service(
             scope("/instance")
@@ -454,10 +658,30 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("", put().to(update_oauth_provider))
           .route("", delete().to(delete_oauth_provider)),
       )
+      .service(
+        scope("/invite_code")
+          .route("", post().to(create_invite_code))
+          .route("", delete().to(delete_invite_code))
+          .route("/list", get().to(list_invite_codes)),
+      )
+      .service(
+        scope("/oauth_application")
+          .route("", post().to(create_oauth_application))
+          .route("", delete().to(delete_oauth_application))
+          .route("/list", get().to(list_oauth_applications)),
+      )
       .service(
         scope("/oauth")
-          .wrap(rate_limit.register())
-          .route("/authenticate", post().to(authenticate_with_oauth)),
+          .service(
+            resource("/authenticate")
+              .wrap(rate_limit.register())
+              .route(post().to(authenticate_with_oauth)),
+          )
+          .route("/authorize", get().to(get_oauth_consent))
+          .route("/authorize", post().to(approve_oauth_authorization))
+          .route("/token", post().to(oauth_token))
+          .route("/revoke", post().to(revoke_oauth_token))
+          .route("/userinfo", get().to(oauth_userinfo)),
       )
       .service(
         scope("/image")
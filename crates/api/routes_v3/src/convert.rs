@@ -67,6 +67,7 @@ use lemmy_db_schema::{
 use lemmy_db_schema_file::enums::{
   CommentSortType,
   CommunityFollowerState,
+  CommunityPostingMode,
   ListingType,
   PostSortType,
   RegistrationMode,
@@ -425,7 +426,7 @@ pub(crate) fn convert_community(community: Community) -> CommunityV3 {
     last_refreshed_at,
     icon,
     banner,
-    posting_restricted_to_mods,
+    posting_mode,
     instance_id,
     summary: description,
     ..
@@ -451,7 +452,7 @@ pub(crate) fn convert_community(community: Community) -> CommunityV3 {
     inbox_url: DUMMY_URL.clone(),
     shared_inbox_url: None,
     hidden: false,
-    posting_restricted_to_mods,
+    posting_restricted_to_mods: posting_mode == CommunityPostingMode::ModsOnly,
     instance_id: InstanceId(instance_id.0),
     moderators_url: None,
     featured_url: None,
@@ -631,7 +632,8 @@ pub(crate) fn convert_local_site(local_site: LocalSite) -> LocalSiteV3 {
     ..
   } = local_site;
   let registration_mode = match registration_mode {
-    RegistrationMode::Closed => RegistrationModeV3::Closed,
+    // Older clients can't send invite codes
+    RegistrationMode::Closed | RegistrationMode::InviteOnly => RegistrationModeV3::Closed,
     RegistrationMode::RequireApplication => RegistrationModeV3::RequireApplication,
     RegistrationMode::Open => RegistrationModeV3::Open,
   };
@@ -727,7 +729,7 @@ pub(crate) fn convert_search_response(
       SearchCombinedView::Comment(c) => res.comments.push(convert_comment_view(c)),
       SearchCombinedView::Community(c) => res.communities.push(convert_community_view(c)),
       SearchCombinedView::Person(p) => res.users.push(convert_person_view(p)),
-      SearchCombinedView::MultiCommunity(_) => continue,
+      SearchCombinedView::MultiCommunity(_) | SearchCombinedView::WikiPage(_) => continue,
     }
   }
   res
@@ -846,6 +848,7 @@ pub(crate) fn convert_language_ids(data: Vec<LanguageId>) -> Vec<LanguageIdV3> {
 pub(crate) fn convert_login_response(res: LoginResponse) -> LemmyResult<Json<LoginResponseV3>> {
   let LoginResponse {
     jwt,
+    refresh_token: _,
     registration_created,
     verify_email_sent,
   } = res;
@@ -105,7 +105,7 @@ use lemmy_api_crud::{
   site::read::get_site,
   user::{create::register, my_user::get_my_user},
 };
-use lemmy_api_utils::context::LemmyContext;
+use lemmy_api_utils::{claims::Claims, context::LemmyContext};
 use lemmy_db_schema::newtypes::{CommentId, CommunityId, LanguageId, PostId};
 use lemmy_db_schema_file::PersonId;
 use lemmy_db_views_comment::api::{
@@ -222,6 +222,7 @@ pub(crate) async fn list_comments_v3(
     post_id: post_id.map(|p| PostId(p.0)),
     parent_id: parent_id.map(|p| CommentId(p.0)),
     time_range_seconds: None,
+    quarantined_only: None,
   };
   let comments = list_comments(Query(data), context, local_user_view)
     .await?
@@ -300,8 +301,8 @@ pub(crate) async fn login_v3(
   req: HttpRequest,
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<LoginResponseV3>> {
-  let res = login(data, req, context).await?.0;
-  convert_login_response(res)
+  let res = login(data, req, context.clone()).await?.0;
+  convert_login_response(Claims::into_long_lived(res, &context).await?)
 }
 
 pub(crate) async fn like_comment_v3(
@@ -369,6 +370,8 @@ pub(crate) async fn create_post_v3(
     custom_thumbnail,
     tags: None,
     scheduled_publish_time_at: None,
+    gallery: None,
+    event: None,
   };
   let res = Box::pin(create_post(Json(data), context, local_user_view)).await?;
   convert_post_response(res)
@@ -655,8 +658,10 @@ pub(crate) async fn register_v3(
   req: HttpRequest,
   context: ApubData<LemmyContext>,
 ) -> LemmyResult<Json<LoginResponseV3>> {
-  let res = Box::pin(register(data, req, context)).await?.0;
-  convert_login_response(res)
+  let res = Box::pin(register(data, req, context.reset_request_count()))
+    .await?
+    .0;
+  convert_login_response(Claims::into_long_lived(res, &context).await?)
 }
 
 pub(crate) async fn block_person_v3(
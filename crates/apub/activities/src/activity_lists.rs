@@ -1,5 +1,5 @@
 use crate::protocol::{
-  block::{block_user::BlockUser, undo_block_user::UndoBlockUser},
+  block::{appeal_ban::AppealBan, block_user::BlockUser, undo_block_user::UndoBlockUser},
   community::{
     announce::{AnnounceActivity, RawAnnouncableActivities},
     collection_add::CollectionAdd,
@@ -45,6 +45,7 @@ pub enum SharedInboxActivities {
   UndoFollow(UndoFollow),
   Report(Report),
   ResolveReport(ResolveReport),
+  AppealBan(AppealBan),
   AnnounceActivity(AnnounceActivity),
   /// This is a catch-all and needs to be last
   RawAnnouncableActivities(RawAnnouncableActivities),
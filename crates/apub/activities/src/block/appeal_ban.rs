@@ -0,0 +1,95 @@
+use crate::{
+  generate_activity_id,
+  protocol::block::appeal_ban::{AppealBan, AppealBanType},
+  send_lemmy_activity,
+};
+use activitypub_federation::{
+  config::Data,
+  protocol::verification::{verify_domains_match, verify_urls_match},
+  traits::{Activity, Actor, Object},
+};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::objects::{community::ApubCommunity, person::ApubPerson};
+use lemmy_db_schema::source::{
+  activity::ActivitySendTargets,
+  ban_appeal::{BanAppeal, BanAppealInsertForm},
+  modlog::Modlog,
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
+use url::Url;
+
+impl AppealBan {
+  pub(crate) async fn send(
+    actor: &ApubPerson,
+    community: &ApubCommunity,
+    reason: String,
+    context: Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    let kind = AppealBanType::AppealBan;
+    let id = generate_activity_id(kind.clone(), &context)?;
+    let appeal = AppealBan {
+      actor: actor.id().clone().into(),
+      to: [community.id().clone().into()],
+      object: community.id().clone().into(),
+      summary: reason,
+      kind,
+      id,
+    };
+    let inboxes = ActivitySendTargets::to_inbox(community.shared_inbox_or_inbox());
+
+    send_lemmy_activity(&context, appeal, actor, inboxes, false).await
+  }
+}
+
+#[async_trait::async_trait]
+impl Activity for AppealBan {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    verify_domains_match(self.actor.inner(), &self.id)?;
+    verify_urls_match(self.to[0].inner(), self.object.inner())?;
+    // Appeals are only handled by the instance which hosts the community
+    let community = self.object.dereference(context).await?;
+    if !community.local {
+      Err(LemmyErrorType::NotFound)?
+    }
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    let actor = self.actor.dereference(context).await?;
+    let community = self.object.dereference(context).await?;
+
+    let ban = Modlog::read_active_ban(
+      &mut context.pool(),
+      actor.id,
+      Some(community.id),
+      community.instance_id,
+    )
+    .await?
+    .ok_or(LemmyErrorType::NotBanned)?;
+    if BanAppeal::read_for_ban(&mut context.pool(), ban.id)
+      .await?
+      .is_some()
+    {
+      Err(LemmyErrorType::BanAlreadyAppealed)?
+    }
+
+    let form = BanAppealInsertForm {
+      ap_id: Some(self.id.into()),
+      ..BanAppealInsertForm::new(actor.id, Some(community.id), ban.id, self.summary)
+    };
+    BanAppeal::create(&mut context.pool(), &form).await?;
+    Ok(())
+  }
+}
@@ -37,6 +37,7 @@ use lemmy_db_schema::{
   },
   traits::Bannable,
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_utils::error::{LemmyError, LemmyResult};
 use url::Url;
 
@@ -121,7 +122,13 @@ impl Activity for BlockUser {
       SiteOrCommunity::Right(community) => {
         verify_visibility(&self.to, &self.cc, &community)?;
         verify_person_in_community(&self.actor, &community, context).await?;
-        verify_mod_action(&self.actor, &community, context).await?;
+        verify_mod_action(
+          &self.actor,
+          &community,
+          Some(ModeratorPermission::ManageBans),
+          context,
+        )
+        .await?;
       }
     }
     Ok(())
@@ -15,6 +15,7 @@ use lemmy_diesel_utils::{connection::DbPool, traits::Crud};
 use lemmy_utils::error::LemmyResult;
 use url::Url;
 
+pub mod appeal_ban;
 pub mod block_user;
 pub mod undo_block_user;
 
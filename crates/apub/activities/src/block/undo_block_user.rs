@@ -26,12 +26,14 @@ use lemmy_apub_objects::{
 use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
+    ban_appeal::BanAppeal,
     community::{CommunityActions, CommunityPersonBanForm},
     instance::{InstanceActions, InstanceBanForm},
     modlog::{Modlog, ModlogInsertForm},
   },
   traits::Bannable,
 };
+use lemmy_db_schema_file::enums::BanAppealDecision;
 use lemmy_utils::error::{LemmyError, LemmyResult};
 use url::Url;
 
@@ -140,6 +142,16 @@ impl Activity for UndoBlockUser {
           .await?;
         }
 
+        // an unban is the only appeal decision which is federated
+        BanAppeal::resolve_apub(
+          &mut context.pool(),
+          blocked_person.id,
+          community.id,
+          mod_person.id,
+          BanAppealDecision::Lifted,
+        )
+        .await?;
+
         // write to mod log
         let form = ModlogInsertForm::mod_ban_from_community(
           mod_person.id,
@@ -21,7 +21,10 @@ use lemmy_apub_objects::{
     protocol::{Id, InCommunity},
   },
 };
-use lemmy_db_schema::source::{activity::ActivitySendTargets, community::CommunityActions};
+use lemmy_db_schema::source::{
+  activity::{ActivitySendTargets, PendingPostActivity},
+  community::CommunityActions,
+};
 use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult, UntranslatedError};
 use serde_json::Value;
 use url::Url;
@@ -55,11 +58,29 @@ impl Activity for RawAnnouncableActivities {
     let community = activity.community(context).await.ok();
     can_accept_activity_in_community(&community, context).await?;
 
+    let post_id = match &activity {
+      AnnouncableActivities::CreateOrUpdatePost(c) => Some(c.object.id.clone()),
+      _ => None,
+    };
+
     // verify and receive activity
     activity.verify(context).await?;
     let ap_id = activity.actor().clone().into();
     activity.receive(context).await?;
 
+    // Posts in the approval queue are announced once a mod approves them. Keep the original
+    // activity for that, as only the remote creator can sign it.
+    if let Some(post_id) = post_id
+      && let Ok(post) = post_id.dereference_local(context).await
+      && post.pending_approval
+    {
+      if community.as_ref().is_some_and(|c| c.local) {
+        PendingPostActivity::create(&mut context.pool(), post.id, &serde_json::to_value(&self)?)
+          .await?;
+      }
+      return Ok(());
+    }
+
     // if community is local, send activity to followers
     if let Some(community) = community
       && community.local
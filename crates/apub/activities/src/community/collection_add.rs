@@ -33,9 +33,10 @@ use lemmy_db_schema::{
     post::{Post, PostUpdateForm},
   },
 };
-use lemmy_db_schema_file::PersonId;
+use lemmy_db_schema_file::{PersonId, enums::ModeratorPermission};
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::error::{LemmyError, LemmyResult};
+use strum::IntoEnumIterator;
 use url::Url;
 
 impl CollectionAdd {
@@ -46,6 +47,15 @@ impl CollectionAdd {
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
     let id = generate_activity_id(AddType::Add, context)?;
+    // Always send the permissions, as a missing list means no change for existing mods
+    let moderator_permissions =
+      CommunityActions::read(&mut context.pool(), community.id, added_mod.id)
+        .await
+        .ok()
+        .map(|a| {
+          a.moderator_permissions
+            .unwrap_or_else(|| ModeratorPermission::iter().collect())
+        });
     let add = CollectionAdd {
       actor: actor.id().clone().into(),
       to: generate_to(community)?,
@@ -55,6 +65,7 @@ impl CollectionAdd {
       kind: AddType::Add,
       id: id.clone(),
       audience: Some(community.ap_id.clone().into()),
+      moderator_permissions,
     };
 
     let activity = AnnouncableActivities::CollectionAdd(add);
@@ -78,6 +89,7 @@ impl CollectionAdd {
       kind: AddType::Add,
       id: id.clone(),
       audience: Some(community.ap_id.clone().into()),
+      moderator_permissions: None,
     };
     let activity = AnnouncableActivities::CollectionAdd(add);
     send_activity_in_community(
@@ -109,7 +121,14 @@ impl Activity for CollectionAdd {
     let community = self.community(context).await?;
     verify_visibility(&self.to, &self.cc, &community)?;
     verify_person_in_community(&self.actor, &community, context).await?;
-    verify_mod_action(&self.actor, &community, context).await?;
+    // Changing the mod team requires all moderator permissions
+    let (_, collection_type) =
+      Community::get_by_collection_url(&mut context.pool(), &self.target.clone().into()).await?;
+    let permission = match collection_type {
+      CollectionType::Moderators => None,
+      CollectionType::Featured => Some(ModeratorPermission::RemoveContent),
+    };
+    verify_mod_action(&self.actor, &community, permission, context).await?;
     Ok(())
   }
 
@@ -129,16 +148,54 @@ impl Activity for CollectionAdd {
         let moderated_communities =
           CommunityActions::get_person_moderated_communities(&mut context.pool(), new_mod_id)
             .await?;
+        let actor = self.actor.dereference(context).await?;
+        // A list holding every permission is stored as full permissions
+        let moderator_permissions = self.moderator_permissions.map(|p| {
+          let all = ModeratorPermission::iter().all(|a| p.contains(&a));
+          (!all).then_some(p)
+        });
         if !moderated_communities.contains(&community.id) {
           let form = CommunityModeratorForm::new(community.id, new_mod.id);
           CommunityActions::join(&mut context.pool(), &form).await?;
+          if let Some(permissions @ Some(_)) = moderator_permissions {
+            CommunityActions::update_moderator_permissions(
+              &mut context.pool(),
+              community.id,
+              new_mod.id,
+              permissions,
+            )
+            .await?;
+          }
 
           // write mod log
-          let actor = self.actor.dereference(context).await?;
           let form =
             ModlogInsertForm::mod_add_to_community(actor.id, community.id, new_mod.id, false);
           let action = Modlog::create(&mut context.pool(), &[form]).await?;
           notify_mod_action(action, context);
+        } else if let Some(permissions) = moderator_permissions {
+          // Adding an existing moderator again updates their permissions, but only if they are
+          // included in the activity
+          let current = CommunityActions::read(&mut context.pool(), community.id, new_mod.id)
+            .await?
+            .moderator_permissions;
+          if current != permissions {
+            CommunityActions::update_moderator_permissions(
+              &mut context.pool(),
+              community.id,
+              new_mod.id,
+              permissions,
+            )
+            .await?;
+
+            let form = ModlogInsertForm::mod_change_moderator_permissions(
+              actor.id,
+              community.id,
+              new_mod.id,
+              None,
+            );
+            let action = Modlog::create(&mut context.pool(), &[form]).await?;
+            notify_mod_action(action, context);
+          }
         }
       }
       CollectionType::Featured => {
@@ -194,3 +251,87 @@ pub(crate) async fn send_feature_post(
     CollectionRemove::send_remove_featured_post(&community, &post, &actor, &context).await
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_apub_objects::utils::test::{parse_lemmy_community, parse_lemmy_person};
+  use lemmy_db_schema::source::{community::CommunityUpdateForm, instance::Instance};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  fn add_mod(
+    community: &ApubCommunity,
+    person: &ApubPerson,
+    moderator_permissions: Option<Vec<ModeratorPermission>>,
+  ) -> LemmyResult<CollectionAdd> {
+    Ok(CollectionAdd {
+      actor: person.id().clone().into(),
+      to: vec![],
+      object: person.id().clone(),
+      target: generate_moderators_url(&community.ap_id)?.into(),
+      cc: vec![community.id().clone()],
+      kind: AddType::Add,
+      id: Url::parse("https://enterprise.lemmy.ml/activities/add/1")?,
+      audience: None,
+      moderator_permissions,
+    })
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_receive_add_existing_mod() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let (person, _) = parse_lemmy_person(&context).await?;
+    let community = parse_lemmy_community(&context).await?;
+    let form = CommunityUpdateForm {
+      moderators_url: Some(Some(generate_moderators_url(&community.ap_id)?)),
+      ..Default::default()
+    };
+    Community::update(&mut context.pool(), community.id, &form).await?;
+
+    let form = CommunityModeratorForm::new(community.id, person.id);
+    CommunityActions::join(&mut context.pool(), &form).await?;
+    let scoped = vec![ModeratorPermission::HandleReports];
+    CommunityActions::update_moderator_permissions(
+      &mut context.pool(),
+      community.id,
+      person.id,
+      Some(scoped.clone()),
+    )
+    .await?;
+    let read_permissions = async || -> LemmyResult<_> {
+      Ok(
+        CommunityActions::read(&mut context.pool(), community.id, person.id)
+          .await?
+          .moderator_permissions,
+      )
+    };
+
+    // Adding again without permissions leaves them unchanged
+    add_mod(&community, &person, None)?
+      .receive(&context)
+      .await?;
+    assert_eq!(Some(scoped), read_permissions().await?);
+
+    // Permissions which are included are updated
+    let changed = vec![
+      ModeratorPermission::HandleReports,
+      ModeratorPermission::EditWiki,
+    ];
+    add_mod(&community, &person, Some(changed.clone()))?
+      .receive(&context)
+      .await?;
+    assert_eq!(Some(changed), read_permissions().await?);
+
+    // A list of all permissions grants full permissions
+    let all = ModeratorPermission::iter().collect();
+    add_mod(&community, &person, Some(all))?
+      .receive(&context)
+      .await?;
+    assert_eq!(None, read_permissions().await?);
+
+    Instance::delete_all(&mut context.pool()).await?;
+    Ok(())
+  }
+}
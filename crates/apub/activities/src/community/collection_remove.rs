@@ -31,6 +31,7 @@ use lemmy_db_schema::{
     post::{Post, PostUpdateForm},
   },
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::error::{LemmyError, LemmyResult};
 use url::Url;
@@ -106,7 +107,14 @@ impl Activity for CollectionRemove {
     let community = self.community(context).await?;
     verify_visibility(&self.to, &self.cc, &community)?;
     verify_person_in_community(&self.actor, &community, context).await?;
-    verify_mod_action(&self.actor, &community, context).await?;
+    // Changing the mod team requires all moderator permissions
+    let (_, collection_type) =
+      Community::get_by_collection_url(&mut context.pool(), &self.target.clone().into()).await?;
+    let permission = match collection_type {
+      CollectionType::Moderators => None,
+      CollectionType::Featured => Some(ModeratorPermission::RemoveContent),
+    };
+    verify_mod_action(&self.actor, &community, permission, context).await?;
     Ok(())
   }
 
@@ -28,6 +28,7 @@ use lemmy_db_schema::source::{
   person::Person,
   post::{Post, PostUpdateForm},
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::error::{LemmyError, LemmyResult};
 use url::Url;
@@ -50,7 +51,13 @@ impl Activity for LockPageOrNote {
     verify_visibility(&self.to, &self.cc, &community)?;
     verify_person_in_community(&self.actor, &community, context).await?;
     check_community_deleted_or_removed(&community)?;
-    verify_mod_action(&self.actor, &community, context).await?;
+    verify_mod_action(
+      &self.actor,
+      &community,
+      Some(ModeratorPermission::RemoveContent),
+      context,
+    )
+    .await?;
     Ok(())
   }
 
@@ -103,7 +110,13 @@ impl Activity for UndoLockPageOrNote {
     verify_visibility(&self.to, &self.cc, &community)?;
     verify_person_in_community(&self.actor, &community, context).await?;
     check_community_deleted_or_removed(&community)?;
-    verify_mod_action(&self.actor, &community, context).await?;
+    verify_mod_action(
+      &self.actor,
+      &community,
+      Some(ModeratorPermission::RemoveContent),
+      context,
+    )
+    .await?;
     Ok(())
   }
 
@@ -5,7 +5,7 @@ use crate::{
 };
 use activitypub_federation::{config::Data, fetch::object_id::ObjectId, traits::Actor};
 use either::Either;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{context::LemmyContext, utils::check_admin_permission};
 use lemmy_apub_objects::{
   objects::{
     PostOrComment,
@@ -21,6 +21,7 @@ use lemmy_db_schema::source::{
   person::{Person, PersonActions},
   site::Site,
 };
+use lemmy_db_schema_file::enums::{AdminPermission, ModeratorPermission};
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_diesel_utils::traits::Crud;
@@ -130,6 +131,7 @@ fn local_community(site_or_community: &Either<ApubSite, ApubCommunity>) -> Optio
 async fn verify_mod_or_admin_action(
   person_id: &ObjectId<ApubPerson>,
   site_or_community: &Either<ApubSite, ApubCommunity>,
+  permission: Option<ModeratorPermission>,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
   match site_or_community {
@@ -142,8 +144,8 @@ async fn verify_mod_or_admin_action(
       }
       let admin = person_id.dereference(context).await?;
       let local_user_view = LocalUserView::read_person(&mut context.pool(), admin.id).await?;
-      is_admin(&local_user_view)
+      check_admin_permission(&local_user_view, Some(AdminPermission::HandleReports))
     }
-    Either::Right(community) => verify_mod_action(person_id, community, context).await,
+    Either::Right(community) => verify_mod_action(person_id, community, permission, context).await,
   }
 }
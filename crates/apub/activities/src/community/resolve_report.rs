@@ -35,6 +35,7 @@ use lemmy_db_schema::{
   },
   traits::Reportable,
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_utils::error::{LemmyError, LemmyResult};
 use url::Url;
 
@@ -81,7 +82,13 @@ impl Activity for ResolveReport {
     let receiver = self.object.to[0].dereference(context).await?;
     verify_person_in_site_or_community(&self.actor, &receiver, context).await?;
     verify_urls_match(self.to[0].inner(), self.object.to[0].inner())?;
-    verify_mod_or_admin_action(&self.actor, &receiver, context).await?;
+    verify_mod_or_admin_action(
+      &self.actor,
+      &receiver,
+      Some(ModeratorPermission::HandleReports),
+      context,
+    )
+    .await?;
     Ok(())
   }
 
@@ -25,6 +25,7 @@ use lemmy_db_schema::source::{
   multi_community::MultiCommunity,
   person::Person,
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_utils::error::{LemmyError, LemmyResult};
 use url::Url;
 
@@ -101,7 +102,13 @@ impl Activity for Update {
         let community = self.community(context).await?;
         verify_visibility(&self.to, &self.cc, &community)?;
         verify_person_in_community(&self.actor, &community, context).await?;
-        verify_mod_action(&self.actor, &community, context).await?;
+        verify_mod_action(
+          &self.actor,
+          &community,
+          Some(ModeratorPermission::EditSettings),
+          context,
+        )
+        .await?;
         ApubCommunity::verify(c, &community.ap_id.clone().into(), context).await?;
       }
       Either::Right(m) => ApubMultiCommunity::verify(m, &self.id, context).await?,
@@ -115,10 +115,21 @@ impl Activity for CreateOrUpdateNote {
     // send the activity, not the comment author.
     let existing_comment = self.object.id.dereference_local(context).await.ok();
     let (post, _) = self.object.get_parents(context).await?;
-    if let (Some(distinguished), Some(existing_comment)) =
-      (self.object.distinguished, existing_comment)
-      && distinguished != existing_comment.distinguished
-    {
+    // Pinned comments are shown first for everyone, so this is also checked for new comments.
+    let mod_change = match existing_comment {
+      Some(existing_comment) => {
+        self
+          .object
+          .distinguished
+          .is_some_and(|d| d != existing_comment.distinguished)
+          || self
+            .object
+            .pinned
+            .is_some_and(|p| p != existing_comment.pinned)
+      }
+      None => self.object.pinned.unwrap_or_default(),
+    };
+    if mod_change {
       let creator = self.actor.dereference(context).await?;
       check_is_mod_or_admin(&mut context.pool(), creator.id, post.community_id).await?;
     }
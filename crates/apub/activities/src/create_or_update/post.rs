@@ -4,7 +4,11 @@ use crate::{
   community::send_activity_in_community,
   create_or_update::{parse_apub_mentions, tagged_user_inboxes},
   generate_activity_id,
-  protocol::{CreateOrUpdateType, create_or_update::page::CreateOrUpdatePage},
+  protocol::{
+    CreateOrUpdateType,
+    community::announce::{AnnounceActivity, RawAnnouncableActivities},
+    create_or_update::page::CreateOrUpdatePage,
+  },
 };
 use activitypub_federation::{
   config::Data,
@@ -26,13 +30,14 @@ use lemmy_apub_objects::{
 };
 use lemmy_db_schema::{
   source::{
+    activity::PendingPostActivity,
     community::Community,
     person::Person,
     post::{Post, PostActions, PostLikeForm, PostUpdateForm},
   },
   traits::Likeable,
 };
-use lemmy_db_schema_file::PersonId;
+use lemmy_db_schema_file::{PersonId, enums::ModeratorPermission};
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::error::{LemmyError, LemmyResult};
@@ -70,6 +75,21 @@ impl CreateOrUpdatePage {
       .await?
       .into();
 
+    // A remote post which was held back in the approval queue of a local community. Announce the
+    // activity which was originally received from the creator's instance. There is none if the
+    // post was fetched instead, then followers receive it with the next edit.
+    if !person.local {
+      let pending = PendingPostActivity::take(&mut context.pool(), post.id).await?;
+      if let Some(data) = pending
+        && community.local
+        && community.visibility.can_federate()
+      {
+        let activity: RawAnnouncableActivities = serde_json::from_value(data)?;
+        AnnounceActivity::send(activity, &community, &context).await?;
+      }
+      return Ok(());
+    }
+
     let create_or_update =
       CreateOrUpdatePage::new(post.into(), &person, &community, kind, &context).await?;
     let inboxes = tagged_user_inboxes(&create_or_update.object.tag, &context).await?;
@@ -115,9 +135,14 @@ impl Activity for CreateOrUpdatePage {
         Post::read_from_apub_id(&mut context.pool(), self.object.id.clone().into()).await
       {
         let community = Community::read(&mut context.pool(), post.community_id).await?;
-        if verify_mod_action(&self.actor, &community, context)
-          .await
-          .is_ok()
+        if verify_mod_action(
+          &self.actor,
+          &community,
+          Some(ModeratorPermission::RemoveContent),
+          context,
+        )
+        .await
+        .is_ok()
         {
           let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
           let form = PostUpdateForm {
@@ -126,7 +151,8 @@ impl Activity for CreateOrUpdatePage {
             ..Default::default()
           };
           Post::update(&mut context.pool(), post.id, &form).await?;
-          update_apub_post_tags(&self.object, &post, context).await?;
+          // The actor was verified as a mod above
+          update_apub_post_tags(&self.object, &post, true, context).await?;
         }
       }
 
@@ -149,6 +175,11 @@ impl Activity for CreateOrUpdatePage {
       self.kind == CreateOrUpdateType::Create && !site_view.local_site.disable_email_notifications;
     let actor = self.actor.dereference(context).await?;
 
+    // Notifications for posts in the approval queue are sent once they are approved
+    if post.pending_approval {
+      return Ok(());
+    }
+
     let community = Community::read(&mut context.pool(), post.community_id).await?;
 
     NotifyData {
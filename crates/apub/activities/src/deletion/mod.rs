@@ -40,6 +40,7 @@ use lemmy_db_schema::source::{
   post::{Post, PostUpdateForm},
   private_message::{PrivateMessage as DbPrivateMessage, PrivateMessageUpdateForm},
 };
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::error::LemmyResult;
@@ -191,7 +192,7 @@ pub(crate) async fn verify_delete_activity(
         verify_person_in_community(&activity.actor, &community, context).await?;
       }
       // community deletion is always a mod (or admin) action
-      verify_mod_action(&activity.actor, &community, context).await?;
+      verify_mod_action(&activity.actor, &community, None, context).await?;
     }
     DeletableObjects::Person(person) => {
       verify_is_public(&activity.to, &[])?;
@@ -239,7 +240,13 @@ async fn verify_delete_post_or_comment(
 ) -> LemmyResult<()> {
   verify_person_in_community(actor, community, context).await?;
   if is_mod_action {
-    verify_mod_action(actor, community, context).await?;
+    verify_mod_action(
+      actor,
+      community,
+      Some(ModeratorPermission::RemoveContent),
+      context,
+    )
+    .await?;
   } else {
     // domain of post ap_id and post.creator ap_id are identical, so we just check the former
     verify_domains_match(actor.inner(), object_id)?;
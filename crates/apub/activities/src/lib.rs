@@ -15,6 +15,7 @@ use crate::{
   following::send_follow,
   protocol::{
     CreateOrUpdateType,
+    block::appeal_ban::AppealBan,
     community::{report::Report, resolve_report::ResolveReport},
     create_or_update::{note::CreateOrUpdateNote, page::CreateOrUpdatePage},
   },
@@ -372,6 +373,11 @@ pub async fn match_outgoing_activities(
       UpdateMultiCommunity(multi, actor) => {
         send_update_multi_community(multi, actor, context).await
       }
+      AppealBan {
+        actor,
+        community,
+        reason,
+      } => AppealBan::send(&actor.into(), &community.into(), reason, context).await,
     }
   })
   .await?;
@@ -0,0 +1,26 @@
+use activitypub_federation::{fetch::object_id::ObjectId, protocol::helpers::deserialize_one};
+use lemmy_apub_objects::objects::{community::ApubCommunity, person::ApubPerson};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Display)]
+pub enum AppealBanType {
+  AppealBan,
+}
+
+/// Sent by a user who is banned from a remote community, to ask the community mods to reconsider
+/// the ban. Works like a `Flag`, except that the object is the community which issued the ban.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppealBan {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubCommunity>; 1],
+  pub(crate) object: ObjectId<ApubCommunity>,
+  /// Appeal text
+  pub(crate) summary: String,
+  #[serde(rename = "type")]
+  pub(crate) kind: AppealBanType,
+  pub(crate) id: Url,
+}
@@ -1,9 +1,14 @@
+pub mod appeal_ban;
 pub mod block_user;
 pub mod undo_block_user;
 
 #[cfg(test)]
 mod tests {
-  use crate::protocol::block::{block_user::BlockUser, undo_block_user::UndoBlockUser};
+  use crate::protocol::block::{
+    appeal_ban::AppealBan,
+    block_user::BlockUser,
+    undo_block_user::UndoBlockUser,
+  };
   use lemmy_apub_objects::utils::test::test_parse_lemmy_item;
   use lemmy_utils::error::LemmyResult;
 
@@ -13,6 +18,7 @@ mod tests {
     test_parse_lemmy_item::<UndoBlockUser>(
       "../apub/assets/lemmy/activities/block/undo_block_user.json",
     )?;
+    test_parse_lemmy_item::<AppealBan>("../apub/assets/lemmy/activities/block/appeal_ban.json")?;
     Ok(())
   }
 }
@@ -10,6 +10,7 @@ use lemmy_apub_objects::{
   utils::protocol::InCommunity,
 };
 use lemmy_db_schema::source::community::Community;
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use lemmy_utils::error::LemmyResult;
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -28,6 +29,11 @@ pub struct CollectionAdd {
   pub(crate) kind: AddType,
   pub(crate) id: Url,
   pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+  /// Lemmy extension, restricts the added moderator to these permissions. A list with every
+  /// permission grants all of them. If missing, a new moderator holds all permissions and the
+  /// permissions of an existing moderator are left unchanged.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub(crate) moderator_permissions: Option<Vec<ModeratorPermission>>,
 }
 
 impl InCommunity for CollectionAdd {
@@ -1,4 +1,7 @@
-use crate::{is_new_instance, protocol::collections::group_moderators::GroupModerators};
+use crate::{
+  is_new_instance,
+  protocol::collections::group_moderators::{GroupModerators, ModeratorPermissions},
+};
 use activitypub_federation::{
   config::Data,
   fetch::object_id::ObjectId,
@@ -25,6 +28,15 @@ impl Collection for ApubCommunityModerators {
 
   async fn read_local(owner: &Self::Owner, data: &Data<Self::DataType>) -> LemmyResult<Self::Kind> {
     let moderators = CommunityModeratorView::for_community(&mut data.pool(), owner.id).await?;
+    let moderator_permissions = moderators
+      .iter()
+      .filter_map(|m| {
+        Some(ModeratorPermissions {
+          moderator: m.moderator.ap_id.clone().into(),
+          permissions: m.permissions.clone()?,
+        })
+      })
+      .collect();
     let ordered_items = moderators
       .into_iter()
       .map(|m| ObjectId::<ApubPerson>::from(m.moderator.ap_id))
@@ -33,6 +45,7 @@ impl Collection for ApubCommunityModerators {
       r#type: OrderedCollectionType::OrderedCollection,
       id: generate_moderators_url(&owner.ap_id)?.into(),
       ordered_items,
+      moderator_permissions,
     })
   }
 
@@ -50,7 +63,13 @@ impl Collection for ApubCommunityModerators {
     owner: &Self::Owner,
     data: &Data<Self::DataType>,
   ) -> LemmyResult<Self> {
-    handle_community_moderators(&apub.ordered_items, owner, data).await?;
+    handle_community_moderators(
+      &apub.ordered_items,
+      &apub.moderator_permissions,
+      owner,
+      data,
+    )
+    .await?;
 
     // This return value is unused, so just set an empty vec
     Ok(ApubCommunityModerators(()))
@@ -59,6 +78,7 @@ impl Collection for ApubCommunityModerators {
 
 pub(super) async fn handle_community_moderators(
   new_mods: &Vec<ObjectId<ApubPerson>>,
+  moderator_permissions: &[ModeratorPermissions],
   community: &ApubCommunity,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
@@ -79,13 +99,29 @@ pub(super) async fn handle_community_moderators(
   for mod_id in new_mods {
     // Ignore errors as mod accounts might be deleted or instances unavailable.
     let mod_user: Option<ApubPerson> = mod_id.dereference(context).await.ok();
-    if let Some(mod_user) = mod_user
-      && !current_moderators
+    if let Some(mod_user) = mod_user {
+      let current_mod = current_moderators
+        .iter()
+        .find(|x| x.moderator.ap_id == mod_user.ap_id);
+      if current_mod.is_none() {
+        let community_moderator_form = CommunityModeratorForm::new(community.id, mod_user.id);
+        CommunityActions::join(&mut context.pool(), &community_moderator_form).await?;
+      }
+
+      // Moderators which are not listed with restricted permissions hold all permissions
+      let permissions = moderator_permissions
         .iter()
-        .any(|x| x.moderator.ap_id == mod_user.ap_id)
-    {
-      let community_moderator_form = CommunityModeratorForm::new(community.id, mod_user.id);
-      CommunityActions::join(&mut context.pool(), &community_moderator_form).await?;
+        .find(|p| &p.moderator == mod_id)
+        .map(|p| p.permissions.clone());
+      if current_mod.and_then(|m| m.permissions.clone()) != permissions {
+        CommunityActions::update_moderator_permissions(
+          &mut context.pool(),
+          community.id,
+          mod_user.id,
+          permissions,
+        )
+        .await?;
+      }
     }
 
     // Only add the top mod in case of new instance
@@ -110,6 +146,7 @@ mod tests {
     source::community::{CommunityActions, CommunityModeratorForm},
     test_data::TestData,
   };
+  use lemmy_db_schema_file::enums::ModeratorPermission;
   use pretty_assertions::assert_eq;
   use serial_test::serial;
 
@@ -140,6 +177,13 @@ mod tests {
 
     assert_eq!(current_moderators.len(), 1);
     assert_eq!(current_moderators[0].moderator.id, new_mod.id);
+    assert_eq!(
+      current_moderators[0].permissions,
+      Some(vec![
+        ModeratorPermission::HandleReports,
+        ModeratorPermission::RemoveContent
+      ])
+    );
 
     data.delete(&mut context.pool()).await?;
     Ok(())
@@ -57,7 +57,7 @@ pub fn fetch_community_collections(
           .filter(|p| p.kind == PersonOrGroupType::Person)
           .map(|p| ObjectId::<ApubPerson>::from(p.id.clone().into_inner()))
           .collect();
-        handle_community_moderators(&new_mods, &community, &context)
+        handle_community_moderators(&new_mods, &[], &community, &context)
           .await
           .ok();
       }
@@ -26,11 +26,12 @@ use lemmy_apub_objects::{
     community::ApubCommunity,
     multi_community::ApubMultiCommunity,
     multi_community_collection::ApubFeedCollection,
+    wiki_page::ApubWikiPage,
   },
   protocol::tags::CommunityTag,
 };
 use lemmy_db_schema::{
-  source::{community::Community, multi_community::MultiCommunity, tag::Tag},
+  source::{community::Community, multi_community::MultiCommunity, tag::Tag, wiki::WikiPage},
   traits::ApubActor,
 };
 use lemmy_db_schema_file::enums::CommunityVisibility;
@@ -226,6 +227,35 @@ pub(crate) async fn get_apub_community_tag_http(
   Ok(create_http_response(tag, &FEDERATION_CONTEXT)?)
 }
 
+#[derive(Deserialize, Clone)]
+pub(crate) struct CommunityWikiPagePath {
+  community_name: String,
+  slug: String,
+}
+
+/// Return the ActivityPub json representation of a wiki page over HTTP.
+pub(crate) async fn get_apub_community_wiki_page_http(
+  info: Path<CommunityWikiPagePath>,
+  context: Data<LemmyContext>,
+  request: HttpRequest,
+) -> LemmyResult<HttpResponse> {
+  let community: ApubCommunity =
+    Community::read_from_name(&mut context.pool(), &info.community_name, None, true)
+      .await?
+      .ok_or(LemmyErrorType::NotFound)?
+      .into();
+
+  check_community_fetchable(&community)?;
+  check_community_content_fetchable(&community, &request, &context).await?;
+
+  let ap_id = WikiPage::build_ap_id(&community.ap_id, &info.slug)?;
+  let wiki_page: ApubWikiPage = WikiPage::read_from_apub_id(&mut context.pool(), ap_id)
+    .await?
+    .ok_or(LemmyErrorType::NotFound)?
+    .into();
+  wiki_page.http_response(&FEDERATION_CONTEXT, &context).await
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
 
@@ -28,6 +28,11 @@ async fn get_post(
   let id = PostId(info.post_id.parse::<i32>()?);
   // Can't use PostView here because it excludes deleted/removed/local-only items
   let post: ApubPost = Post::read(&mut context.pool(), id).await?.into();
+  // Quarantined posts and posts waiting in the approval queue are not federated until they are
+  // approved
+  if post.quarantined || post.pending_approval {
+    return Err(LemmyErrorType::NotFound.into());
+  }
   let community = Community::read(&mut context.pool(), post.community_id).await?;
 
   check_community_content_fetchable(&community, request, context).await?;
@@ -7,6 +7,7 @@ use crate::http::{
     get_apub_community_moderators,
     get_apub_community_outbox,
     get_apub_community_tag_http,
+    get_apub_community_wiki_page_http,
     get_apub_person_multi_community,
     get_apub_person_multi_community_follows,
   },
@@ -50,6 +51,10 @@ pub fn config(cfg: &mut web::ServiceConfig) {
       "/c/{community_name}/tag/{tag_name}",
       web::get().to(get_apub_community_tag_http),
     )
+    .route(
+      "/c/{community_name}/wiki/{slug:.*}",
+      web::get().to(get_apub_community_wiki_page_http),
+    )
     .route("/u/{user_name}", web::get().to(get_apub_person_http))
     .route(
       "/u/{user_name}/outbox",
@@ -3,6 +3,7 @@ use activitypub_federation::{
   kinds::collection::OrderedCollectionType,
 };
 use lemmy_apub_objects::objects::person::ApubPerson;
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -12,4 +13,13 @@ pub struct GroupModerators {
   pub(crate) r#type: OrderedCollectionType,
   pub(crate) id: Url,
   pub(crate) ordered_items: Vec<ObjectId<ApubPerson>>,
+  /// Lemmy extension, lists the moderators which only hold some of the moderator permissions.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub(crate) moderator_permissions: Vec<ModeratorPermissions>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModeratorPermissions {
+  pub(crate) moderator: ObjectId<ApubPerson>,
+  pub(crate) permissions: Vec<ModeratorPermission>,
 }
@@ -1,5 +1,5 @@
 use crate::{
-  protocol::note::Note,
+  protocol::{note::Note, tags::UserFlair},
   utils::{
     functions::{
       append_attachments_to_comment,
@@ -7,6 +7,7 @@ use crate::{
       context_url,
       generate_to,
       read_from_string_or_source,
+      update_apub_user_flair,
       verify_person_in_community,
       verify_visibility,
     },
@@ -38,9 +39,10 @@ use lemmy_api_utils::{
 };
 use lemmy_db_schema::source::{
   comment::{Comment, CommentInsertForm, CommentUpdateForm},
-  community::Community,
+  community::{Community, CommunityActions},
   person::Person,
   post::Post,
+  revision::CommentRevision,
 };
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::{
@@ -121,6 +123,11 @@ impl Object for ApubComment {
     let parent_creator = get_comment_parent_creator(&mut context.pool(), &self).await?;
     let maa =
       collect_non_local_mentions(Some(&self.content), Some(parent_creator), context).await?;
+    let mut tag = maa.mentions;
+    if let Ok(actions) = CommunityActions::read(&mut context.pool(), community_id, creator_id).await
+    {
+      tag.extend(UserFlair::to_json(actions));
+    }
 
     let note = Note {
       r#type: NoteType::Note,
@@ -134,8 +141,9 @@ impl Object for ApubComment {
       in_reply_to,
       published: Some(self.published_at),
       updated: self.updated_at,
-      tag: maa.mentions,
+      tag,
       distinguished: Some(self.distinguished),
+      pinned: Some(self.pinned),
       language,
       audience: Some(community.ap_id.into()),
       attachment: vec![],
@@ -196,6 +204,7 @@ impl Object for ApubComment {
   async fn from_json(note: Note, context: &Data<LemmyContext>) -> LemmyResult<ApubComment> {
     let creator = note.attributed_to.dereference(context).await?;
     let (post, parent_comment) = note.get_parents(context).await?;
+    let community = Community::read(&mut context.pool(), post.community_id).await?;
     if let Some(c) = &parent_comment {
       check_comment_depth(c)?;
     }
@@ -212,6 +221,19 @@ impl Object for ApubComment {
         .await?,
     );
 
+    let orig_comment = Comment::read_from_apub_id(&mut context.pool(), note.id.clone().into())
+      .await
+      .ok()
+      .flatten();
+    // Only mods can pin comments, otherwise keep the existing value
+    let pinned = if check_is_mod_or_admin(&mut context.pool(), creator.id, community.id)
+      .await
+      .is_ok()
+    {
+      note.pinned
+    } else {
+      None
+    };
     let mut form = CommentInsertForm {
       creator_id: creator.id,
       post_id: post.id,
@@ -226,6 +248,8 @@ impl Object for ApubComment {
       language_id,
       federation_pending: Some(false),
       locked: None,
+      quarantined: None,
+      pinned,
     };
     form = plugin_hook_before("federated_comment_before_receive", form).await?;
     let parent_comment_path = parent_comment.map(|t| t.0.path);
@@ -238,6 +262,10 @@ impl Object for ApubComment {
     )
     .await?;
     plugin_hook_after("federated_comment_after_receive", &comment);
+    update_apub_user_flair(&note.tag, &creator, &community, context).await?;
+    if let Some(orig_comment) = &orig_comment {
+      CommentRevision::create_if_changed(&mut context.pool(), orig_comment, &comment).await?;
+    }
     Ok(comment.into())
   }
 }
@@ -275,7 +303,7 @@ pub(crate) mod tests {
     let context = LemmyContext::init_test_context().await;
     let test_data = TestData::create(&mut context.pool()).await?;
     let url = Url::parse("https://enterprise.lemmy.ml/comment/38741")?;
-    prepare_comment_test(&url, &context).await?;
+    let (person, community, _, _) = prepare_comment_test(&url, &context).await?;
 
     let json: Note = file_to_json_object("../apub/assets/lemmy/objects/comment.json")?;
     ApubComment::verify(&json, &url, &context).await?;
@@ -286,6 +314,13 @@ pub(crate) mod tests {
     assert!(!comment.local);
     assert_eq!(context.request_count(), 0);
 
+    // The flair is taken from the comment, as it comes from the community instance
+    let actions = CommunityActions::read(&mut context.pool(), community.id, person.id).await?;
+    assert_eq!(
+      (Some("Captain"), Some("🖖")),
+      (actions.flair.as_deref(), actions.flair_emoji.as_deref())
+    );
+
     let to_apub = comment.into_json(&context).await?;
     assert_json_include!(actual: json, expected: to_apub);
 
@@ -40,7 +40,7 @@ use lemmy_db_schema::{
   },
   traits::ApubActor,
 };
-use lemmy_db_schema_file::enums::{ActorType, CommunityVisibility};
+use lemmy_db_schema_file::enums::{ActorType, CommunityPostingMode, CommunityVisibility};
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::{sensitive::SensitiveString, traits::Crud};
 use lemmy_utils::{
@@ -117,7 +117,7 @@ impl Object for ApubCommunity {
     let community_id = self.id;
     let langs = CommunityLanguage::read(&mut data.pool(), community_id).await?;
     let language = LanguageTag::new_multiple(langs, &mut data.pool()).await?;
-    let post_tags = Tag::read_for_community(&mut data.pool(), community_id).await?;
+    let tags = Tag::read_for_community(&mut data.pool(), community_id).await?;
     let group = Group {
       kind: GroupType::Group,
       id: self.id().clone().into(),
@@ -139,13 +139,14 @@ impl Object for ApubCommunity {
       language,
       published: Some(self.published_at),
       updated: self.updated_at,
-      posting_restricted_to_mods: Some(self.posting_restricted_to_mods),
+      posting_restricted_to_mods: Some(self.posting_mode == CommunityPostingMode::ModsOnly),
+      posting_mode: Some(self.posting_mode),
       attributed_to: Some(AttributedTo::Lemmy(
         generate_moderators_url(&self.ap_id)?.into(),
       )),
       manually_approves_followers: Some(self.visibility == CommunityVisibility::Private),
       discoverable: Some(self.visibility != CommunityVisibility::Unlisted),
-      tag: post_tags.into_iter().map(CommunityTag::to_json).collect(),
+      tag: tags.into_iter().map(CommunityTag::to_json).collect(),
     };
     Ok(group)
   }
@@ -220,7 +221,16 @@ impl Object for ApubCommunity {
         .clone()
         .clone()
         .and_then(AttributedTo::url),
-      posting_restricted_to_mods: group.posting_restricted_to_mods,
+      // Older versions only federate whether posting is restricted to mods
+      posting_mode: group
+        .posting_mode
+        .or(group.posting_restricted_to_mods.map(|r| {
+          if r {
+            CommunityPostingMode::ModsOnly
+          } else {
+            CommunityPostingMode::Open
+          }
+        })),
       featured_url: group.featured.clone().clone().map(Into::into),
       visibility,
       ..CommunityInsertForm::new(
@@ -6,6 +6,7 @@ pub mod multi_community_collection;
 pub mod person;
 pub mod post;
 pub mod private_message;
+pub mod wiki_page;
 
 use comment::ApubComment;
 use community::ApubCommunity;
@@ -14,12 +15,14 @@ use instance::ApubSite;
 use multi_community::ApubMultiCommunity;
 use person::ApubPerson;
 use post::ApubPost;
+use wiki_page::ApubWikiPage;
 
 // TODO: some of these are redundant?
 
 pub type PostOrComment = Either<ApubPost, ApubComment>;
 
-pub type SearchableObjects = Either<Either<PostOrComment, UserOrCommunity>, ApubMultiCommunity>;
+pub type SearchableObjects =
+  Either<Either<PostOrComment, UserOrCommunity>, Either<ApubMultiCommunity, ApubWikiPage>>;
 
 pub type ReportableObjects = Either<PostOrComment, ApubCommunity>;
 
@@ -1,7 +1,7 @@
 use crate::{
   protocol::{
-    page::{Attachment, Page, PageType},
-    tags::{ApubTag, CommunityTag, Hashtag, HashtagType},
+    page::{Attachment, Location, LocationType, Page, PageType},
+    tags::{ApubTag, CommunityTag, Hashtag, HashtagType, UserFlair},
   },
   utils::{
     functions::{
@@ -9,6 +9,7 @@ use crate::{
       context_url,
       generate_to,
       read_from_string_or_source_opt,
+      update_apub_user_flair,
       verify_person_in_community,
       verify_visibility,
     },
@@ -28,22 +29,29 @@ use html2text::{from_read_with_decorator, render::TrivialDecorator};
 use lemmy_api_utils::{
   context::LemmyContext,
   plugins::{plugin_hook_after, plugin_hook_before},
-  request::generate_post_link_metadata,
+  request::{generate_post_gallery_urls, generate_post_link_metadata},
   utils::{
+    check_is_mod_or_admin,
     check_nsfw_allowed,
     get_url_blocklist,
+    post_requires_approval,
     process_markdown_opt,
     slur_regex,
     update_post_tags,
   },
 };
 use lemmy_db_schema::source::{
-  community::Community,
+  comment::Comment,
+  community::{Community, CommunityActions},
   local_site::LocalSite,
   person::Person,
   post::{Post, PostInsertForm, PostUpdateForm},
+  post_event::{PostEvent, PostEventForm},
+  post_gallery::{PostGalleryItem, PostGalleryItemInsertForm},
+  revision::PostRevision,
   tag::Tag,
 };
+use lemmy_db_schema_file::enums::CommunityPostingMode;
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::traits::Crud;
@@ -52,8 +60,14 @@ use lemmy_utils::{
   spawn_try_task,
   utils::{
     markdown::markdown_to_html,
-    slurs::check_slurs_opt,
-    validation::{is_url_blocked, is_valid_url},
+    slurs::{check_slurs, check_slurs_opt},
+    validation::{
+      POST_GALLERY_MAX_IMAGES,
+      is_url_blocked,
+      is_valid_event_location,
+      is_valid_event_timezone,
+      is_valid_url,
+    },
   },
 };
 use std::{collections::HashSet, ops::Deref};
@@ -123,18 +137,27 @@ impl Object for ApubPost {
     let community = Community::read(&mut context.pool(), community_id).await?;
     let language = Some(LanguageTag::new_single(self.language_id, &mut context.pool()).await?);
 
-    let attachment = self
-      .url
-      .clone()
-      .map(|url| {
-        Attachment::new(
-          url.into(),
-          self.url_content_type.clone(),
-          self.alt_text.clone(),
-        )
-      })
-      .into_iter()
-      .collect();
+    // Gallery posts send all of their images, other posts only the url
+    let gallery = PostGalleryItem::list_for_post(&mut context.pool(), self.id).await?;
+    let attachment = if gallery.is_empty() {
+      self
+        .url
+        .clone()
+        .map(|url| {
+          Attachment::new(
+            url.into(),
+            self.url_content_type.clone(),
+            self.alt_text.clone(),
+          )
+        })
+        .into_iter()
+        .collect()
+    } else {
+      gallery
+        .into_iter()
+        .map(|item| Attachment::image(item.url.into(), item.alt_text))
+        .collect()
+    };
 
     // Add tags defined by community and applied to this post
     let mut tags: Vec<ApubTag> = Tag::read_for_post(&mut context.pool(), self.id)
@@ -154,8 +177,40 @@ impl Object for ApubPost {
     let maa = collect_non_local_mentions(self.body.as_deref(), None, context).await?;
     tags.extend(maa.mentions);
 
+    // Add the flair of the creator in this community
+    if let Ok(actions) = CommunityActions::read(&mut context.pool(), community_id, creator_id).await
+    {
+      tags.extend(UserFlair::to_json(actions));
+    }
+
+    // Event posts are sent as Mobilizon compatible events. Only one location can be sent, so the
+    // online url is left out if the event also has a physical location.
+    let event = PostEvent::read(&mut context.pool(), self.id).await?;
+    let location = event.as_ref().and_then(|e| {
+      e.location.clone().map(Location::place).or_else(|| {
+        e.online_url
+          .clone()
+          .map(|u| Location::virtual_location(u.into()))
+      })
+    });
+
+    let accepted_answer = if let Some(comment_id) = self.accepted_answer_id {
+      Some(
+        Comment::read(&mut context.pool(), comment_id)
+          .await?
+          .ap_id
+          .into(),
+      )
+    } else {
+      None
+    };
+
     let page = Page {
-      kind: PageType::Page,
+      kind: if event.is_some() {
+        PageType::Event
+      } else {
+        PageType::Page
+      },
       id: self.ap_id.clone().into(),
       attributed_to: AttributedTo::Lemmy(creator.ap_id.into()),
       to: generate_to(&community)?,
@@ -174,6 +229,12 @@ impl Object for ApubPost {
       in_reply_to: None,
       tag: tags,
       context: Some(context_url(&self.ap_id)),
+      start_time: event.as_ref().map(|e| e.start_at),
+      end_time: event.as_ref().map(|e| e.end_at),
+      is_online: event.as_ref().map(|e| e.online_url.is_some()),
+      timezone: event.map(|e| e.timezone),
+      location,
+      accepted_answer,
     };
     Ok(page)
   }
@@ -211,7 +272,7 @@ impl Object for ApubPost {
     // Prevent posts from non-mod users in local, restricted community. If its a remote community
     // then its possible that the restricted setting was enabled recently, so existing user posts
     // should still be fetched.
-    if community.local && community.posting_restricted_to_mods {
+    if community.local && community.posting_mode == CommunityPostingMode::ModsOnly {
       CommunityModeratorView::check_is_community_moderator(
         &mut context.pool(),
         community.id,
@@ -283,7 +344,16 @@ impl Object for ApubPost {
       .await?,
     );
 
-    let orig_post = Post::read_from_apub_id(&mut context.pool(), page.id.clone().into()).await;
+    let orig_post = Post::read_from_apub_id(&mut context.pool(), page.id.clone().into())
+      .await
+      .ok()
+      .flatten();
+    // Only new posts go into the approval queue, edits of existing posts are applied directly.
+    let pending_approval = if orig_post.is_none() {
+      Some(post_requires_approval(&mut context.pool(), &community, creator.id).await)
+    } else {
+      None
+    };
     let mut form = PostInsertForm {
       url: url.map(Into::into),
       body,
@@ -296,6 +366,7 @@ impl Object for ApubPost {
       // May be a local post which is updated by remote mod.
       local: Some(page.id.is_local(context)),
       language_id,
+      pending_approval,
       ..PostInsertForm::new(name, creator.id, community.id)
     };
     form = plugin_hook_before("federated_post_after_receive", form).await?;
@@ -303,14 +374,35 @@ impl Object for ApubPost {
     let timestamp = page.updated.or(page.published).unwrap_or_else(Utc::now);
     let post = Post::insert_apub(&mut context.pool(), timestamp, &form).await?;
     plugin_hook_after("federated_post_after_receive", &post);
+    if let Some(orig_post) = &orig_post {
+      PostRevision::create_if_changed(&mut context.pool(), orig_post, &post).await?;
+    }
 
-    update_apub_post_tags(&page, &post, context).await?;
+    // Mod-only tags of remote communities are checked by the community instance. For local
+    // communities only mods and admins may set them.
+    let allow_mod_only = !community.local
+      || check_is_mod_or_admin(&mut context.pool(), creator.id, community.id)
+        .await
+        .is_ok();
+    update_apub_post_tags(&page, &post, allow_mod_only, context).await?;
+    update_apub_user_flair(&page.tag, &creator, &community, context).await?;
+    if orig_post.is_some() || page.attachment.len() > 1 {
+      update_apub_post_gallery(&page, &post, context).await?;
+    }
+    if orig_post.is_some() || page.start_time.is_some() {
+      update_apub_post_event(&page, &post, context).await?;
+    }
+    let post = if orig_post.is_some() || page.accepted_answer.is_some() {
+      update_apub_post_answer(&page, post, context).await?
+    } else {
+      post
+    };
 
     let post_ = post.clone();
     let context_ = context.clone();
 
     // Avoid regenerating metadata if the post already existed with the same url
-    let no_generate_metadata = orig_post.ok().flatten().is_some_and(|p| p.url == post.url);
+    let no_generate_metadata = orig_post.is_some_and(|p| p.url == post.url);
     if !no_generate_metadata {
       // Generates a post thumbnail in background task, because some sites can be very slow to
       // respond.
@@ -326,6 +418,7 @@ impl Object for ApubPost {
 pub async fn update_apub_post_tags(
   page: &Page,
   post: &Post,
+  allow_mod_only: bool,
   context: &LemmyContext,
 ) -> LemmyResult<()> {
   let post_tag_ap_ids = page
@@ -339,10 +432,119 @@ pub async fn update_apub_post_tags(
     .filter(|t| post_tag_ap_ids.contains(&*t.ap_id.0))
     .map(|t| t.id)
     .collect::<Vec<_>>();
-  update_post_tags(post, &post_tags, context).await?;
+  update_post_tags(post, &post_tags, allow_mod_only, context).await?;
+  Ok(())
+}
+
+/// Posts with multiple image attachments are stored as gallery. A single image is only used as
+/// post url.
+async fn update_apub_post_gallery(
+  page: &Page,
+  post: &Post,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let url_blocklist = get_url_blocklist(context).await?;
+  // Invalid images are skipped instead of rejecting the whole post
+  let images = page
+    .attachment
+    .iter()
+    .filter(|a| a.is_image())
+    .map(|a| (a.clone().url(), a.clone().alt_text()))
+    .filter(|(url, _)| is_url_blocked(url, &url_blocklist).is_ok() && is_valid_url(url).is_ok())
+    .take(POST_GALLERY_MAX_IMAGES)
+    .collect::<Vec<_>>();
+  let forms = if images.len() > 1 {
+    let (urls, alt_texts): (Vec<_>, Vec<_>) = images
+      .into_iter()
+      .map(|(url, alt_text)| (url.into(), alt_text))
+      .unzip();
+    generate_post_gallery_urls(urls, context)
+      .await
+      .into_iter()
+      .zip(alt_texts)
+      .zip(0..)
+      .map(|((url, alt_text), position)| {
+        PostGalleryItemInsertForm::new(post.id, url, alt_text, position)
+      })
+      .collect()
+  } else {
+    vec![]
+  };
+  PostGalleryItem::replace(&mut context.pool(), post.id, &forms).await?;
   Ok(())
 }
 
+/// Stores the date, time and place of events. Invalid locations or timezones are left out
+/// instead of rejecting the whole post.
+async fn update_apub_post_event(
+  page: &Page,
+  post: &Post,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let Some(start_at) = page.start_time else {
+    PostEvent::delete(&mut context.pool(), post.id).await?;
+    return Ok(());
+  };
+  let end_at = page.end_time.filter(|e| e >= &start_at).unwrap_or(start_at);
+  let timezone = page
+    .timezone
+    .clone()
+    .filter(|t| is_valid_event_timezone(t).is_ok())
+    .unwrap_or_else(|| "UTC".to_string());
+
+  let slur_regex = slur_regex(context).await?;
+  let url_blocklist = get_url_blocklist(context).await?;
+  let location = page
+    .location
+    .as_ref()
+    .and_then(Location::as_text)
+    .filter(|l| is_valid_event_location(l).is_ok() && check_slurs(l, &slur_regex).is_ok());
+  let online_url = page
+    .location
+    .as_ref()
+    .filter(|l| l.kind == LocationType::VirtualLocation)
+    .and_then(|l| l.url.clone())
+    .filter(|u| is_url_blocked(u, &url_blocklist).is_ok() && is_valid_url(u).is_ok());
+
+  let form = PostEventForm::new(
+    post.id,
+    start_at,
+    end_at,
+    timezone,
+    location,
+    online_url.map(Into::into),
+  );
+  PostEvent::upsert(&mut context.pool(), &form).await?;
+  Ok(())
+}
+
+/// The answer is only set if the comment is already known locally. It can't be fetched here, as
+/// that would require the post which is being received.
+async fn update_apub_post_answer(
+  page: &Page,
+  post: Post,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<Post> {
+  let accepted_answer_id = if let Some(answer) = &page.accepted_answer {
+    answer
+      .dereference_local(context)
+      .await
+      .ok()
+      .filter(|c| c.post_id == post.id)
+      .map(|c| c.id)
+  } else {
+    None
+  };
+  if accepted_answer_id == post.accepted_answer_id {
+    return Ok(post);
+  }
+  let form = PostUpdateForm {
+    accepted_answer_id: Some(accepted_answer_id),
+    ..Default::default()
+  };
+  Post::update(&mut context.pool(), post.id, &form).await
+}
+
 pub async fn post_nsfw(
   page: &Page,
   community: &Community,
@@ -26,6 +26,7 @@ use lemmy_db_schema::{
     instance::{Instance, InstanceActions},
     person::{Person, PersonActions},
     private_message::{PrivateMessage as DbPrivateMessage, PrivateMessageInsertForm},
+    revision::PrivateMessageRevision,
   },
   traits::Blockable,
 };
@@ -154,6 +155,10 @@ impl Object for ApubPrivateMessage {
     let content = process_markdown(&content, &slur_regex, &url_blocklist, context).await?;
     let content = markdown_rewrite_remote_links(content, context).await;
 
+    let orig_pm = DbPrivateMessage::read_from_apub_id(&mut context.pool(), note.id.clone().into())
+      .await
+      .ok()
+      .flatten();
     let mut form = PrivateMessageInsertForm {
       creator_id: creator.id,
       recipient_id: recipient.id,
@@ -168,6 +173,9 @@ impl Object for ApubPrivateMessage {
     let timestamp = note.updated.or(note.published).unwrap_or_else(Utc::now);
     let pm = DbPrivateMessage::insert_apub(&mut context.pool(), timestamp, &form).await?;
     plugin_hook_after("federated_private_message_after_receive", &pm);
+    if let Some(orig_pm) = &orig_pm {
+      PrivateMessageRevision::create_if_changed(&mut context.pool(), orig_pm, &pm).await?;
+    }
     let view = PrivateMessageView::read(&mut context.pool(), pm.id).await?;
     notify_private_message(&view, pm.updated_at.is_none(), context);
     Ok(pm.into())
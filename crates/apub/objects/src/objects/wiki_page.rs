@@ -0,0 +1,142 @@
+use crate::{
+  protocol::wiki_page::WikiPageArticle,
+  utils::{
+    functions::{check_apub_id_valid_with_strictness, read_from_string_or_source},
+    markdown_links::markdown_rewrite_remote_links,
+    protocol::Source,
+  },
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::object::ArticleType,
+  protocol::{
+    values::MediaTypeHtml,
+    verification::{verify_domains_match, verify_is_remote_object},
+  },
+  traits::Object,
+};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::source::{
+  community::Community,
+  wiki::{WikiPage, WikiPageInsertForm, WikiPageUpdateForm},
+};
+use lemmy_diesel_utils::{dburl::DbUrl, traits::Crud};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType, LemmyResult},
+  utils::{markdown::markdown_to_html, slurs::check_slurs, validation::is_valid_wiki_slug},
+};
+use std::ops::Deref;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub struct ApubWikiPage(pub WikiPage);
+
+impl Deref for ApubWikiPage {
+  type Target = WikiPage;
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl From<WikiPage> for ApubWikiPage {
+  fn from(w: WikiPage) -> Self {
+    ApubWikiPage(w)
+  }
+}
+
+#[async_trait::async_trait]
+impl Object for ApubWikiPage {
+  type DataType = LemmyContext;
+  type Kind = WikiPageArticle;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    self.ap_id.inner()
+  }
+
+  async fn read_from_id(
+    object_id: Url,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<Option<Self>> {
+    Ok(
+      WikiPage::read_from_apub_id(&mut context.pool(), object_id.into())
+        .await?
+        .map(Into::into),
+    )
+  }
+
+  async fn delete(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    if !self.deleted {
+      let form = WikiPageUpdateForm {
+        deleted: Some(true),
+        ..Default::default()
+      };
+      WikiPage::update(&mut context.pool(), self.id, &form).await?;
+    }
+    Ok(())
+  }
+
+  fn is_deleted(&self) -> bool {
+    self.deleted
+  }
+
+  async fn into_json(self, context: &Data<Self::DataType>) -> LemmyResult<WikiPageArticle> {
+    let community = Community::read(&mut context.pool(), self.community_id).await?;
+    Ok(WikiPageArticle {
+      kind: ArticleType::Article,
+      id: self.ap_id.clone().into(),
+      name: self.title.clone(),
+      content: markdown_to_html(&self.body),
+      media_type: Some(MediaTypeHtml::Html),
+      source: Some(Source::new(self.body.clone())),
+      audience: community.ap_id.into(),
+      published: Some(self.published_at),
+      updated: self.updated_at,
+    })
+  }
+
+  async fn verify(
+    article: &WikiPageArticle,
+    expected_domain: &Url,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<()> {
+    verify_domains_match(article.id.inner(), expected_domain)?;
+    verify_domains_match(article.audience.inner(), article.id.inner())?;
+    verify_is_remote_object(&article.id, context)?;
+    check_apub_id_valid_with_strictness(article.id.inner(), true, context).await?;
+
+    check_slurs(&article.name, &slur_regex(context).await?)?;
+    Ok(())
+  }
+
+  async fn from_json(
+    article: WikiPageArticle,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<ApubWikiPage> {
+    let community = article.audience.dereference(context).await?;
+    let id: DbUrl = article.id.into();
+    // The slug is only part of the url, so the page must be located below the community
+    let slug =
+      WikiPage::slug_from_ap_id(&community.ap_id, &id).ok_or(LemmyErrorType::InvalidWikiSlug)?;
+    is_valid_wiki_slug(&slug)?;
+
+    let slur_regex = slur_regex(context).await?;
+    let url_blocklist = get_url_blocklist(context).await?;
+    let body = read_from_string_or_source(&article.content, &None, &article.source);
+    let body = process_markdown(&body, &slur_regex, &url_blocklist, context).await?;
+    let body = markdown_rewrite_remote_links(body, context).await;
+
+    let form = WikiPageInsertForm {
+      local: Some(false),
+      deleted: Some(false),
+      published_at: article.published,
+      updated_at: article.updated,
+      ..WikiPageInsertForm::new(community.id, slug, article.name, body, id)
+    };
+    let wiki_page = WikiPage::upsert(&mut context.pool(), &form).await?;
+    Ok(wiki_page.into())
+  }
+}
@@ -13,6 +13,7 @@ use activitypub_federation::{
   },
 };
 use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::enums::CommunityPostingMode;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::fmt::Debug;
@@ -51,6 +52,9 @@ pub struct Group {
   pub attributed_to: Option<AttributedTo>,
   // lemmy extension
   pub posting_restricted_to_mods: Option<bool>,
+  // lemmy extension
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub posting_mode: Option<CommunityPostingMode>,
   pub outbox: Url,
   pub endpoints: Option<Endpoints>,
   pub featured: Option<Url>,
@@ -6,6 +6,7 @@ pub mod page;
 pub mod person;
 pub mod private_message;
 pub mod tags;
+pub mod wiki_page;
 
 #[cfg(test)]
 mod tests {
@@ -13,7 +14,7 @@ mod tests {
     group::Group,
     instance::Instance,
     note::Note,
-    page::Page,
+    page::{Location, Page},
     person::Person,
     private_message::PrivateMessage,
   };
@@ -98,7 +99,17 @@ mod tests {
   #[test]
   fn test_parse_object_mobilizon() -> LemmyResult<()> {
     test_json::<Group>("../apub/assets/mobilizon/objects/group.json")?;
-    test_json::<Page>("../apub/assets/mobilizon/objects/event.json")?;
+    let event = test_json::<Page>("../apub/assets/mobilizon/objects/event.json")?;
+    let event = event.inner();
+    assert_eq!(Some("Europe/London"), event.timezone.as_deref());
+    assert!(event.start_time.is_some() && event.end_time > event.start_time);
+    assert_eq!(
+      Some(
+        "The Kenn Centre, Devon Expressway, EX6 7TW Teignbridge, England, United Kingdom"
+          .to_string()
+      ),
+      event.location.as_ref().and_then(Location::as_text)
+    );
     test_json::<Person>("../apub/assets/mobilizon/objects/person.json")?;
     Ok(())
   }
@@ -53,6 +53,8 @@ pub struct Note {
   pub tag: Vec<ApubTag>,
   // lemmy extension
   pub distinguished: Option<bool>,
+  // lemmy extension
+  pub pinned: Option<bool>,
   pub(crate) language: Option<LanguageTag>,
   pub(crate) audience: Option<ObjectId<ApubCommunity>>,
   #[serde(default)]
@@ -1,5 +1,5 @@
 use crate::{
-  objects::{community::ApubCommunity, person::ApubPerson, post::ApubPost},
+  objects::{comment::ApubComment, community::ApubCommunity, person::ApubPerson, post::ApubPost},
   protocol::tags::ApubTag,
   utils::protocol::{
     AttributedTo,
@@ -61,8 +61,8 @@ pub struct Page {
   pub(crate) media_type: Option<MediaTypeMarkdownOrHtml>,
   #[serde(deserialize_with = "deserialize_skip_error", default)]
   pub(crate) source: Option<Source>,
-  /// most software uses array type for attachment field, so we do the same. nevertheless, we only
-  /// use the first item
+  /// most software uses array type for attachment field, so we do the same. the first item is
+  /// used as post url, multiple images are stored as gallery.
   #[serde(default)]
   pub(crate) attachment: Vec<Attachment>,
   pub(crate) image: Option<ImageObject>,
@@ -76,6 +76,100 @@ pub struct Page {
   #[serde(deserialize_with = "deserialize_skip_error", default)]
   pub tag: Vec<ApubTag>,
   pub(crate) context: Option<String>,
+  /// Only for events, in the same format as Mobilizon.
+  pub(crate) start_time: Option<DateTime<Utc>>,
+  pub(crate) end_time: Option<DateTime<Utc>>,
+  pub(crate) timezone: Option<String>,
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) location: Option<Location>,
+  pub(crate) is_online: Option<bool>,
+  /// Lemmy extension, the comment which was marked as answer to the post.
+  pub(crate) accepted_answer: Option<ObjectId<ApubComment>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum LocationType {
+  Place,
+  VirtualLocation,
+}
+
+/// Where an event takes place. Physical places have a name or address, online events use
+/// `VirtualLocation` with the url to join.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+  #[serde(rename = "type")]
+  pub(crate) kind: LocationType,
+  pub(crate) name: Option<String>,
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) address: Option<PostalAddress>,
+  pub(crate) url: Option<Url>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PostalAddressType {
+  PostalAddress,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostalAddress {
+  #[serde(rename = "type")]
+  kind: PostalAddressType,
+  street_address: Option<String>,
+  postal_code: Option<String>,
+  address_locality: Option<String>,
+  address_region: Option<String>,
+  address_country: Option<String>,
+}
+
+impl Location {
+  pub(crate) fn place(name: String) -> Self {
+    Location {
+      kind: LocationType::Place,
+      name: Some(name),
+      address: None,
+      url: None,
+    }
+  }
+
+  pub(crate) fn virtual_location(url: Url) -> Self {
+    Location {
+      kind: LocationType::VirtualLocation,
+      name: None,
+      address: None,
+      url: Some(url),
+    }
+  }
+
+  /// The name and address of a physical place as single line, like
+  /// `The Kenn Centre, Devon Expressway, EX6 7TW Teignbridge, England, United Kingdom`.
+  pub(crate) fn as_text(&self) -> Option<String> {
+    if self.kind != LocationType::Place {
+      return None;
+    }
+    let address = self.address.as_ref();
+    let locality = address
+      .map(|a| [&a.postal_code, &a.address_locality])
+      .map(|parts| parts.iter().filter_map(|p| p.as_deref()).join(" "))
+      .filter(|l| !l.is_empty());
+    let parts = [
+      self.name.clone(),
+      address.and_then(|a| a.street_address.clone()),
+      locality,
+      address.and_then(|a| a.address_region.clone()),
+      address.and_then(|a| a.address_country.clone()),
+    ];
+    let text = parts
+      .into_iter()
+      .flatten()
+      .map(|p| p.trim().to_string())
+      .filter(|p| !p.is_empty())
+      .join(", ");
+    (!text.is_empty()).then_some(text)
+  }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -127,6 +221,19 @@ impl Attachment {
     }
   }
 
+  pub(crate) fn is_image(&self) -> bool {
+    let is_image_type = |media_type: &Option<String>| {
+      media_type
+        .as_deref()
+        .is_some_and(|m| m.starts_with("image"))
+    };
+    match self {
+      Attachment::Image(_) => true,
+      Attachment::Document(d) => is_image_type(&d.media_type),
+      Attachment::Link(l) => is_image_type(&l.media_type),
+    }
+  }
+
   pub(crate) fn alt_text(self) -> Option<String> {
     match self {
       Attachment::Image(i) => i.name,
@@ -175,11 +282,7 @@ impl Attachment {
   pub(crate) fn new(url: Url, media_type: Option<String>, alt_text: Option<String>) -> Attachment {
     let is_image = media_type.clone().unwrap_or_default().starts_with("image");
     if is_image {
-      Attachment::Image(Image {
-        kind: Default::default(),
-        url,
-        name: alt_text,
-      })
+      Attachment::image(url, alt_text)
     } else {
       Attachment::Link(Link {
         href: url,
@@ -188,6 +291,15 @@ impl Attachment {
       })
     }
   }
+
+  /// Creates an image attachment, used for the images of gallery posts.
+  pub(crate) fn image(url: Url, alt_text: Option<String>) -> Attachment {
+    Attachment::Image(Image {
+      kind: Default::default(),
+      url,
+      name: alt_text,
+    })
+  }
 }
 
 // Used for community outbox, so that it can be compatible with Pleroma/Mastodon.
@@ -2,8 +2,12 @@ use crate::objects::person::ApubPerson;
 use activitypub_federation::{fetch::object_id::ObjectId, kinds::link::MentionType};
 use lemmy_db_schema::{
   newtypes::CommunityId,
-  source::tag::{Tag, TagInsertForm},
+  source::{
+    community::CommunityActions,
+    tag::{Tag, TagInsertForm},
+  },
 };
+use lemmy_utils::utils::validation::{is_valid_flair_emoji, is_valid_tag_color};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use url::Url;
@@ -17,13 +21,20 @@ pub enum ApubTag {
   Hashtag(Hashtag),
   CommunityTag(CommunityTag),
   Mention(Mention),
+  UserFlair(UserFlair),
   Unknown(Value),
 }
 
 impl ApubTag {
   pub(crate) fn community_tag_id(&self) -> Option<&Url> {
     match self {
-      ApubTag::CommunityTag(t) => Some(&t.id),
+      ApubTag::CommunityTag(t) if t.kind == CommunityTagType::CommunityPostTag => Some(&t.id),
+      _ => None,
+    }
+  }
+  pub(crate) fn user_flair(&self) -> Option<&UserFlair> {
+    match self {
+      ApubTag::UserFlair(f) => Some(f),
       _ => None,
     }
   }
@@ -63,6 +74,8 @@ pub enum HashtagType {
 enum CommunityTagType {
   #[default]
   CommunityPostTag,
+  /// A flair which users can pick in the community, only used in the `Group`.
+  CommunityUserFlair,
 }
 
 /// A tag that a community owns, that is added to a post.
@@ -75,16 +88,30 @@ pub struct CommunityTag {
   pub name: Option<String>,
   pub preferred_username: String,
   pub content: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub color: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub emoji: Option<String>,
+  #[serde(default)]
+  pub mod_only: bool,
 }
 
 impl CommunityTag {
   pub fn to_json(tag: Tag) -> Self {
+    let kind = if tag.user_flair {
+      CommunityTagType::CommunityUserFlair
+    } else {
+      CommunityTagType::CommunityPostTag
+    };
     CommunityTag {
-      kind: Default::default(),
+      kind,
       id: tag.ap_id.into(),
       name: tag.display_name,
       preferred_username: tag.name,
       content: tag.description,
+      color: tag.color,
+      emoji: tag.emoji,
+      mod_only: tag.mod_only,
     }
   }
 
@@ -96,6 +123,41 @@ impl CommunityTag {
       description: self.content.clone(),
       community_id,
       deleted: Some(false),
+      // Invalid values are dropped instead of rejecting the whole community
+      color: self.color.clone().filter(|c| is_valid_tag_color(c).is_ok()),
+      emoji: self
+        .emoji
+        .clone()
+        .filter(|e| is_valid_flair_emoji(e).is_ok()),
+      mod_only: Some(self.mod_only),
+      user_flair: Some(self.kind == CommunityTagType::CommunityUserFlair),
     }
   }
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+enum UserFlairType {
+  UserFlair,
+}
+
+/// The flair of the author in the community, attached to their posts and comments.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct UserFlair {
+  #[serde(rename = "type")]
+  kind: UserFlairType,
+  pub name: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub emoji: Option<String>,
+}
+
+impl UserFlair {
+  pub fn to_json(actions: CommunityActions) -> Option<ApubTag> {
+    actions.flair.map(|name| {
+      ApubTag::UserFlair(UserFlair {
+        kind: UserFlairType::UserFlair,
+        name,
+        emoji: actions.flair_emoji,
+      })
+    })
+  }
+}
@@ -0,0 +1,33 @@
+use crate::{
+  objects::{community::ApubCommunity, wiki_page::ApubWikiPage},
+  utils::protocol::Source,
+};
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::object::ArticleType,
+  protocol::{helpers::deserialize_skip_error, values::MediaTypeHtml},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A page of a community wiki.
+///
+/// Unlike posts it has no `attributedTo`, as pages are edited by many users. The community is
+/// given in `audience` instead.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WikiPageArticle {
+  #[serde(rename = "type")]
+  pub(crate) kind: ArticleType,
+  pub id: ObjectId<ApubWikiPage>,
+  pub(crate) name: String,
+  pub(crate) content: String,
+  pub(crate) media_type: Option<MediaTypeHtml>,
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) source: Option<Source>,
+  pub(crate) audience: ObjectId<ApubCommunity>,
+  pub(crate) published: Option<DateTime<Utc>>,
+  pub(crate) updated: Option<DateTime<Utc>>,
+}
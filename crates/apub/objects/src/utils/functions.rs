@@ -1,7 +1,7 @@
 use super::protocol::Source;
 use crate::{
   objects::{community::ApubCommunity, instance::ApubSite, person::ApubPerson},
-  protocol::{group::Group, page::Attachment},
+  protocol::{group::Group, page::Attachment, tags::ApubTag},
 };
 use activitypub_federation::{
   config::Data,
@@ -13,18 +13,21 @@ use either::Either;
 use html2md::parse_html;
 use lemmy_api_utils::{context::LemmyContext, utils::check_is_mod_or_admin};
 use lemmy_db_schema::source::{
-  community::Community,
+  community::{Community, CommunityActions},
   instance::{Instance, InstanceActions},
   local_site::LocalSite,
+  person::Person,
+  tag::Tag,
 };
-use lemmy_db_schema_file::enums::{ActorType, CommunityVisibility};
-use lemmy_db_views_community_moderator::CommunityPersonBanView;
+use lemmy_db_schema_file::enums::{ActorType, CommunityVisibility, ModeratorPermission};
+use lemmy_db_views_community_moderator::{CommunityModeratorView, CommunityPersonBanView};
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::connection::DbPool;
 use lemmy_utils::{
   CACHE_DURATION_FEDERATION,
   CacheLock,
   error::{LemmyError, LemmyResult, UntranslatedError},
+  utils::validation::{is_valid_flair_emoji, is_valid_user_flair},
 };
 use moka::future::Cache;
 use std::sync::{Arc, LazyLock};
@@ -275,6 +278,44 @@ pub async fn append_attachments_to_comment(
   Ok(content)
 }
 
+/// Stores the flair of the author of a post or comment in its community. Flairs are accepted from
+/// users of the community instance, otherwise only the user flairs which anyone can pick. If the
+/// object has no flair tag the existing flair is kept, as not all software sends it.
+pub async fn update_apub_user_flair(
+  tags: &[ApubTag],
+  creator: &Person,
+  community: &Community,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let Some(flair) = tags.iter().find_map(ApubTag::user_flair) else {
+    return Ok(());
+  };
+  if is_valid_user_flair(&flair.name).is_err() {
+    return Ok(());
+  }
+  let emoji = flair
+    .emoji
+    .clone()
+    .filter(|e| is_valid_flair_emoji(e).is_ok());
+
+  let allowed = creator.instance_id == community.instance_id
+    || Tag::read_for_community(&mut context.pool(), community.id)
+      .await?
+      .into_iter()
+      .filter(|t| t.user_flair && !t.mod_only)
+      .any(|t| t.display_name.as_deref().unwrap_or(&t.name) == flair.name && t.emoji == emoji);
+  if allowed {
+    CommunityActions::update_flair(
+      &mut context.pool(),
+      community.id,
+      creator.id,
+      Some((flair.name.clone(), emoji)),
+    )
+    .await?;
+  }
+  Ok(())
+}
+
 pub fn community_visibility(group: &Group) -> CommunityVisibility {
   if group.manually_approves_followers.unwrap_or_default() {
     CommunityVisibility::Private
@@ -295,9 +336,12 @@ pub fn context_url(id: &Url) -> String {
 /// * `mod_id` - Activitypub ID of the mod or admin who performed the action
 /// * `object_id` - Activitypub ID of the actor or object that is being moderated
 /// * `community` - The community inside which moderation is happening
+/// * `permission` - The moderator permission required for the action, `None` if all permissions
+///   are required
 pub async fn verify_mod_action(
   mod_id: &ObjectId<ApubPerson>,
   community: &Community,
+  permission: Option<ModeratorPermission>,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
   // mod action comes from the same instance as the community, so it was presumably done
@@ -308,5 +352,17 @@ pub async fn verify_mod_action(
   }
 
   let mod_ = mod_id.dereference(context).await?;
-  check_is_mod_or_admin(&mut context.pool(), mod_.id, community.id).await
+  check_is_mod_or_admin(&mut context.pool(), mod_.id, community.id).await?;
+
+  // Remote users can't be admins here, so they must be moderators with the required permission
+  if !mod_.local {
+    CommunityModeratorView::check_moderator_permission(
+      &mut context.pool(),
+      community.id,
+      mod_.id,
+      permission,
+    )
+    .await?;
+  }
+  Ok(())
 }
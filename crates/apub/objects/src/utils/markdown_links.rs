@@ -59,7 +59,9 @@ pub(crate) async fn to_local_url(url: &str, context: &Data<LemmyContext>) -> Opt
     Left(Left(Right(comment))) => comment.local_url(context.settings()),
     Left(Right(Left(user))) => user.actor_url(context.settings()),
     Left(Right(Right(community))) => community.actor_url(context.settings()),
-    Right(multi) => multi.actor_url(context.settings()),
+    Right(Left(multi)) => multi.actor_url(context.settings()),
+    // Wiki pages are only readable through the api
+    Right(Right(_)) => return None,
   }
   .ok()
 }
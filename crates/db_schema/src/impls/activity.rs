@@ -1,15 +1,20 @@
 use crate::{
   diesel::OptionalExtension,
-  newtypes::ActivityId,
-  source::activity::{ReceivedActivity, SentActivity, SentActivityForm},
+  newtypes::{ActivityId, PostId},
+  source::activity::{PendingPostActivity, ReceivedActivity, SentActivity, SentActivityForm},
+};
+use diesel::{
+  ExpressionMethods,
+  QueryDsl,
+  dsl::{delete, insert_into},
 };
-use diesel::{ExpressionMethods, QueryDsl, dsl::insert_into};
 use diesel_async::RunQueryDsl;
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
   dburl::DbUrl,
 };
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use serde_json::Value;
 
 impl SentActivity {
   pub async fn create(pool: &mut DbPool<'_>, form: SentActivityForm) -> LemmyResult<Self> {
@@ -61,6 +66,37 @@ impl ReceivedActivity {
   }
 }
 
+impl PendingPostActivity {
+  /// Only the first activity is kept, which is the one that created the post.
+  pub async fn create(pool: &mut DbPool<'_>, post_id_: PostId, data_: &Value) -> LemmyResult<()> {
+    use lemmy_db_schema_file::schema::pending_post_activity::dsl::{
+      data,
+      pending_post_activity,
+      post_id,
+    };
+    let conn = &mut get_conn(pool).await?;
+    insert_into(pending_post_activity)
+      .values((post_id.eq(post_id_), data.eq(data_)))
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)?;
+    Ok(())
+  }
+
+  /// Removes the held back activity of the post, and returns it if there was one.
+  pub async fn take(pool: &mut DbPool<'_>, post_id_: PostId) -> LemmyResult<Option<Value>> {
+    use lemmy_db_schema_file::schema::pending_post_activity::dsl::{data, pending_post_activity};
+    let conn = &mut get_conn(pool).await?;
+    delete(pending_post_activity.find(post_id_))
+      .returning(data)
+      .get_result::<Value>(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::Deleted)
+  }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,29 @@
+use crate::{
+  newtypes::AdminAuditLogId,
+  source::admin_audit_log::{AdminAuditLog, AdminAuditLogInsertForm},
+};
+use diesel::{QueryDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::admin_audit_log;
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl AdminAuditLog {
+  pub async fn create(pool: &mut DbPool<'_>, form: &AdminAuditLogInsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(admin_audit_log::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, id: AdminAuditLogId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    admin_audit_log::table
+      .find(id)
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
@@ -0,0 +1,91 @@
+use crate::{
+  newtypes::{BanAppealId, CommunityId, ModlogId},
+  source::ban_appeal::{BanAppeal, BanAppealInsertForm, BanAppealUpdateForm},
+};
+use chrono::Utc;
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, insert_into, update};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{PersonId, enums::BanAppealDecision, schema::ban_appeal};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  dburl::DbUrl,
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for BanAppeal {
+  type InsertForm = BanAppealInsertForm;
+  type UpdateForm = BanAppealUpdateForm;
+  type IdType = BanAppealId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(ban_appeal::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    id_: Self::IdType,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(ban_appeal::table.find(id_))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl BanAppeal {
+  /// Returns the appeal against the given ban, if one was submitted.
+  pub async fn read_for_ban(pool: &mut DbPool<'_>, ban_id: ModlogId) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    ban_appeal::table
+      .filter(ban_appeal::ban_id.eq(ban_id))
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Resolves open appeals of a person against a community ban. Used when the decision on a
+  /// remote community arrives over federation.
+  pub async fn resolve_apub(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+    community_id: CommunityId,
+    resolver_id: PersonId,
+    decision: BanAppealDecision,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      ban_appeal::table
+        .filter(ban_appeal::creator_id.eq(creator_id))
+        .filter(ban_appeal::community_id.eq(community_id))
+        .filter(ban_appeal::resolved.eq(false)),
+    )
+    .set((
+      ban_appeal::resolved.eq(true),
+      ban_appeal::resolver_id.eq(resolver_id),
+      ban_appeal::decision.eq(decision),
+      ban_appeal::updated_at.eq(Utc::now()),
+    ))
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  pub async fn read_from_apub_id(pool: &mut DbPool<'_>, ap_id: &DbUrl) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    ban_appeal::table
+      .filter(ban_appeal::ap_id.eq(ap_id))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
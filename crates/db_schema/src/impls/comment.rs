@@ -27,7 +27,7 @@ use diesel_uplete::{UpleteCount, uplete};
 use lemmy_db_schema_file::{
   InstanceId,
   PersonId,
-  schema::{comment, comment_actions, community, post},
+  schema::{comment, comment_actions, comment_revision, community, post},
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -48,6 +48,19 @@ impl Comment {
   ) -> LemmyResult<Vec<Self>> {
     let conn = &mut get_conn(pool).await?;
 
+    diesel::delete(
+      comment_revision::table.filter(
+        comment_revision::comment_id.eq_any(
+          comment::table
+            .filter(comment::creator_id.eq(creator_id))
+            .select(comment::id),
+        ),
+      ),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)?;
+
     diesel::update(comment::table.filter(comment::creator_id.eq(creator_id)))
       .set((
         comment::content.eq(DELETED_REPLACEMENT_TEXT),
@@ -489,6 +502,8 @@ mod tests {
       unresolved_report_count: 0,
       federation_pending: false,
       locked: false,
+      quarantined: false,
+      pinned: false,
     };
 
     let child_comment_form = CommentInsertForm::new(
@@ -33,7 +33,14 @@ use diesel_async::RunQueryDsl;
 use diesel_uplete::{UpleteCount, uplete};
 use lemmy_db_schema_file::{
   PersonId,
-  enums::{CommunityFollowerState, CommunityNotificationsMode, CommunityVisibility, ListingType},
+  enums::{
+    CommunityFollowerState,
+    CommunityNotificationsMode,
+    CommunityPostingMode,
+    CommunityVisibility,
+    ListingType,
+    ModeratorPermission,
+  },
   schema::{comment, community, community_actions, instance, local_user, post},
 };
 use lemmy_diesel_utils::{
@@ -109,10 +116,29 @@ impl CommunityActions {
     let conn = &mut get_conn(pool).await?;
     uplete(community_actions::table.find((form.person_id, form.community_id)))
       .set_null(community_actions::became_moderator_at)
+      .set_null(community_actions::moderator_permissions)
       .get_result(conn)
       .await
       .with_lemmy_type(LemmyErrorType::AlreadyExists)
   }
+
+  /// Restricts an existing moderator to the given permissions, or grants all permissions if
+  /// `None` is passed.
+  pub async fn update_moderator_permissions(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+    permissions: Option<Vec<ModeratorPermission>>,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(community_actions::table.find((person_id, community_id)))
+      .filter(community_actions::became_moderator_at.is_not_null())
+      .set(community_actions::moderator_permissions.eq(permissions))
+      .returning(Self::as_select())
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotAModerator)
+  }
 }
 
 #[derive(Debug)]
@@ -306,6 +332,7 @@ impl CommunityActions {
 
     uplete(community_actions::table.filter(community_actions::community_id.eq(for_community_id)))
       .set_null(community_actions::became_moderator_at)
+      .set_null(community_actions::moderator_permissions)
       .get_result(conn)
       .await
       .with_lemmy_type(LemmyErrorType::NotFound)
@@ -318,6 +345,7 @@ impl CommunityActions {
     let conn = &mut get_conn(pool).await?;
     uplete(community_actions::table.filter(community_actions::person_id.eq(for_person_id)))
       .set_null(community_actions::became_moderator_at)
+      .set_null(community_actions::moderator_permissions)
       .get_result(conn)
       .await
       .with_lemmy_type(LemmyErrorType::NotFound)
@@ -440,6 +468,43 @@ impl CommunityActions {
     Ok(())
   }
 
+  /// Sets the flair of a user in the community. Passing `None` removes the flair.
+  pub async fn update_flair(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+    flair: Option<(String, Option<String>)>,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    if let Some((flair, flair_emoji)) = flair {
+      let form = (
+        community_actions::person_id.eq(person_id),
+        community_actions::community_id.eq(community_id),
+        community_actions::flair.eq(flair),
+        community_actions::flair_emoji.eq(flair_emoji),
+      );
+      insert_into(community_actions::table)
+        .values(form.clone())
+        .on_conflict((
+          community_actions::person_id,
+          community_actions::community_id,
+        ))
+        .do_update()
+        .set(form)
+        .execute(conn)
+        .await
+        .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    } else {
+      uplete(community_actions::table.find((person_id, community_id)))
+        .set_null(community_actions::flair)
+        .set_null(community_actions::flair_emoji)
+        .get_result::<UpleteCount>(conn)
+        .await
+        .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    }
+    Ok(())
+  }
+
   pub async fn list_subscribers(
     community_id: CommunityId,
     is_post: bool,
@@ -750,7 +815,6 @@ mod tests {
       inbox_url: inserted_community.inbox_url.clone(),
       moderators_url: None,
       featured_url: None,
-      posting_restricted_to_mods: false,
       instance_id: inserted_instance.id,
       visibility: CommunityVisibility::Public,
       random_number: inserted_community.random_number,
@@ -767,6 +831,7 @@ mod tests {
       unresolved_report_count: 0,
       interactions_month: 0,
       local_removed: false,
+      posting_mode: CommunityPostingMode::Open,
     };
 
     let community_follower_form = CommunityFollowerForm::new(
@@ -794,6 +859,41 @@ mod tests {
 
     let _inserted_artemis_moderator = CommunityActions::join(pool, &artemis_moderator_form).await?;
 
+    // Restrict artemis to handling reports
+    let restricted_artemis = CommunityActions::update_moderator_permissions(
+      pool,
+      inserted_community.id,
+      inserted_artemis.id,
+      Some(vec![ModeratorPermission::HandleReports]),
+    )
+    .await?;
+    assert_eq!(
+      Some(vec![ModeratorPermission::HandleReports]),
+      restricted_artemis.moderator_permissions
+    );
+
+    // Give artemis a flair, then remove it again
+    CommunityActions::update_flair(
+      pool,
+      inserted_community.id,
+      inserted_artemis.id,
+      Some(("Helper".to_string(), Some("🛟".to_string()))),
+    )
+    .await?;
+    let artemis_actions =
+      CommunityActions::read(pool, inserted_community.id, inserted_artemis.id).await?;
+    assert_eq!(
+      (Some("Helper"), Some("🛟")),
+      (
+        artemis_actions.flair.as_deref(),
+        artemis_actions.flair_emoji.as_deref()
+      )
+    );
+    CommunityActions::update_flair(pool, inserted_community.id, inserted_artemis.id, None).await?;
+    let artemis_actions =
+      CommunityActions::read(pool, inserted_community.id, inserted_artemis.id).await?;
+    assert_eq!(None, artemis_actions.flair);
+
     let moderator_person_ids = vec![inserted_bobby.id, inserted_artemis.id];
 
     // Make sure bobby is marked as a higher mod than artemis, and vice versa
@@ -840,6 +940,15 @@ mod tests {
     )
     .await?;
     let left_community = CommunityActions::leave(pool, &bobby_moderator_form).await?;
+    // Permissions can only be changed for moderators
+    let not_a_mod = CommunityActions::update_moderator_permissions(
+      pool,
+      inserted_community.id,
+      inserted_bobby.id,
+      None,
+    )
+    .await;
+    assert!(not_a_mod.is_err());
     let unban = CommunityActions::unban(pool, &community_person_ban_form).await?;
     let num_deleted = Community::delete(pool, inserted_community.id).await?;
     Person::delete(pool, inserted_bobby.id).await?;
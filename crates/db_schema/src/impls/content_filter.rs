@@ -0,0 +1,303 @@
+use crate::{
+  newtypes::{ContentFilterId, LocalUserId},
+  source::content_filter::{ContentFilter, ContentFilterInsertForm, ContentFilterUpdateForm},
+};
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  QueryDsl,
+  delete,
+  insert_into,
+  sql_query,
+};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use lemmy_db_schema_file::{
+  enums::{ContentFilterAction, ContentFilterMatch},
+  schema::content_filter,
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+  utils::{functions::regex_match, now},
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for ContentFilter {
+  type InsertForm = ContentFilterInsertForm;
+  type UpdateForm = ContentFilterUpdateForm;
+  type IdType = ContentFilterId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(content_filter::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    content_filter_id: ContentFilterId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(content_filter::table.find(content_filter_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl ContentFilter {
+  /// Converts the phrase entered by the user into a case insensitive postgres regex.
+  pub fn build_pattern(match_type: ContentFilterMatch, phrase: &str) -> String {
+    // Postgres treats a backslash followed by a non-alphanumeric character as that literal
+    // character. Non-ascii characters are left alone, as they may count as alphanumeric.
+    let escape = |phrase: &str| {
+      phrase
+        .chars()
+        .flat_map(|c| {
+          if c.is_ascii_punctuation() {
+            vec!['\\', c]
+          } else {
+            vec![c]
+          }
+        })
+        .collect::<String>()
+    };
+    match match_type {
+      ContentFilterMatch::Keyword => escape(phrase),
+      ContentFilterMatch::WholeWord => format!("\\y{}\\y", escape(phrase)),
+      ContentFilterMatch::Regex => phrase.to_string(),
+    }
+  }
+
+  /// Lets postgres compile the pattern, so that invalid regexes are rejected when the filter is
+  /// saved instead of breaking post listings later.
+  pub async fn check_pattern(pool: &mut DbPool<'_>, pattern: &str) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::select(regex_match("", pattern))
+      .get_result::<bool>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::InvalidContentFilter)?;
+    Ok(())
+  }
+
+  /// Reads a filter, but only if it belongs to the given user.
+  pub async fn read_for_user(
+    pool: &mut DbPool<'_>,
+    id: ContentFilterId,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    content_filter::table
+      .find(id)
+      .filter(content_filter::local_user_id.eq(local_user_id))
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    content_filter::table
+      .filter(content_filter::local_user_id.eq(local_user_id))
+      .order_by(content_filter::published_at.desc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn delete_for_user(
+    pool: &mut DbPool<'_>,
+    id: ContentFilterId,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let deleted = delete(
+      content_filter::table
+        .find(id)
+        .filter(content_filter::local_user_id.eq(local_user_id)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)?;
+    if deleted == 0 {
+      Err(LemmyErrorType::NotFound)?
+    }
+    Ok(())
+  }
+
+  /// The actions of the user's filters which are not expired. Listings use this to skip the
+  /// regex matching for users without filters.
+  pub async fn list_active_actions(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<Vec<ContentFilterAction>> {
+    let conn = &mut get_conn(pool).await?;
+    content_filter::table
+      .filter(content_filter::local_user_id.eq(local_user_id))
+      .filter(
+        content_filter::expires_at
+          .is_null()
+          .or(content_filter::expires_at.gt(now().nullable())),
+      )
+      .select(content_filter::action)
+      .distinct()
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Limits the runtime of the queries which match user regexes against content. Must be called
+  /// inside a transaction, so that the setting is reset afterwards.
+  pub async fn set_statement_timeout(conn: &mut AsyncPgConnection) -> LemmyResult<()> {
+    sql_query("SET LOCAL statement_timeout = '5s'")
+      .execute(conn)
+      .await?;
+    Ok(())
+  }
+
+  /// Removes filters whose expiry time has passed.
+  pub async fn delete_expired(pool: &mut DbPool<'_>) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    delete(content_filter::table.filter(content_filter::expires_at.lt(now().nullable())))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::Deleted)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    content_filter::{ContentFilter, ContentFilterInsertForm, ContentFilterUpdateForm},
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+  };
+  use chrono::{Days, Utc};
+  use lemmy_db_schema_file::enums::{ContentFilterAction, ContentFilterMatch};
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[test]
+  fn test_build_pattern() {
+    assert_eq!(
+      "c\\+\\+ tips",
+      ContentFilter::build_pattern(ContentFilterMatch::Keyword, "c++ tips")
+    );
+    assert_eq!(
+      "\\yspoiler\\y",
+      ContentFilter::build_pattern(ContentFilterMatch::WholeWord, "spoiler")
+    );
+    assert_eq!(
+      "^rust.*",
+      ContentFilter::build_pattern(ContentFilterMatch::Regex, "^rust.*")
+    );
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_content_filter() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "filterer");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+    let other_person_form = PersonInsertForm::test_form(inserted_instance.id, "other_filterer");
+    let other_person = Person::create(pool, &other_person_form).await?;
+    let other_local_user_form = LocalUserInsertForm::test_form(other_person.id);
+    let other_local_user = LocalUser::create(pool, &other_local_user_form, vec![]).await?;
+
+    assert!(
+      ContentFilter::check_pattern(pool, "\\yrust\\y")
+        .await
+        .is_ok()
+    );
+    assert!(
+      ContentFilter::check_pattern(pool, "(unclosed")
+        .await
+        .is_err()
+    );
+
+    let pattern = ContentFilter::build_pattern(ContentFilterMatch::WholeWord, "rust");
+    let form = ContentFilterInsertForm::new(
+      inserted_local_user.id,
+      "rust".to_string(),
+      ContentFilterMatch::WholeWord,
+      pattern.clone(),
+    );
+    let filter = ContentFilter::create(pool, &form).await?;
+    assert_eq!(pattern, filter.pattern);
+    assert_eq!(ContentFilterAction::Hide, filter.action);
+    assert!(filter.filter_titles);
+    assert!(!filter.filter_usernames);
+
+    let update_form = ContentFilterUpdateForm {
+      action: Some(ContentFilterAction::Warn),
+      ..Default::default()
+    };
+    let updated = ContentFilter::update(pool, filter.id, &update_form).await?;
+    assert_eq!(ContentFilterAction::Warn, updated.action);
+
+    // Other users can't see or delete the filter
+    assert!(
+      ContentFilter::read_for_user(pool, filter.id, other_local_user.id)
+        .await
+        .is_err()
+    );
+    assert!(
+      ContentFilter::delete_for_user(pool, filter.id, other_local_user.id)
+        .await
+        .is_err()
+    );
+
+    let expired_form = ContentFilterInsertForm {
+      expires_at: Some(Utc::now() - Days::new(1)),
+      ..ContentFilterInsertForm::new(
+        inserted_local_user.id,
+        "old".to_string(),
+        ContentFilterMatch::Keyword,
+        "old".to_string(),
+      )
+    };
+    ContentFilter::create(pool, &expired_form).await?;
+    assert_eq!(
+      2,
+      ContentFilter::list(pool, inserted_local_user.id)
+        .await?
+        .len()
+    );
+    // The expired filter is ignored
+    assert_eq!(
+      vec![ContentFilterAction::Warn],
+      ContentFilter::list_active_actions(pool, inserted_local_user.id).await?
+    );
+    assert_eq!(1, ContentFilter::delete_expired(pool).await?);
+
+    ContentFilter::delete_for_user(pool, filter.id, inserted_local_user.id).await?;
+    assert!(
+      ContentFilter::list(pool, inserted_local_user.id)
+        .await?
+        .is_empty()
+    );
+    assert!(
+      ContentFilter::list_active_actions(pool, inserted_local_user.id)
+        .await?
+        .is_empty()
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
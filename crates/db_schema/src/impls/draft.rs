@@ -0,0 +1,195 @@
+use crate::{
+  newtypes::{DraftId, LocalUserId},
+  source::draft::{Draft, DraftInsertForm, DraftUpdateForm},
+};
+use diesel::{ExpressionMethods, QueryDsl, delete, dsl::IntervalDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::draft;
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+  utils::{functions::coalesce, now},
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for Draft {
+  type InsertForm = DraftInsertForm;
+  type UpdateForm = DraftUpdateForm;
+  type IdType = DraftId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(draft::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    draft_id: DraftId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(draft::table.find(draft_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl Draft {
+  /// Reads a draft, but only if it belongs to the given user.
+  pub async fn read_for_user(
+    pool: &mut DbPool<'_>,
+    id: DraftId,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    draft::table
+      .find(id)
+      .filter(draft::local_user_id.eq(local_user_id))
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Lists the drafts of a user, most recently edited first.
+  pub async fn list(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    draft::table
+      .filter(draft::local_user_id.eq(local_user_id))
+      .order_by(coalesce(draft::updated_at, draft::published_at).desc())
+      .then_order_by(draft::id.desc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn delete_for_user(
+    pool: &mut DbPool<'_>,
+    id: DraftId,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let deleted = delete(
+      draft::table
+        .find(id)
+        .filter(draft::local_user_id.eq(local_user_id)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)?;
+    if deleted == 0 {
+      Err(LemmyErrorType::NotFound)?
+    }
+    Ok(())
+  }
+
+  /// Removes drafts which weren't edited for 90 days.
+  pub async fn delete_old(pool: &mut DbPool<'_>) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      draft::table.filter(coalesce(draft::updated_at, draft::published_at).lt(now() - 90.days())),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    draft::{Draft, DraftInsertForm, DraftUpdateForm},
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+  };
+  use chrono::Utc;
+  use lemmy_db_schema_file::enums::DraftKind;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_draft() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "drafter");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+    let other_person_form = PersonInsertForm::test_form(inserted_instance.id, "other_drafter");
+    let other_person = Person::create(pool, &other_person_form).await?;
+    let other_local_user_form = LocalUserInsertForm::test_form(other_person.id);
+    let other_local_user = LocalUser::create(pool, &other_local_user_form, vec![]).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "drafts".to_string(),
+      "drafts".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+
+    let post_form = DraftInsertForm {
+      community_id: Some(inserted_community.id),
+      name: Some("A long post".to_string()),
+      ..DraftInsertForm::new(inserted_local_user.id, DraftKind::Post)
+    };
+    let post_draft = Draft::create(pool, &post_form).await?;
+    assert_eq!(Some(inserted_community.id), post_draft.community_id);
+
+    let message_form = DraftInsertForm {
+      recipient_id: Some(other_person.id),
+      ..DraftInsertForm::new(inserted_local_user.id, DraftKind::PrivateMessage)
+    };
+    let message_draft = Draft::create(pool, &message_form).await?;
+
+    // Editing a draft moves it to the top of the list
+    let update_form = DraftUpdateForm {
+      body: Some(Some("Half finished".to_string())),
+      updated_at: Some(Some(Utc::now())),
+      ..Default::default()
+    };
+    let updated = Draft::update(pool, post_draft.id, &update_form).await?;
+    assert_eq!(Some("Half finished".to_string()), updated.body);
+    assert_eq!(Some("A long post".to_string()), updated.name);
+    let ids = Draft::list(pool, inserted_local_user.id)
+      .await?
+      .into_iter()
+      .map(|d| d.id)
+      .collect::<Vec<_>>();
+    assert_eq!(vec![post_draft.id, message_draft.id], ids);
+
+    // Other users can't see or delete the draft
+    assert!(
+      Draft::read_for_user(pool, post_draft.id, other_local_user.id)
+        .await
+        .is_err()
+    );
+    assert!(
+      Draft::delete_for_user(pool, post_draft.id, other_local_user.id)
+        .await
+        .is_err()
+    );
+    assert!(Draft::list(pool, other_local_user.id).await?.is_empty());
+
+    // Recent drafts are kept
+    assert_eq!(0, Draft::delete_old(pool).await?);
+
+    Draft::delete_for_user(pool, post_draft.id, inserted_local_user.id).await?;
+    assert_eq!(1, Draft::list(pool, inserted_local_user.id).await?.len());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -7,6 +7,7 @@ use crate::{
     InstanceCommunitiesBlockForm,
     InstanceForm,
     InstancePersonsBlockForm,
+    InstanceQuarantineForm,
   },
   traits::Bannable,
 };
@@ -332,6 +333,52 @@ impl InstanceActions {
     }
     Ok(())
   }
+
+  pub async fn quarantine(
+    pool: &mut DbPool<'_>,
+    form: &InstanceQuarantineForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(instance_actions::table)
+      .values(form)
+      .on_conflict((instance_actions::person_id, instance_actions::instance_id))
+      .do_update()
+      .set(form)
+      .returning(Self::as_select())
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  pub async fn unquarantine(
+    pool: &mut DbPool<'_>,
+    form: &InstanceQuarantineForm,
+  ) -> LemmyResult<UpleteCount> {
+    let conn = &mut get_conn(pool).await?;
+    uplete(instance_actions::table.find((form.person_id, form.instance_id)))
+      .set_null(instance_actions::quarantined_at)
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  /// Returns true if the person is quarantined on the given instance. New content of quarantined
+  /// persons is held back for review.
+  pub async fn is_quarantined(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    instance_id: InstanceId,
+  ) -> LemmyResult<bool> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      instance_actions::table
+        .find((person_id, instance_id))
+        .filter(instance_actions::quarantined_at.is_not_null()),
+    ))
+    .get_result::<bool>(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::NotFound)
+  }
 }
 
 impl Bannable for InstanceActions {
@@ -0,0 +1,199 @@
+use crate::{
+  newtypes::{InviteCodeId, LocalUserId},
+  source::invite_code::{InviteCode, InviteCodeInsertForm},
+};
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  QueryDsl,
+  delete,
+  insert_into,
+  update,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::invite_code;
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  utils::now,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use uuid::Uuid;
+
+impl InviteCode {
+  /// Generates a new random code.
+  pub fn generate_code() -> String {
+    Uuid::new_v4().simple().to_string()
+  }
+
+  pub async fn create(pool: &mut DbPool<'_>, form: &InviteCodeInsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(invite_code::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, id: InviteCodeId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    invite_code::table
+      .find(id)
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Lists the codes created by the given user, or all codes if no user is given.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    creator_id: Option<LocalUserId>,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = invite_code::table.into_boxed();
+    if let Some(creator_id) = creator_id {
+      query = query.filter(invite_code::creator_id.eq(creator_id));
+    }
+    query
+      .order_by(invite_code::published_at.desc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Counts the codes of the user which are not expired and have uses left.
+  pub async fn count_active(pool: &mut DbPool<'_>, creator_id: LocalUserId) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    invite_code::table
+      .filter(invite_code::creator_id.eq(creator_id))
+      .filter(
+        invite_code::expires_at
+          .is_null()
+          .or(invite_code::expires_at.gt(now().nullable())),
+      )
+      .filter(
+        invite_code::max_uses
+          .is_null()
+          .or(invite_code::uses.nullable().lt(invite_code::max_uses)),
+      )
+      .count()
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn delete(pool: &mut DbPool<'_>, id: InviteCodeId) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    delete(invite_code::table.find(id))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::Deleted)?;
+    Ok(())
+  }
+
+  /// Counts a use of the code, as long as it is not expired and has uses left.
+  pub async fn use_code(pool: &mut DbPool<'_>, code: &str) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      invite_code::table
+        .filter(invite_code::code.eq(code.trim()))
+        .filter(
+          invite_code::expires_at
+            .is_null()
+            .or(invite_code::expires_at.gt(now().nullable())),
+        )
+        .filter(
+          invite_code::max_uses
+            .is_null()
+            .or(invite_code::uses.nullable().lt(invite_code::max_uses)),
+        ),
+    )
+    .set(invite_code::uses.eq(invite_code::uses + 1))
+    .get_result::<Self>(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::InvalidInviteCode)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    instance::Instance,
+    invite_code::{InviteCode, InviteCodeInsertForm},
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+  };
+  use chrono::{Days, Utc};
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_invite_code() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "inviter");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    // A code with a single use can't be used twice
+    let form = InviteCodeInsertForm {
+      max_uses: Some(1),
+      ..InviteCodeInsertForm::new(InviteCode::generate_code(), inserted_local_user.id)
+    };
+    let code = InviteCode::create(pool, &form).await?;
+    let used = InviteCode::use_code(pool, &code.code).await?;
+    assert_eq!(1, used.uses);
+    assert!(InviteCode::use_code(pool, &code.code).await.is_err());
+
+    // Expired codes can't be used
+    let expired_form = InviteCodeInsertForm {
+      expires_at: Some(Utc::now() - Days::new(1)),
+      ..InviteCodeInsertForm::new(InviteCode::generate_code(), inserted_local_user.id)
+    };
+    let expired = InviteCode::create(pool, &expired_form).await?;
+    assert!(InviteCode::use_code(pool, &expired.code).await.is_err());
+
+    // Codes without a limit can be used any number of times
+    let unlimited_form =
+      InviteCodeInsertForm::new(InviteCode::generate_code(), inserted_local_user.id);
+    let unlimited = InviteCode::create(pool, &unlimited_form).await?;
+    InviteCode::use_code(pool, &unlimited.code).await?;
+    let used = InviteCode::use_code(pool, &unlimited.code).await?;
+    assert_eq!(2, used.uses);
+    assert!(InviteCode::use_code(pool, "unknown").await.is_err());
+
+    let list = InviteCode::list(pool, Some(inserted_local_user.id)).await?;
+    assert_eq!(3, list.len());
+    // Only the unlimited code is still active
+    assert_eq!(
+      1,
+      InviteCode::count_active(pool, inserted_local_user.id).await?
+    );
+
+    InviteCode::delete(pool, unlimited.id).await?;
+    assert!(InviteCode::read(pool, unlimited.id).await.is_err());
+
+    // Who invited whom is tracked on the new user
+    let invited_person_form = PersonInsertForm::test_form(inserted_instance.id, "invitee");
+    let invited_person = Person::create(pool, &invited_person_form).await?;
+    let invited_form = LocalUserInsertForm {
+      invited_by_id: Some(inserted_local_user.id),
+      ..LocalUserInsertForm::test_form(invited_person.id)
+    };
+    let invited_local_user = LocalUser::create(pool, &invited_form, vec![]).await?;
+    assert_eq!(
+      Some(inserted_local_user.id),
+      invited_local_user.invited_by_id
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
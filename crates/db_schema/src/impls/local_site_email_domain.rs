@@ -0,0 +1,97 @@
+use crate::source::local_site_email_domain::{LocalSiteEmailDomain, LocalSiteEmailDomainForm};
+use diesel::{ExpressionMethods, QueryDsl, dsl::insert_into};
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
+use lemmy_db_schema_file::schema::local_site_email_domain;
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl LocalSiteEmailDomain {
+  /// Replaces either the allowed or the blocked domains.
+  pub async fn replace(
+    pool: &mut DbPool<'_>,
+    allowed: bool,
+    domains: Vec<String>,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+
+    conn
+      .run_transaction(|conn| {
+        async move {
+          diesel::delete(
+            local_site_email_domain::table.filter(local_site_email_domain::allowed.eq(allowed)),
+          )
+          .execute(conn)
+          .await
+          .with_lemmy_type(LemmyErrorType::Deleted)?;
+
+          let forms = domains
+            .into_iter()
+            .map(|domain| LocalSiteEmailDomainForm { domain, allowed })
+            .collect::<Vec<_>>();
+
+          insert_into(local_site_email_domain::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  pub async fn get_all(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    local_site_email_domain::table
+      .order_by(local_site_email_domain::domain)
+      .get_results::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::local_site_email_domain::LocalSiteEmailDomain;
+  use lemmy_diesel_utils::connection::build_db_pool_for_tests;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_replace_email_domains() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    LocalSiteEmailDomain::replace(pool, true, vec!["uni.edu".to_string()]).await?;
+    LocalSiteEmailDomain::replace(
+      pool,
+      false,
+      vec!["spam.com".to_string(), "spam.com".to_string()],
+    )
+    .await?;
+
+    // Replacing one list keeps the other
+    LocalSiteEmailDomain::replace(pool, false, vec!["junk.org".to_string()]).await?;
+    let domains = LocalSiteEmailDomain::get_all(pool)
+      .await?
+      .into_iter()
+      .map(|d| (d.domain, d.allowed))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      vec![
+        ("junk.org".to_string(), false),
+        ("uni.edu".to_string(), true)
+      ],
+      domains
+    );
+
+    LocalSiteEmailDomain::replace(pool, true, vec![]).await?;
+    LocalSiteEmailDomain::replace(pool, false, vec![]).await?;
+    assert!(LocalSiteEmailDomain::get_all(pool).await?.is_empty());
+
+    Ok(())
+  }
+}
@@ -18,7 +18,7 @@ use diesel::{
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema_file::{
   PersonId,
-  enums::CommunityVisibility,
+  enums::{AdminPermission, CommunityVisibility},
   schema::{community, community_actions, local_user, person, registration_application},
 };
 use lemmy_diesel_utils::{
@@ -144,6 +144,16 @@ impl LocalUser {
       .with_lemmy_type(LemmyErrorType::Deleted)
   }
 
+  /// Users whose scheduled account deletion is due.
+  pub async fn list_due_for_deletion(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    local_user::table
+      .filter(local_user::deletion_scheduled_at.le(now()))
+      .get_results::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   pub async fn check_is_email_taken(pool: &mut DbPool<'_>, email: &str) -> LemmyResult<()> {
     use diesel::dsl::{exists, select};
     let conn = &mut get_conn(pool).await?;
@@ -309,6 +319,19 @@ impl LocalUser {
       Err(LemmyErrorType::NotHigherMod)?
     }
   }
+
+  /// Checks if the user is an admin holding the given permission. Passing `None` requires a full
+  /// admin without any restrictions.
+  pub fn has_admin_permission(&self, permission: Option<AdminPermission>) -> bool {
+    if !self.admin {
+      return false;
+    }
+    match (&self.admin_permissions, permission) {
+      (None, _) => true,
+      (Some(_), None) => false,
+      (Some(permissions), Some(permission)) => permissions.contains(&permission),
+    }
+  }
 }
 
 /// Adds some helper functions for an optional LocalUser
@@ -320,6 +343,7 @@ pub trait LocalUserOptionHelper {
   fn is_admin(&self) -> bool;
   fn show_nsfw(&self, site: &Site) -> bool;
   fn hide_media(&self) -> bool;
+  fn collapse_crossposts(&self) -> bool;
   fn visible_communities_only<Q>(&self, query: Q) -> Q
   where
     Q: diesel::query_dsl::methods::FilterDsl<
@@ -359,6 +383,10 @@ impl LocalUserOptionHelper for Option<&LocalUser> {
     self.map(|l| l.hide_media).unwrap_or(false)
   }
 
+  fn collapse_crossposts(&self) -> bool {
+    self.map(|l| l.collapse_crossposts).unwrap_or(false)
+  }
+
   // TODO: use this function for private community checks, but the generics get extremely confusing
   fn visible_communities_only<Q>(&self, query: Q) -> Q
   where
@@ -401,10 +429,13 @@ pub struct UserBackupLists {
 #[cfg(test)]
 mod tests {
   use crate::source::{
+    admin_audit_log::{AdminAuditLog, AdminAuditLogInsertForm},
     instance::Instance,
-    local_user::{LocalUser, LocalUserInsertForm},
+    local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
     person::{Person, PersonInsertForm},
   };
+  use chrono::{Days, Utc};
+  use lemmy_db_schema_file::enums::AdminPermission;
   use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
   use lemmy_utils::error::LemmyResult;
   use serial_test::serial;
@@ -448,6 +479,45 @@ mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_admin_permissions() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+
+    let gertrude_person = PersonInsertForm::test_form(inserted_instance.id, "gertrude");
+    let inserted_gertrude_person = Person::create(pool, &gertrude_person).await?;
+    let gertrude_local_user_form =
+      LocalUserInsertForm::test_form_admin(inserted_gertrude_person.id);
+    let inserted_gertrude_local_user =
+      LocalUser::create(pool, &gertrude_local_user_form, vec![]).await?;
+
+    // Admins without explicit permissions hold all of them
+    assert!(inserted_gertrude_local_user.has_admin_permission(None));
+    assert!(inserted_gertrude_local_user.has_admin_permission(Some(AdminPermission::Purge)));
+
+    let scoped_gertrude = LocalUser {
+      admin_permissions: Some(vec![AdminPermission::HandleReports]),
+      ..inserted_gertrude_local_user
+    };
+    assert!(scoped_gertrude.has_admin_permission(Some(AdminPermission::HandleReports)));
+    assert!(!scoped_gertrude.has_admin_permission(Some(AdminPermission::Purge)));
+    assert!(!scoped_gertrude.has_admin_permission(None));
+
+    let audit_form =
+      AdminAuditLogInsertForm::new(inserted_gertrude_person.id, "ResolvePostReport".to_string());
+    let inserted_audit_log = AdminAuditLog::create(pool, &audit_form).await?;
+    let read_audit_log = AdminAuditLog::read(pool, inserted_audit_log.id).await?;
+    assert_eq!(inserted_audit_log, read_audit_log);
+    assert_eq!("ResolvePostReport", read_audit_log.action);
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_email_taken() -> LemmyResult<()> {
@@ -475,4 +545,47 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_list_due_for_deletion() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let erin_person = PersonInsertForm::test_form(inserted_instance.id, "erin");
+    let inserted_erin_person = Person::create(pool, &erin_person).await?;
+    let erin_local_user_form = LocalUserInsertForm::test_form(inserted_erin_person.id);
+    let inserted_erin_local_user = LocalUser::create(pool, &erin_local_user_form, vec![]).await?;
+
+    // A deletion in the future is not due yet
+    let form = LocalUserUpdateForm {
+      deletion_scheduled_at: Some(Some(Utc::now() + Days::new(1))),
+      deletion_delete_content: Some(true),
+      ..Default::default()
+    };
+    LocalUser::update(pool, inserted_erin_local_user.id, &form).await?;
+    assert!(LocalUser::list_due_for_deletion(pool).await?.is_empty());
+
+    let form = LocalUserUpdateForm {
+      deletion_scheduled_at: Some(Some(Utc::now() - Days::new(1))),
+      ..Default::default()
+    };
+    LocalUser::update(pool, inserted_erin_local_user.id, &form).await?;
+    let due = LocalUser::list_due_for_deletion(pool).await?;
+    assert_eq!(1, due.len());
+    assert!(due.first().is_some_and(|u| u.deletion_delete_content));
+
+    // Cancelling removes it from the list
+    let form = LocalUserUpdateForm {
+      deletion_scheduled_at: Some(None),
+      ..Default::default()
+    };
+    LocalUser::update(pool, inserted_erin_local_user.id, &form).await?;
+    assert!(LocalUser::list_due_for_deletion(pool).await?.is_empty());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
 }
@@ -1,15 +1,36 @@
 use crate::{
   diesel::{ExpressionMethods, QueryDsl},
-  newtypes::LocalUserId,
+  newtypes::{LocalUserId, LoginTokenId},
   source::login_token::{LoginToken, LoginTokenCreateForm},
+  utils::hash_token,
 };
-use diesel::{delete, dsl::exists, insert_into, select};
+use chrono::{DateTime, TimeDelta, Utc};
+use diesel::{OptionalExtension, delete, insert_into, update};
 use diesel_async::RunQueryDsl;
-use lemmy_db_schema_file::schema::login_token::{dsl::login_token, user_id};
-use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_db_schema_file::schema::login_token::{
+  dsl::login_token,
+  expires_at,
+  id,
+  last_used_at,
+  refresh_token_hash,
+  token,
+  user_id,
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  sensitive::SensitiveString,
+  utils::now,
+};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use uuid::Uuid;
+
+const LAST_USED_UPDATE_INTERVAL: TimeDelta = TimeDelta::minutes(5);
 
 impl LoginToken {
+  pub fn generate_refresh_token() -> SensitiveString {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple()).into()
+  }
+
   pub async fn create(pool: &mut DbPool<'_>, form: LoginTokenCreateForm) -> LemmyResult<Self> {
     let conn = &mut get_conn(pool).await?;
     insert_into(login_token)
@@ -19,20 +40,68 @@ impl LoginToken {
       .with_lemmy_type(LemmyErrorType::CouldntCreate)
   }
 
-  /// Check if the given token is valid for user.
+  /// Check if the given token is valid for user. Sessions which expired or were idle for too long
+  /// are deleted. Otherwise the session is marked as used, so that it doesn't run into the idle
+  /// timeout while the user is active. To avoid a write on every request, this is only done once
+  /// every few minutes.
   pub async fn validate(
     pool: &mut DbPool<'_>,
     user_id_: LocalUserId,
     token_: &str,
+    idle_timeout: Option<TimeDelta>,
   ) -> LemmyResult<()> {
     let conn = &mut get_conn(pool).await?;
-    select(exists(
-      login_token.find(token_).filter(user_id.eq(user_id_)),
-    ))
-    .get_result::<bool>(conn)
-    .await?
-    .then_some(())
-    .ok_or(LemmyErrorType::NotLoggedIn.into())
+    let (last_used, expires) = login_token
+      .find(token_)
+      .filter(user_id.eq(user_id_))
+      .select((last_used_at, expires_at))
+      .first::<(DateTime<Utc>, Option<DateTime<Utc>>)>(conn)
+      .await
+      .optional()?
+      .ok_or(LemmyErrorType::NotLoggedIn)?;
+    if session_ended(last_used, expires, idle_timeout) {
+      delete(login_token.find(token_)).execute(conn).await?;
+      Err(LemmyErrorType::NotLoggedIn)?
+    }
+    if last_used < Utc::now() - LAST_USED_UPDATE_INTERVAL {
+      update(login_token.find(token_))
+        .set(last_used_at.eq(now()))
+        .execute(conn)
+        .await?;
+    }
+    Ok(())
+  }
+
+  pub async fn read_from_refresh_token(
+    pool: &mut DbPool<'_>,
+    refresh_token_: &str,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    login_token
+      .filter(refresh_token_hash.eq(hash_token(refresh_token_)))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotLoggedIn)
+  }
+
+  /// Replaces the access and refresh token of a session. This only succeeds once for each refresh
+  /// token, so a leaked refresh token stops working as soon as the legitimate client uses it.
+  pub async fn rotate(
+    pool: &mut DbPool<'_>,
+    old_refresh_token: &str,
+    new_token: &str,
+    new_refresh_token: &str,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    update(login_token.filter(refresh_token_hash.eq(hash_token(old_refresh_token))))
+      .set((
+        token.eq(new_token),
+        refresh_token_hash.eq(hash_token(new_refresh_token)),
+        last_used_at.eq(now()),
+      ))
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotLoggedIn)
   }
 
   pub async fn list(pool: &mut DbPool<'_>, user_id_: LocalUserId) -> LemmyResult<Vec<LoginToken>> {
@@ -40,6 +109,7 @@ impl LoginToken {
 
     login_token
       .filter(user_id.eq(user_id_))
+      .order_by(last_used_at.desc())
       .get_results(conn)
       .await
       .with_lemmy_type(LemmyErrorType::NotFound)
@@ -54,6 +124,23 @@ impl LoginToken {
       .with_lemmy_type(LemmyErrorType::Deleted)
   }
 
+  /// Log out a single session of the user, eg on a lost device.
+  pub async fn revoke(
+    pool: &mut DbPool<'_>,
+    id_: LoginTokenId,
+    user_id_: LocalUserId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let deleted = delete(login_token.filter(id.eq(id_)).filter(user_id.eq(user_id_)))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::Deleted)?;
+    if deleted == 0 {
+      Err(LemmyErrorType::NotFound)?
+    }
+    Ok(())
+  }
+
   /// Invalidate all logins of given user on password reset/change, or account deletion.
   pub async fn invalidate_all(pool: &mut DbPool<'_>, user_id_: LocalUserId) -> LemmyResult<usize> {
     let conn = &mut get_conn(pool).await?;
@@ -62,4 +149,160 @@ impl LoginToken {
       .await
       .with_lemmy_type(LemmyErrorType::Deleted)
   }
+
+  /// Invalidate all logins of given user except the current one, on email change or when
+  /// requested by the user.
+  pub async fn invalidate_others(
+    pool: &mut DbPool<'_>,
+    user_id_: LocalUserId,
+    current_token: &str,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      login_token
+        .filter(user_id.eq(user_id_))
+        .filter(token.ne(current_token)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)
+  }
+
+  /// Sessions end at their expiry time, or when they weren't used for longer than the idle
+  /// timeout which is set by the admin.
+  pub fn has_ended(&self, idle_timeout: Option<TimeDelta>) -> bool {
+    session_ended(self.last_used_at, self.expires_at, idle_timeout)
+  }
+
+  /// A readable name for the device of this session, like "Firefox on Linux". Derived from the
+  /// user agent, so only a best guess.
+  pub fn device_label(&self) -> String {
+    let Some(user_agent) = &self.user_agent else {
+      return "Unknown device".to_string();
+    };
+    // Order matters, because many browsers also claim to be Chrome and Safari.
+    let browser = [
+      ("Edg/", "Edge"),
+      ("OPR/", "Opera"),
+      ("Firefox/", "Firefox"),
+      ("Chrome/", "Chrome"),
+      ("Safari/", "Safari"),
+    ]
+    .into_iter()
+    .find(|(pattern, _)| user_agent.contains(pattern))
+    .map(|(_, name)| name.to_string())
+    // Apps usually send their own name first, eg "Jerboa/0.0.60"
+    .or_else(|| {
+      user_agent
+        .split(['/', ' '])
+        .next()
+        .filter(|name| !name.is_empty())
+        .map(ToString::to_string)
+    })
+    .unwrap_or_else(|| "Unknown device".to_string());
+    let os = [
+      ("Android", "Android"),
+      ("iPhone", "iOS"),
+      ("iPad", "iPadOS"),
+      ("Windows", "Windows"),
+      ("Mac OS X", "macOS"),
+      ("CrOS", "ChromeOS"),
+      ("Linux", "Linux"),
+    ]
+    .into_iter()
+    .find(|(pattern, _)| user_agent.contains(pattern))
+    .map(|(_, name)| name);
+    match os {
+      Some(os) => format!("{browser} on {os}"),
+      None => browser,
+    }
+  }
+}
+
+fn session_ended(
+  last_used: DateTime<Utc>,
+  expires: Option<DateTime<Utc>>,
+  idle_timeout: Option<TimeDelta>,
+) -> bool {
+  let now = Utc::now();
+  expires.is_some_and(|e| e < now) || idle_timeout.is_some_and(|t| last_used + t < now)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    newtypes::{LocalUserId, LoginTokenId},
+    source::login_token::LoginToken,
+  };
+  use chrono::{TimeDelta, Utc};
+  use pretty_assertions::assert_eq;
+
+  fn login_token(user_agent: Option<&str>) -> LoginToken {
+    LoginToken {
+      token: String::new().into(),
+      user_id: LocalUserId(1),
+      published_at: Utc::now(),
+      ip: None,
+      user_agent: user_agent.map(ToString::to_string),
+      id: LoginTokenId(1),
+      refresh_token_hash: None,
+      last_used_at: Utc::now(),
+      expires_at: None,
+    }
+  }
+
+  fn label(user_agent: Option<&str>) -> String {
+    login_token(user_agent).device_label()
+  }
+
+  #[test]
+  fn test_has_ended() {
+    let active = login_token(None);
+    assert!(!active.has_ended(None));
+    assert!(!active.has_ended(Some(TimeDelta::hours(1))));
+    let idle = LoginToken {
+      last_used_at: Utc::now() - TimeDelta::hours(2),
+      ..active.clone()
+    };
+    assert!(!idle.has_ended(None));
+    assert!(idle.has_ended(Some(TimeDelta::hours(1))));
+    let expired = LoginToken {
+      expires_at: Some(Utc::now() - TimeDelta::minutes(1)),
+      ..active
+    };
+    assert!(expired.has_ended(None));
+  }
+
+  #[test]
+  fn test_device_label() {
+    assert_eq!(
+      "Firefox on Linux",
+      label(Some(
+        "Mozilla/5.0 (X11; Linux x86_64; rv:140.0) Gecko/20100101 Firefox/140.0"
+      ))
+    );
+    assert_eq!(
+      "Chrome on Windows",
+      label(Some(
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) \
+         Chrome/138.0.0.0 Safari/537.36"
+      ))
+    );
+    assert_eq!(
+      "Edge on Windows",
+      label(Some(
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) \
+         Chrome/138.0.0.0 Safari/537.36 Edg/138.0.0.0"
+      ))
+    );
+    assert_eq!(
+      "Safari on iOS",
+      label(Some(
+        "Mozilla/5.0 (iPhone; CPU iPhone OS 18_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like \
+         Gecko) Version/18.5 Mobile/15E148 Safari/604.1"
+      ))
+    );
+    assert_eq!("Jerboa", label(Some("Jerboa/0.0.60")));
+    assert_eq!("Unknown device", label(None));
+  }
 }
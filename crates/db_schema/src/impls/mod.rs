@@ -1,21 +1,27 @@
 pub mod activity;
 pub mod actor_language;
+pub mod admin_audit_log;
+pub mod ban_appeal;
 pub mod captcha_answer;
 pub mod comment;
 pub mod comment_report;
 pub mod community;
 pub mod community_community_follow;
 pub mod community_report;
+pub mod content_filter;
 pub mod custom_emoji;
+pub mod draft;
 pub mod email_verification;
 pub mod federation_allowlist;
 pub mod federation_blocklist;
 pub mod federation_queue_state;
 pub mod images;
 pub mod instance;
+pub mod invite_code;
 pub mod keyword_block;
 pub mod language;
 pub mod local_site;
+pub mod local_site_email_domain;
 pub mod local_site_rate_limit;
 pub mod local_site_url_blocklist;
 pub mod local_user;
@@ -24,15 +30,28 @@ pub mod modlog;
 pub mod multi_community;
 pub mod notification;
 pub mod oauth_account;
+pub mod oauth_application;
 pub mod oauth_provider;
 pub mod password_reset_request;
 pub mod person;
+pub mod personal_access_token;
 pub mod post;
+pub mod post_event;
+pub mod post_gallery;
 pub mod post_report;
 pub mod private_message;
 pub mod private_message_report;
+pub mod recovery_code;
+pub mod recurring_post;
 pub mod registration_application;
+pub mod registration_question;
+pub mod registration_screening_rule;
+pub mod revision;
 pub mod secret;
 pub mod site;
 pub mod tag;
 pub mod tagline;
+pub mod user_data_export;
+pub mod user_settings_import;
+pub mod webauthn_credential;
+pub mod wiki;
@@ -1,6 +1,7 @@
 use crate::{
   newtypes::CommunityId,
   source::{
+    ban_appeal::BanAppeal,
     comment::Comment,
     modlog::{Modlog, ModlogInsertForm},
     person::Person,
@@ -8,12 +9,26 @@ use crate::{
   },
 };
 use chrono::{DateTime, Utc};
-use diesel::dsl::insert_into;
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+  dsl::{exists, insert_into, select},
+};
 use diesel_async::RunQueryDsl;
 #[cfg(feature = "full")]
-use lemmy_db_schema_file::schema::modlog;
-use lemmy_db_schema_file::{InstanceId, PersonId, enums::ModlogKind};
-use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_db_schema_file::schema::{community_actions, instance_actions, modlog};
+use lemmy_db_schema_file::{
+  InstanceId,
+  PersonId,
+  enums::{BanAppealDecision, ModlogKind},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  utils::now,
+};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
 impl Modlog {
@@ -28,6 +43,68 @@ impl Modlog {
       .await
       .with_lemmy_type(LemmyErrorType::CouldntCreate)
   }
+
+  /// Returns the modlog entry of the ban which is currently in effect for the given person, either
+  /// in a community or (if `community_id` is none) on the given instance. Whether the person is
+  /// banned is read from the community or instance actions, the modlog only provides the entry.
+  pub async fn read_active_ban(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_id: Option<CommunityId>,
+    instance_id: InstanceId,
+  ) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let banned = if let Some(community_id) = community_id {
+      select(exists(
+        community_actions::table
+          .find((person_id, community_id))
+          .filter(community_actions::received_ban_at.is_not_null())
+          .filter(
+            community_actions::ban_expires_at
+              .is_null()
+              .or(community_actions::ban_expires_at.gt(now().nullable())),
+          ),
+      ))
+      .get_result::<bool>(conn)
+      .await?
+    } else {
+      select(exists(
+        instance_actions::table
+          .find((person_id, instance_id))
+          .filter(instance_actions::received_ban_at.is_not_null())
+          .filter(
+            instance_actions::ban_expires_at
+              .is_null()
+              .or(instance_actions::ban_expires_at.gt(now().nullable())),
+          ),
+      ))
+      .get_result::<bool>(conn)
+      .await?
+    };
+    if !banned {
+      return Ok(None);
+    }
+
+    let mut query = modlog::table
+      .filter(modlog::target_person_id.eq(person_id))
+      .filter(modlog::is_revert.eq(false))
+      .order_by(modlog::published_at.desc())
+      .into_boxed();
+    query = if let Some(community_id) = community_id {
+      query
+        .filter(modlog::kind.eq(ModlogKind::ModBanFromCommunity))
+        .filter(modlog::target_community_id.eq(community_id))
+    } else {
+      query
+        .filter(modlog::kind.eq(ModlogKind::AdminBan))
+        .filter(modlog::target_instance_id.eq(instance_id))
+    };
+    query
+      .first::<Self>(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
 }
 
 impl<'a> ModlogInsertForm<'a> {
@@ -46,6 +123,29 @@ impl<'a> ModlogInsertForm<'a> {
       ..ModlogInsertForm::new(ModlogKind::AdminBan, !banned, mod_person.id)
     }
   }
+  pub fn admin_quarantine_person(
+    mod_person: &Person,
+    target_person_id: PersonId,
+    quarantined: bool,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      target_person_id: Some(target_person_id),
+      target_instance_id: Some(mod_person.instance_id),
+      ..ModlogInsertForm::new(
+        ModlogKind::AdminQuarantinePerson,
+        !quarantined,
+        mod_person.id,
+      )
+    }
+  }
+  pub fn admin_moderator_override(admin_person_id: PersonId, community_id: CommunityId) -> Self {
+    Self {
+      target_community_id: Some(community_id),
+      ..ModlogInsertForm::new(ModlogKind::AdminModeratorOverride, false, admin_person_id)
+    }
+  }
   pub fn admin_add(mod_person: &Person, target_person_id: PersonId, added: bool) -> Self {
     Self {
       target_person_id: Some(target_person_id),
@@ -65,6 +165,41 @@ impl<'a> ModlogInsertForm<'a> {
       ..ModlogInsertForm::new(ModlogKind::ModRemovePost, !removed, mod_person_id)
     }
   }
+  pub fn mod_approve_post(
+    mod_person_id: PersonId,
+    post: &Post,
+    approved: bool,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      target_post_id: Some(post.id),
+      target_community_id: Some(post.community_id),
+      target_person_id: Some(post.creator_id),
+      ..ModlogInsertForm::new(ModlogKind::ModApprovePost, !approved, mod_person_id)
+    }
+  }
+  /// Records the decision on a ban appeal. A lifted ban is stored as revert, a shortened ban
+  /// carries the new expiry.
+  pub fn mod_resolve_ban_appeal(
+    mod_person_id: PersonId,
+    appeal: &BanAppeal,
+    decision: BanAppealDecision,
+    expires_at: Option<DateTime<Utc>>,
+    reason: Option<&'a str>,
+  ) -> Self {
+    Self {
+      reason,
+      expires_at,
+      target_person_id: Some(appeal.creator_id),
+      target_community_id: appeal.community_id,
+      ..ModlogInsertForm::new(
+        ModlogKind::ModResolveBanAppeal,
+        decision == BanAppealDecision::Lifted,
+        mod_person_id,
+      )
+    }
+  }
   pub fn mod_remove_comment(
     mod_person_id: PersonId,
     comment: &Comment,
@@ -162,6 +297,23 @@ impl<'a> ModlogInsertForm<'a> {
       ..ModlogInsertForm::new(ModlogKind::ModAddToCommunity, !added, mod_person_id)
     }
   }
+  pub fn mod_change_moderator_permissions(
+    mod_person_id: PersonId,
+    community_id: CommunityId,
+    target_person_id: PersonId,
+    reason: Option<&'a str>,
+  ) -> Self {
+    Self {
+      reason,
+      target_community_id: Some(community_id),
+      target_person_id: Some(target_person_id),
+      ..ModlogInsertForm::new(
+        ModlogKind::ModChangeModeratorPermissions,
+        false,
+        mod_person_id,
+      )
+    }
+  }
   pub fn mod_transfer_community(
     mod_person_id: PersonId,
     community_id: CommunityId,
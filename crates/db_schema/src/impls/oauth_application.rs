@@ -0,0 +1,300 @@
+use crate::{
+  newtypes::{LocalUserId, OAuthApplicationId},
+  source::oauth_application::{
+    OAuthApplication,
+    OAuthApplicationInsertForm,
+    OAuthAuthorizationCode,
+    OAuthAuthorizationCodeInsertForm,
+    OAuthRefreshToken,
+    OAuthRefreshTokenInsertForm,
+  },
+  utils::hash_token,
+};
+use diesel::{ExpressionMethods, QueryDsl, delete, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::{
+  oauth_application,
+  oauth_authorization_code,
+  oauth_refresh_token,
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  sensitive::SensitiveString,
+  utils::now,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use subtle::ConstantTimeEq;
+use uuid::Uuid;
+
+impl OAuthApplication {
+  pub fn generate_client_id() -> String {
+    Uuid::new_v4().simple().to_string()
+  }
+
+  pub fn generate_client_secret() -> SensitiveString {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple()).into()
+  }
+
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &OAuthApplicationInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(oauth_application::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn read_from_client_id(pool: &mut DbPool<'_>, client_id: &str) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    oauth_application::table
+      .filter(oauth_application::client_id.eq(client_id))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::InvalidClient)
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    oauth_application::table
+      .filter(oauth_application::local_user_id.eq(local_user_id))
+      .order_by(oauth_application::published_at.desc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Deletes an app of the given user, which also revokes all of its tokens.
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    id: OAuthApplicationId,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let deleted = delete(
+      oauth_application::table
+        .find(id)
+        .filter(oauth_application::local_user_id.eq(local_user_id)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)?;
+    if deleted == 0 {
+      Err(LemmyErrorType::NotFound)?
+    }
+    Ok(())
+  }
+
+  /// Confidential clients need to authenticate with their secret, public clients have none.
+  pub fn check_client_secret(&self, client_secret: Option<&str>) -> LemmyResult<()> {
+    match (&self.client_secret_hash, client_secret) {
+      (None, _) => Ok(()),
+      (Some(secret_hash), Some(given))
+        if bool::from(hash_token(given).as_bytes().ct_eq(secret_hash.as_bytes())) =>
+      {
+        Ok(())
+      }
+      _ => Err(LemmyErrorType::InvalidClient.into()),
+    }
+  }
+}
+
+impl OAuthAuthorizationCode {
+  pub fn generate_code() -> SensitiveString {
+    Uuid::new_v4().simple().to_string().into()
+  }
+
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &OAuthAuthorizationCodeInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(oauth_authorization_code::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// Codes can only be used once, so they are deleted when they are read.
+  pub async fn consume(
+    pool: &mut DbPool<'_>,
+    code: &str,
+    application_id: OAuthApplicationId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      oauth_authorization_code::table
+        .find(hash_token(code))
+        .filter(oauth_authorization_code::application_id.eq(application_id))
+        .filter(oauth_authorization_code::expires_at.gt(now())),
+    )
+    .get_result::<Self>(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::InvalidGrant)
+  }
+}
+
+impl OAuthRefreshToken {
+  pub fn generate_token() -> SensitiveString {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple()).into()
+  }
+
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &OAuthRefreshTokenInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(oauth_refresh_token::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// Refresh tokens are rotated, so the old one is deleted when it is used.
+  pub async fn consume(
+    pool: &mut DbPool<'_>,
+    token: &str,
+    application_id: OAuthApplicationId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      oauth_refresh_token::table
+        .find(hash_token(token))
+        .filter(oauth_refresh_token::application_id.eq(application_id))
+        .filter(oauth_refresh_token::expires_at.gt(now())),
+    )
+    .get_result::<Self>(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::InvalidGrant)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      oauth_application::{
+        OAuthApplication,
+        OAuthApplicationInsertForm,
+        OAuthAuthorizationCode,
+        OAuthAuthorizationCodeInsertForm,
+        OAuthRefreshToken,
+        OAuthRefreshTokenInsertForm,
+      },
+      person::{Person, PersonInsertForm},
+    },
+    utils::hash_token,
+  };
+  use chrono::{Days, Utc};
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_oauth_application() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "app_developer");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    let client_secret = OAuthApplication::generate_client_secret();
+    let form = OAuthApplicationInsertForm {
+      client_secret_hash: Some(hash_token(&client_secret)),
+      ..OAuthApplicationInsertForm::new(
+        inserted_local_user.id,
+        "Mobile app".to_string(),
+        OAuthApplication::generate_client_id(),
+        vec!["https://app.example.com/callback".to_string()],
+      )
+    };
+    let app = OAuthApplication::create(pool, &form).await?;
+    let read_app = OAuthApplication::read_from_client_id(pool, &app.client_id).await?;
+    assert_eq!(app, read_app);
+    assert!(app.check_client_secret(Some(&*client_secret)).is_ok());
+    assert!(app.check_client_secret(Some("wrong")).is_err());
+    assert!(app.check_client_secret(None).is_err());
+
+    // Codes can only be used once, and not after they expire
+    let raw_code = OAuthAuthorizationCode::generate_code();
+    let code_form = OAuthAuthorizationCodeInsertForm::new(
+      hash_token(&raw_code),
+      app.id,
+      inserted_local_user.id,
+      "https://app.example.com/callback".to_string(),
+      "read".to_string(),
+      None,
+      Utc::now() + Days::new(1),
+    );
+    let code = OAuthAuthorizationCode::create(pool, &code_form).await?;
+    let consumed = OAuthAuthorizationCode::consume(pool, &raw_code, app.id).await?;
+    assert_eq!(code, consumed);
+    assert!(
+      OAuthAuthorizationCode::consume(pool, &raw_code, app.id)
+        .await
+        .is_err()
+    );
+    let expired_code = OAuthAuthorizationCode::generate_code();
+    let expired_form = OAuthAuthorizationCodeInsertForm {
+      code_hash: hash_token(&expired_code),
+      expires_at: Utc::now() - Days::new(1),
+      ..code_form
+    };
+    OAuthAuthorizationCode::create(pool, &expired_form).await?;
+    assert!(
+      OAuthAuthorizationCode::consume(pool, &expired_code, app.id)
+        .await
+        .is_err()
+    );
+
+    let raw_refresh_token = OAuthRefreshToken::generate_token();
+    let refresh_form = OAuthRefreshTokenInsertForm::new(
+      hash_token(&raw_refresh_token),
+      app.id,
+      inserted_local_user.id,
+      "read".to_string(),
+      Utc::now() + Days::new(1),
+    );
+    OAuthRefreshToken::create(pool, &refresh_form).await?;
+    let expired_refresh_token = OAuthRefreshToken::generate_token();
+    let expired_refresh_form = OAuthRefreshTokenInsertForm {
+      token_hash: hash_token(&expired_refresh_token),
+      expires_at: Utc::now() - Days::new(1),
+      ..refresh_form
+    };
+    OAuthRefreshToken::create(pool, &expired_refresh_form).await?;
+    assert!(
+      OAuthRefreshToken::consume(pool, &expired_refresh_token, app.id)
+        .await
+        .is_err()
+    );
+
+    // Deleting the app also deletes its tokens
+    OAuthApplication::delete(pool, app.id, inserted_local_user.id).await?;
+    assert!(
+      OAuthApplication::read_from_client_id(pool, &app.client_id)
+        .await
+        .is_err()
+    );
+    assert!(
+      OAuthRefreshToken::consume(pool, &raw_refresh_token, app.id)
+        .await
+        .is_err()
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
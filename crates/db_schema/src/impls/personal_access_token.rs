@@ -0,0 +1,206 @@
+use crate::{
+  newtypes::{LocalUserId, OAuthApplicationId, PersonalAccessTokenId},
+  source::personal_access_token::{
+    PERSONAL_ACCESS_TOKEN_PREFIX,
+    PersonalAccessToken,
+    PersonalAccessTokenInsertForm,
+  },
+  utils::hash_token,
+};
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  QueryDsl,
+  delete,
+  insert_into,
+  update,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::personal_access_token;
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  sensitive::SensitiveString,
+  utils::now,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use uuid::Uuid;
+
+impl PersonalAccessToken {
+  /// Generates a new random token string.
+  pub fn generate_token() -> SensitiveString {
+    format!("{PERSONAL_ACCESS_TOKEN_PREFIX}{}", Uuid::new_v4().simple()).into()
+  }
+
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &PersonalAccessTokenInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(personal_access_token::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// Lists the tokens which the user created themselves, without those of OAuth apps.
+  pub async fn list(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    personal_access_token::table
+      .filter(personal_access_token::local_user_id.eq(local_user_id))
+      .filter(personal_access_token::oauth_application_id.is_null())
+      .order_by(personal_access_token::published_at.desc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Checks that the token exists and is not expired, and marks it as used.
+  pub async fn validate(pool: &mut DbPool<'_>, token: &str) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      personal_access_token::table
+        .filter(personal_access_token::token_hash.eq(hash_token(token)))
+        .filter(
+          personal_access_token::expires_at
+            .is_null()
+            .or(personal_access_token::expires_at.gt(now().nullable())),
+        ),
+    )
+    .set(personal_access_token::last_used_at.eq(now().nullable()))
+    .get_result::<Self>(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::NotLoggedIn)
+  }
+
+  /// Revokes a token of the given user.
+  pub async fn revoke(
+    pool: &mut DbPool<'_>,
+    id: PersonalAccessTokenId,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let deleted = delete(
+      personal_access_token::table
+        .find(id)
+        .filter(personal_access_token::local_user_id.eq(local_user_id)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)?;
+    if deleted == 0 {
+      Err(LemmyErrorType::NotFound)?
+    }
+    Ok(())
+  }
+
+  /// Revokes an access token which was issued to the given OAuth app. Unknown tokens are ignored.
+  pub async fn revoke_for_application(
+    pool: &mut DbPool<'_>,
+    token: &str,
+    application_id: OAuthApplicationId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      personal_access_token::table
+        .filter(personal_access_token::token_hash.eq(hash_token(token)))
+        .filter(personal_access_token::oauth_application_id.eq(application_id)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+      personal_access_token::{PersonalAccessToken, PersonalAccessTokenInsertForm},
+    },
+    utils::hash_token,
+  };
+  use chrono::{Days, Utc};
+  use lemmy_db_schema_file::enums::ApiTokenScope;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_personal_access_token() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "bot_owner");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    let raw_token = PersonalAccessToken::generate_token();
+    let form = PersonalAccessTokenInsertForm::new(
+      inserted_local_user.id,
+      "dashboard".to_string(),
+      hash_token(&raw_token),
+      vec![ApiTokenScope::Read],
+    );
+    let token = PersonalAccessToken::create(pool, &form).await?;
+    assert!(token.last_used_at.is_none());
+    // Only the hash is stored
+    assert_ne!(*raw_token, token.token_hash);
+
+    let validated = PersonalAccessToken::validate(pool, &raw_token).await?;
+    assert_eq!(token.id, validated.id);
+    assert!(validated.last_used_at.is_some());
+    // The hash itself can't be used as token
+    assert!(
+      PersonalAccessToken::validate(pool, &token.token_hash)
+        .await
+        .is_err()
+    );
+
+    // Expired tokens are rejected
+    let expired_token = PersonalAccessToken::generate_token();
+    let expired_form = PersonalAccessTokenInsertForm {
+      expires_at: Some(Utc::now() - Days::new(1)),
+      ..PersonalAccessTokenInsertForm::new(
+        inserted_local_user.id,
+        "expired".to_string(),
+        hash_token(&expired_token),
+        vec![ApiTokenScope::Read],
+      )
+    };
+    PersonalAccessToken::create(pool, &expired_form).await?;
+    assert!(
+      PersonalAccessToken::validate(pool, &expired_token)
+        .await
+        .is_err()
+    );
+
+    let list = PersonalAccessToken::list(pool, inserted_local_user.id).await?;
+    assert_eq!(2, list.len());
+
+    PersonalAccessToken::revoke(pool, token.id, inserted_local_user.id).await?;
+    assert!(
+      PersonalAccessToken::validate(pool, &raw_token)
+        .await
+        .is_err()
+    );
+    assert!(
+      PersonalAccessToken::revoke(pool, token.id, inserted_local_user.id)
+        .await
+        .is_err()
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
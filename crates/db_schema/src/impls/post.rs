@@ -3,6 +3,8 @@ use crate::{
   source::post::{
     Post,
     PostActions,
+    PostCrosspostsView,
+    PostEventRsvpForm,
     PostHideForm,
     PostInsertForm,
     PostLikeForm,
@@ -23,8 +25,12 @@ use diesel::{
   NullableExpressionMethods,
   OptionalExtension,
   QueryDsl,
+  deserialize::FromSql,
   dsl::{count, insert_into, not, update},
   expression::SelectableHelper,
+  pg::{Pg, PgValue},
+  serialize::ToSql,
+  sql_types::{Json, Nullable},
 };
 use diesel_async::RunQueryDsl;
 use diesel_uplete::{UpleteCount, uplete};
@@ -32,7 +38,7 @@ use lemmy_db_schema_file::{
   InstanceId,
   PersonId,
   enums::PostNotificationsMode,
-  schema::{community, local_user, person, post, post_actions},
+  schema::{community, local_user, person, post, post_actions, post_revision},
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -144,6 +150,19 @@ impl Post {
   ) -> LemmyResult<Vec<Self>> {
     let conn = &mut get_conn(pool).await?;
 
+    diesel::delete(
+      post_revision::table.filter(
+        post_revision::post_id.eq_any(
+          post::table
+            .filter(post::creator_id.eq(for_creator_id))
+            .select(post::id),
+        ),
+      ),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)?;
+
     diesel::update(post::table.filter(post::creator_id.eq(for_creator_id)))
       .set((
         post::name.eq(DELETED_REPLACEMENT_TEXT),
@@ -490,6 +509,39 @@ impl PostActions {
     .await
     .with_lemmy_type(LemmyErrorType::CouldntUpdate)
   }
+
+  /// Answers an event post. The counts of the event are updated by a trigger.
+  pub async fn rsvp(pool: &mut DbPool<'_>, form: &PostEventRsvpForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+
+    insert_into(post_actions::table)
+      .values(form)
+      .on_conflict((post_actions::person_id, post_actions::post_id))
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  pub async fn remove_rsvp(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    person_id: PersonId,
+  ) -> LemmyResult<UpleteCount> {
+    let conn = &mut get_conn(pool).await?;
+
+    uplete(
+      post_actions::table
+        .filter(post_actions::post_id.eq(post_id))
+        .filter(post_actions::person_id.eq(person_id)),
+    )
+    .set_null(post_actions::event_rsvp)
+    .set_null(post_actions::event_rsvp_at)
+    .get_result(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
 }
 
 impl PostActions {
@@ -565,6 +617,28 @@ impl PostActions {
   }
 }
 
+impl FromSql<Nullable<Json>, Pg> for PostCrosspostsView {
+  fn from_sql(bytes: PgValue) -> diesel::deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Json, Pg>>::from_sql(bytes)?;
+    Ok(serde_json::from_value::<PostCrosspostsView>(value)?)
+  }
+  fn from_nullable_sql(
+    bytes: Option<<Pg as diesel::backend::Backend>::RawValue<'_>>,
+  ) -> diesel::deserialize::Result<Self> {
+    match bytes {
+      Some(bytes) => Self::from_sql(bytes),
+      None => Ok(Self(vec![])),
+    }
+  }
+}
+
+impl ToSql<Nullable<Json>, Pg> for PostCrosspostsView {
+  fn to_sql(&self, out: &mut diesel::serialize::Output<Pg>) -> diesel::serialize::Result {
+    let value = serde_json::to_value(self)?;
+    <serde_json::Value as ToSql<Json, Pg>>::to_sql(&value, &mut out.reborrow())
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use crate::{
@@ -667,6 +741,10 @@ mod tests {
       scaled_rank: RANK_DEFAULT,
       unresolved_report_count: 0,
       federation_pending: false,
+      quarantined: false,
+      pending_approval: false,
+      original_post_id: None,
+      accepted_answer_id: None,
     };
 
     // Post Like
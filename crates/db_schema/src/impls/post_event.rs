@@ -0,0 +1,205 @@
+use crate::{
+  newtypes::{CommunityId, PostId},
+  source::{
+    post::Post,
+    post_event::{PostEvent, PostEventForm},
+  },
+};
+use chrono::{DateTime, Utc};
+use diesel::{
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+  SelectableHelper,
+  delete,
+  insert_into,
+};
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
+use diesel_uplete::{UpleteCount, uplete};
+use lemmy_db_schema_file::schema::{post, post_actions, post_event};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl PostEvent {
+  /// Creates the event of a post, or replaces the existing one. The RSVP counts are kept.
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &PostEventForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(post_event::table)
+      .values(form)
+      .on_conflict(post_event::post_id)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, post_id: PostId) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    post_event::table
+      .find(post_id)
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Turns an event post back into a regular post.
+  pub async fn delete(pool: &mut DbPool<'_>, post_id: PostId) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .run_transaction(|conn| {
+        async move {
+          let deleted = delete(post_event::table.find(post_id))
+            .execute(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::Deleted)?;
+
+          // The RSVPs would otherwise come back if the post becomes an event again
+          uplete(post_actions::table.filter(post_actions::post_id.eq(post_id)))
+            .set_null(post_actions::event_rsvp)
+            .set_null(post_actions::event_rsvp_at)
+            .get_result::<UpleteCount>(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+          Ok(deleted)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// The visible events of a community which haven't ended before `since`, sorted by start time.
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    since: DateTime<Utc>,
+  ) -> LemmyResult<Vec<(Post, Self)>> {
+    let conn = &mut get_conn(pool).await?;
+    post_event::table
+      .inner_join(post::table)
+      .filter(post::community_id.eq(community_id))
+      .filter(post::deleted.eq(false))
+      .filter(post::removed.eq(false))
+      .filter(post::scheduled_publish_time_at.is_null())
+      .filter(post::quarantined.eq(false))
+      .filter(post::pending_approval.eq(false))
+      .filter(post_event::end_at.ge(since))
+      .order_by(post_event::start_at.asc())
+      .then_order_by(post::id.asc())
+      .select((Post::as_select(), Self::as_select()))
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+    post::{Post, PostActions, PostEventRsvpForm, PostInsertForm},
+    post_event::{PostEvent, PostEventForm},
+  };
+  use chrono::{Days, Utc};
+  use lemmy_db_schema_file::enums::PostEventRsvp;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_post_event_rsvp() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let alice_form = PersonInsertForm::test_form(inserted_instance.id, "event_alice");
+    let alice = Person::create(pool, &alice_form).await?;
+    let bob_form = PersonInsertForm::test_form(inserted_instance.id, "event_bob");
+    let bob = Person::create(pool, &bob_form).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "event_community".to_string(),
+      "event community".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm::new("Meetup".into(), alice.id, inserted_community.id);
+    let inserted_post = Post::create(pool, &post_form).await?;
+
+    let start_at = Utc::now();
+    let end_at = start_at.checked_add_days(Days::new(1)).unwrap_or(start_at);
+    let event_form = PostEventForm::new(
+      inserted_post.id,
+      start_at,
+      end_at,
+      "Europe/Berlin".to_string(),
+      Some("Town hall".to_string()),
+      None,
+    );
+    PostEvent::upsert(pool, &event_form).await?;
+
+    let going = PostEventRsvpForm::new(inserted_post.id, alice.id, PostEventRsvp::Going);
+    PostActions::rsvp(pool, &going).await?;
+    let interested = PostEventRsvpForm::new(inserted_post.id, bob.id, PostEventRsvp::Interested);
+    PostActions::rsvp(pool, &interested).await?;
+    let event = PostEvent::read(pool, inserted_post.id).await?;
+    assert_eq!(
+      Some((1, 1)),
+      event.map(|e| (e.going_count, e.interested_count))
+    );
+
+    // Changing the answer moves the user to the other count
+    let going = PostEventRsvpForm::new(inserted_post.id, bob.id, PostEventRsvp::Going);
+    PostActions::rsvp(pool, &going).await?;
+    let event = PostEvent::read(pool, inserted_post.id).await?;
+    assert_eq!(
+      Some((2, 0)),
+      event.map(|e| (e.going_count, e.interested_count))
+    );
+
+    PostActions::remove_rsvp(pool, inserted_post.id, alice.id).await?;
+    let event = PostEvent::read(pool, inserted_post.id).await?;
+    assert_eq!(
+      Some((1, 0)),
+      event.map(|e| (e.going_count, e.interested_count))
+    );
+
+    // Editing the event keeps the counts
+    PostEvent::upsert(pool, &event_form).await?;
+    let listed = PostEvent::list_for_community(pool, inserted_community.id, start_at).await?;
+    assert_eq!(
+      vec![(inserted_post.id, 1)],
+      listed
+        .into_iter()
+        .map(|(p, e)| (p.id, e.going_count))
+        .collect::<Vec<_>>()
+    );
+    let later = end_at.checked_add_days(Days::new(1)).unwrap_or(end_at);
+    assert!(
+      PostEvent::list_for_community(pool, inserted_community.id, later)
+        .await?
+        .is_empty()
+    );
+
+    assert_eq!(1, PostEvent::delete(pool, inserted_post.id).await?);
+    assert_eq!(None, PostEvent::read(pool, inserted_post.id).await?);
+
+    // The RSVPs are deleted with the event, so they don't count for a new one
+    PostEvent::upsert(pool, &event_form).await?;
+    PostActions::remove_rsvp(pool, inserted_post.id, bob.id).await?;
+    let event = PostEvent::read(pool, inserted_post.id).await?;
+    assert_eq!(
+      Some((0, 0)),
+      event.map(|e| (e.going_count, e.interested_count))
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
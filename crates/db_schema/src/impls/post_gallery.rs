@@ -0,0 +1,156 @@
+use crate::{
+  newtypes::PostId,
+  source::post_gallery::{PostGalleryItem, PostGalleryItemInsertForm, PostGalleryView},
+};
+use diesel::{
+  ExpressionMethods,
+  QueryDsl,
+  deserialize::FromSql,
+  insert_into,
+  pg::{Pg, PgValue},
+  serialize::ToSql,
+  sql_types::{Json, Nullable},
+};
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
+use lemmy_db_schema_file::schema::post_gallery_item;
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl PostGalleryItem {
+  /// Replaces all images of a gallery post. Passing an empty list turns it into a regular post.
+  pub async fn replace(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    forms: &[PostGalleryItemInsertForm],
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .run_transaction(|conn| {
+        async move {
+          diesel::delete(post_gallery_item::table.filter(post_gallery_item::post_id.eq(post_id)))
+            .execute(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::Deleted)?;
+
+          insert_into(post_gallery_item::table)
+            .values(forms)
+            .get_results::<Self>(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntCreate)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Lists the images of a gallery post in the order in which they are shown.
+  pub async fn list_for_post(pool: &mut DbPool<'_>, post_id: PostId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    post_gallery_item::table
+      .filter(post_gallery_item::post_id.eq(post_id))
+      .order_by(post_gallery_item::position.asc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl FromSql<Nullable<Json>, Pg> for PostGalleryView {
+  fn from_sql(bytes: PgValue) -> diesel::deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Json, Pg>>::from_sql(bytes)?;
+    Ok(serde_json::from_value::<PostGalleryView>(value)?)
+  }
+  fn from_nullable_sql(
+    bytes: Option<<Pg as diesel::backend::Backend>::RawValue<'_>>,
+  ) -> diesel::deserialize::Result<Self> {
+    match bytes {
+      Some(bytes) => Self::from_sql(bytes),
+      None => Ok(Self(vec![])),
+    }
+  }
+}
+
+impl ToSql<Nullable<Json>, Pg> for PostGalleryView {
+  fn to_sql(&self, out: &mut diesel::serialize::Output<Pg>) -> diesel::serialize::Result {
+    let value = serde_json::to_value(self)?;
+    <serde_json::Value as ToSql<Json, Pg>>::to_sql(&value, &mut out.reborrow())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+    post::{Post, PostInsertForm},
+    post_gallery::{PostGalleryItem, PostGalleryItemInsertForm},
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, dburl::DbUrl, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_post_gallery() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "photographer");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "photos".to_string(),
+      "photos".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm::new(
+      "holiday".to_string(),
+      inserted_person.id,
+      inserted_community.id,
+    );
+    let post = Post::create(pool, &post_form).await?;
+
+    let url = |path: &str| -> LemmyResult<DbUrl> {
+      Ok(Url::parse(&format!("https://example.com/{path}"))?.into())
+    };
+    let forms = vec![
+      PostGalleryItemInsertForm::new(post.id, url("beach.jpg")?, None, 0),
+      PostGalleryItemInsertForm::new(post.id, url("hotel.jpg")?, Some("The hotel".to_string()), 1),
+    ];
+    PostGalleryItem::replace(pool, post.id, &forms).await?;
+    let urls = |items: Vec<PostGalleryItem>| items.into_iter().map(|i| i.url).collect::<Vec<_>>();
+    assert_eq!(
+      vec![url("beach.jpg")?, url("hotel.jpg")?],
+      urls(PostGalleryItem::list_for_post(pool, post.id).await?)
+    );
+
+    // Replacing the gallery removes the previous images
+    let forms = vec![PostGalleryItemInsertForm::new(
+      post.id,
+      url("hotel.jpg")?,
+      None,
+      0,
+    )];
+    PostGalleryItem::replace(pool, post.id, &forms).await?;
+    assert_eq!(
+      vec![url("hotel.jpg")?],
+      urls(PostGalleryItem::list_for_post(pool, post.id).await?)
+    );
+
+    PostGalleryItem::replace(pool, post.id, &[]).await?;
+    assert!(
+      PostGalleryItem::list_for_post(pool, post.id)
+        .await?
+        .is_empty()
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -79,6 +79,21 @@ impl PrivateMessage {
       .optional()
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
+
+  /// All private messages sent by the given person, oldest first.
+  pub async fn list_for_creator(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    private_message::table
+      .filter(private_message::creator_id.eq(creator_id))
+      .order_by(private_message::published_at.asc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   pub fn local_url(&self, settings: &Settings) -> LemmyResult<DbUrl> {
     let domain = settings.get_protocol_and_hostname();
     Ok(Url::parse(&format!("{domain}/private_message/{}", self.id))?.into())
@@ -178,6 +193,18 @@ mod tests {
       },
     )
     .await?;
+
+    let sent = PrivateMessage::list_for_creator(pool, inserted_creator.id).await?;
+    assert_eq!(
+      vec![inserted_private_message.id],
+      sent.iter().map(|pm| pm.id).collect::<Vec<_>>()
+    );
+    assert!(
+      PrivateMessage::list_for_creator(pool, inserted_recipient.id)
+        .await?
+        .is_empty()
+    );
+
     Person::delete(pool, inserted_creator.id).await?;
     Person::delete(pool, inserted_recipient.id).await?;
     Instance::delete(pool, inserted_instance.id).await?;
@@ -0,0 +1,53 @@
+use crate::{
+  newtypes::LocalUserId,
+  source::recovery_code::{RecoveryCode, RecoveryCodeInsertForm},
+};
+use diesel::{ExpressionMethods, QueryDsl, delete, insert_into};
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
+use lemmy_db_schema_file::schema::recovery_code;
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl RecoveryCode {
+  /// Replaces all recovery codes of the user.
+  pub async fn replace(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    forms: &[RecoveryCodeInsertForm],
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .run_transaction(|conn| {
+        async move {
+          delete(recovery_code::table.filter(recovery_code::local_user_id.eq(local_user_id)))
+            .execute(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+          insert_into(recovery_code::table)
+            .values(forms)
+            .execute(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntCreate)?;
+          Ok(())
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Recovery codes can only be used once, so they are deleted when they are checked.
+  pub async fn consume(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    code_hash: &str,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let deleted = delete(recovery_code::table.find((local_user_id, code_hash)))
+      .execute(conn)
+      .await?;
+    if deleted == 0 {
+      Err(LemmyErrorType::IncorrectRecoveryCode)?
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,231 @@
+use crate::{
+  newtypes::{CommunityId, RecurringPostId},
+  source::recurring_post::{RecurringPost, RecurringPostInsertForm, RecurringPostUpdateForm},
+};
+use chrono::{DateTime, Days, Months, Utc};
+use diesel::{ExpressionMethods, QueryDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{enums::RecurringPostFrequency, schema::recurring_post};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+  utils::now,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for RecurringPost {
+  type InsertForm = RecurringPostInsertForm;
+  type UpdateForm = RecurringPostUpdateForm;
+  type IdType = RecurringPostId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(recurring_post::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    recurring_post_id: RecurringPostId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(recurring_post::table.find(recurring_post_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl RecurringPost {
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    recurring_post::table
+      .filter(recurring_post::community_id.eq(community_id))
+      .order_by(recurring_post::next_publish_at.asc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Recurring posts whose next publish time has passed.
+  pub async fn list_due(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    recurring_post::table
+      .filter(recurring_post::next_publish_at.lt(now()))
+      .order_by(recurring_post::next_publish_at.asc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Fills in the date placeholders of the title template.
+  pub fn render_name(name_template: &str, date: DateTime<Utc>) -> String {
+    [
+      ("{date}", "%Y-%m-%d"),
+      ("{day}", "%-d"),
+      ("{week}", "%V"),
+      ("{month}", "%B"),
+      ("{year}", "%Y"),
+    ]
+    .into_iter()
+    .fold(name_template.to_string(), |name, (placeholder, format)| {
+      name.replace(placeholder, &date.format(format).to_string())
+    })
+  }
+
+  /// The first publish time after `after`, continuing the schedule which started at `start`.
+  ///
+  /// Occurrences which were missed, for example because the server was down, are skipped instead
+  /// of being published all at once.
+  pub fn next_publish_time(
+    frequency: RecurringPostFrequency,
+    start: DateTime<Utc>,
+    after: DateTime<Utc>,
+  ) -> Option<DateTime<Utc>> {
+    let mut next = start;
+    // Monthly steps are always counted from the start, so that a schedule on the 31st goes back
+    // to the 31st after shorter months.
+    let mut months = 0;
+    while next <= after {
+      next = match frequency {
+        RecurringPostFrequency::Daily => next.checked_add_days(Days::new(1))?,
+        RecurringPostFrequency::Weekly => next.checked_add_days(Days::new(7))?,
+        RecurringPostFrequency::Monthly => {
+          months += 1;
+          start.checked_add_months(Months::new(months))?
+        }
+      };
+    }
+    Some(next)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+    recurring_post::{RecurringPost, RecurringPostInsertForm, RecurringPostUpdateForm},
+  };
+  use chrono::{DateTime, Days, TimeZone, Utc};
+  use lemmy_db_schema_file::enums::RecurringPostFrequency;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  fn date(year: i32, month: u32, day: u32) -> Option<DateTime<Utc>> {
+    Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).single()
+  }
+
+  #[test]
+  fn test_render_name() {
+    let saturday = date(2026, 4, 18);
+    assert_eq!(
+      Some("Self-promo Saturday 2026-04-18".to_string()),
+      saturday.map(|d| RecurringPost::render_name("Self-promo Saturday {date}", d))
+    );
+    assert_eq!(
+      Some("Weekly discussion, week 16 of 2026 (April 18)".to_string()),
+      saturday.map(|d| RecurringPost::render_name(
+        "Weekly discussion, week {week} of {year} ({month} {day})",
+        d
+      ))
+    );
+  }
+
+  #[test]
+  fn test_next_publish_time() {
+    let next =
+      |frequency, start, after| RecurringPost::next_publish_time(frequency, start?, after?);
+    let start = date(2026, 1, 31);
+    assert_eq!(
+      date(2026, 2, 1),
+      next(RecurringPostFrequency::Daily, start, start)
+    );
+    // Missed weeks are skipped
+    assert_eq!(
+      date(2026, 2, 21),
+      next(RecurringPostFrequency::Weekly, start, date(2026, 2, 15))
+    );
+    assert_eq!(
+      date(2026, 2, 28),
+      next(RecurringPostFrequency::Monthly, start, start)
+    );
+    assert_eq!(
+      date(2026, 3, 31),
+      next(RecurringPostFrequency::Monthly, start, date(2026, 2, 28))
+    );
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_recurring_post() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "scheduler");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "weekly".to_string(),
+      "weekly".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+
+    let yesterday = Utc::now() - Days::new(1);
+    let due_form = RecurringPostInsertForm::new(
+      inserted_community.id,
+      inserted_person.id,
+      "Weekly discussion {date}".to_string(),
+      RecurringPostFrequency::Weekly,
+      yesterday,
+      yesterday,
+    );
+    let due = RecurringPost::create(pool, &due_form).await?;
+    let tomorrow = Utc::now() + Days::new(1);
+    let later_form = RecurringPostInsertForm {
+      feature_latest: Some(true),
+      ..RecurringPostInsertForm::new(
+        inserted_community.id,
+        inserted_person.id,
+        "Monthly meta thread".to_string(),
+        RecurringPostFrequency::Monthly,
+        tomorrow,
+        tomorrow,
+      )
+    };
+    let later = RecurringPost::create(pool, &later_form).await?;
+    assert!(later.feature_latest);
+    assert!(!later.lock_previous);
+
+    let ids = |posts: Vec<RecurringPost>| posts.into_iter().map(|p| p.id).collect::<Vec<_>>();
+    assert_eq!(vec![due.id], ids(RecurringPost::list_due(pool).await?));
+    assert_eq!(
+      vec![due.id, later.id],
+      ids(RecurringPost::list_for_community(pool, inserted_community.id).await?)
+    );
+
+    let update_form = RecurringPostUpdateForm {
+      next_publish_at: Some(Utc::now() + Days::new(7)),
+      ..Default::default()
+    };
+    RecurringPost::update(pool, due.id, &update_form).await?;
+    assert!(RecurringPost::list_due(pool).await?.is_empty());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
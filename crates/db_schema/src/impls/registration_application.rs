@@ -6,9 +6,9 @@ use crate::{
     RegistrationApplicationUpdateForm,
   },
 };
-use diesel::{ExpressionMethods, QueryDsl, insert_into};
+use diesel::{BoolExpressionMethods, ExpressionMethods, QueryDsl, insert_into};
 use diesel_async::RunQueryDsl;
-use lemmy_db_schema_file::schema::registration_application;
+use lemmy_db_schema_file::{enums::RegistrationScreeningAction, schema::registration_application};
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
   traits::Crud,
@@ -76,9 +76,14 @@ impl RegistrationApplication {
       .map(|updated| (updated - self.published_at).num_seconds())
   }
 
-  /// A missing admin id, means the application is unread
+  /// A missing admin id, means the application is unread. Applications which were approved or
+  /// denied by the screening rules don't need to be reviewed, unless they were flagged.
   #[diesel::dsl::auto_type(no_type_alias)]
   pub fn is_unread() -> _ {
-    registration_application::admin_id.is_null()
+    registration_application::admin_id.is_null().and(
+      registration_application::screening_action
+        .is_null()
+        .or(registration_application::screening_action.eq(RegistrationScreeningAction::Flag)),
+    )
   }
 }
@@ -0,0 +1,196 @@
+use crate::{
+  newtypes::RegistrationQuestionId,
+  source::registration_question::{
+    RegistrationAnswer,
+    RegistrationAnswerInsertForm,
+    RegistrationAnswersView,
+    RegistrationQuestion,
+    RegistrationQuestionInsertForm,
+    RegistrationQuestionUpdateForm,
+  },
+};
+use diesel::{
+  ExpressionMethods,
+  QueryDsl,
+  deserialize::FromSql,
+  insert_into,
+  pg::{Pg, PgValue},
+  serialize::ToSql,
+  sql_types::{Json, Nullable},
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::{registration_answer, registration_question};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for RegistrationQuestion {
+  type InsertForm = RegistrationQuestionInsertForm;
+  type UpdateForm = RegistrationQuestionUpdateForm;
+  type IdType = RegistrationQuestionId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(registration_question::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    id: Self::IdType,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(registration_question::table.find(id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl RegistrationQuestion {
+  /// Lists the questions in the order in which they are shown.
+  pub async fn list(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    registration_question::table
+      .order_by(registration_question::display_order.asc())
+      .then_order_by(registration_question::id.asc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl RegistrationAnswer {
+  pub async fn create_many(
+    pool: &mut DbPool<'_>,
+    forms: &[RegistrationAnswerInsertForm],
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(registration_answer::table)
+      .values(forms)
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+}
+
+impl FromSql<Nullable<Json>, Pg> for RegistrationAnswersView {
+  fn from_sql(bytes: PgValue) -> diesel::deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Json, Pg>>::from_sql(bytes)?;
+    Ok(serde_json::from_value::<RegistrationAnswersView>(value)?)
+  }
+  fn from_nullable_sql(
+    bytes: Option<<Pg as diesel::backend::Backend>::RawValue<'_>>,
+  ) -> diesel::deserialize::Result<Self> {
+    match bytes {
+      Some(bytes) => Self::from_sql(bytes),
+      None => Ok(Self(vec![])),
+    }
+  }
+}
+
+impl ToSql<Nullable<Json>, Pg> for RegistrationAnswersView {
+  fn to_sql(&self, out: &mut diesel::serialize::Output<Pg>) -> diesel::serialize::Result {
+    let value = serde_json::to_value(self)?;
+    <serde_json::Value as ToSql<Json, Pg>>::to_sql(&value, &mut out.reborrow())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+    registration_application::{RegistrationApplication, RegistrationApplicationInsertForm},
+    registration_question::{
+      RegistrationAnswer,
+      RegistrationAnswerInsertForm,
+      RegistrationQuestion,
+      RegistrationQuestionInsertForm,
+      RegistrationQuestionUpdateForm,
+    },
+  };
+  use lemmy_db_schema_file::enums::RegistrationQuestionKind;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_registration_question() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+
+    let first_form = RegistrationQuestionInsertForm {
+      display_order: Some(2),
+      ..RegistrationQuestionInsertForm::new("Why do you want to join?".to_string())
+    };
+    let first = RegistrationQuestion::create(pool, &first_form).await?;
+    assert_eq!(RegistrationQuestionKind::Text, first.kind);
+    assert!(first.required);
+
+    let second_form = RegistrationQuestionInsertForm {
+      kind: Some(RegistrationQuestionKind::Choice),
+      choices: Some(vec!["Yes".to_string(), "No".to_string()]),
+      display_order: Some(1),
+      ..RegistrationQuestionInsertForm::new("Did you read the rules?".to_string())
+    };
+    let second = RegistrationQuestion::create(pool, &second_form).await?;
+
+    // Questions are listed by their display order
+    let list = RegistrationQuestion::list(pool).await?;
+    assert_eq!(vec![second.clone(), first.clone()], list);
+
+    let update_form = RegistrationQuestionUpdateForm {
+      required: Some(false),
+      ..Default::default()
+    };
+    let updated = RegistrationQuestion::update(pool, first.id, &update_form).await?;
+    assert!(!updated.required);
+
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "applicant");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+    let application_form =
+      RegistrationApplicationInsertForm::new(inserted_local_user.id, String::new());
+    let application = RegistrationApplication::create(pool, &application_form).await?;
+
+    let answers = vec![
+      RegistrationAnswerInsertForm::new(
+        application.id,
+        Some(second.id),
+        second.question.clone(),
+        "Yes".to_string(),
+      ),
+      RegistrationAnswerInsertForm::new(
+        application.id,
+        Some(first.id),
+        first.question.clone(),
+        "To talk about gardening".to_string(),
+      ),
+    ];
+    assert_eq!(2, RegistrationAnswer::create_many(pool, &answers).await?);
+
+    // Deleting a question keeps the answers given to it
+    RegistrationQuestion::delete(pool, first.id).await?;
+    assert_eq!(1, RegistrationQuestion::list(pool).await?.len());
+
+    RegistrationQuestion::delete(pool, second.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,257 @@
+use crate::{
+  newtypes::RegistrationScreeningRuleId,
+  source::registration_screening_rule::{
+    RegistrationScreeningRule,
+    RegistrationScreeningRuleInsertForm,
+  },
+};
+use diesel::{ExpressionMethods, QueryDsl, delete, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  enums::{RegistrationScreeningAction, RegistrationScreeningRuleKind},
+  schema::registration_screening_rule,
+};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use std::net::IpAddr;
+
+impl RegistrationScreeningRule {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &RegistrationScreeningRuleInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(registration_screening_rule::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    registration_screening_rule::table
+      .order_by(registration_screening_rule::id.asc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn delete(pool: &mut DbPool<'_>, id: RegistrationScreeningRuleId) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let deleted = delete(registration_screening_rule::table.find(id))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::Deleted)?;
+    if deleted == 0 {
+      Err(LemmyErrorType::NotFound)?
+    }
+    Ok(())
+  }
+
+  /// Checks if the rule has a valid value for its kind.
+  pub fn is_valid(kind: RegistrationScreeningRuleKind, value: &str) -> bool {
+    let value = value.trim();
+    match kind {
+      RegistrationScreeningRuleKind::EmailDomain => !value.is_empty() && !value.contains('@'),
+      RegistrationScreeningRuleKind::AnswerContains => !value.is_empty(),
+      RegistrationScreeningRuleKind::IpRange => parse_ip_range(value).is_some(),
+    }
+  }
+
+  /// Checks if the rule matches the given registration.
+  pub fn matches(&self, email: Option<&str>, ip: Option<IpAddr>, answers: &[&str]) -> bool {
+    let value = self.value.trim().to_lowercase();
+    match self.kind {
+      RegistrationScreeningRuleKind::EmailDomain => email
+        .and_then(|e| e.rsplit_once('@'))
+        .map(|(_, domain)| domain.to_lowercase())
+        .is_some_and(|domain| domain == value || domain.ends_with(&format!(".{value}"))),
+      RegistrationScreeningRuleKind::AnswerContains => answers
+        .iter()
+        .any(|answer| answer.to_lowercase().contains(&value)),
+      RegistrationScreeningRuleKind::IpRange => ip.is_some_and(|ip| ip_in_range(ip, &value)),
+    }
+  }
+
+  /// The reason shown to admins, with a default if the rule has none.
+  pub fn reason_text(&self) -> String {
+    match &self.reason {
+      Some(reason) if !reason.trim().is_empty() => reason.clone(),
+      _ => match self.kind {
+        RegistrationScreeningRuleKind::EmailDomain => format!("Email domain {}", self.value),
+        RegistrationScreeningRuleKind::AnswerContains => format!("Answer contains {}", self.value),
+        RegistrationScreeningRuleKind::IpRange => format!("IP address in {}", self.value),
+      },
+    }
+  }
+
+  /// Checks all rules against a registration, and returns the resulting action together with the
+  /// reasons of all matching rules.
+  pub fn screen(
+    rules: &[Self],
+    email: Option<&str>,
+    ip: Option<IpAddr>,
+    answers: &[&str],
+  ) -> (Option<RegistrationScreeningAction>, Vec<String>) {
+    let matching: Vec<&Self> = rules
+      .iter()
+      .filter(|rule| rule.matches(email, ip, answers))
+      .collect();
+    let has_action = |action| matching.iter().any(|rule| rule.action == action);
+    let action = if has_action(RegistrationScreeningAction::Deny) {
+      Some(RegistrationScreeningAction::Deny)
+    } else if has_action(RegistrationScreeningAction::Flag) {
+      // A passphrase in the answer shouldn't be enough to skip review of a suspicious signup
+      Some(RegistrationScreeningAction::Flag)
+    } else if has_action(RegistrationScreeningAction::Approve) {
+      Some(RegistrationScreeningAction::Approve)
+    } else {
+      None
+    };
+    let reasons = matching.iter().map(|rule| rule.reason_text()).collect();
+    (action, reasons)
+  }
+}
+
+/// Parses a range like `10.0.0.0/8` or a single address into the network address and the prefix
+/// length.
+fn parse_ip_range(range: &str) -> Option<(IpAddr, u32)> {
+  let (addr, prefix) = match range.split_once('/') {
+    Some((addr, prefix)) => (
+      addr.parse::<IpAddr>().ok()?,
+      Some(prefix.parse::<u32>().ok()?),
+    ),
+    None => (range.parse::<IpAddr>().ok()?, None),
+  };
+  let max_prefix = match addr {
+    IpAddr::V4(_) => 32,
+    IpAddr::V6(_) => 128,
+  };
+  let prefix = prefix.unwrap_or(max_prefix);
+  (prefix <= max_prefix).then_some((addr, prefix))
+}
+
+fn ip_in_range(ip: IpAddr, range: &str) -> bool {
+  let Some((network, prefix)) = parse_ip_range(range) else {
+    return false;
+  };
+  match (ip.to_canonical(), network) {
+    (IpAddr::V4(ip), IpAddr::V4(network)) => {
+      let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+      u32::from(ip) & mask == u32::from(network) & mask
+    }
+    (IpAddr::V6(ip), IpAddr::V6(network)) => {
+      let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+      u128::from(ip) & mask == u128::from(network) & mask
+    }
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    newtypes::RegistrationScreeningRuleId,
+    source::registration_screening_rule::RegistrationScreeningRule,
+  };
+  use chrono::Utc;
+  use lemmy_db_schema_file::enums::{RegistrationScreeningAction, RegistrationScreeningRuleKind};
+  use pretty_assertions::assert_eq;
+  use std::net::IpAddr;
+
+  fn rule(
+    kind: RegistrationScreeningRuleKind,
+    value: &str,
+    action: RegistrationScreeningAction,
+  ) -> RegistrationScreeningRule {
+    RegistrationScreeningRule {
+      id: RegistrationScreeningRuleId(0),
+      kind,
+      value: value.to_string(),
+      action,
+      reason: None,
+      published_at: Utc::now(),
+    }
+  }
+
+  #[test]
+  fn test_screening_rules() {
+    let disposable = rule(
+      RegistrationScreeningRuleKind::EmailDomain,
+      "mailinator.com",
+      RegistrationScreeningAction::Deny,
+    );
+    assert!(disposable.matches(Some("spam@Mailinator.com"), None, &[]));
+    assert!(disposable.matches(Some("spam@eu.mailinator.com"), None, &[]));
+    assert!(!disposable.matches(Some("user@notmailinator.com"), None, &[]));
+    assert!(!disposable.matches(None, None, &[]));
+
+    let passphrase = rule(
+      RegistrationScreeningRuleKind::AnswerContains,
+      "purple penguin",
+      RegistrationScreeningAction::Approve,
+    );
+    assert!(passphrase.matches(None, None, &["I read the rules, Purple Penguin!"]));
+    assert!(!passphrase.matches(None, None, &["I read the rules"]));
+
+    let vpn = rule(
+      RegistrationScreeningRuleKind::IpRange,
+      "10.0.0.0/8",
+      RegistrationScreeningAction::Flag,
+    );
+    let ip = |ip: &str| ip.parse::<IpAddr>().ok();
+    assert!(vpn.matches(None, ip("10.1.2.3"), &[]));
+    assert!(vpn.matches(None, ip("::ffff:10.1.2.3"), &[]));
+    assert!(!vpn.matches(None, ip("11.1.2.3"), &[]));
+    assert!(!vpn.matches(None, ip("2001:db8::1"), &[]));
+    let vpn6 = rule(
+      RegistrationScreeningRuleKind::IpRange,
+      "2001:db8::/32",
+      RegistrationScreeningAction::Flag,
+    );
+    assert!(vpn6.matches(None, ip("2001:db8::1"), &[]));
+    assert!(RegistrationScreeningRule::is_valid(
+      RegistrationScreeningRuleKind::IpRange,
+      "0.0.0.0/0"
+    ));
+    assert!(!RegistrationScreeningRule::is_valid(
+      RegistrationScreeningRuleKind::IpRange,
+      "10.0.0.0/33"
+    ));
+
+    // Deny wins over flag, and flag over approve
+    let rules = vec![vpn.clone(), passphrase.clone(), disposable.clone()];
+    let (action, reasons) = RegistrationScreeningRule::screen(
+      &rules,
+      Some("spam@mailinator.com"),
+      ip("10.0.0.1"),
+      &["purple penguin"],
+    );
+    assert_eq!(Some(RegistrationScreeningAction::Deny), action);
+    assert_eq!(3, reasons.len());
+    let (action, reasons) = RegistrationScreeningRule::screen(
+      &rules,
+      Some("user@example.com"),
+      ip("10.0.0.1"),
+      &["purple penguin"],
+    );
+    assert_eq!(Some(RegistrationScreeningAction::Flag), action);
+    assert_eq!(
+      vec!["IP address in 10.0.0.0/8", "Answer contains purple penguin"],
+      reasons
+    );
+    let (action, reasons) = RegistrationScreeningRule::screen(
+      &rules,
+      Some("user@example.com"),
+      ip("11.0.0.1"),
+      &["purple penguin"],
+    );
+    assert_eq!(Some(RegistrationScreeningAction::Approve), action);
+    assert_eq!(vec!["Answer contains purple penguin"], reasons);
+    let (action, reasons) =
+      RegistrationScreeningRule::screen(&rules, Some("user@example.com"), None, &["hello"]);
+    assert_eq!(None, action);
+    assert!(reasons.is_empty());
+  }
+}
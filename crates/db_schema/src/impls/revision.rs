@@ -0,0 +1,235 @@
+use crate::{
+  newtypes::{CommentId, PostId, PrivateMessageId},
+  source::{
+    comment::Comment,
+    post::Post,
+    private_message::PrivateMessage,
+    revision::{CommentRevision, PostRevision, PrivateMessageRevision},
+  },
+};
+use diesel::{ExpressionMethods, QueryDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::{comment_revision, post_revision, private_message_revision};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl PostRevision {
+  /// Saves the previous version of an edited post, if the title, body or url changed.
+  pub async fn create_if_changed(
+    pool: &mut DbPool<'_>,
+    orig: &Post,
+    updated: &Post,
+  ) -> LemmyResult<Option<Self>> {
+    if orig.name == updated.name && orig.body == updated.body && orig.url == updated.url {
+      return Ok(None);
+    }
+    let conn = &mut get_conn(pool).await?;
+    insert_into(post_revision::table)
+      .values((
+        post_revision::post_id.eq(orig.id),
+        post_revision::name.eq(&orig.name),
+        post_revision::body.eq(&orig.body),
+        post_revision::url.eq(&orig.url),
+      ))
+      .get_result::<Self>(conn)
+      .await
+      .map(Some)
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// Lists the previous versions of a post, oldest first.
+  pub async fn list(pool: &mut DbPool<'_>, post_id: PostId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    post_revision::table
+      .filter(post_revision::post_id.eq(post_id))
+      .order_by((post_revision::published_at.asc(), post_revision::id.asc()))
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl CommentRevision {
+  /// Saves the previous version of an edited comment, if the content changed.
+  pub async fn create_if_changed(
+    pool: &mut DbPool<'_>,
+    orig: &Comment,
+    updated: &Comment,
+  ) -> LemmyResult<Option<Self>> {
+    if orig.content == updated.content {
+      return Ok(None);
+    }
+    let conn = &mut get_conn(pool).await?;
+    insert_into(comment_revision::table)
+      .values((
+        comment_revision::comment_id.eq(orig.id),
+        comment_revision::content.eq(&orig.content),
+      ))
+      .get_result::<Self>(conn)
+      .await
+      .map(Some)
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// Lists the previous versions of a comment, oldest first.
+  pub async fn list(pool: &mut DbPool<'_>, comment_id: CommentId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    comment_revision::table
+      .filter(comment_revision::comment_id.eq(comment_id))
+      .order_by((
+        comment_revision::published_at.asc(),
+        comment_revision::id.asc(),
+      ))
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl PrivateMessageRevision {
+  /// Saves the previous version of an edited private message, if the content changed.
+  pub async fn create_if_changed(
+    pool: &mut DbPool<'_>,
+    orig: &PrivateMessage,
+    updated: &PrivateMessage,
+  ) -> LemmyResult<Option<Self>> {
+    if orig.content == updated.content {
+      return Ok(None);
+    }
+    let conn = &mut get_conn(pool).await?;
+    insert_into(private_message_revision::table)
+      .values((
+        private_message_revision::private_message_id.eq(orig.id),
+        private_message_revision::content.eq(&orig.content),
+      ))
+      .get_result::<Self>(conn)
+      .await
+      .map(Some)
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// Lists the previous versions of a private message, oldest first.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    private_message_id: PrivateMessageId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    private_message_revision::table
+      .filter(private_message_revision::private_message_id.eq(private_message_id))
+      .order_by((
+        private_message_revision::published_at.asc(),
+        private_message_revision::id.asc(),
+      ))
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    comment::{Comment, CommentInsertForm, CommentUpdateForm},
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+    post::{Post, PostInsertForm, PostUpdateForm},
+    revision::{CommentRevision, PostRevision},
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_revisions() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "editor");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "edits".to_string(),
+      "edits".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm {
+      body: Some("first body".to_string()),
+      ..PostInsertForm::new(
+        "first title".to_string(),
+        inserted_person.id,
+        inserted_community.id,
+      )
+    };
+    let post = Post::create(pool, &post_form).await?;
+
+    // Edits which don't change the content aren't saved
+    let unchanged = Post::update(
+      pool,
+      post.id,
+      &PostUpdateForm {
+        nsfw: Some(true),
+        ..Default::default()
+      },
+    )
+    .await?;
+    assert!(
+      PostRevision::create_if_changed(pool, &post, &unchanged)
+        .await?
+        .is_none()
+    );
+
+    let edited = Post::update(
+      pool,
+      post.id,
+      &PostUpdateForm {
+        body: Some(Some("second body".to_string())),
+        ..Default::default()
+      },
+    )
+    .await?;
+    PostRevision::create_if_changed(pool, &unchanged, &edited).await?;
+    let revisions = PostRevision::list(pool, post.id).await?;
+    assert_eq!(1, revisions.len());
+    let revision = revisions.first();
+    assert_eq!(
+      Some(Some("first body".to_string())),
+      revision.map(|r| r.body.clone())
+    );
+    assert_eq!(Some("first title"), revision.map(|r| r.name.as_str()));
+
+    let comment_form =
+      CommentInsertForm::new(inserted_person.id, post.id, "first comment".to_string());
+    let comment = Comment::create(pool, &comment_form, None).await?;
+    let edited_comment = Comment::update(
+      pool,
+      comment.id,
+      &CommentUpdateForm {
+        content: Some("second comment".to_string()),
+        ..Default::default()
+      },
+    )
+    .await?;
+    CommentRevision::create_if_changed(pool, &comment, &edited_comment).await?;
+    let comment_revisions = CommentRevision::list(pool, comment.id).await?;
+    assert_eq!(
+      vec!["first comment"],
+      comment_revisions
+        .iter()
+        .map(|r| r.content.as_str())
+        .collect::<Vec<_>>()
+    );
+
+    // Revisions are removed together with the post
+    Post::delete(pool, post.id).await?;
+    assert!(PostRevision::list(pool, post.id).await?.is_empty());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
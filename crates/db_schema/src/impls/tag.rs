@@ -85,6 +85,10 @@ impl Tag {
         community_id: t.community_id,
         deleted: Some(true),
         description: None,
+        color: None,
+        emoji: None,
+        mod_only: None,
+        user_flair: None,
       });
     forms.extend(delete_forms);
 
@@ -99,6 +103,10 @@ impl Tag {
               tag::display_name.eq(excluded(tag::display_name)),
               tag::description.eq(excluded(tag::description)),
               tag::deleted.eq(excluded(tag::deleted)),
+              tag::color.eq(excluded(tag::color)),
+              tag::emoji.eq(excluded(tag::emoji)),
+              tag::mod_only.eq(excluded(tag::mod_only)),
+              tag::user_flair.eq(excluded(tag::user_flair)),
             ))
             .execute(conn)
             .await?;
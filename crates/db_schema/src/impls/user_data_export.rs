@@ -0,0 +1,161 @@
+use crate::{
+  newtypes::{LocalUserId, UserDataExportId},
+  source::user_data_export::UserDataExport,
+};
+use chrono::Utc;
+use diesel::{
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+  SelectableHelper,
+  insert_into,
+  update,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{enums::DataExportStatus, schema::user_data_export};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl UserDataExport {
+  /// Creates a new pending export. Fails if the user already has an export which is pending.
+  pub async fn create(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    let pending = user_data_export::table
+      .filter(user_data_export::local_user_id.eq(local_user_id))
+      .filter(user_data_export::status.eq(DataExportStatus::Pending))
+      .select(user_data_export::id)
+      .first::<UserDataExportId>(conn)
+      .await
+      .optional()?;
+    if pending.is_some() {
+      Err(LemmyErrorType::DataExportAlreadyPending)?
+    }
+    insert_into(user_data_export::table)
+      .values(user_data_export::local_user_id.eq(local_user_id))
+      .returning(Self::as_returning())
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    user_data_export::table
+      .filter(user_data_export::local_user_id.eq(local_user_id))
+      .order_by(user_data_export::published_at.desc())
+      .select(Self::as_select())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Marks the export as complete, once the archive was written.
+  pub async fn complete(pool: &mut DbPool<'_>, id: UserDataExportId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    update(user_data_export::table.find(id))
+      .set((
+        user_data_export::status.eq(DataExportStatus::Complete),
+        user_data_export::completed_at.eq(Utc::now()),
+      ))
+      .returning(Self::as_returning())
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  pub async fn fail(pool: &mut DbPool<'_>, id: UserDataExportId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    update(user_data_export::table.find(id))
+      .set((
+        user_data_export::status.eq(DataExportStatus::Failed),
+        user_data_export::completed_at.eq(Utc::now()),
+      ))
+      .returning(Self::as_returning())
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  /// Reads a complete export which belongs to the given user, so that its archive can be
+  /// downloaded.
+  pub async fn read_complete(
+    pool: &mut DbPool<'_>,
+    id: UserDataExportId,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    user_data_export::table
+      .find(id)
+      .filter(user_data_export::local_user_id.eq(local_user_id))
+      .filter(user_data_export::status.eq(DataExportStatus::Complete))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+    user_data_export::UserDataExport,
+  };
+  use lemmy_db_schema_file::enums::DataExportStatus;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_user_data_export() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "exporter");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    // Only one export can be pending at a time
+    let export = UserDataExport::create(pool, inserted_local_user.id).await?;
+    assert_eq!(DataExportStatus::Pending, export.status);
+    assert!(
+      UserDataExport::create(pool, inserted_local_user.id)
+        .await
+        .is_err()
+    );
+
+    // The archive can only be downloaded once the export is complete
+    assert!(
+      UserDataExport::read_complete(pool, export.id, inserted_local_user.id)
+        .await
+        .is_err()
+    );
+    let completed = UserDataExport::complete(pool, export.id).await?;
+    assert_eq!(DataExportStatus::Complete, completed.status);
+    assert!(completed.completed_at.is_some());
+    assert_eq!(
+      completed,
+      UserDataExport::read_complete(pool, export.id, inserted_local_user.id).await?
+    );
+
+    let failed = UserDataExport::create(pool, inserted_local_user.id).await?;
+    UserDataExport::fail(pool, failed.id).await?;
+    let list = UserDataExport::list(pool, inserted_local_user.id).await?;
+    assert_eq!(2, list.len());
+    assert!(
+      UserDataExport::read_complete(pool, failed.id, inserted_local_user.id)
+        .await
+        .is_err()
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
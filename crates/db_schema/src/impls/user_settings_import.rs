@@ -0,0 +1,174 @@
+use crate::{
+  newtypes::{LocalUserId, UserSettingsImportId},
+  source::user_settings_import::UserSettingsImport,
+};
+use chrono::Utc;
+use diesel::{
+  ExpressionMethods,
+  OptionalExtension,
+  PgArrayExpressionMethods,
+  QueryDsl,
+  SelectableHelper,
+  insert_into,
+  update,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  enums::{SettingsImportFormat, SettingsImportStatus},
+  schema::user_settings_import,
+};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl UserSettingsImport {
+  /// Creates a new pending import. Fails if the user already has an import which is pending.
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    format: SettingsImportFormat,
+    total_items: i32,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    let pending = user_settings_import::table
+      .filter(user_settings_import::local_user_id.eq(local_user_id))
+      .filter(user_settings_import::status.eq(SettingsImportStatus::Pending))
+      .select(user_settings_import::id)
+      .first::<UserSettingsImportId>(conn)
+      .await
+      .optional()?;
+    if pending.is_some() {
+      Err(LemmyErrorType::SettingsImportAlreadyPending)?
+    }
+    insert_into(user_settings_import::table)
+      .values((
+        user_settings_import::local_user_id.eq(local_user_id),
+        user_settings_import::format.eq(format),
+        user_settings_import::total_items.eq(total_items),
+      ))
+      .returning(Self::as_returning())
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    user_settings_import::table
+      .filter(user_settings_import::local_user_id.eq(local_user_id))
+      .order_by(user_settings_import::published_at.desc())
+      .select(Self::as_select())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Adds a batch of processed items to the progress, along with the ones that failed.
+  pub async fn add_progress(
+    pool: &mut DbPool<'_>,
+    id: UserSettingsImportId,
+    processed: i32,
+    failed: Vec<String>,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    update(user_settings_import::table.find(id))
+      .set((
+        user_settings_import::processed_items.eq(user_settings_import::processed_items + processed),
+        user_settings_import::failed_items.eq(user_settings_import::failed_items.concat(failed)),
+      ))
+      .returning(Self::as_returning())
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  pub async fn complete(pool: &mut DbPool<'_>, id: UserSettingsImportId) -> LemmyResult<Self> {
+    Self::finish(pool, id, SettingsImportStatus::Complete).await
+  }
+
+  pub async fn fail(pool: &mut DbPool<'_>, id: UserSettingsImportId) -> LemmyResult<Self> {
+    Self::finish(pool, id, SettingsImportStatus::Failed).await
+  }
+
+  async fn finish(
+    pool: &mut DbPool<'_>,
+    id: UserSettingsImportId,
+    status: SettingsImportStatus,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    update(user_settings_import::table.find(id))
+      .set((
+        user_settings_import::status.eq(status),
+        user_settings_import::completed_at.eq(Utc::now()),
+      ))
+      .returning(Self::as_returning())
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+    user_settings_import::UserSettingsImport,
+  };
+  use lemmy_db_schema_file::enums::{SettingsImportFormat, SettingsImportStatus};
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_user_settings_import() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "importer");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    // Only one import can be pending at a time
+    let import = UserSettingsImport::create(
+      pool,
+      inserted_local_user.id,
+      SettingsImportFormat::MastodonFollows,
+      3,
+    )
+    .await?;
+    assert_eq!(SettingsImportStatus::Pending, import.status);
+    assert_eq!(0, import.processed_items);
+    assert!(
+      UserSettingsImport::create(pool, inserted_local_user.id, SettingsImportFormat::Lemmy, 1)
+        .await
+        .is_err()
+    );
+
+    UserSettingsImport::add_progress(pool, import.id, 2, vec![]).await?;
+    let progress =
+      UserSettingsImport::add_progress(pool, import.id, 1, vec!["a@b.com".to_string()]).await?;
+    assert_eq!(3, progress.processed_items);
+    assert_eq!(vec!["a@b.com".to_string()], progress.failed_items);
+
+    let completed = UserSettingsImport::complete(pool, import.id).await?;
+    assert_eq!(SettingsImportStatus::Complete, completed.status);
+    assert!(completed.completed_at.is_some());
+
+    // A new import can be started once the previous one is done
+    let failed =
+      UserSettingsImport::create(pool, inserted_local_user.id, SettingsImportFormat::Mbin, 0)
+        .await?;
+    UserSettingsImport::fail(pool, failed.id).await?;
+    let list = UserSettingsImport::list(pool, inserted_local_user.id).await?;
+    assert_eq!(2, list.len());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
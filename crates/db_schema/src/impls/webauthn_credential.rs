@@ -0,0 +1,226 @@
+use crate::{
+  newtypes::{LocalUserId, WebauthnCredentialId},
+  source::webauthn_credential::{
+    WebauthnChallenge,
+    WebauthnChallengeInsertForm,
+    WebauthnCredential,
+    WebauthnCredentialInsertForm,
+  },
+};
+use diesel::{
+  ExpressionMethods,
+  NullableExpressionMethods,
+  QueryDsl,
+  delete,
+  dsl::IntervalDsl,
+  insert_into,
+  update,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::{webauthn_challenge, webauthn_credential};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  utils::now,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use uuid::Uuid;
+
+impl WebauthnCredential {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &WebauthnCredentialInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(webauthn_credential::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    webauthn_credential::table
+      .filter(webauthn_credential::local_user_id.eq(local_user_id))
+      .order_by(webauthn_credential::published_at.desc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn rename(
+    pool: &mut DbPool<'_>,
+    id: WebauthnCredentialId,
+    local_user_id: LocalUserId,
+    name: &str,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      webauthn_credential::table
+        .find(id)
+        .filter(webauthn_credential::local_user_id.eq(local_user_id)),
+    )
+    .set(webauthn_credential::name.eq(name))
+    .get_result::<Self>(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Stores the new signature counter after the credential was used to log in.
+  pub async fn mark_used(
+    pool: &mut DbPool<'_>,
+    id: WebauthnCredentialId,
+    passkey: &str,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    update(webauthn_credential::table.find(id))
+      .set((
+        webauthn_credential::passkey.eq(passkey),
+        webauthn_credential::last_used_at.eq(now().nullable()),
+      ))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    Ok(())
+  }
+
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    id: WebauthnCredentialId,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let deleted = delete(
+      webauthn_credential::table
+        .find(id)
+        .filter(webauthn_credential::local_user_id.eq(local_user_id)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)?;
+    if deleted == 0 {
+      Err(LemmyErrorType::NotFound)?
+    }
+    Ok(())
+  }
+}
+
+impl WebauthnChallenge {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &WebauthnChallengeInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(webauthn_challenge::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// Each challenge can only be answered once, and only within ten minutes.
+  pub async fn consume(pool: &mut DbPool<'_>, id: Uuid) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      webauthn_challenge::table
+        .find(id)
+        .filter(webauthn_challenge::published_at.gt(now() - 10.minutes())),
+    )
+    .get_result::<Self>(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::IncorrectWebauthnCredential)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+    recovery_code::{RecoveryCode, RecoveryCodeInsertForm},
+    webauthn_credential::{
+      WebauthnChallenge,
+      WebauthnChallengeInsertForm,
+      WebauthnCredential,
+      WebauthnCredentialInsertForm,
+    },
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_webauthn_credential() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "passkey_user");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    let form = WebauthnCredentialInsertForm::new(
+      inserted_local_user.id,
+      "Security key".to_string(),
+      "credential_id".to_string(),
+      "{}".to_string(),
+    );
+    let credential = WebauthnCredential::create(pool, &form).await?;
+    let renamed =
+      WebauthnCredential::rename(pool, credential.id, inserted_local_user.id, "Phone").await?;
+    assert_eq!("Phone", renamed.name);
+    WebauthnCredential::mark_used(pool, credential.id, "{\"counter\":1}").await?;
+    let list = WebauthnCredential::list(pool, inserted_local_user.id).await?;
+    assert_eq!(1, list.len());
+    assert!(list.iter().all(|c| c.last_used_at.is_some()));
+
+    // Challenges can only be consumed once
+    let challenge_form = WebauthnChallengeInsertForm::new(inserted_local_user.id, "{}".to_string());
+    let challenge = WebauthnChallenge::create(pool, &challenge_form).await?;
+    let consumed = WebauthnChallenge::consume(pool, challenge.id).await?;
+    assert_eq!(challenge, consumed);
+    assert!(
+      WebauthnChallenge::consume(pool, challenge.id)
+        .await
+        .is_err()
+    );
+
+    // Recovery codes are replaced as a whole, and can only be used once
+    let codes = vec![
+      RecoveryCodeInsertForm::new(inserted_local_user.id, "hash1".to_string()),
+      RecoveryCodeInsertForm::new(inserted_local_user.id, "hash2".to_string()),
+    ];
+    RecoveryCode::replace(pool, inserted_local_user.id, &codes).await?;
+    RecoveryCode::consume(pool, inserted_local_user.id, "hash1").await?;
+    assert!(
+      RecoveryCode::consume(pool, inserted_local_user.id, "hash1")
+        .await
+        .is_err()
+    );
+    let new_codes = vec![RecoveryCodeInsertForm::new(
+      inserted_local_user.id,
+      "hash3".to_string(),
+    )];
+    RecoveryCode::replace(pool, inserted_local_user.id, &new_codes).await?;
+    assert!(
+      RecoveryCode::consume(pool, inserted_local_user.id, "hash2")
+        .await
+        .is_err()
+    );
+
+    WebauthnCredential::delete(pool, credential.id, inserted_local_user.id).await?;
+    assert!(
+      WebauthnCredential::list(pool, inserted_local_user.id)
+        .await?
+        .is_empty()
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,357 @@
+use crate::{
+  newtypes::{CommunityId, WikiPageId, WikiPageRevisionId},
+  source::{
+    person::Person,
+    wiki::{
+      WikiEditor,
+      WikiPage,
+      WikiPageInsertForm,
+      WikiPageRevision,
+      WikiPageRevisionInsertForm,
+      WikiPageRevisionView,
+      WikiPageUpdateForm,
+    },
+  },
+};
+use diesel::{
+  ExpressionMethods,
+  JoinOnDsl,
+  OptionalExtension,
+  QueryDsl,
+  SelectableHelper,
+  delete,
+  insert_into,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{person, wiki_editor, wiki_page, wiki_page_revision},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  dburl::DbUrl,
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use url::Url;
+
+impl Crud for WikiPage {
+  type InsertForm = WikiPageInsertForm;
+  type UpdateForm = WikiPageUpdateForm;
+  type IdType = WikiPageId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(wiki_page::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    wiki_page_id: WikiPageId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(wiki_page::table.find(wiki_page_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl WikiPage {
+  /// Inserts a page received over federation, or updates the existing copy.
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &WikiPageInsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(wiki_page::table)
+      .values(form)
+      .on_conflict(wiki_page::ap_id)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn read_from_slug(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    slug: &str,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    wiki_page::table
+      .filter(wiki_page::community_id.eq(community_id))
+      .filter(wiki_page::slug.eq(slug))
+      .filter(wiki_page::deleted.eq(false))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: DbUrl,
+  ) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    wiki_page::table
+      .filter(wiki_page::ap_id.eq(object_id))
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// All pages of the community wiki, sorted by slug so that subpages follow their parent.
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    wiki_page::table
+      .filter(wiki_page::community_id.eq(community_id))
+      .filter(wiki_page::deleted.eq(false))
+      .order_by(wiki_page::slug.asc())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// The activitypub id of a wiki page, like `https://example.com/c/rust/wiki/guides/setup`.
+  pub fn build_ap_id(community_ap_id: &DbUrl, slug: &str) -> LemmyResult<DbUrl> {
+    Ok(Url::parse(&format!("{community_ap_id}/wiki/{slug}"))?.into())
+  }
+
+  /// Reverse of [`WikiPage::build_ap_id`], returns `None` if the page doesn't belong to the
+  /// community.
+  pub fn slug_from_ap_id(community_ap_id: &DbUrl, ap_id: &DbUrl) -> Option<String> {
+    ap_id
+      .as_str()
+      .strip_prefix(&format!("{community_ap_id}/wiki/"))
+      .filter(|s| !s.is_empty())
+      .map(ToString::to_string)
+  }
+}
+
+impl WikiPageRevision {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &WikiPageRevisionInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(wiki_page_revision::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, revision_id: WikiPageRevisionId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    wiki_page_revision::table
+      .find(revision_id)
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Revisions of a page, newest first.
+  pub async fn list_for_page(
+    pool: &mut DbPool<'_>,
+    wiki_page_id: WikiPageId,
+  ) -> LemmyResult<Vec<WikiPageRevisionView>> {
+    let conn = &mut get_conn(pool).await?;
+    wiki_page_revision::table
+      .inner_join(person::table.on(wiki_page_revision::creator_id.eq(person::id)))
+      .filter(wiki_page_revision::wiki_page_id.eq(wiki_page_id))
+      .order_by(wiki_page_revision::published_at.desc())
+      .then_order_by(wiki_page_revision::id.desc())
+      .select(WikiPageRevisionView::as_select())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl WikiEditor {
+  /// Allows the user to edit pages of the community which aren't reserved for mods.
+  pub async fn approve(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(wiki_editor::table)
+      .values((
+        wiki_editor::community_id.eq(community_id),
+        wiki_editor::person_id.eq(person_id),
+      ))
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)?;
+    Ok(())
+  }
+
+  pub async fn remove(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      wiki_editor::table
+        .filter(wiki_editor::community_id.eq(community_id))
+        .filter(wiki_editor::person_id.eq(person_id)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)?;
+    Ok(())
+  }
+
+  pub async fn is_approved(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> LemmyResult<bool> {
+    let conn = &mut get_conn(pool).await?;
+    let count = wiki_editor::table
+      .filter(wiki_editor::community_id.eq(community_id))
+      .filter(wiki_editor::person_id.eq(person_id))
+      .count()
+      .get_result::<i64>(conn)
+      .await?;
+    Ok(count > 0)
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, community_id: CommunityId) -> LemmyResult<Vec<Person>> {
+    let conn = &mut get_conn(pool).await?;
+    wiki_editor::table
+      .inner_join(person::table)
+      .filter(wiki_editor::community_id.eq(community_id))
+      .order_by(wiki_editor::published_at.asc())
+      .select(Person::as_select())
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+    wiki::{
+      WikiEditor,
+      WikiPage,
+      WikiPageInsertForm,
+      WikiPageRevision,
+      WikiPageRevisionInsertForm,
+      WikiPageUpdateForm,
+    },
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_wiki_page() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "wiki_writer");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "wiki_community".to_string(),
+      "wiki community".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+
+    let slug = "guides/getting-started";
+    let ap_id = WikiPage::build_ap_id(&inserted_community.ap_id, slug)?;
+    assert_eq!(
+      Some(slug.to_string()),
+      WikiPage::slug_from_ap_id(&inserted_community.ap_id, &ap_id)
+    );
+    let page_form = WikiPageInsertForm::new(
+      inserted_community.id,
+      slug.to_string(),
+      "Getting started".to_string(),
+      "first version".to_string(),
+      ap_id,
+    );
+    let page = WikiPage::create(pool, &page_form).await?;
+    let first_form = WikiPageRevisionInsertForm::new(
+      page.id,
+      inserted_person.id,
+      page.title.clone(),
+      page.body.clone(),
+    );
+    let first = WikiPageRevision::create(pool, &first_form).await?;
+
+    let update_form = WikiPageUpdateForm {
+      body: Some("second version".to_string()),
+      ..Default::default()
+    };
+    WikiPage::update(pool, page.id, &update_form).await?;
+    let second_form = WikiPageRevisionInsertForm {
+      summary: Some("typo".to_string()),
+      ..WikiPageRevisionInsertForm::new(
+        page.id,
+        inserted_person.id,
+        page.title.clone(),
+        "second version".to_string(),
+      )
+    };
+    let second = WikiPageRevision::create(pool, &second_form).await?;
+
+    let read_page = WikiPage::read_from_slug(pool, inserted_community.id, slug).await?;
+    assert_eq!("second version", read_page.body);
+    let revisions = WikiPageRevision::list_for_page(pool, page.id)
+      .await?
+      .into_iter()
+      .map(|r| r.revision.id)
+      .collect::<Vec<_>>();
+    assert_eq!(vec![second.id, first.id], revisions);
+
+    assert!(!WikiEditor::is_approved(pool, inserted_community.id, inserted_person.id).await?);
+    WikiEditor::approve(pool, inserted_community.id, inserted_person.id).await?;
+    assert!(WikiEditor::is_approved(pool, inserted_community.id, inserted_person.id).await?);
+    assert_eq!(
+      1,
+      WikiEditor::list(pool, inserted_community.id).await?.len()
+    );
+    WikiEditor::remove(pool, inserted_community.id, inserted_person.id).await?;
+    assert!(
+      WikiEditor::list(pool, inserted_community.id)
+        .await?
+        .is_empty()
+    );
+
+    let delete_form = WikiPageUpdateForm {
+      deleted: Some(true),
+      ..Default::default()
+    };
+    WikiPage::update(pool, page.id, &delete_form).await?;
+    assert!(
+      WikiPage::list_for_community(pool, inserted_community.id)
+        .await?
+        .is_empty()
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -145,6 +145,7 @@ pub enum ReportType {
   Comments,
   PrivateMessages,
   Communities,
+  BanAppeals,
 }
 
 #[derive(
@@ -246,4 +247,5 @@ pub type MyInstancePersonsActionsAllColumnsTuple = (
   AliasedField<aliases::MyInstancePersonsActions, instance_actions::received_ban_at>,
   AliasedField<aliases::MyInstancePersonsActions, instance_actions::ban_expires_at>,
   AliasedField<aliases::MyInstancePersonsActions, instance_actions::blocked_persons_at>,
+  AliasedField<aliases::MyInstancePersonsActions, instance_actions::quarantined_at>,
 );
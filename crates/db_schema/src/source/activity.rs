@@ -1,9 +1,9 @@
-use crate::newtypes::{ActivityId, CommunityId};
+use crate::newtypes::{ActivityId, CommunityId, PostId};
 use chrono::{DateTime, Utc};
 use diesel::Queryable;
 use lemmy_db_schema_file::{
   enums::ActorType,
-  schema::{received_activity, sent_activity},
+  schema::{pending_post_activity, received_activity, sent_activity},
 };
 use lemmy_diesel_utils::dburl::DbUrl;
 use serde_json::Value;
@@ -92,3 +92,16 @@ pub struct ReceivedActivity {
   pub ap_id: DbUrl,
   pub published_at: DateTime<Utc>,
 }
+
+/// The activity of a remote post which is held back in the approval queue of a local community.
+/// It is announced to the community followers once a mod approves the post.
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(primary_key(post_id)))]
+#[cfg_attr(feature = "full", diesel(table_name = pending_post_activity))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PendingPostActivity {
+  pub post_id: PostId,
+  pub data: Value,
+  pub published_at: DateTime<Utc>,
+}
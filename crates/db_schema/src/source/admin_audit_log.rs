@@ -0,0 +1,39 @@
+use crate::newtypes::AdminAuditLogId;
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "full")]
+use {i_love_jesus::CursorKeysModule, lemmy_db_schema_file::schema::admin_audit_log};
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, CursorKeysModule)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = admin_audit_log))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", cursor_keys_module(name = admin_audit_log_keys))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A single admin API call.
+pub struct AdminAuditLog {
+  pub id: AdminAuditLogId,
+  pub admin_id: PersonId,
+  /// The name of the API action, for example `EditSite`.
+  pub action: String,
+  /// Id of the affected item, its type depends on the action.
+  pub target_id: Option<i32>,
+  /// Parameters of the API call as JSON, without secrets.
+  pub parameters: Option<String>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = admin_audit_log))]
+pub struct AdminAuditLogInsertForm {
+  pub admin_id: PersonId,
+  pub action: String,
+  pub target_id: Option<i32>,
+  pub parameters: Option<String>,
+}
@@ -0,0 +1,57 @@
+use crate::newtypes::{BanAppealId, CommunityId, ModlogId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::ban_appeal;
+use lemmy_db_schema_file::{PersonId, enums::BanAppealDecision};
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An appeal against a site ban or a community ban.
+pub struct BanAppeal {
+  pub id: BanAppealId,
+  pub creator_id: PersonId,
+  /// The community the user was banned from, or none for a site ban.
+  pub community_id: Option<CommunityId>,
+  /// The modlog entry of the ban which is appealed.
+  pub ban_id: ModlogId,
+  pub reason: String,
+  /// Activity id, only set for appeals received from another instance.
+  #[serde(skip)]
+  pub ap_id: Option<DbUrl>,
+  pub resolved: bool,
+  pub resolver_id: Option<PersonId>,
+  pub decision: Option<BanAppealDecision>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+pub struct BanAppealInsertForm {
+  pub creator_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub ban_id: ModlogId,
+  pub reason: String,
+  #[new(default)]
+  pub ap_id: Option<DbUrl>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+pub struct BanAppealUpdateForm {
+  pub ap_id: Option<Option<DbUrl>>,
+  pub resolved: Option<bool>,
+  pub resolver_id: Option<Option<PersonId>>,
+  pub decision: Option<Option<BanAppealDecision>>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
@@ -1,4 +1,5 @@
 use crate::newtypes::{
+  BanAppealId,
   CommentReportId,
   CommunityReportId,
   PostReportId,
@@ -30,4 +31,5 @@ pub struct ReportCombined {
   pub comment_report_id: Option<CommentReportId>,
   pub private_message_report_id: Option<PrivateMessageReportId>,
   pub community_report_id: Option<CommunityReportId>,
+  pub ban_appeal_id: Option<BanAppealId>,
 }
@@ -67,6 +67,12 @@ pub struct Comment {
   pub federation_pending: bool,
   /// Whether the comment is locked.
   pub locked: bool,
+  /// The creator was quarantined when this comment was made. It is only visible to the creator
+  /// and to mods/admins, and isn't federated until approved.
+  pub quarantined: bool,
+  /// Whether the comment has been pinned by a mod. Pinned comments are shown before the other
+  /// replies, regardless of the sort type.
+  pub pinned: bool,
 }
 
 #[derive(Debug, Clone, derive_new::new, Serialize, Deserialize)]
@@ -96,6 +102,10 @@ pub struct CommentInsertForm {
   pub federation_pending: Option<bool>,
   #[new(default)]
   pub locked: Option<bool>,
+  #[new(default)]
+  pub quarantined: Option<bool>,
+  #[new(default)]
+  pub pinned: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -113,6 +123,8 @@ pub struct CommentUpdateForm {
   pub language_id: Option<LanguageId>,
   pub federation_pending: Option<bool>,
   pub locked: Option<bool>,
+  pub quarantined: Option<bool>,
+  pub pinned: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -3,7 +3,13 @@ use chrono::{DateTime, Utc};
 use lemmy_db_schema_file::{
   InstanceId,
   PersonId,
-  enums::{CommunityFollowerState, CommunityNotificationsMode, CommunityVisibility},
+  enums::{
+    CommunityFollowerState,
+    CommunityNotificationsMode,
+    CommunityPostingMode,
+    CommunityVisibility,
+    ModeratorPermission,
+  },
 };
 use lemmy_diesel_utils::{dburl::DbUrl, sensitive::SensitiveString};
 use serde::{Deserialize, Serialize};
@@ -61,8 +67,6 @@ pub struct Community {
   #[cfg_attr(feature = "ts-rs", ts(skip))]
   #[serde(skip, default = "placeholder_apub_url")]
   pub inbox_url: DbUrl,
-  /// Whether posting is restricted to mods only.
-  pub posting_restricted_to_mods: bool,
   pub instance_id: InstanceId,
   /// Url where moderators collection is served over Activitypub
   #[serde(skip)]
@@ -95,6 +99,8 @@ pub struct Community {
   pub report_count: i16,
   pub unresolved_report_count: i16,
   pub local_removed: bool,
+  /// Who can create posts in the community.
+  pub posting_mode: CommunityPostingMode,
 }
 
 #[derive(Debug, Clone, derive_new::new)]
@@ -138,13 +144,13 @@ pub struct CommunityInsertForm {
   #[new(default)]
   pub featured_url: Option<DbUrl>,
   #[new(default)]
-  pub posting_restricted_to_mods: Option<bool>,
-  #[new(default)]
   pub visibility: Option<CommunityVisibility>,
   #[new(default)]
   pub summary: Option<String>,
   #[new(default)]
   pub local_removed: Option<bool>,
+  #[new(default)]
+  pub posting_mode: Option<CommunityPostingMode>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -169,10 +175,10 @@ pub struct CommunityUpdateForm {
   pub inbox_url: Option<DbUrl>,
   pub moderators_url: Option<Option<DbUrl>>,
   pub featured_url: Option<Option<DbUrl>>,
-  pub posting_restricted_to_mods: Option<bool>,
   pub visibility: Option<CommunityVisibility>,
   pub summary: Option<Option<String>>,
   pub local_removed: Option<bool>,
+  pub posting_mode: Option<CommunityPostingMode>,
 }
 
 #[skip_serializing_none]
@@ -212,6 +218,11 @@ pub struct CommunityActions {
   #[serde(skip)]
   pub follow_approver_id: Option<PersonId>,
   pub notifications: Option<CommunityNotificationsMode>,
+  /// The permissions of this moderator. None means that they hold all permissions.
+  pub moderator_permissions: Option<Vec<ModeratorPermission>>,
+  /// The flair of the user in this community, shown next to their name.
+  pub flair: Option<String>,
+  pub flair_emoji: Option<String>,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -0,0 +1,81 @@
+use crate::newtypes::{CommunityId, ContentFilterId, LocalUserId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::enums::{ContentFilterAction, ContentFilterMatch};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::content_filter;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A filter which hides matching posts and comments, or collapses them behind a warning.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = content_filter))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ContentFilter {
+  pub id: ContentFilterId,
+  pub local_user_id: LocalUserId,
+  pub phrase: String,
+  pub match_type: ContentFilterMatch,
+  /// The regex which is actually matched, generated from the phrase.
+  #[serde(skip)]
+  pub pattern: String,
+  pub action: ContentFilterAction,
+  pub filter_titles: bool,
+  pub filter_bodies: bool,
+  pub filter_comments: bool,
+  pub filter_usernames: bool,
+  /// Also matches the domain of links.
+  pub filter_urls: bool,
+  /// Only apply the filter in this community.
+  pub community_id: Option<CommunityId>,
+  pub expires_at: Option<DateTime<Utc>>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = content_filter))]
+pub struct ContentFilterInsertForm {
+  pub local_user_id: LocalUserId,
+  pub phrase: String,
+  pub match_type: ContentFilterMatch,
+  pub pattern: String,
+  #[new(default)]
+  pub action: Option<ContentFilterAction>,
+  #[new(default)]
+  pub filter_titles: Option<bool>,
+  #[new(default)]
+  pub filter_bodies: Option<bool>,
+  #[new(default)]
+  pub filter_comments: Option<bool>,
+  #[new(default)]
+  pub filter_usernames: Option<bool>,
+  #[new(default)]
+  pub filter_urls: Option<bool>,
+  #[new(default)]
+  pub community_id: Option<CommunityId>,
+  #[new(default)]
+  pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = content_filter))]
+pub struct ContentFilterUpdateForm {
+  pub phrase: Option<String>,
+  pub match_type: Option<ContentFilterMatch>,
+  pub pattern: Option<String>,
+  pub action: Option<ContentFilterAction>,
+  pub filter_titles: Option<bool>,
+  pub filter_bodies: Option<bool>,
+  pub filter_comments: Option<bool>,
+  pub filter_usernames: Option<bool>,
+  pub filter_urls: Option<bool>,
+  pub community_id: Option<Option<CommunityId>>,
+  pub expires_at: Option<Option<DateTime<Utc>>>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
@@ -0,0 +1,91 @@
+use crate::newtypes::{CommentId, CommunityId, DraftId, LanguageId, LocalUserId, PostId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::draft;
+use lemmy_db_schema_file::{PersonId, enums::DraftKind};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// An unfinished post, comment or private message which is stored on the server, so that it
+/// isn't lost when the client goes away.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = draft))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct Draft {
+  pub id: DraftId,
+  pub local_user_id: LocalUserId,
+  pub kind: DraftKind,
+  /// The community of a post draft.
+  pub community_id: Option<CommunityId>,
+  /// The post of a comment draft.
+  pub post_id: Option<PostId>,
+  /// The comment which a comment draft replies to.
+  pub parent_comment_id: Option<CommentId>,
+  /// The recipient of a private message draft.
+  pub recipient_id: Option<PersonId>,
+  /// The title of a post draft.
+  pub name: Option<String>,
+  /// The url of a post draft. This isn't validated until the draft is published.
+  pub url: Option<String>,
+  /// The alt text of an image post draft.
+  pub alt_text: Option<String>,
+  /// The custom thumbnail of a post draft. Also not validated until the draft is published.
+  pub custom_thumbnail: Option<String>,
+  pub body: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = draft))]
+pub struct DraftInsertForm {
+  pub local_user_id: LocalUserId,
+  pub kind: DraftKind,
+  #[new(default)]
+  pub community_id: Option<CommunityId>,
+  #[new(default)]
+  pub post_id: Option<PostId>,
+  #[new(default)]
+  pub parent_comment_id: Option<CommentId>,
+  #[new(default)]
+  pub recipient_id: Option<PersonId>,
+  #[new(default)]
+  pub name: Option<String>,
+  #[new(default)]
+  pub url: Option<String>,
+  #[new(default)]
+  pub alt_text: Option<String>,
+  #[new(default)]
+  pub custom_thumbnail: Option<String>,
+  #[new(default)]
+  pub body: Option<String>,
+  #[new(default)]
+  pub nsfw: Option<bool>,
+  #[new(default)]
+  pub language_id: Option<LanguageId>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = draft))]
+pub struct DraftUpdateForm {
+  pub community_id: Option<Option<CommunityId>>,
+  pub post_id: Option<Option<PostId>>,
+  pub parent_comment_id: Option<Option<CommentId>>,
+  pub recipient_id: Option<Option<PersonId>>,
+  pub name: Option<Option<String>>,
+  pub url: Option<Option<String>>,
+  pub alt_text: Option<Option<String>>,
+  pub custom_thumbnail: Option<Option<String>>,
+  pub body: Option<Option<String>>,
+  pub nsfw: Option<Option<bool>>,
+  pub language_id: Option<Option<LanguageId>>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
@@ -74,6 +74,8 @@ pub struct InstanceActions {
   pub ban_expires_at: Option<DateTime<Utc>>,
   /// When the instance's persons were blocked.
   pub blocked_persons_at: Option<DateTime<Utc>>,
+  /// When this user was quarantined by the site admins.
+  pub quarantined_at: Option<DateTime<Utc>>,
 }
 
 #[derive(derive_new::new)]
@@ -106,3 +108,13 @@ pub struct InstanceBanForm {
   pub received_ban_at: DateTime<Utc>,
   pub ban_expires_at: Option<DateTime<Utc>>,
 }
+
+#[derive(derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = instance_actions))]
+pub struct InstanceQuarantineForm {
+  pub person_id: PersonId,
+  pub instance_id: InstanceId,
+  #[new(value = "Utc::now()")]
+  pub quarantined_at: DateTime<Utc>,
+}
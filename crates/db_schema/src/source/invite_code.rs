@@ -0,0 +1,41 @@
+use crate::newtypes::{CommunityId, InviteCodeId, LocalUserId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::invite_code;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A code which allows signing up while registration is invite only.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = invite_code))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct InviteCode {
+  pub id: InviteCodeId,
+  pub code: String,
+  pub creator_id: LocalUserId,
+  /// None means the code can be used any number of times.
+  pub max_uses: Option<i32>,
+  pub uses: i32,
+  pub expires_at: Option<DateTime<Utc>>,
+  /// Users who register with this code automatically follow these communities.
+  pub community_ids: Vec<CommunityId>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = invite_code))]
+pub struct InviteCodeInsertForm {
+  pub code: String,
+  pub creator_id: LocalUserId,
+  #[new(default)]
+  pub max_uses: Option<i32>,
+  #[new(default)]
+  pub expires_at: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub community_ids: Vec<CommunityId>,
+}
@@ -6,6 +6,7 @@ use lemmy_db_schema_file::{
   PersonId,
   enums::{
     CommentSortType,
+    EditHistoryVisibility,
     FederationMode,
     ListingType,
     PostListingMode,
@@ -99,6 +100,23 @@ pub struct LocalSite {
   #[serde(skip)]
   pub system_account: PersonId,
   pub default_items_per_page: i32,
+  /// Sessions which are not refreshed for this many seconds are logged out.
+  pub session_idle_timeout_seconds: Option<i32>,
+  /// Users whose account is at least this many days old can create invite codes. None means only
+  /// admins can.
+  pub invite_min_account_age_days: Option<i32>,
+  /// Only email addresses from the allowed domains can be used.
+  pub email_domain_allowlist_only: bool,
+  /// Block email addresses from known disposable email providers.
+  pub block_disposable_email_domains: bool,
+  /// Deleted accounts are only removed after this many days, so users can still cancel the
+  /// deletion. None means accounts are deleted right away.
+  pub account_deletion_grace_period_days: Option<i32>,
+  /// Who can see the previous versions of edited content.
+  pub edit_history_visibility: EditHistoryVisibility,
+  /// Previous versions of edited content are deleted after this many days. None means they are
+  /// kept forever.
+  pub edit_history_retention_days: Option<i32>,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -164,6 +182,20 @@ pub struct LocalSiteInsertForm {
   pub suggested_communities: Option<MultiCommunityId>,
   #[new(default)]
   pub system_account: Option<PersonId>,
+  #[new(default)]
+  pub session_idle_timeout_seconds: Option<i32>,
+  #[new(default)]
+  pub invite_min_account_age_days: Option<i32>,
+  #[new(default)]
+  pub email_domain_allowlist_only: Option<bool>,
+  #[new(default)]
+  pub block_disposable_email_domains: Option<bool>,
+  #[new(default)]
+  pub account_deletion_grace_period_days: Option<i32>,
+  #[new(default)]
+  pub edit_history_visibility: Option<EditHistoryVisibility>,
+  #[new(default)]
+  pub edit_history_retention_days: Option<i32>,
 }
 
 #[derive(Clone, Default)]
@@ -200,4 +232,11 @@ pub struct LocalSiteUpdateForm {
   pub disable_email_notifications: Option<bool>,
   pub suggested_communities: Option<MultiCommunityId>,
   pub default_items_per_page: Option<i32>,
+  pub session_idle_timeout_seconds: Option<Option<i32>>,
+  pub invite_min_account_age_days: Option<Option<i32>>,
+  pub email_domain_allowlist_only: Option<bool>,
+  pub block_disposable_email_domains: Option<bool>,
+  pub account_deletion_grace_period_days: Option<Option<i32>>,
+  pub edit_history_visibility: Option<EditHistoryVisibility>,
+  pub edit_history_retention_days: Option<Option<i32>>,
 }
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::local_site_email_domain;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = local_site_email_domain))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An email domain which is allowed or blocked for signups, including its subdomains.
+pub struct LocalSiteEmailDomain {
+  pub id: i32,
+  pub domain: String,
+  pub allowed: bool,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = local_site_email_domain))]
+pub struct LocalSiteEmailDomainForm {
+  pub domain: String,
+  pub allowed: bool,
+}
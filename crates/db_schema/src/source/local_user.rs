@@ -4,7 +4,7 @@ use chrono::{DateTime, Utc};
 use lemmy_db_schema_file::schema::local_user;
 use lemmy_db_schema_file::{
   PersonId,
-  enums::{CommentSortType, ListingType, PostListingMode, PostSortType, VoteShow},
+  enums::{AdminPermission, CommentSortType, ListingType, PostListingMode, PostSortType, VoteShow},
 };
 use lemmy_diesel_utils::sensitive::SensitiveString;
 use serde::{Deserialize, Serialize};
@@ -78,6 +78,16 @@ pub struct LocalUser {
   pub show_upvote_percentage: bool,
   pub show_person_votes: bool,
   pub default_items_per_page: i32,
+  /// The capabilities of an admin. None means the admin holds all of them.
+  pub admin_permissions: Option<Vec<AdminPermission>>,
+  /// The user whose invite code was used to sign up.
+  pub invited_by_id: Option<LocalUserId>,
+  /// The user deleted their account, which will be removed at this time unless they cancel it.
+  pub deletion_scheduled_at: Option<DateTime<Utc>>,
+  /// Whether the posts and comments are also removed with the scheduled deletion.
+  pub deletion_delete_content: bool,
+  /// Show crossposts of the same link only once in the front page.
+  pub collapse_crossposts: bool,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -150,6 +160,8 @@ pub struct LocalUserInsertForm {
   pub show_upvote_percentage: Option<bool>,
   #[new(default)]
   pub show_person_votes: Option<bool>,
+  #[new(default)]
+  pub invited_by_id: Option<LocalUserId>,
 }
 
 #[derive(Clone, Default)]
@@ -190,4 +202,8 @@ pub struct LocalUserUpdateForm {
   pub show_upvote_percentage: Option<bool>,
   pub show_person_votes: Option<bool>,
   pub default_items_per_page: Option<i32>,
+  pub admin_permissions: Option<Option<Vec<AdminPermission>>>,
+  pub deletion_scheduled_at: Option<Option<DateTime<Utc>>>,
+  pub deletion_delete_content: Option<bool>,
+  pub collapse_crossposts: Option<bool>,
 }
@@ -1,4 +1,4 @@
-use crate::newtypes::LocalUserId;
+use crate::newtypes::{LocalUserId, LoginTokenId};
 use chrono::{DateTime, Utc};
 #[cfg(feature = "full")]
 use lemmy_db_schema_file::schema::login_token;
@@ -26,6 +26,15 @@ pub struct LoginToken {
   /// Could be stored in truncated format, or store derived information for better privacy.
   pub ip: Option<String>,
   pub user_agent: Option<String>,
+  pub id: LoginTokenId,
+  /// Hash of the token which is used to get a new access token once the current one expires. It
+  /// changes every time.
+  #[serde(skip)]
+  pub refresh_token_hash: Option<String>,
+  /// The last time a new access token was issued for this session.
+  pub last_used_at: DateTime<Utc>,
+  /// Sessions without `stay_logged_in` end at this time, even if they are in use.
+  pub expires_at: Option<DateTime<Utc>>,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -35,4 +44,6 @@ pub struct LoginTokenCreateForm {
   pub user_id: LocalUserId,
   pub ip: Option<String>,
   pub user_agent: Option<String>,
+  pub refresh_token_hash: Option<String>,
+  pub expires_at: Option<DateTime<Utc>>,
 }
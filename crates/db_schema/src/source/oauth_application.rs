@@ -0,0 +1,107 @@
+use crate::newtypes::{LocalUserId, OAuthApplicationId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::{
+  oauth_application,
+  oauth_authorization_code,
+  oauth_refresh_token,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A third party app which can ask users for access to their account, with lemmy acting as
+/// OAuth 2.0 provider.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_application))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct OAuthApplication {
+  pub id: OAuthApplicationId,
+  /// The user who registered the app.
+  pub local_user_id: LocalUserId,
+  pub name: String,
+  pub description: Option<String>,
+  pub client_id: String,
+  /// SHA-256 hash of the secret, which is only returned once when the app is created. Public
+  /// clients don't have a secret, and need to use PKCE instead.
+  #[serde(skip)]
+  pub client_secret_hash: Option<String>,
+  /// Users can only be redirected to one of these after approving the app.
+  pub redirect_uris: Vec<String>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_application))]
+pub struct OAuthApplicationInsertForm {
+  pub local_user_id: LocalUserId,
+  pub name: String,
+  pub client_id: String,
+  pub redirect_uris: Vec<String>,
+  #[new(default)]
+  pub description: Option<String>,
+  #[new(default)]
+  pub client_secret_hash: Option<String>,
+}
+
+/// Issued after a user approved an app, and exchanged by the app for an access token.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_authorization_code))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct OAuthAuthorizationCode {
+  /// SHA-256 hash of the code.
+  pub code_hash: String,
+  pub application_id: OAuthApplicationId,
+  pub local_user_id: LocalUserId,
+  pub redirect_uri: String,
+  /// The approved scopes, separated by spaces.
+  pub scope: String,
+  /// The PKCE challenge, which is always S256.
+  pub code_challenge: Option<String>,
+  pub expires_at: DateTime<Utc>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_authorization_code))]
+pub struct OAuthAuthorizationCodeInsertForm {
+  pub code_hash: String,
+  pub application_id: OAuthApplicationId,
+  pub local_user_id: LocalUserId,
+  pub redirect_uri: String,
+  pub scope: String,
+  pub code_challenge: Option<String>,
+  pub expires_at: DateTime<Utc>,
+}
+
+/// Lets an app get a new access token without asking the user again.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_refresh_token))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct OAuthRefreshToken {
+  /// SHA-256 hash of the token.
+  pub token_hash: String,
+  pub application_id: OAuthApplicationId,
+  pub local_user_id: LocalUserId,
+  pub scope: String,
+  pub expires_at: DateTime<Utc>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_refresh_token))]
+pub struct OAuthRefreshTokenInsertForm {
+  pub token_hash: String,
+  pub application_id: OAuthApplicationId,
+  pub local_user_id: LocalUserId,
+  pub scope: String,
+  pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,51 @@
+use crate::newtypes::{CommunityId, LocalUserId, OAuthApplicationId, PersonalAccessTokenId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::enums::ApiTokenScope;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::personal_access_token;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// All personal access tokens start with this prefix, so they can be told apart from login jwts.
+pub const PERSONAL_ACCESS_TOKEN_PREFIX: &str = "lemmy_pat_";
+
+/// A long lived token with restricted rights, for bots and integrations.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = personal_access_token))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct PersonalAccessToken {
+  pub id: PersonalAccessTokenId,
+  pub local_user_id: LocalUserId,
+  pub name: String,
+  /// SHA-256 hash of the token. The token itself is only returned once, when it is created.
+  #[serde(skip)]
+  pub token_hash: String,
+  pub scopes: Vec<ApiTokenScope>,
+  /// Communities in which the token may take moderator actions.
+  pub moderate_community_ids: Vec<CommunityId>,
+  pub expires_at: Option<DateTime<Utc>>,
+  pub last_used_at: Option<DateTime<Utc>>,
+  pub published_at: DateTime<Utc>,
+  /// Set for the access tokens of third party apps, which are issued with OAuth.
+  pub oauth_application_id: Option<OAuthApplicationId>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = personal_access_token))]
+pub struct PersonalAccessTokenInsertForm {
+  pub local_user_id: LocalUserId,
+  pub name: String,
+  pub token_hash: String,
+  pub scopes: Vec<ApiTokenScope>,
+  #[new(default)]
+  pub moderate_community_ids: Vec<CommunityId>,
+  #[new(default)]
+  pub expires_at: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub oauth_application_id: Option<OAuthApplicationId>,
+}
@@ -1,6 +1,14 @@
-use crate::newtypes::{CommunityId, LanguageId, PostId};
+use crate::{
+  newtypes::{CommentId, CommunityId, LanguageId, PostId},
+  source::community::Community,
+};
 use chrono::{DateTime, Utc};
-use lemmy_db_schema_file::{PersonId, enums::PostNotificationsMode};
+#[cfg(feature = "full")]
+use diesel::{AsExpression, FromSqlRow, sql_types::Nullable};
+use lemmy_db_schema_file::{
+  PersonId,
+  enums::{PostEventRsvp, PostNotificationsMode},
+};
 use lemmy_diesel_utils::dburl::DbUrl;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -88,6 +96,16 @@ pub struct Post {
   pub federation_pending: bool,
   pub embed_video_width: Option<i32>,
   pub embed_video_height: Option<i32>,
+  /// The creator was quarantined when this post was made. It is only visible to the creator and
+  /// to mods/admins, and isn't federated until approved.
+  pub quarantined: bool,
+  /// The post was submitted to a community which requires approval, and is waiting for a mod to
+  /// review it.
+  pub pending_approval: bool,
+  /// If this is a crosspost, the post which it was copied from.
+  pub original_post_id: Option<PostId>,
+  /// The comment which the post creator or a mod marked as answer to the post.
+  pub accepted_answer_id: Option<CommentId>,
 }
 
 // TODO: FromBytes, ToBytes are only needed to develop wasm plugin, could be behind feature flag
@@ -144,6 +162,12 @@ pub struct PostInsertForm {
   pub scheduled_publish_time_at: Option<DateTime<Utc>>,
   #[new(default)]
   pub federation_pending: Option<bool>,
+  #[new(default)]
+  pub quarantined: Option<bool>,
+  #[new(default)]
+  pub pending_approval: Option<bool>,
+  #[new(default)]
+  pub original_post_id: Option<PostId>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -174,6 +198,10 @@ pub struct PostUpdateForm {
   pub alt_text: Option<Option<String>>,
   pub scheduled_publish_time_at: Option<Option<DateTime<Utc>>>,
   pub federation_pending: Option<bool>,
+  pub quarantined: Option<bool>,
+  pub pending_approval: Option<bool>,
+  pub original_post_id: Option<Option<PostId>>,
+  pub accepted_answer_id: Option<Option<CommentId>>,
 }
 
 #[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
@@ -209,6 +237,10 @@ pub struct PostActions {
   /// True if upvoted, false if downvoted. Upvote is greater than downvote.
   pub vote_is_upvote: Option<bool>,
   pub notifications: Option<PostNotificationsMode>,
+  /// The answer to an event post.
+  pub event_rsvp: Option<PostEventRsvp>,
+  /// When the event post was answered.
+  pub event_rsvp_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -239,6 +271,17 @@ impl PostLikeForm {
   }
 }
 
+#[derive(derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = post_actions))]
+pub struct PostEventRsvpForm {
+  pub post_id: PostId,
+  pub person_id: PersonId,
+  pub event_rsvp: PostEventRsvp,
+  #[new(value = "Utc::now()")]
+  pub event_rsvp_at: DateTime<Utc>,
+}
+
 #[derive(derive_new::new)]
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
 #[cfg_attr(feature = "full", diesel(table_name = post_actions))]
@@ -279,3 +322,22 @@ pub struct PostHideForm {
   #[new(value = "Utc::now()")]
   pub hidden_at: DateTime<Utc>,
 }
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Another copy of a post in a different community, either an explicit crosspost or a post of the
+/// same link.
+pub struct PostCrosspost {
+  pub post_id: PostId,
+  pub community: Community,
+}
+
+/// We wrap this in a struct so we can implement FromSqlRow<Json> for it
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Default)]
+#[serde(transparent)]
+#[cfg_attr(feature = "full", derive(FromSqlRow, AsExpression))]
+#[cfg_attr(feature = "full", diesel(sql_type = Nullable<diesel::sql_types::Json>))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct PostCrosspostsView(pub Vec<PostCrosspost>);
@@ -0,0 +1,48 @@
+use crate::newtypes::PostId;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::post_event;
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The date, time and place of an event post.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Associations, Identifiable)
+)]
+#[cfg_attr(feature = "full", diesel(belongs_to(crate::source::post::Post)))]
+#[cfg_attr(feature = "full", diesel(table_name = post_event))]
+#[cfg_attr(feature = "full", diesel(primary_key(post_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct PostEvent {
+  pub post_id: PostId,
+  pub start_at: DateTime<Utc>,
+  pub end_at: DateTime<Utc>,
+  /// The timezone in which the event takes place, like `Europe/Berlin`. Clients should show the
+  /// times in this timezone.
+  pub timezone: String,
+  /// The name or address of the place where the event happens.
+  pub location: Option<String>,
+  /// A link to join the event online.
+  pub online_url: Option<DbUrl>,
+  pub going_count: i32,
+  pub interested_count: i32,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = post_event))]
+#[cfg_attr(feature = "full", diesel(treat_none_as_null = true))]
+pub struct PostEventForm {
+  pub post_id: PostId,
+  pub start_at: DateTime<Utc>,
+  pub end_at: DateTime<Utc>,
+  pub timezone: String,
+  pub location: Option<String>,
+  pub online_url: Option<DbUrl>,
+}
@@ -0,0 +1,61 @@
+use crate::{
+  newtypes::{PostGalleryItemId, PostId},
+  source::images::ImageDetails,
+};
+#[cfg(feature = "full")]
+use diesel::{AsExpression, FromSqlRow, sql_types::Nullable};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::post_gallery_item;
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// An image of a gallery post.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Associations, Identifiable)
+)]
+#[cfg_attr(feature = "full", diesel(belongs_to(crate::source::post::Post)))]
+#[cfg_attr(feature = "full", diesel(table_name = post_gallery_item))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct PostGalleryItem {
+  pub id: PostGalleryItemId,
+  pub post_id: PostId,
+  pub url: DbUrl,
+  pub alt_text: Option<String>,
+  /// Images are shown in ascending order of their position, starting at 0.
+  pub position: i32,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = post_gallery_item))]
+pub struct PostGalleryItemInsertForm {
+  pub post_id: PostId,
+  pub url: DbUrl,
+  pub alt_text: Option<String>,
+  pub position: i32,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A gallery image, together with its dimensions and blurhash if they are known.
+pub struct PostGalleryItemView {
+  pub item: PostGalleryItem,
+  pub image_details: Option<ImageDetails>,
+}
+
+/// We wrap this in a struct so we can implement FromSqlRow<Json> for it
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Default)]
+#[serde(transparent)]
+#[cfg_attr(feature = "full", derive(FromSqlRow, AsExpression))]
+#[cfg_attr(feature = "full", diesel(sql_type = Nullable<diesel::sql_types::Json>))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct PostGalleryView(pub Vec<PostGalleryItemView>);
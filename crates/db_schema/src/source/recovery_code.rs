@@ -0,0 +1,23 @@
+use crate::newtypes::LocalUserId;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::recovery_code;
+
+/// A single use code to log in when the second factor is lost. Only a hash of the code is stored.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(table_name = recovery_code))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct RecoveryCode {
+  pub local_user_id: LocalUserId,
+  pub code_hash: String,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = recovery_code))]
+pub struct RecoveryCodeInsertForm {
+  pub local_user_id: LocalUserId,
+  pub code_hash: String,
+}
@@ -0,0 +1,80 @@
+use crate::newtypes::{CommunityId, LanguageId, PostId, RecurringPostId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::recurring_post;
+use lemmy_db_schema_file::{PersonId, enums::RecurringPostFrequency};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A post which is published in a community on a regular schedule, like a weekly discussion
+/// thread.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = recurring_post))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RecurringPost {
+  pub id: RecurringPostId,
+  pub community_id: CommunityId,
+  /// The moderator who set up the schedule. Posts are published in their name.
+  pub creator_id: PersonId,
+  /// The post title. The placeholders {date}, {day}, {week}, {month} and {year} are replaced
+  /// with the publish date.
+  pub name_template: String,
+  pub body: Option<String>,
+  pub nsfw: bool,
+  pub language_id: Option<LanguageId>,
+  pub frequency: RecurringPostFrequency,
+  /// The time of the first post, from which the schedule is counted.
+  pub schedule_start_at: DateTime<Utc>,
+  pub next_publish_at: DateTime<Utc>,
+  /// Feature each new post in the community, and unfeature the previous one.
+  pub feature_latest: bool,
+  /// Lock the previous post when a new one is published.
+  pub lock_previous: bool,
+  pub last_post_id: Option<PostId>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = recurring_post))]
+pub struct RecurringPostInsertForm {
+  pub community_id: CommunityId,
+  pub creator_id: PersonId,
+  pub name_template: String,
+  pub frequency: RecurringPostFrequency,
+  pub schedule_start_at: DateTime<Utc>,
+  pub next_publish_at: DateTime<Utc>,
+  #[new(default)]
+  pub body: Option<String>,
+  #[new(default)]
+  pub nsfw: Option<bool>,
+  #[new(default)]
+  pub language_id: Option<LanguageId>,
+  #[new(default)]
+  pub feature_latest: Option<bool>,
+  #[new(default)]
+  pub lock_previous: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = recurring_post))]
+pub struct RecurringPostUpdateForm {
+  pub creator_id: Option<PersonId>,
+  pub name_template: Option<String>,
+  pub body: Option<Option<String>>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<Option<LanguageId>>,
+  pub frequency: Option<RecurringPostFrequency>,
+  pub schedule_start_at: Option<DateTime<Utc>>,
+  pub next_publish_at: Option<DateTime<Utc>>,
+  pub feature_latest: Option<bool>,
+  pub lock_previous: Option<bool>,
+  pub last_post_id: Option<Option<PostId>>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
@@ -1,6 +1,6 @@
 use crate::newtypes::{LocalUserId, RegistrationApplicationId};
 use chrono::{DateTime, Utc};
-use lemmy_db_schema_file::PersonId;
+use lemmy_db_schema_file::{PersonId, enums::RegistrationScreeningAction};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -26,13 +26,22 @@ pub struct RegistrationApplication {
   pub deny_reason: Option<String>,
   pub published_at: DateTime<Utc>,
   pub updated_at: Option<DateTime<Utc>>,
+  /// The decision of the automatic screening, if any rule matched.
+  pub screening_action: Option<RegistrationScreeningAction>,
+  /// Why the screening rules matched.
+  pub screening_reasons: Vec<String>,
 }
 
+#[derive(derive_new::new)]
 #[cfg_attr(feature = "full", derive(Insertable))]
 #[cfg_attr(feature = "full", diesel(table_name = registration_application))]
 pub struct RegistrationApplicationInsertForm {
   pub local_user_id: LocalUserId,
   pub answer: String,
+  #[new(default)]
+  pub screening_action: Option<RegistrationScreeningAction>,
+  #[new(default)]
+  pub screening_reasons: Vec<String>,
 }
 
 #[cfg_attr(feature = "full", derive(AsChangeset))]
@@ -0,0 +1,88 @@
+use crate::newtypes::{RegistrationApplicationId, RegistrationQuestionId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use diesel::{AsExpression, FromSqlRow, sql_types::Nullable};
+use lemmy_db_schema_file::enums::RegistrationQuestionKind;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::{registration_answer, registration_question};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_question))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A question of the registration questionnaire, defined by the admins.
+pub struct RegistrationQuestion {
+  pub id: RegistrationQuestionId,
+  pub question: String,
+  pub kind: RegistrationQuestionKind,
+  /// The possible answers, for choice questions.
+  pub choices: Vec<String>,
+  /// Whether an answer is mandatory when registration applications are required.
+  pub required: bool,
+  pub display_order: i32,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_question))]
+pub struct RegistrationQuestionInsertForm {
+  pub question: String,
+  #[new(default)]
+  pub kind: Option<RegistrationQuestionKind>,
+  #[new(default)]
+  pub choices: Option<Vec<String>>,
+  #[new(default)]
+  pub required: Option<bool>,
+  #[new(default)]
+  pub display_order: Option<i32>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_question))]
+pub struct RegistrationQuestionUpdateForm {
+  pub question: Option<String>,
+  pub kind: Option<RegistrationQuestionKind>,
+  pub choices: Option<Vec<String>>,
+  pub required: Option<bool>,
+  pub display_order: Option<i32>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An answer to a registration question. The question is stored as it was at registration time.
+pub struct RegistrationAnswer {
+  /// Empty if the question was deleted since.
+  pub question_id: Option<RegistrationQuestionId>,
+  pub question: String,
+  pub answer: String,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_answer))]
+pub struct RegistrationAnswerInsertForm {
+  pub registration_application_id: RegistrationApplicationId,
+  pub question_id: Option<RegistrationQuestionId>,
+  pub question: String,
+  pub answer: String,
+}
+
+/// We wrap this in a struct so we can implement FromSqlRow<Json> for it
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Default)]
+#[serde(transparent)]
+#[cfg_attr(feature = "full", derive(FromSqlRow, AsExpression))]
+#[cfg_attr(feature = "full", diesel(sql_type = Nullable<diesel::sql_types::Json>))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RegistrationAnswersView(pub Vec<RegistrationAnswer>);
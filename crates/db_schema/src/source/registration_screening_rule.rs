@@ -0,0 +1,36 @@
+use crate::newtypes::RegistrationScreeningRuleId;
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::enums::{RegistrationScreeningAction, RegistrationScreeningRuleKind};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::registration_screening_rule;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_screening_rule))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A rule which is checked automatically for new registration applications.
+pub struct RegistrationScreeningRule {
+  pub id: RegistrationScreeningRuleId,
+  pub kind: RegistrationScreeningRuleKind,
+  pub value: String,
+  pub action: RegistrationScreeningAction,
+  /// Shown to admins in the application. A default reason is used if this is empty.
+  pub reason: Option<String>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_screening_rule))]
+pub struct RegistrationScreeningRuleInsertForm {
+  pub kind: RegistrationScreeningRuleKind,
+  pub value: String,
+  pub action: RegistrationScreeningAction,
+  #[new(default)]
+  pub reason: Option<String>,
+}
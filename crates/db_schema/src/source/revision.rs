@@ -0,0 +1,73 @@
+use crate::newtypes::{
+  CommentId,
+  CommentRevisionId,
+  PostId,
+  PostRevisionId,
+  PrivateMessageId,
+  PrivateMessageRevisionId,
+};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::{comment_revision, post_revision, private_message_revision};
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A previous version of an edited post.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = post_revision))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct PostRevision {
+  pub id: PostRevisionId,
+  pub post_id: PostId,
+  pub name: String,
+  pub body: Option<String>,
+  pub url: Option<DbUrl>,
+  /// When this version was replaced by an edit.
+  pub published_at: DateTime<Utc>,
+}
+
+/// A previous version of an edited comment.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = comment_revision))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct CommentRevision {
+  pub id: CommentRevisionId,
+  pub comment_id: CommentId,
+  pub content: String,
+  /// When this version was replaced by an edit.
+  pub published_at: DateTime<Utc>,
+}
+
+/// A previous version of an edited private message.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = private_message_revision))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct PrivateMessageRevision {
+  pub id: PrivateMessageRevisionId,
+  pub private_message_id: PrivateMessageId,
+  pub content: String,
+  /// When this version was replaced by an edit.
+  pub published_at: DateTime<Utc>,
+}
+
+/// One line of a diff between two versions of a text.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(tag = "type_", content = "line", rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+pub enum DiffLine {
+  Unchanged(String),
+  Removed(String),
+  Added(String),
+}
@@ -9,7 +9,8 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 /// A tag that is created by community moderators, and assigned to posts by the creator
-/// or by mods.
+/// or by mods. Tags marked as user flair are instead the flairs which users can pick for
+/// themselves in the community.
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
@@ -28,6 +29,13 @@ pub struct Tag {
   pub published_at: DateTime<Utc>,
   pub updated_at: Option<DateTime<Utc>>,
   pub deleted: bool,
+  /// A hex color like `#ff8800`, used as background when the tag is displayed.
+  pub color: Option<String>,
+  pub emoji: Option<String>,
+  /// Only mods can assign this tag to posts, or give this flair to users.
+  pub mod_only: bool,
+  /// The tag is a user flair option, and can't be assigned to posts.
+  pub user_flair: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -40,6 +48,10 @@ pub struct TagInsertForm {
   pub description: Option<String>,
   pub community_id: CommunityId,
   pub deleted: Option<bool>,
+  pub color: Option<String>,
+  pub emoji: Option<String>,
+  pub mod_only: Option<bool>,
+  pub user_flair: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -52,6 +64,9 @@ pub struct TagUpdateForm {
   pub published_at: Option<DateTime<Utc>>,
   pub updated_at: Option<Option<DateTime<Utc>>>,
   pub deleted: Option<bool>,
+  pub color: Option<Option<String>>,
+  pub emoji: Option<Option<String>>,
+  pub mod_only: Option<bool>,
 }
 
 /// We wrap this in a struct so we can implement FromSqlRow<Json> for it
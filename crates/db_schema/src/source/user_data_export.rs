@@ -0,0 +1,24 @@
+use crate::newtypes::{LocalUserId, UserDataExportId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::enums::DataExportStatus;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::user_data_export;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// An export of all data of a local user. The archive itself is only returned by the download
+/// endpoint.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = user_data_export))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct UserDataExport {
+  pub id: UserDataExportId,
+  pub local_user_id: LocalUserId,
+  pub status: DataExportStatus,
+  pub published_at: DateTime<Utc>,
+  pub completed_at: Option<DateTime<Utc>>,
+}
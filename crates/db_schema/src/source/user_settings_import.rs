@@ -0,0 +1,30 @@
+use crate::newtypes::{LocalUserId, UserSettingsImportId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::enums::{SettingsImportFormat, SettingsImportStatus};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::user_settings_import;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// An import of follows and blocks which runs in the background. The progress can be polled while
+/// it is pending.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = user_settings_import))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct UserSettingsImport {
+  pub id: UserSettingsImportId,
+  pub local_user_id: LocalUserId,
+  pub format: SettingsImportFormat,
+  pub status: SettingsImportStatus,
+  /// The number of communities, users and instances to follow or block.
+  pub total_items: i32,
+  pub processed_items: i32,
+  /// Urls or names of the items which couldn't be imported.
+  pub failed_items: Vec<String>,
+  pub published_at: DateTime<Utc>,
+  pub completed_at: Option<DateTime<Utc>>,
+}
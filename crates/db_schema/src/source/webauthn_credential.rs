@@ -0,0 +1,57 @@
+use crate::newtypes::{LocalUserId, WebauthnCredentialId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::{webauthn_challenge, webauthn_credential};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
+
+/// A passkey or security key of a user.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_credential))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct WebauthnCredential {
+  pub id: WebauthnCredentialId,
+  pub local_user_id: LocalUserId,
+  pub name: String,
+  pub credential_id: String,
+  /// The serialized public key, only used by the backend.
+  #[serde(skip)]
+  pub passkey: String,
+  pub last_used_at: Option<DateTime<Utc>>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_credential))]
+pub struct WebauthnCredentialInsertForm {
+  pub local_user_id: LocalUserId,
+  pub name: String,
+  pub credential_id: String,
+  pub passkey: String,
+}
+
+/// The server side state of a webauthn ceremony.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_challenge))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct WebauthnChallenge {
+  pub id: Uuid,
+  pub local_user_id: LocalUserId,
+  pub state: String,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_challenge))]
+pub struct WebauthnChallengeInsertForm {
+  pub local_user_id: LocalUserId,
+  pub state: String,
+}
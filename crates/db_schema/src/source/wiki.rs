@@ -0,0 +1,123 @@
+use crate::{
+  newtypes::{CommunityId, WikiPageId, WikiPageRevisionId},
+  source::person::Person,
+};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::{wiki_editor, wiki_page, wiki_page_revision};
+use lemmy_db_schema_file::{PersonId, enums::WikiEditPermission};
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A page of a community wiki, for FAQs, rules or resource lists.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = wiki_page))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct WikiPage {
+  pub id: WikiPageId,
+  pub community_id: CommunityId,
+  /// The path of the page within the wiki. Slashes separate the levels, like
+  /// `guides/getting-started`.
+  pub slug: String,
+  pub title: String,
+  /// The page content in markdown.
+  pub body: String,
+  pub edit_permission: WikiEditPermission,
+  pub ap_id: DbUrl,
+  pub local: bool,
+  pub deleted: bool,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = wiki_page))]
+pub struct WikiPageInsertForm {
+  pub community_id: CommunityId,
+  pub slug: String,
+  pub title: String,
+  pub body: String,
+  pub ap_id: DbUrl,
+  #[new(default)]
+  pub edit_permission: Option<WikiEditPermission>,
+  #[new(default)]
+  pub local: Option<bool>,
+  #[new(default)]
+  pub deleted: Option<bool>,
+  #[new(default)]
+  pub published_at: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = wiki_page))]
+pub struct WikiPageUpdateForm {
+  pub title: Option<String>,
+  pub body: Option<String>,
+  pub edit_permission: Option<WikiEditPermission>,
+  pub deleted: Option<bool>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
+
+/// A saved version of a wiki page. The newest revision has the current content of the page.
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = wiki_page_revision))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct WikiPageRevision {
+  pub id: WikiPageRevisionId,
+  pub wiki_page_id: WikiPageId,
+  pub creator_id: PersonId,
+  pub title: String,
+  pub body: String,
+  /// A short description of the change.
+  pub summary: Option<String>,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = wiki_page_revision))]
+pub struct WikiPageRevisionInsertForm {
+  pub wiki_page_id: WikiPageId,
+  pub creator_id: PersonId,
+  pub title: String,
+  pub body: String,
+  #[new(default)]
+  pub summary: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A wiki page revision, together with the user who made it.
+pub struct WikiPageRevisionView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub revision: WikiPageRevision,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub creator: Person,
+}
+
+/// A user who may edit the wiki pages of a community which aren't reserved for mods.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(table_name = wiki_editor))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct WikiEditor {
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  pub published_at: DateTime<Utc>,
+}
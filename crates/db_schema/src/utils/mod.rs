@@ -5,6 +5,7 @@ use lemmy_utils::{
   error::{LemmyErrorType, LemmyResult},
   settings::structs::Settings,
 };
+use sha2::{Digest, Sha256};
 use url::Url;
 
 const FETCH_LIMIT_DEFAULT: i64 = 20;
@@ -33,6 +34,12 @@ pub fn limit_fetch_check(limit: i64) -> LemmyResult<i64> {
   }
 }
 
+/// Secret tokens are only stored as SHA-256 hash, so that they can't be used if the database
+/// leaks. The hex encoding matches `encode(sha256(...), 'hex')` in Postgres.
+pub fn hash_token(token: &str) -> String {
+  format!("{:x}", Sha256::digest(token))
+}
+
 pub(crate) fn format_actor_url(
   name: &str,
   domain: &str,
@@ -9,15 +9,20 @@ use lemmy_db_schema_file::{
   aliases::my_instance_persons_actions,
   enums::{CommunityFollowerState, CommunityVisibility},
   schema::{
+    comment,
     community,
     community_actions,
+    content_filter,
     instance_actions,
     local_site,
     multi_community,
     multi_community_entry,
+    person,
     person_actions,
+    post,
   },
 };
+use lemmy_diesel_utils::utils::functions::{regex_match, regex_match_nullable};
 
 /// Hide all content from blocked communities and persons. Content from blocked instances is also
 /// hidden, unless the user followed the community explicitly.
@@ -61,3 +66,43 @@ pub fn filter_suggested_communities() -> _ {
       .select(multi_community_entry::community_id.assume_not_null()),
   )
 }
+
+/// Content filters which are not expired, and apply to the community of the post.
+#[diesel::dsl::auto_type]
+fn content_filter_applies() -> _ {
+  content_filter::expires_at
+    .is_null()
+    .or(content_filter::expires_at.gt(diesel::dsl::now))
+    .and(
+      content_filter::community_id
+        .is_null()
+        .or(content_filter::community_id.eq(post::community_id.nullable())),
+    )
+}
+
+/// Content filters which match the post and its creator, depending on the filter scope.
+#[diesel::dsl::auto_type]
+pub fn content_filter_matches_post() -> _ {
+  let applies: content_filter_applies = content_filter_applies();
+  applies.and(
+    content_filter::filter_titles
+      .and(regex_match(post::name, content_filter::pattern))
+      .or(
+        content_filter::filter_bodies
+          .and(regex_match_nullable(post::body, content_filter::pattern)),
+      )
+      .or(content_filter::filter_urls.and(regex_match_nullable(post::url, content_filter::pattern)))
+      .or(content_filter::filter_usernames.and(regex_match(person::name, content_filter::pattern))),
+  )
+}
+
+/// Content filters which match the comment and its creator, depending on the filter scope.
+#[diesel::dsl::auto_type]
+pub fn content_filter_matches_comment() -> _ {
+  let applies: content_filter_applies = content_filter_applies();
+  applies.and(
+    content_filter::filter_comments
+      .and(regex_match(comment::content, content_filter::pattern))
+      .or(content_filter::filter_usernames.and(regex_match(person::name, content_filter::pattern))),
+  )
+}
@@ -9,7 +9,7 @@ use diesel::{
   expression::SqlLiteral,
   helper_types::Nullable,
   query_source::AliasedField,
-  sql_types::{Json, Timestamptz},
+  sql_types::{Array, Integer, Json, Timestamptz},
 };
 use lemmy_db_schema_file::{
   aliases::{
@@ -21,9 +21,12 @@ use lemmy_db_schema_file::{
     creator_home_instance_actions,
     creator_local_instance_actions,
     creator_local_user,
+    crosspost,
+    crosspost_community,
     person1,
     person2,
   },
+  enums::CommunityVisibility,
   schema::{
     comment,
     community,
@@ -32,11 +35,15 @@ use lemmy_db_schema_file::{
     local_user,
     person,
     post,
+    post_gallery_item,
     post_tag,
+    registration_answer,
+    registration_application,
     tag,
   },
 };
 use lemmy_diesel_utils::utils::functions::{coalesce_2_nullable, coalesce_3_nullable};
+use strum::IntoEnumIterator;
 
 /// Checks that the creator_local_user is an admin.
 #[diesel::dsl::auto_type]
@@ -99,6 +106,21 @@ pub fn creator_ban_expires_from_community() -> _ {
     .nullable()
 }
 
+#[diesel::dsl::auto_type]
+/// The flair of the creator in the community of the post or comment.
+pub fn creator_community_flair() -> _ {
+  creator_community_actions
+    .field(community_actions::flair)
+    .nullable()
+}
+
+#[diesel::dsl::auto_type]
+pub fn creator_community_flair_emoji() -> _ {
+  creator_community_actions
+    .field(community_actions::flair_emoji)
+    .nullable()
+}
+
 #[diesel::dsl::auto_type]
 /// Checks to see if a creator is banned from the local instance.
 fn creator_local_banned() -> _ {
@@ -115,6 +137,15 @@ fn creator_local_ban_expires() -> _ {
     .nullable()
 }
 
+#[diesel::dsl::auto_type]
+/// Checks to see if a creator is quarantined on the local instance.
+pub fn creator_local_quarantined() -> _ {
+  creator_local_instance_actions
+    .field(instance_actions::quarantined_at)
+    .nullable()
+    .is_not_null()
+}
+
 #[diesel::dsl::auto_type]
 /// Checks to see if a creator is banned from their community's instance
 fn creator_community_instance_banned() -> _ {
@@ -279,6 +310,8 @@ pub fn comment_select_remove_deletes() -> _ {
     comment::unresolved_report_count,
     comment::federation_pending,
     comment::locked,
+    comment::quarantined,
+    comment::pinned,
   )
 }
 
@@ -337,6 +370,10 @@ pub fn post_select_remove_deletes() -> _ {
     post::federation_pending,
     post::embed_video_width,
     post::embed_video_height,
+    post::quarantined,
+    post::pending_approval,
+    post::original_post_id,
+    post::accepted_answer_id,
   )
 }
 
@@ -352,6 +389,77 @@ pub fn post_tags_fragment() -> _ {
     .single_value()
 }
 
+#[diesel::dsl::auto_type]
+/// Gets the images of a gallery post in order, each with its image details if they are known
+pub fn post_gallery_fragment() -> _ {
+  let sel: SqlLiteral<Json> = diesel::dsl::sql::<diesel::sql_types::Json>(
+    "json_agg(json_build_object('item', post_gallery_item, 'image_details', \
+      (SELECT d FROM image_details d WHERE d.link = post_gallery_item.url)) \
+      ORDER BY post_gallery_item.position)",
+  );
+  post_gallery_item::table
+    .select(sel)
+    .filter(post_gallery_item::post_id.eq(post::id))
+    .single_value()
+}
+
+#[diesel::dsl::auto_type]
+/// Gets the other copies of a post in different communities: posts of the same link, the original
+/// of a crosspost and other crossposts of the same original. Only copies which can be viewed
+/// without login are included.
+pub fn post_crossposts_fragment() -> _ {
+  let sel: SqlLiteral<Json> = diesel::dsl::sql::<diesel::sql_types::Json>(
+    "json_agg(json_build_object('post_id', crosspost.id, 'community', crosspost_community) \
+      ORDER BY crosspost.published_at)",
+  );
+  let visible: Vec<CommunityVisibility> = CommunityVisibility::iter()
+    .filter(CommunityVisibility::can_view_without_login)
+    .collect();
+  crosspost
+    .inner_join(
+      crosspost_community.on(
+        crosspost
+          .field(post::community_id)
+          .eq(crosspost_community.field(community::id)),
+      ),
+    )
+    .select(sel)
+    .filter(crosspost.field(post::id).ne(post::id))
+    .filter(
+      crosspost
+        .field(post::url)
+        .eq(post::url)
+        .or(post::original_post_id.eq(crosspost.field(post::id).nullable()))
+        .or(
+          crosspost
+            .field(post::original_post_id)
+            .eq(coalesce_2_nullable(
+              post::original_post_id,
+              post::id.nullable(),
+            )),
+        ),
+    )
+    .filter(crosspost.field(post::deleted).eq(false))
+    .filter(crosspost.field(post::removed).eq(false))
+    .filter(crosspost.field(post::federation_pending).eq(false))
+    .filter(crosspost.field(post::quarantined).eq(false))
+    .filter(crosspost.field(post::pending_approval).eq(false))
+    .filter(crosspost.field(post::scheduled_publish_time_at).is_null())
+    .filter(crosspost_community.field(community::deleted).eq(false))
+    .filter(crosspost_community.field(community::removed).eq(false))
+    .filter(
+      crosspost_community
+        .field(community::local_removed)
+        .eq(false),
+    )
+    .filter(
+      crosspost_community
+        .field(community::visibility)
+        .eq_any(visible),
+    )
+    .single_value()
+}
+
 #[diesel::dsl::auto_type]
 /// Gets the post tags available within a specific community
 pub fn community_post_tags_fragment() -> _ {
@@ -360,9 +468,42 @@ pub fn community_post_tags_fragment() -> _ {
     .select(sel)
     .filter(tag::community_id.eq(community::id))
     .filter(tag::deleted.eq(false))
+    .filter(tag::user_flair.eq(false))
     .single_value()
 }
 
+#[diesel::dsl::auto_type]
+/// Gets the user flairs which can be picked within a specific community
+pub fn community_user_flairs_fragment() -> _ {
+  let sel: SqlLiteral<Json> = diesel::dsl::sql::<diesel::sql_types::Json>("json_agg(tag.*)");
+  tag::table
+    .select(sel)
+    .filter(tag::community_id.eq(community::id))
+    .filter(tag::deleted.eq(false))
+    .filter(tag::user_flair.eq(true))
+    .single_value()
+}
+
+#[diesel::dsl::auto_type]
+/// Gets the questionnaire answers of a registration application
+pub fn registration_answers_fragment() -> _ {
+  let sel: SqlLiteral<Json> = diesel::dsl::sql::<diesel::sql_types::Json>(
+    "json_agg(registration_answer.* ORDER BY registration_answer.id)",
+  );
+  registration_answer::table
+    .select(sel)
+    .filter(registration_answer::registration_application_id.eq(registration_application::id))
+    .single_value()
+}
+
+pub type NoMatchedFiltersType = SqlLiteral<Array<Integer>>;
+
+/// Placeholder for the warn filters matching a post or comment. These depend on the user and are
+/// filled in after loading the views.
+pub fn no_matched_filters() -> NoMatchedFiltersType {
+  diesel::dsl::sql::<Array<Integer>>("'{}'::int[]")
+}
+
 /// The select for the person1 alias.
 pub fn person1_select() -> Person1AliasAllColumnsTuple {
   person1.fields(person::all_columns)
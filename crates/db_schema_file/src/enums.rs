@@ -1,7 +1,7 @@
 #[cfg(feature = "full")]
 use diesel_derive_enum::DbEnum;
 use serde::{Deserialize, Serialize};
-use strum::Display;
+use strum::{Display, EnumIter};
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
 #[serde(rename_all = "snake_case")]
@@ -89,6 +89,8 @@ pub enum RegistrationMode {
   Closed,
   /// Open, but pending approval of a registration application.
   RequireApplication,
+  /// Only users with a valid invite code can sign up.
+  InviteOnly,
   /// Open to all.
   #[default]
   Open,
@@ -115,7 +117,7 @@ pub enum PostListingMode {
   SmallCard,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash, EnumIter)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "full", derive(DbEnum))]
 #[cfg_attr(
@@ -152,6 +154,27 @@ impl CommunityVisibility {
   }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::CommunityPostingModeEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// Defines who can create posts in a community.
+pub enum CommunityPostingMode {
+  /// Everyone who can interact with the community.
+  #[default]
+  Open,
+  /// Everyone can submit posts, but they are only published once a moderator approves them.
+  RequireApproval,
+  /// Only moderators can post.
+  ModsOnly,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "full", derive(DbEnum))]
@@ -308,4 +331,364 @@ pub enum ModlogKind {
   ModRemovePost,
   ModTransferCommunity,
   ModLockComment,
+  AdminQuarantinePerson,
+  ModApprovePost,
+  ModResolveBanAppeal,
+  ModChangeModeratorPermissions,
+  AdminModeratorOverride,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::BanAppealDecisionEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// The outcome of a ban appeal.
+pub enum BanAppealDecision {
+  /// The ban stays as it is.
+  Upheld,
+  /// The ban stays, but with an earlier expiry.
+  Shortened,
+  /// The ban is removed.
+  Lifted,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::ModeratorPermissionEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// A single permission which can be granted to a community moderator. Moderators without an
+/// explicit list of permissions hold all of them.
+pub enum ModeratorPermission {
+  /// View and resolve reports.
+  HandleReports,
+  /// Remove, lock, feature, approve, pin and distinguish posts and comments, and mark accepted
+  /// answers.
+  RemoveContent,
+  /// Ban users from the community and resolve their ban appeals.
+  ManageBans,
+  /// Edit community settings, icon, banner and tags, manage recurring posts, and set the flair of
+  /// users.
+  EditSettings,
+  /// Edit the community wiki.
+  EditWiki,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::AdminPermissionEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// A single capability which can be granted to an admin. Admins without an explicit list of
+/// permissions hold all of them, and only those can manage other admins.
+pub enum AdminPermission {
+  /// View and approve or deny registration applications.
+  ReviewRegistrations,
+  /// Resolve site-wide reports, ban and quarantine users, create, transfer and remove
+  /// communities, and act as moderator in any community.
+  HandleReports,
+  /// Block and allow other instances.
+  ManageFederation,
+  /// Edit the site settings, taglines, custom emojis and OAuth providers.
+  EditSite,
+  /// Purge content and users, and manage uploaded media.
+  Purge,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::ApiTokenScopeEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// A scope which can be granted to a personal access token.
+pub enum ApiTokenScope {
+  /// Read anything the user can see.
+  Read,
+  /// Create, edit and delete posts, comments and private messages, and file reports.
+  Post,
+  /// Vote on posts and comments.
+  Vote,
+  /// Handle site-wide reports, for admins with the matching permission.
+  AdminReports,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::RegistrationQuestionKindEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// How a registration question is answered.
+pub enum RegistrationQuestionKind {
+  /// A free text answer.
+  #[default]
+  Text,
+  /// One of the given choices.
+  Choice,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::RegistrationScreeningRuleKindEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// What a registration screening rule is checked against.
+pub enum RegistrationScreeningRuleKind {
+  /// The domain of the email address, including its subdomains.
+  EmailDomain,
+  /// Any of the answers contains the value, ignoring case.
+  AnswerContains,
+  /// The IP address of the signup is in the given range, like `10.0.0.0/8`. Useful to flag known
+  /// VPN and hosting providers.
+  IpRange,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::RegistrationScreeningActionEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// What happens to a registration application which matches a screening rule. If rules with
+/// different actions match, deny wins over flag, and flag over approve.
+pub enum RegistrationScreeningAction {
+  /// Accept the application right away.
+  Approve,
+  /// Deny the application right away.
+  Deny,
+  /// Leave the application for the admins, with the reason shown.
+  Flag,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::DataExportStatusEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// The state of a user data export.
+pub enum DataExportStatus {
+  /// The archive is still being generated.
+  #[default]
+  Pending,
+  /// The archive is ready for download.
+  Complete,
+  /// Generating the archive failed, a new export has to be requested.
+  Failed,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::SettingsImportFormatEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// The format of a file with follows and blocks to import.
+pub enum SettingsImportFormat {
+  /// The settings backup exported by Lemmy.
+  Lemmy,
+  /// The `following_accounts.csv` exported by Mastodon.
+  MastodonFollows,
+  /// The `blocked_accounts.csv` exported by Mastodon.
+  MastodonBlocks,
+  /// The `blocked_domains.csv` exported by Mastodon.
+  MastodonDomainBlocks,
+  /// The json export of magazine subscriptions and blocks from mbin or kbin.
+  Mbin,
+  /// The settings export of Piefed.
+  Piefed,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::SettingsImportStatusEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// The state of a settings import.
+pub enum SettingsImportStatus {
+  /// The items are still being imported.
+  #[default]
+  Pending,
+  /// All items were processed, some of them may have failed.
+  Complete,
+  /// The import was aborted.
+  Failed,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::ContentFilterMatchEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// How the phrase of a content filter is matched. All matching is case insensitive.
+pub enum ContentFilterMatch {
+  /// Matches the phrase anywhere, also inside of words.
+  #[default]
+  Keyword,
+  /// Only matches the phrase as a whole word.
+  WholeWord,
+  /// The phrase is a regular expression.
+  Regex,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::ContentFilterActionEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// What happens to posts and comments which match a content filter.
+pub enum ContentFilterAction {
+  /// Don't show them at all.
+  #[default]
+  Hide,
+  /// Show them collapsed behind a warning.
+  Warn,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::EditHistoryVisibilityEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// Who can see the previous versions of edited posts, comments and private messages.
+pub enum EditHistoryVisibility {
+  /// Everyone who can see the content.
+  Public,
+  /// Only the creator, mods of the community and admins.
+  #[default]
+  ModsAndAdmins,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::DraftKindEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// What a draft turns into once it is published.
+pub enum DraftKind {
+  #[default]
+  Post,
+  Comment,
+  PrivateMessage,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::RecurringPostFrequencyEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// How often a recurring post is published.
+pub enum RecurringPostFrequency {
+  Daily,
+  #[default]
+  Weekly,
+  /// On the same day of every month, or the last day for shorter months.
+  Monthly,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::WikiEditPermissionEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// Who can edit a wiki page.
+pub enum WikiEditPermission {
+  /// Only mods with the wiki permission.
+  #[default]
+  Moderators,
+  /// Mods, and users who were approved as wiki editors of the community.
+  ApprovedEditors,
+}
+
+#[derive(Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::PostEventRsvpEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// A user's answer to an event post.
+pub enum PostEventRsvp {
+  Going,
+  Interested,
 }
@@ -23,6 +23,7 @@ use crate::{
     person_actions,
     post,
     post_actions,
+    post_event,
   },
 };
 use diesel::{BoolExpressionMethods, ExpressionMethods, JoinOnDsl, NullableExpressionMethods};
@@ -126,6 +127,11 @@ pub fn image_details_join() -> _ {
   image_details::table.on(post::thumbnail_url.eq(image_details::link.nullable()))
 }
 
+#[diesel::dsl::auto_type]
+pub fn post_event_join() -> _ {
+  post_event::table.on(post_event::post_id.eq(post::id))
+}
+
 #[diesel::dsl::auto_type]
 pub fn my_community_actions_join(my_person_id: Option<PersonId>) -> _ {
   community_actions::table.on(
@@ -13,8 +13,9 @@ pub mod table_impls;
 
 #[cfg(feature = "full")]
 pub mod aliases {
-  use crate::schema::{community_actions, instance_actions, local_user, person};
+  use crate::schema::{community, community_actions, instance_actions, local_user, person, post};
   diesel::alias!(
+    community as crosspost_community: CrosspostCommunity,
     community_actions as creator_community_actions: CreatorCommunityActions,
     instance_actions as creator_home_instance_actions: CreatorHomeInstanceActions,
     instance_actions as creator_community_instance_actions: CreatorCommunityInstanceActions,
@@ -23,6 +24,7 @@ pub mod aliases {
     local_user as creator_local_user: CreatorLocalUser,
     person as person1: Person1,
     person as person2: Person2,
+    post as crosspost: Crosspost,
   );
 }
 
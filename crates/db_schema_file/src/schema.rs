@@ -5,6 +5,18 @@ pub mod sql_types {
   #[diesel(postgres_type(name = "actor_type_enum"))]
   pub struct ActorTypeEnum;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "admin_permission_enum"))]
+  pub struct AdminPermissionEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "api_token_scope_enum"))]
+  pub struct ApiTokenScopeEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "ban_appeal_decision_enum"))]
+  pub struct BanAppealDecisionEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "comment_sort_type_enum"))]
   pub struct CommentSortTypeEnum;
@@ -17,10 +29,34 @@ pub mod sql_types {
   #[diesel(postgres_type(name = "community_notifications_mode_enum"))]
   pub struct CommunityNotificationsModeEnum;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "community_posting_mode_enum"))]
+  pub struct CommunityPostingModeEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "community_visibility"))]
   pub struct CommunityVisibility;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "content_filter_action_enum"))]
+  pub struct ContentFilterActionEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "content_filter_match_enum"))]
+  pub struct ContentFilterMatchEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "data_export_status_enum"))]
+  pub struct DataExportStatusEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "draft_kind_enum"))]
+  pub struct DraftKindEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "edit_history_visibility_enum"))]
+  pub struct EditHistoryVisibilityEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "federation_mode_enum"))]
   pub struct FederationModeEnum;
@@ -33,6 +69,10 @@ pub mod sql_types {
   #[diesel(postgres_type(name = "ltree"))]
   pub struct Ltree;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "moderator_permission_enum"))]
+  pub struct ModeratorPermissionEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "modlog_kind"))]
   pub struct ModlogKind;
@@ -41,6 +81,10 @@ pub mod sql_types {
   #[diesel(postgres_type(name = "notification_type_enum"))]
   pub struct NotificationTypeEnum;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "post_event_rsvp_enum"))]
+  pub struct PostEventRsvpEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "post_listing_mode_enum"))]
   pub struct PostListingModeEnum;
@@ -53,13 +97,71 @@ pub mod sql_types {
   #[diesel(postgres_type(name = "post_sort_type_enum"))]
   pub struct PostSortTypeEnum;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "recurring_post_frequency_enum"))]
+  pub struct RecurringPostFrequencyEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "registration_mode_enum"))]
   pub struct RegistrationModeEnum;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "registration_question_kind_enum"))]
+  pub struct RegistrationQuestionKindEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "registration_screening_action_enum"))]
+  pub struct RegistrationScreeningActionEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "registration_screening_rule_kind_enum"))]
+  pub struct RegistrationScreeningRuleKindEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "settings_import_format_enum"))]
+  pub struct SettingsImportFormatEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "settings_import_status_enum"))]
+  pub struct SettingsImportStatusEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "vote_show_enum"))]
   pub struct VoteShowEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "wiki_edit_permission_enum"))]
+  pub struct WikiEditPermissionEnum;
+}
+
+diesel::table! {
+    admin_audit_log (id) {
+        id -> Int4,
+        admin_id -> Int4,
+        action -> Text,
+        target_id -> Nullable<Int4>,
+        parameters -> Nullable<Text>,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::BanAppealDecisionEnum;
+
+    ban_appeal (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        community_id -> Nullable<Int4>,
+        ban_id -> Int4,
+        reason -> Text,
+        ap_id -> Nullable<Text>,
+        resolved -> Bool,
+        resolver_id -> Nullable<Int4>,
+        decision -> Nullable<BanAppealDecisionEnum>,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
 }
 
 diesel::table! {
@@ -99,6 +201,8 @@ diesel::table! {
         unresolved_report_count -> Int2,
         federation_pending -> Bool,
         locked -> Bool,
+        quarantined -> Bool,
+        pinned -> Bool,
     }
 }
 
@@ -127,8 +231,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    comment_revision (id) {
+        id -> Int4,
+        comment_id -> Int4,
+        content -> Text,
+        published_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
+    use super::sql_types::CommunityPostingModeEnum;
     use super::sql_types::CommunityVisibility;
 
     community (id) {
@@ -155,7 +269,6 @@ diesel::table! {
         followers_url -> Nullable<Varchar>,
         #[max_length = 255]
         inbox_url -> Varchar,
-        posting_restricted_to_mods -> Bool,
         instance_id -> Int4,
         #[max_length = 255]
         moderators_url -> Nullable<Varchar>,
@@ -178,6 +291,7 @@ diesel::table! {
         report_count -> Int2,
         unresolved_report_count -> Int2,
         local_removed -> Bool,
+        posting_mode -> CommunityPostingModeEnum,
     }
 }
 
@@ -185,6 +299,7 @@ diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::CommunityFollowerState;
     use super::sql_types::CommunityNotificationsModeEnum;
+    use super::sql_types::ModeratorPermissionEnum;
 
     community_actions (person_id, community_id) {
         followed_at -> Nullable<Timestamptz>,
@@ -197,6 +312,11 @@ diesel::table! {
         follow_state -> Nullable<CommunityFollowerState>,
         follow_approver_id -> Nullable<Int4>,
         notifications -> Nullable<CommunityNotificationsModeEnum>,
+        moderator_permissions -> Nullable<Array<ModeratorPermissionEnum>>,
+        #[max_length = 50]
+        flair -> Nullable<Varchar>,
+        #[max_length = 16]
+        flair_emoji -> Nullable<Varchar>,
     }
 }
 
@@ -234,6 +354,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ContentFilterMatchEnum;
+    use super::sql_types::ContentFilterActionEnum;
+
+    content_filter (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        #[max_length = 200]
+        phrase -> Varchar,
+        match_type -> ContentFilterMatchEnum,
+        pattern -> Text,
+        action -> ContentFilterActionEnum,
+        filter_titles -> Bool,
+        filter_bodies -> Bool,
+        filter_comments -> Bool,
+        filter_usernames -> Bool,
+        filter_urls -> Bool,
+        community_id -> Nullable<Int4>,
+        expires_at -> Nullable<Timestamptz>,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     custom_emoji (id) {
         id -> Int4,
@@ -255,6 +400,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::DraftKindEnum;
+
+    draft (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        kind -> DraftKindEnum,
+        community_id -> Nullable<Int4>,
+        post_id -> Nullable<Int4>,
+        parent_comment_id -> Nullable<Int4>,
+        recipient_id -> Nullable<Int4>,
+        name -> Nullable<Text>,
+        url -> Nullable<Text>,
+        alt_text -> Nullable<Text>,
+        custom_thumbnail -> Nullable<Text>,
+        body -> Nullable<Text>,
+        nsfw -> Nullable<Bool>,
+        language_id -> Nullable<Int4>,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     email_verification (id) {
         id -> Int4,
@@ -325,6 +494,20 @@ diesel::table! {
         received_ban_at -> Nullable<Timestamptz>,
         ban_expires_at -> Nullable<Timestamptz>,
         blocked_persons_at -> Nullable<Timestamptz>,
+        quarantined_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    invite_code (id) {
+        id -> Int4,
+        code -> Text,
+        creator_id -> Int4,
+        max_uses -> Nullable<Int4>,
+        uses -> Int4,
+        expires_at -> Nullable<Timestamptz>,
+        community_ids -> Array<Int4>,
+        published_at -> Timestamptz,
     }
 }
 
@@ -354,6 +537,7 @@ diesel::table! {
     use super::sql_types::PostSortTypeEnum;
     use super::sql_types::CommentSortTypeEnum;
     use super::sql_types::FederationModeEnum;
+    use super::sql_types::EditHistoryVisibilityEnum;
 
     local_site (id) {
         id -> Int4,
@@ -399,6 +583,22 @@ diesel::table! {
         suggested_communities -> Nullable<Int4>,
         system_account -> Int4,
         default_items_per_page -> Int4,
+        session_idle_timeout_seconds -> Nullable<Int4>,
+        invite_min_account_age_days -> Nullable<Int4>,
+        email_domain_allowlist_only -> Bool,
+        block_disposable_email_domains -> Bool,
+        account_deletion_grace_period_days -> Nullable<Int4>,
+        edit_history_visibility -> EditHistoryVisibilityEnum,
+        edit_history_retention_days -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    local_site_email_domain (id) {
+        id -> Int4,
+        domain -> Text,
+        allowed -> Bool,
+        published_at -> Timestamptz,
     }
 }
 
@@ -440,6 +640,7 @@ diesel::table! {
     use super::sql_types::PostListingModeEnum;
     use super::sql_types::CommentSortTypeEnum;
     use super::sql_types::VoteShowEnum;
+    use super::sql_types::AdminPermissionEnum;
 
     local_user (id) {
         id -> Int4,
@@ -479,6 +680,11 @@ diesel::table! {
         show_upvote_percentage -> Bool,
         show_person_votes -> Bool,
         default_items_per_page -> Int4,
+        admin_permissions -> Nullable<Array<AdminPermissionEnum>>,
+        invited_by_id -> Nullable<Int4>,
+        deletion_scheduled_at -> Nullable<Timestamptz>,
+        deletion_delete_content -> Bool,
+        collapse_crossposts -> Bool,
     }
 }
 
@@ -504,6 +710,10 @@ diesel::table! {
         published_at -> Timestamptz,
         ip -> Nullable<Text>,
         user_agent -> Nullable<Text>,
+        id -> Int4,
+        refresh_token_hash -> Nullable<Text>,
+        last_used_at -> Timestamptz,
+        expires_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -599,6 +809,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    oauth_application (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        name -> Text,
+        description -> Nullable<Text>,
+        client_id -> Text,
+        client_secret_hash -> Nullable<Text>,
+        redirect_uris -> Array<Text>,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    oauth_authorization_code (code_hash) {
+        code_hash -> Text,
+        application_id -> Int4,
+        local_user_id -> Int4,
+        redirect_uri -> Text,
+        scope -> Text,
+        code_challenge -> Nullable<Text>,
+        expires_at -> Timestamptz,
+        published_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     oauth_provider (id) {
         id -> Int4,
@@ -620,6 +856,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    oauth_refresh_token (token_hash) {
+        token_hash -> Text,
+        application_id -> Int4,
+        local_user_id -> Int4,
+        scope -> Text,
+        expires_at -> Timestamptz,
+        published_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     password_reset_request (id) {
         id -> Int4,
@@ -629,6 +876,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    pending_post_activity (post_id) {
+        post_id -> Int4,
+        data -> Json,
+        published_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     person (id) {
         id -> Int4,
@@ -705,6 +960,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ApiTokenScopeEnum;
+
+    personal_access_token (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        name -> Text,
+        token_hash -> Text,
+        scopes -> Array<ApiTokenScopeEnum>,
+        moderate_community_ids -> Array<Int4>,
+        expires_at -> Nullable<Timestamptz>,
+        last_used_at -> Nullable<Timestamptz>,
+        published_at -> Timestamptz,
+        oauth_application_id -> Nullable<Int4>,
+    }
+}
+
 diesel::table! {
     post (id) {
         id -> Int4,
@@ -749,12 +1022,17 @@ diesel::table! {
         federation_pending -> Bool,
         embed_video_width -> Nullable<Int4>,
         embed_video_height -> Nullable<Int4>,
+        quarantined -> Bool,
+        pending_approval -> Bool,
+        original_post_id -> Nullable<Int4>,
+        accepted_answer_id -> Nullable<Int4>,
     }
 }
 
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::PostNotificationsModeEnum;
+    use super::sql_types::PostEventRsvpEnum;
 
     post_actions (person_id, post_id) {
         read_at -> Nullable<Timestamptz>,
@@ -767,6 +1045,32 @@ diesel::table! {
         read_comments_amount -> Nullable<Int4>,
         vote_is_upvote -> Nullable<Bool>,
         notifications -> Nullable<PostNotificationsModeEnum>,
+        event_rsvp -> Nullable<PostEventRsvpEnum>,
+        event_rsvp_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    post_event (post_id) {
+        post_id -> Int4,
+        start_at -> Timestamptz,
+        end_at -> Timestamptz,
+        #[max_length = 50]
+        timezone -> Varchar,
+        location -> Nullable<Text>,
+        online_url -> Nullable<Text>,
+        going_count -> Int4,
+        interested_count -> Int4,
+    }
+}
+
+diesel::table! {
+    post_gallery_item (id) {
+        id -> Int4,
+        post_id -> Int4,
+        url -> Text,
+        alt_text -> Nullable<Text>,
+        position -> Int4,
     }
 }
 
@@ -788,6 +1092,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    post_revision (id) {
+        id -> Int4,
+        post_id -> Int4,
+        name -> Text,
+        body -> Nullable<Text>,
+        url -> Nullable<Text>,
+        published_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     post_tag (post_id, tag_id) {
         post_id -> Int4,
@@ -826,6 +1141,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    private_message_revision (id) {
+        id -> Int4,
+        private_message_id -> Int4,
+        content -> Text,
+        published_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     received_activity (ap_id) {
         ap_id -> Text,
@@ -834,6 +1158,51 @@ diesel::table! {
 }
 
 diesel::table! {
+    recovery_code (local_user_id, code_hash) {
+        local_user_id -> Int4,
+        code_hash -> Text,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RecurringPostFrequencyEnum;
+
+    recurring_post (id) {
+        id -> Int4,
+        community_id -> Int4,
+        creator_id -> Int4,
+        #[max_length = 200]
+        name_template -> Varchar,
+        body -> Nullable<Text>,
+        nsfw -> Bool,
+        language_id -> Nullable<Int4>,
+        frequency -> RecurringPostFrequencyEnum,
+        schedule_start_at -> Timestamptz,
+        next_publish_at -> Timestamptz,
+        feature_latest -> Bool,
+        lock_previous -> Bool,
+        last_post_id -> Nullable<Int4>,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    registration_answer (id) {
+        id -> Int4,
+        registration_application_id -> Int4,
+        question_id -> Nullable<Int4>,
+        question -> Text,
+        answer -> Text,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RegistrationScreeningActionEnum;
+
     registration_application (id) {
         id -> Int4,
         local_user_id -> Int4,
@@ -842,6 +1211,39 @@ diesel::table! {
         deny_reason -> Nullable<Text>,
         published_at -> Timestamptz,
         updated_at -> Nullable<Timestamptz>,
+        screening_action -> Nullable<RegistrationScreeningActionEnum>,
+        screening_reasons -> Array<Text>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RegistrationQuestionKindEnum;
+
+    registration_question (id) {
+        id -> Int4,
+        question -> Text,
+        kind -> RegistrationQuestionKindEnum,
+        choices -> Array<Text>,
+        required -> Bool,
+        display_order -> Int4,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RegistrationScreeningActionEnum;
+    use super::sql_types::RegistrationScreeningRuleKindEnum;
+
+    registration_screening_rule (id) {
+        id -> Int4,
+        kind -> RegistrationScreeningRuleKindEnum,
+        value -> Text,
+        action -> RegistrationScreeningActionEnum,
+        reason -> Nullable<Text>,
+        published_at -> Timestamptz,
     }
 }
 
@@ -860,6 +1262,7 @@ diesel::table! {
         comment_report_id -> Nullable<Int4>,
         private_message_report_id -> Nullable<Int4>,
         community_report_id -> Nullable<Int4>,
+        ban_appeal_id -> Nullable<Int4>,
     }
 }
 
@@ -945,6 +1348,12 @@ diesel::table! {
         published_at -> Timestamptz,
         updated_at -> Nullable<Timestamptz>,
         deleted -> Bool,
+        #[max_length = 7]
+        color -> Nullable<Varchar>,
+        #[max_length = 16]
+        emoji -> Nullable<Varchar>,
+        mod_only -> Bool,
+        user_flair -> Bool,
     }
 }
 
@@ -957,24 +1366,132 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::DataExportStatusEnum;
+
+    user_data_export (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        status -> DataExportStatusEnum,
+        published_at -> Timestamptz,
+        completed_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::SettingsImportFormatEnum;
+    use super::sql_types::SettingsImportStatusEnum;
+
+    user_settings_import (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        format -> SettingsImportFormatEnum,
+        status -> SettingsImportStatusEnum,
+        total_items -> Int4,
+        processed_items -> Int4,
+        failed_items -> Array<Text>,
+        published_at -> Timestamptz,
+        completed_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    webauthn_challenge (id) {
+        id -> Uuid,
+        local_user_id -> Int4,
+        state -> Text,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    webauthn_credential (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        name -> Text,
+        credential_id -> Text,
+        passkey -> Text,
+        last_used_at -> Nullable<Timestamptz>,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    wiki_editor (community_id, person_id) {
+        community_id -> Int4,
+        person_id -> Int4,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WikiEditPermissionEnum;
+
+    wiki_page (id) {
+        id -> Int4,
+        community_id -> Int4,
+        #[max_length = 100]
+        slug -> Varchar,
+        #[max_length = 200]
+        title -> Varchar,
+        body -> Text,
+        edit_permission -> WikiEditPermissionEnum,
+        ap_id -> Text,
+        local -> Bool,
+        deleted -> Bool,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    wiki_page_revision (id) {
+        id -> Int4,
+        wiki_page_id -> Int4,
+        creator_id -> Int4,
+        #[max_length = 200]
+        title -> Varchar,
+        body -> Text,
+        #[max_length = 200]
+        summary -> Nullable<Varchar>,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::joinable!(admin_audit_log -> person (admin_id));
+diesel::joinable!(ban_appeal -> community (community_id));
+diesel::joinable!(ban_appeal -> modlog (ban_id));
 diesel::joinable!(comment -> language (language_id));
 diesel::joinable!(comment -> person (creator_id));
 diesel::joinable!(comment -> post (post_id));
 diesel::joinable!(comment_actions -> comment (comment_id));
 diesel::joinable!(comment_actions -> person (person_id));
 diesel::joinable!(comment_report -> comment (comment_id));
+diesel::joinable!(comment_revision -> comment (comment_id));
 diesel::joinable!(community -> instance (instance_id));
 diesel::joinable!(community_actions -> community (community_id));
 diesel::joinable!(community_language -> community (community_id));
 diesel::joinable!(community_language -> language (language_id));
 diesel::joinable!(community_report -> community (community_id));
+diesel::joinable!(content_filter -> community (community_id));
+diesel::joinable!(content_filter -> local_user (local_user_id));
 diesel::joinable!(custom_emoji_keyword -> custom_emoji (custom_emoji_id));
+diesel::joinable!(draft -> comment (parent_comment_id));
+diesel::joinable!(draft -> community (community_id));
+diesel::joinable!(draft -> language (language_id));
+diesel::joinable!(draft -> local_user (local_user_id));
+diesel::joinable!(draft -> person (recipient_id));
+diesel::joinable!(draft -> post (post_id));
 diesel::joinable!(email_verification -> local_user (local_user_id));
 diesel::joinable!(federation_allowlist -> instance (instance_id));
 diesel::joinable!(federation_blocklist -> instance (instance_id));
 diesel::joinable!(federation_queue_state -> instance (instance_id));
 diesel::joinable!(instance_actions -> instance (instance_id));
 diesel::joinable!(instance_actions -> person (person_id));
+diesel::joinable!(invite_code -> local_user (creator_id));
 diesel::joinable!(local_image -> person (person_id));
 diesel::joinable!(local_image -> post (thumbnail_for_post_id));
 diesel::joinable!(local_site -> multi_community (suggested_communities));
@@ -999,7 +1516,13 @@ diesel::joinable!(notification -> post (post_id));
 diesel::joinable!(notification -> private_message (private_message_id));
 diesel::joinable!(oauth_account -> local_user (local_user_id));
 diesel::joinable!(oauth_account -> oauth_provider (oauth_provider_id));
+diesel::joinable!(oauth_application -> local_user (local_user_id));
+diesel::joinable!(oauth_authorization_code -> local_user (local_user_id));
+diesel::joinable!(oauth_authorization_code -> oauth_application (application_id));
+diesel::joinable!(oauth_refresh_token -> local_user (local_user_id));
+diesel::joinable!(oauth_refresh_token -> oauth_application (application_id));
 diesel::joinable!(password_reset_request -> local_user (local_user_id));
+diesel::joinable!(pending_post_activity -> post (post_id));
 diesel::joinable!(person -> instance (instance_id));
 diesel::joinable!(person_content_combined -> comment (comment_id));
 diesel::joinable!(person_content_combined -> post (post_id));
@@ -1009,17 +1532,31 @@ diesel::joinable!(person_liked_combined -> post (post_id));
 diesel::joinable!(person_saved_combined -> comment (comment_id));
 diesel::joinable!(person_saved_combined -> person (person_id));
 diesel::joinable!(person_saved_combined -> post (post_id));
+diesel::joinable!(personal_access_token -> local_user (local_user_id));
+diesel::joinable!(personal_access_token -> oauth_application (oauth_application_id));
 diesel::joinable!(post -> community (community_id));
 diesel::joinable!(post -> language (language_id));
 diesel::joinable!(post -> person (creator_id));
 diesel::joinable!(post_actions -> person (person_id));
 diesel::joinable!(post_actions -> post (post_id));
+diesel::joinable!(post_event -> post (post_id));
+diesel::joinable!(post_gallery_item -> post (post_id));
 diesel::joinable!(post_report -> post (post_id));
+diesel::joinable!(post_revision -> post (post_id));
 diesel::joinable!(post_tag -> post (post_id));
 diesel::joinable!(post_tag -> tag (tag_id));
 diesel::joinable!(private_message_report -> private_message (private_message_id));
+diesel::joinable!(private_message_revision -> private_message (private_message_id));
+diesel::joinable!(recovery_code -> local_user (local_user_id));
+diesel::joinable!(recurring_post -> community (community_id));
+diesel::joinable!(recurring_post -> language (language_id));
+diesel::joinable!(recurring_post -> person (creator_id));
+diesel::joinable!(recurring_post -> post (last_post_id));
+diesel::joinable!(registration_answer -> registration_application (registration_application_id));
+diesel::joinable!(registration_answer -> registration_question (question_id));
 diesel::joinable!(registration_application -> local_user (local_user_id));
 diesel::joinable!(registration_application -> person (admin_id));
+diesel::joinable!(report_combined -> ban_appeal (ban_appeal_id));
 diesel::joinable!(report_combined -> comment_report (comment_report_id));
 diesel::joinable!(report_combined -> community_report (community_report_id));
 diesel::joinable!(report_combined -> post_report (post_report_id));
@@ -1033,24 +1570,40 @@ diesel::joinable!(site -> instance (instance_id));
 diesel::joinable!(site_language -> language (language_id));
 diesel::joinable!(site_language -> site (site_id));
 diesel::joinable!(tag -> community (community_id));
+diesel::joinable!(user_data_export -> local_user (local_user_id));
+diesel::joinable!(user_settings_import -> local_user (local_user_id));
+diesel::joinable!(webauthn_challenge -> local_user (local_user_id));
+diesel::joinable!(webauthn_credential -> local_user (local_user_id));
+diesel::joinable!(wiki_editor -> community (community_id));
+diesel::joinable!(wiki_editor -> person (person_id));
+diesel::joinable!(wiki_page -> community (community_id));
+diesel::joinable!(wiki_page_revision -> person (creator_id));
+diesel::joinable!(wiki_page_revision -> wiki_page (wiki_page_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+  admin_audit_log,
+  ban_appeal,
   comment,
   comment_actions,
   comment_report,
+  comment_revision,
   community,
   community_actions,
   community_language,
   community_report,
+  content_filter,
+  draft,
   email_verification,
   federation_allowlist,
   federation_blocklist,
   federation_queue_state,
   instance,
   instance_actions,
+  invite_code,
   language,
   local_image,
   local_site,
+  local_site_email_domain,
   local_site_rate_limit,
   local_user,
   local_user_keyword_block,
@@ -1062,24 +1615,45 @@ diesel::allow_tables_to_appear_in_same_query!(
   multi_community_follow,
   notification,
   oauth_account,
+  oauth_application,
+  oauth_authorization_code,
   oauth_provider,
+  oauth_refresh_token,
   password_reset_request,
+  pending_post_activity,
   person,
   person_content_combined,
   person_liked_combined,
   person_saved_combined,
+  personal_access_token,
   post,
   post_actions,
+  post_event,
+  post_gallery_item,
   post_report,
+  post_revision,
   post_tag,
   private_message,
   private_message_report,
+  private_message_revision,
+  recovery_code,
+  recurring_post,
+  registration_answer,
   registration_application,
+  registration_question,
+  registration_screening_rule,
   report_combined,
   search_combined,
   site,
   site_language,
   tag,
+  user_data_export,
+  user_settings_import,
+  webauthn_challenge,
+  webauthn_credential,
+  wiki_editor,
+  wiki_page,
+  wiki_page_revision,
   person_actions,
   image_details,
 );
@@ -1,10 +1,26 @@
 use crate::CommentView;
-use lemmy_db_schema::newtypes::{CommentId, CommunityId, LanguageId, PostId};
+use lemmy_db_schema::{
+  newtypes::{CommentId, CommunityId, LanguageId, PostId},
+  source::revision::{CommentRevision, DiffLine},
+};
 use lemmy_db_schema_file::enums::{CommentSortType, ListingType};
 use lemmy_diesel_utils::pagination::PaginationCursor;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Approve or deny a comment which is held for review because its creator is quarantined.
+///
+/// Approving publishes and federates the comment, denying removes it.
+pub struct ApproveQuarantinedComment {
+  pub comment_id: CommentId,
+  pub approve: bool,
+  pub deny_reason: Option<String>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -56,6 +72,16 @@ pub struct DistinguishComment {
   pub distinguished: bool,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Pin a comment, so that it is shown before the other comments (only for mods).
+pub struct PinComment {
+  pub comment_id: CommentId,
+  pub pinned: bool,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -83,6 +109,54 @@ pub struct GetComments {
   pub community_name: Option<String>,
   pub post_id: Option<PostId>,
   pub parent_id: Option<CommentId>,
+  /// If true, then only show comments which are held for review because their creator is
+  /// quarantined. Mods see those of their communities, admins see all of them.
+  pub quarantined_only: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Get the comments of a post, or the replies to a comment, as a nested tree.
+///
+/// Either `post_id` or `parent_id` is required. The cursor pages through the top level of the
+/// tree.
+pub struct GetCommentTree {
+  pub post_id: Option<PostId>,
+  pub parent_id: Option<CommentId>,
+  pub sort: Option<CommentSortType>,
+  /// How many levels of replies are included below the top level, at most 10.
+  pub max_depth: Option<i32>,
+  /// How many replies are included for each comment.
+  pub max_breadth: Option<i64>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the edit history of a comment.
+pub struct ListCommentRevisions {
+  pub comment_id: CommentId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A previous version of a comment, with the changes made by the edit which replaced it.
+pub struct CommentRevisionDiff {
+  pub revision: CommentRevision,
+  pub content_diff: Vec<DiffLine>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The edit history of a comment, oldest first.
+pub struct ListCommentRevisionsResponse {
+  pub revisions: Vec<CommentRevisionDiff>,
 }
 
 #[skip_serializing_none]
@@ -1,4 +1,4 @@
-use crate::{CommentSlimView, CommentView};
+use crate::{CommentSlimView, CommentTreeNode, CommentView};
 use diesel::{
   BoolExpressionMethods,
   ExpressionMethods,
@@ -6,22 +6,28 @@ use diesel::{
   NullableExpressionMethods,
   QueryDsl,
   SelectableHelper,
-  dsl::exists,
+  dsl::{exists, not},
 };
-use diesel_async::RunQueryDsl;
-use diesel_ltree::{Ltree, LtreeExtensions, nlevel};
-use i_love_jesus::asc_if;
+use diesel_async::{AsyncPgConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
+use diesel_ltree::{Ltree, LtreeExtensions, nlevel, subpath};
+use i_love_jesus::{PaginatedQueryBuilder, asc_if};
 use lemmy_db_schema::{
   impls::local_user::LocalUserOptionHelper,
-  newtypes::{CommentId, CommunityId, PostId},
+  newtypes::{CommentId, CommunityId, ContentFilterId, LocalUserId, PostId},
   source::{
     comment::{Comment, comment_keys as key},
+    content_filter::ContentFilter,
     local_user::LocalUser,
     site::Site,
   },
   utils::{
+    FETCH_LIMIT_MAX,
     limit_fetch,
-    queries::filters::{filter_blocked, filter_suggested_communities},
+    queries::filters::{
+      content_filter_matches_comment,
+      filter_blocked,
+      filter_suggested_communities,
+    },
   },
 };
 use lemmy_db_schema_file::{
@@ -31,6 +37,7 @@ use lemmy_db_schema_file::{
     CommentSortType::{self, *},
     CommunityFollowerState,
     CommunityVisibility,
+    ContentFilterAction,
     ListingType,
   },
   joins::{
@@ -45,7 +52,15 @@ use lemmy_db_schema_file::{
     my_local_user_admin_join,
     my_person_actions_join,
   },
-  schema::{comment, community, community_actions, local_user_language, person, post},
+  schema::{
+    comment,
+    community,
+    community_actions,
+    content_filter,
+    local_user_language,
+    person,
+    post,
+  },
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -57,9 +72,10 @@ use lemmy_diesel_utils::{
     paginate_response,
   },
   traits::Crud,
-  utils::{Subpath, now, seconds_to_pg_interval},
+  utils::{NotKey, Subpath, now, seconds_to_pg_interval},
 };
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use std::collections::{HashMap, HashSet};
 
 impl PaginationCursorConversion for CommentView {
   type PaginatedType = Comment;
@@ -128,11 +144,19 @@ impl CommentView {
     // content, otherwise it is filtered out. Admins can view private community content
     // without restriction.
     if !my_local_user.is_admin() {
-      query = query.filter(
-        community::visibility
-          .ne(CommunityVisibility::Private)
-          .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
-      );
+      query = query
+        .filter(
+          community::visibility
+            .ne(CommunityVisibility::Private)
+            .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
+        )
+        // Quarantined comments are only visible to the creator and to mods
+        .filter(
+          comment::quarantined
+            .eq(false)
+            .or(comment::creator_id.nullable().eq(my_local_user.person_id()))
+            .or(community_actions::became_moderator_at.is_not_null()),
+        );
     }
 
     query
@@ -152,6 +176,9 @@ impl CommentView {
       creator_banned: self.creator_banned,
       creator_banned_from_community: self.creator_banned_from_community,
       creator_is_moderator: self.creator_is_moderator,
+      creator_flair: self.creator_flair,
+      creator_flair_emoji: self.creator_flair_emoji,
+      matched_filters: self.matched_filters,
     }
   }
 }
@@ -164,8 +191,15 @@ pub struct CommentQuery<'a> {
   pub community_id: Option<CommunityId>,
   pub post_id: Option<PostId>,
   pub parent_path: Option<Ltree>,
+  /// Only list direct replies to the comments with these paths. The path `0` stands for the
+  /// top-level comments of a post.
+  pub reply_to_paths: Option<Vec<Ltree>>,
+  /// Only list this many replies to each of the `reply_to_paths`.
+  pub max_replies_per_parent: Option<i64>,
   pub local_user: Option<&'a LocalUser>,
   pub max_depth: Option<i32>,
+  /// Only list comments which are held back for review because their creator is quarantined.
+  pub quarantined_only: Option<bool>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
 }
@@ -181,97 +215,139 @@ impl CommentQuery<'_> {
     // The left join below will return None in this case
     let my_person_id = o.local_user.person_id();
     let local_user_id = o.local_user.local_user_id();
-
-    let mut query = CommentView::joins(my_person_id, site.instance_id)
-      .select(CommentView::as_select())
-      .into_boxed();
-
-    if let Some(post_id) = o.post_id {
-      query = query.filter(comment::post_id.eq(post_id));
+    // Matching the content filters is expensive, so it is skipped for users without filters
+    let filter_actions = match local_user_id {
+      Some(local_user_id) => ContentFilter::list_active_actions(pool, local_user_id).await?,
+      None => vec![],
     };
 
-    if let Some(parent_path) = o.parent_path.as_ref() {
-      query = query.filter(comment::path.contained_by(parent_path));
+    // A Max depth given means its a tree fetch
+    let depth_limit = if let Some(max_depth) = o.max_depth {
+      if let Some(parent_path) = o.parent_path.as_ref() {
+        let count: i32 = parent_path.0.split('.').count().try_into()?;
+        Some(count + max_depth)
+      } else {
+        // Add one because of root "0"
+        Some(max_depth + 1)
+      }
+    } else {
+      None
     };
 
-    if let Some(community_id) = o.community_id {
-      query = query.filter(post::community_id.eq(community_id));
-    }
+    // All the filters of the listing, so that the per-parent window below ranks the same rows
+    let filtered_query = || {
+      let mut query = CommentView::joins(my_person_id, site.instance_id).into_boxed();
+
+      if let Some(post_id) = o.post_id {
+        query = query.filter(comment::post_id.eq(post_id));
+      };
 
-    let is_subscribed = community_actions::followed_at.is_not_null();
+      if let Some(parent_path) = o.parent_path.as_ref() {
+        query = query.filter(comment::path.contained_by(parent_path));
+      };
 
-    // For posts, we only show hidden if its subscribed, but for comments,
-    // we ignore hidden.
-    query = match o.listing_type.unwrap_or_default() {
-      ListingType::Subscribed => query.filter(is_subscribed),
-      ListingType::Local => query.filter(community::local.eq(true)),
-      ListingType::All => query,
-      ListingType::ModeratorView => {
-        query.filter(community_actions::became_moderator_at.is_not_null())
+      if let Some(reply_to_paths) = o.reply_to_paths.clone() {
+        query = query.filter(subpath(comment::path, 0, -1).eq_any(reply_to_paths));
+      };
+
+      if let Some(community_id) = o.community_id {
+        query = query.filter(post::community_id.eq(community_id));
       }
-      ListingType::Suggested => query.filter(filter_suggested_communities()),
-    };
 
-    if !o.local_user.show_bot_accounts() {
-      query = query.filter(person::bot_account.eq(false));
-    };
+      let is_subscribed = community_actions::followed_at.is_not_null();
+
+      // For posts, we only show hidden if its subscribed, but for comments,
+      // we ignore hidden.
+      query = match o.listing_type.unwrap_or_default() {
+        ListingType::Subscribed => query.filter(is_subscribed),
+        ListingType::Local => query.filter(community::local.eq(true)),
+        ListingType::All => query,
+        ListingType::ModeratorView => {
+          query.filter(community_actions::became_moderator_at.is_not_null())
+        }
+        ListingType::Suggested => query.filter(filter_suggested_communities()),
+      };
 
-    if o.local_user.is_some() && o.listing_type.unwrap_or_default() != ListingType::ModeratorView {
-      // Filter out the rows with missing languages
-      query = query.filter(exists(
-        local_user_language::table.filter(
-          comment::language_id
-            .eq(local_user_language::language_id)
-            .and(
-              local_user_language::local_user_id
-                .nullable()
-                .eq(local_user_id),
-            ),
-        ),
-      ));
-
-      query = query.filter(filter_blocked());
-    };
+      if !o.local_user.show_bot_accounts() {
+        query = query.filter(person::bot_account.eq(false));
+      };
 
-    if !o.local_user.show_nsfw(site) {
-      query = query
-        .filter(post::nsfw.eq(false))
-        .filter(community::nsfw.eq(false));
-    };
+      if o.local_user.is_some() && o.listing_type.unwrap_or_default() != ListingType::ModeratorView
+      {
+        // Filter out the rows with missing languages
+        query = query.filter(exists(
+          local_user_language::table.filter(
+            comment::language_id
+              .eq(local_user_language::language_id)
+              .and(
+                local_user_language::local_user_id
+                  .nullable()
+                  .eq(local_user_id),
+              ),
+          ),
+        ));
+
+        query = query.filter(filter_blocked());
+
+        if filter_actions.contains(&ContentFilterAction::Hide) {
+          query = query.filter(not(exists(
+            content_filter::table
+              .filter(content_filter::local_user_id.nullable().eq(local_user_id))
+              .filter(content_filter::action.eq(ContentFilterAction::Hide))
+              .filter(content_filter_matches_comment()),
+          )));
+        }
+      };
 
-    query = o.local_user.visible_communities_only(query);
-    query = query.filter(
-      comment::federation_pending
-        .eq(false)
-        .or(comment::creator_id.nullable().eq(my_person_id)),
-    );
+      if !o.local_user.show_nsfw(site) {
+        query = query
+          .filter(post::nsfw.eq(false))
+          .filter(community::nsfw.eq(false));
+      };
 
-    if !o.local_user.is_admin() {
+      query = o.local_user.visible_communities_only(query);
       query = query.filter(
-        community::visibility
-          .ne(CommunityVisibility::Private)
-          .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
+        comment::federation_pending
+          .eq(false)
+          .or(comment::creator_id.nullable().eq(my_person_id)),
       );
-    }
 
-    // Filter by the time range
-    if let Some(time_range_seconds) = o.time_range_seconds {
-      query =
-        query.filter(comment::published_at.gt(now() - seconds_to_pg_interval(time_range_seconds)));
-    }
+      if o.quarantined_only.unwrap_or_default() {
+        query = query
+          .filter(comment::quarantined.eq(true))
+          .filter(comment::removed.eq(false));
+      }
 
-    // A Max depth given means its a tree fetch
-    let limit = if let Some(max_depth) = o.max_depth {
-      let depth_limit = if let Some(parent_path) = o.parent_path.as_ref() {
-        let count: i32 = parent_path.0.split('.').count().try_into()?;
-        count + max_depth
-        // Add one because of root "0"
-      } else {
-        max_depth + 1
-      };
+      if !o.local_user.is_admin() {
+        query = query
+          .filter(
+            community::visibility
+              .ne(CommunityVisibility::Private)
+              .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
+          )
+          // quarantined comments are only visible to the creator and to mods
+          .filter(
+            comment::quarantined
+              .eq(false)
+              .or(comment::creator_id.nullable().eq(my_person_id))
+              .or(community_actions::became_moderator_at.is_not_null()),
+          );
+      }
+
+      // Filter by the time range
+      if let Some(time_range_seconds) = o.time_range_seconds {
+        query = query
+          .filter(comment::published_at.gt(now() - seconds_to_pg_interval(time_range_seconds)));
+      }
 
-      query = query.filter(nlevel(comment::path).le(depth_limit));
+      if let Some(depth_limit) = depth_limit {
+        query = query.filter(nlevel(comment::path).le(depth_limit));
+      }
+
+      query
+    };
 
+    let limit = if depth_limit.is_some() {
       // TODO limit question. Limiting does not work for comment threads ATM, only max_depth
       // For now, don't do any limiting for tree fetches
       // https://stackoverflow.com/questions/72983614/postgres-ltree-how-to-limit-the-max-number-of-children-at-any-given-level
@@ -286,39 +362,290 @@ impl CommentQuery<'_> {
     } else {
       limit_fetch(o.limit, None)?
     };
-    query = query.limit(limit);
 
     // Only sort by ascending for Old
     let sort = o.sort.unwrap_or(Hot);
     let sort_direction = asc_if(sort == Old);
+    let in_post = o.post_id.is_some() || o.parent_path.is_some();
+
+    let mut query = filtered_query().select(CommentView::as_select());
+
+    // Ranks the replies of each parent in the order of the listing, so that a comment with many
+    // replies can't use up the limit for all the others
+    if let Some(max_replies) = o.max_replies_per_parent
+      && o.reply_to_paths.is_some()
+    {
+      let ranked = filtered_query().select((comment::id, subpath(comment::path, 0, -1)));
+      let ranked = order_by_sort(
+        PaginatedQueryBuilder::new(ranked, sort_direction),
+        sort,
+        in_post,
+        false,
+      );
+      let conn = &mut get_conn(pool).await?;
+      let ranked = ranked.load::<(CommentId, Ltree)>(conn).await?;
+      let mut reply_counts: HashMap<String, i64> = HashMap::new();
+      let comment_ids: Vec<CommentId> = ranked
+        .into_iter()
+        .filter(|(_, parent)| {
+          let count = reply_counts.entry(parent.0.clone()).or_default();
+          *count += 1;
+          *count <= max_replies
+        })
+        .map(|(id, _)| id)
+        .collect();
+      query = query.filter(comment::id.eq_any(comment_ids));
+    }
 
-    let mut pq = CommentView::paginate(query, &o.page_cursor, sort_direction, pool, None).await?;
+    query = query.limit(limit);
 
+    let pq = CommentView::paginate(query, &o.page_cursor, sort_direction, pool, None).await?;
     // Order by a subpath for max depth queries
     // Only order if filtering by a post id, or parent_path. DOS potential otherwise and max_depth
     // + !post_id isn't used anyways (afaik)
-    if o.max_depth.is_some() && (o.post_id.is_some() || o.parent_path.is_some()) {
-      // Always order by the parent path first
-      pq = pq.then_order_by(Subpath(key::path));
-    }
+    let pq = order_by_sort(pq, sort, in_post, o.max_depth.is_some() && in_post);
 
-    // Distinguished comments should go first when viewing post
-    // Don't do for new / old sorts
-    if sort != New && sort != Old && (o.post_id.is_some() || o.parent_path.is_some()) {
-      pq = pq.then_order_by(key::distinguished);
-    }
+    let conn = &mut get_conn(pool).await?;
+    let res = if let Some(local_user_id) = local_user_id
+      && !filter_actions.is_empty()
+    {
+      conn
+        .run_transaction(|conn| {
+          async move {
+            ContentFilter::set_statement_timeout(conn).await?;
+            let mut res = pq.load::<CommentView>(conn).await?;
+            if filter_actions.contains(&ContentFilterAction::Warn) {
+              mark_warn_filters(&mut res, local_user_id, conn).await?;
+            }
+            Ok(res)
+          }
+          .scope_boxed()
+        })
+        .await?
+    } else {
+      pq.load::<CommentView>(conn).await?
+    };
+
+    paginate_response(res, limit, o.page_cursor)
+  }
+}
 
-    pq = match sort {
-      Hot => pq.then_order_by(key::hot_rank).then_order_by(key::score),
-      Controversial => pq.then_order_by(key::controversy_rank),
-      Old | New => pq.then_order_by(key::published_at),
-      Top => pq.then_order_by(key::score),
+/// Orders the comments in the same way for the listing and for the per-parent reply window.
+fn order_by_sort<Q>(
+  mut pq: PaginatedQueryBuilder<Comment, Q>,
+  sort: CommentSortType,
+  in_post: bool,
+  by_subpath: bool,
+) -> PaginatedQueryBuilder<Comment, Q> {
+  if by_subpath {
+    // Always order by the parent path first
+    pq = pq.then_order_by(Subpath(key::path));
+  }
+
+  // Pinned comments always go first when viewing post, also for old sort
+  if in_post {
+    pq = if sort == Old {
+      pq.then_order_by(NotKey(key::pinned))
+    } else {
+      pq.then_order_by(key::pinned)
     };
+  }
 
-    let conn = &mut get_conn(pool).await?;
-    let res = pq.load::<CommentView>(conn).await?;
+  // Distinguished comments should go first when viewing post
+  // Don't do for new / old sorts
+  if sort != New && sort != Old && in_post {
+    pq = pq.then_order_by(key::distinguished);
+  }
 
-    paginate_response(res, limit, o.page_cursor)
+  match sort {
+    Hot => pq.then_order_by(key::hot_rank).then_order_by(key::score),
+    Controversial => pq.then_order_by(key::controversy_rank),
+    Old | New => pq.then_order_by(key::published_at),
+    Top => pq.then_order_by(key::score),
+  }
+}
+
+/// Marks the comments which match any of the user's warn filters.
+async fn mark_warn_filters(
+  res: &mut [CommentView],
+  local_user_id: LocalUserId,
+  conn: &mut AsyncPgConnection,
+) -> LemmyResult<()> {
+  if res.is_empty() {
+    return Ok(());
+  }
+  let comment_ids: Vec<CommentId> = res.iter().map(|cv| cv.comment.id).collect();
+  let matched: Vec<(CommentId, ContentFilterId)> = comment::table
+    .inner_join(person::table)
+    .inner_join(post::table)
+    .inner_join(
+      content_filter::table.on(
+        content_filter::local_user_id
+          .eq(local_user_id)
+          .and(content_filter::action.eq(ContentFilterAction::Warn)),
+      ),
+    )
+    .filter(comment::id.eq_any(comment_ids))
+    .filter(content_filter_matches_comment())
+    .select((comment::id, content_filter::id))
+    .load(conn)
+    .await?;
+  for cv in res {
+    cv.matched_filters = matched
+      .iter()
+      .filter(|(comment_id, _)| *comment_id == cv.comment.id)
+      .map(|(_, filter_id)| *filter_id)
+      .collect();
+  }
+  Ok(())
+}
+
+/// Each level of the tree needs one query, so the depth is limited.
+const COMMENT_TREE_MAX_DEPTH: i32 = 10;
+const COMMENT_TREE_DEFAULT_DEPTH: i32 = 5;
+const COMMENT_TREE_DEFAULT_BREADTH: i64 = 5;
+
+#[derive(Default)]
+pub struct CommentTreeQuery<'a> {
+  pub post_id: PostId,
+  /// Load the replies to this comment, instead of the top-level comments of the post.
+  pub parent_path: Option<Ltree>,
+  pub sort: Option<CommentSortType>,
+  pub local_user: Option<&'a LocalUser>,
+  /// How many levels of replies are loaded below the top level.
+  pub max_depth: Option<i32>,
+  /// How many replies are loaded for each comment.
+  pub max_breadth: Option<i64>,
+  /// Pages through the top level only.
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+impl CommentTreeQuery<'_> {
+  /// Loads the comments level by level, each level only contains replies to the comments which
+  /// were kept on the level above.
+  pub async fn list(
+    self,
+    site: &Site,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<PagedResponse<CommentTreeNode>> {
+    let o = self;
+    let max_depth = o
+      .max_depth
+      .unwrap_or(COMMENT_TREE_DEFAULT_DEPTH)
+      .clamp(0, COMMENT_TREE_MAX_DEPTH);
+    let max_breadth = usize::try_from(o.max_breadth.unwrap_or(COMMENT_TREE_DEFAULT_BREADTH))?
+      .clamp(1, FETCH_LIMIT_MAX);
+    let replies_query = |reply_to_paths: Vec<Ltree>,
+                         max_replies: Option<i64>,
+                         page_cursor,
+                         limit: Option<i64>| CommentQuery {
+      listing_type: Some(ListingType::All),
+      sort: o.sort,
+      post_id: Some(o.post_id),
+      reply_to_paths: Some(reply_to_paths),
+      max_replies_per_parent: max_replies,
+      local_user: o.local_user,
+      page_cursor,
+      limit,
+      ..Default::default()
+    };
+
+    let root_path = o.parent_path.unwrap_or_else(|| Ltree("0".to_string()));
+    let top = replies_query(vec![root_path], None, o.page_cursor, o.limit)
+      .list(site, pool)
+      .await?;
+    let (next_page, prev_page) = (top.next_page.clone(), top.prev_page.clone());
+    let mut levels = vec![top.items];
+    // Comments which have more replies than the ones that were loaded
+    let mut truncated = HashSet::new();
+
+    for _ in 0..max_depth {
+      let paths = levels
+        .last()
+        .into_iter()
+        .flatten()
+        .filter(|c| c.comment.child_count > 0)
+        .map(|c| c.comment.path.clone())
+        .collect::<Vec<_>>();
+      if paths.is_empty() {
+        break;
+      }
+      // Fetch one additional reply per comment to find out if there are more
+      let limit = paths
+        .len()
+        .saturating_mul(max_breadth + 1)
+        .min(FETCH_LIMIT_MAX);
+      let max_replies = Some(i64::try_from(max_breadth + 1)?);
+      let replies = replies_query(paths, max_replies, None, Some(limit.try_into()?))
+        .list(site, pool)
+        .await?;
+
+      let mut reply_counts = HashMap::<CommentId, usize>::new();
+      let mut level = vec![];
+      for reply in replies {
+        let Some(parent_id) = reply.comment.parent_comment_id() else {
+          continue;
+        };
+        let count = reply_counts.entry(parent_id).or_default();
+        *count += 1;
+        if *count > max_breadth {
+          truncated.insert(parent_id);
+        } else {
+          level.push(reply);
+        }
+      }
+      levels.push(level);
+    }
+
+    // Build the tree bottom up, so that the replies of each comment are complete when it is added
+    // to its parent.
+    let mut replies_by_parent =
+      HashMap::<CommentId, Vec<(PaginationCursor, CommentTreeNode)>>::new();
+    let mut items = vec![];
+    for (depth, level) in levels.into_iter().enumerate().rev() {
+      let mut parents = HashMap::<CommentId, Vec<_>>::new();
+      for comment_view in level {
+        let comment_id = comment_view.comment.id;
+        let replies = replies_by_parent.remove(&comment_id).unwrap_or_default();
+        // The child count includes all descendants, so the subtrees of loaded replies are
+        // subtracted. Those report their own missing replies.
+        let loaded: i32 = replies
+          .iter()
+          .map(|(_, r)| r.comment_view.comment.child_count + 1)
+          .sum();
+        let mut more_replies = (comment_view.comment.child_count - loaded).max(0);
+        if truncated.contains(&comment_id) {
+          more_replies = more_replies.max(1);
+        }
+        let more_replies_cursor = replies
+          .last()
+          .filter(|_| more_replies > 0)
+          .map(|(cursor, _)| cursor.clone());
+
+        let cursor = PaginationCursor::next_after(&comment_view)?;
+        let parent_id = comment_view.comment.parent_comment_id();
+        let node = CommentTreeNode {
+          comment_view: comment_view.map_to_slim(),
+          replies: replies.into_iter().map(|(_, r)| r).collect(),
+          more_replies,
+          more_replies_cursor,
+        };
+        match parent_id {
+          Some(parent_id) if depth > 0 => {
+            parents.entry(parent_id).or_default().push((cursor, node))
+          }
+          _ => items.push(node),
+        }
+      }
+      replies_by_parent = parents;
+    }
+
+    Ok(PagedResponse {
+      items,
+      next_page,
+      prev_page,
+    })
   }
 }
 
@@ -626,6 +953,96 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_comment_tree_nested() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let tree = CommentTreeQuery {
+      post_id: data.post.id,
+      sort: Some(CommentSortType::Old),
+      max_depth: Some(1),
+      max_breadth: Some(1),
+      ..Default::default()
+    }
+    .list(&data.site, pool)
+    .await?;
+
+    // Only comment 1 is loaded below comment 0, comment 2 is left for "load more"
+    assert_length!(1, tree);
+    let top = &tree[0];
+    assert_eq!(data.comment_0.id, top.comment_view.comment.id);
+    assert_length!(1, top.replies);
+    assert_eq!(1, top.more_replies);
+    assert!(top.more_replies_cursor.is_some());
+
+    // The replies of comment 1 are below the depth limit, so the thread has to be continued
+    let reply = &top.replies[0];
+    assert_eq!(data.comment_1.id, reply.comment_view.comment.id);
+    assert!(reply.replies.is_empty());
+    assert_eq!(3, reply.more_replies);
+    assert_eq!(None, reply.more_replies_cursor);
+
+    let more = CommentTreeQuery {
+      post_id: data.post.id,
+      parent_path: Some(data.comment_0.path.clone()),
+      sort: Some(CommentSortType::Old),
+      page_cursor: top.more_replies_cursor.clone(),
+      ..Default::default()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(
+      vec![data.comment_2.id],
+      more
+        .iter()
+        .map(|n| n.comment_view.comment.id)
+        .collect::<Vec<_>>()
+    );
+
+    // Each parent gets its own share of replies, even if the limit is used up by the first one
+    let replies = CommentQuery {
+      post_id: Some(data.post.id),
+      reply_to_paths: Some(vec![
+        data.comment_0.path.clone(),
+        data.comment_1.path.clone(),
+      ]),
+      max_replies_per_parent: Some(1),
+      sort: Some(CommentSortType::Old),
+      limit: Some(2),
+      ..Default::default()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(
+      vec!["Comment 1", "Comment 3"],
+      replies
+        .iter()
+        .map(|r| r.comment.content.as_str())
+        .collect::<Vec<&str>>()
+    );
+
+    // Replies which are hidden from the user don't take up the share of their parent
+    let replies = CommentQuery {
+      post_id: Some(data.post.id),
+      reply_to_paths: Some(vec![data.comment_0.path.clone()]),
+      max_replies_per_parent: Some(1),
+      sort: Some(CommentSortType::Old),
+      local_user: Some(&data.timmy_local_user_view.local_user),
+      ..Default::default()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(
+      vec![data.comment_2.id],
+      replies.iter().map(|r| r.comment.id).collect::<Vec<_>>()
+    );
+
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_languages() -> LemmyResult<()> {
@@ -710,6 +1127,39 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_pinned_first() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let form = CommentUpdateForm {
+      pinned: Some(true),
+      ..Default::default()
+    };
+    Comment::update(pool, data.comment_2.id, &form).await?;
+
+    // Unlike distinguished comments, this also applies to the new and old sorts
+    for sort in [
+      CommentSortType::Old,
+      CommentSortType::New,
+      CommentSortType::Hot,
+    ] {
+      let comments = CommentQuery {
+        post_id: Some(data.comment_2.post_id),
+        sort: Some(sort),
+        ..Default::default()
+      }
+      .list(&data.site, pool)
+      .await?;
+      assert_eq!(comments[0].comment.id, data.comment_2.id);
+      assert!(comments[0].comment.pinned);
+    }
+
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_creator_is_moderator() -> LemmyResult<()> {
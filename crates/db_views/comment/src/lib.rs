@@ -1,11 +1,15 @@
 use chrono::{DateTime, Utc};
-use lemmy_db_schema::source::{
-  comment::{Comment, CommentActions},
-  community::{Community, CommunityActions},
-  person::{Person, PersonActions},
-  post::Post,
-  tag::TagsView,
+use lemmy_db_schema::{
+  newtypes::ContentFilterId,
+  source::{
+    comment::{Comment, CommentActions},
+    community::{Community, CommunityActions},
+    person::{Person, PersonActions},
+    post::Post,
+    tag::TagsView,
+  },
 };
+use lemmy_diesel_utils::pagination::PaginationCursor;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -13,14 +17,18 @@ use {
   diesel::{Queryable, Selectable},
   lemmy_db_schema::utils::queries::selects::{
     CreatorLocalHomeCommunityBanExpiresType,
+    NoMatchedFiltersType,
     comment_creator_is_admin,
     comment_select_remove_deletes,
     creator_ban_expires_from_community,
     creator_banned_from_community,
+    creator_community_flair,
+    creator_community_flair_emoji,
     creator_is_moderator,
     creator_local_home_community_ban_expires,
     creator_local_home_community_banned,
     local_user_can_mod_comment,
+    no_matched_filters,
     post_tags_fragment,
   },
 };
@@ -104,6 +112,27 @@ pub struct CommentView {
     )
   )]
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = creator_community_flair()
+    )
+  )]
+  pub creator_flair: Option<String>,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = creator_community_flair_emoji()
+    )
+  )]
+  pub creator_flair_emoji: Option<String>,
+  /// Content filters of the user with the warn action which match this comment. The comment should
+  /// be collapsed behind a warning.
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression_type = NoMatchedFiltersType,
+      select_expression = no_matched_filters()
+    )
+  )]
+  pub matched_filters: Vec<ContentFilterId>,
 }
 
 #[skip_serializing_none]
@@ -123,4 +152,23 @@ pub struct CommentSlimView {
   pub creator_banned: bool,
   pub creator_is_moderator: bool,
   pub creator_banned_from_community: bool,
+  pub creator_flair: Option<String>,
+  pub creator_flair_emoji: Option<String>,
+  pub matched_filters: Vec<ContentFilterId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A comment with its loaded replies.
+pub struct CommentTreeNode {
+  pub comment_view: CommentSlimView,
+  pub replies: Vec<CommentTreeNode>,
+  /// The number of replies which weren't loaded, including their own replies.
+  pub more_replies: i32,
+  /// To load more replies, pass this as `page_cursor` together with this comment as `parent_id`.
+  /// If it is missing while `more_replies` is above zero, continue the thread by passing this
+  /// comment as `parent_id` without a cursor.
+  pub more_replies_cursor: Option<PaginationCursor>,
 }
@@ -3,12 +3,33 @@ use lemmy_db_schema::{
   CommunitySortType,
   MultiCommunityListingType,
   MultiCommunitySortType,
-  newtypes::{CommunityId, LanguageId, MultiCommunityId, TagId},
-  source::site::Site,
+  newtypes::{
+    CommunityId,
+    LanguageId,
+    MultiCommunityId,
+    RecurringPostId,
+    TagId,
+    WikiPageId,
+    WikiPageRevisionId,
+  },
+  source::{
+    person::Person,
+    recurring_post::RecurringPost,
+    site::Site,
+    wiki::{WikiPage, WikiPageRevisionView},
+  },
 };
 use lemmy_db_schema_file::{
   PersonId,
-  enums::{CommunityNotificationsMode, CommunityVisibility, ListingType},
+  enums::{
+    CommunityNotificationsMode,
+    CommunityPostingMode,
+    CommunityVisibility,
+    ListingType,
+    ModeratorPermission,
+    RecurringPostFrequency,
+    WikiEditPermission,
+  },
 };
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use lemmy_diesel_utils::pagination::PaginationCursor;
@@ -33,6 +54,19 @@ pub struct AddModToCommunityResponse {
   pub moderators: Vec<CommunityModeratorView>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Change the permissions of a community moderator.
+pub struct EditModeratorPermissions {
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  /// The permissions the moderator should hold. If missing, they hold all permissions.
+  pub permissions: Option<Vec<ModeratorPermission>>,
+  pub reason: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -108,8 +142,8 @@ pub struct CreateCommunity {
   pub banner: Option<String>,
   /// Whether its an NSFW community.
   pub nsfw: Option<bool>,
-  /// Whether to restrict posting only to moderators.
-  pub posting_restricted_to_mods: Option<bool>,
+  /// Who can create posts in the community.
+  pub posting_mode: Option<CommunityPostingMode>,
   pub discussion_languages: Option<Vec<LanguageId>>,
   pub visibility: Option<CommunityVisibility>,
 }
@@ -139,8 +173,8 @@ pub struct EditCommunity {
   pub summary: Option<String>,
   /// Whether its an NSFW community.
   pub nsfw: Option<bool>,
-  /// Whether to restrict posting only to moderators.
-  pub posting_restricted_to_mods: Option<bool>,
+  /// Who can create posts in the community.
+  pub posting_mode: Option<CommunityPostingMode>,
   pub discussion_languages: Option<Vec<LanguageId>>,
   pub visibility: Option<CommunityVisibility>,
 }
@@ -336,6 +370,13 @@ pub struct CreateCommunityTag {
   pub name: String,
   pub display_name: Option<String>,
   pub description: Option<String>,
+  /// A hex color like `#ff8800`.
+  pub color: Option<String>,
+  pub emoji: Option<String>,
+  /// Only mods can assign the tag to posts, or give the flair to users.
+  pub mod_only: Option<bool>,
+  /// Create a user flair instead of a post tag.
+  pub user_flair: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -347,6 +388,9 @@ pub struct UpdateCommunityTag {
   pub tag_id: TagId,
   pub display_name: Option<String>,
   pub description: Option<String>,
+  pub color: Option<String>,
+  pub emoji: Option<String>,
+  pub mod_only: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -357,3 +401,210 @@ pub struct UpdateCommunityTag {
 pub struct DeleteCommunityTag {
   pub tag_id: TagId,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Set the flair of a user in a community, which is shown next to their name. Users can pick
+/// one of the user flairs of the community for themselves. Mods can also set flairs for other
+/// users, or use a custom text. Leave out `tag_id` and `flair` to remove the flair.
+pub struct EditUserFlair {
+  pub community_id: CommunityId,
+  /// The user to change, if not yourself.
+  pub person_id: Option<PersonId>,
+  pub tag_id: Option<TagId>,
+  pub flair: Option<String>,
+  pub flair_emoji: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Publish a post in a community on a regular schedule, like a weekly discussion thread.
+pub struct CreateRecurringPost {
+  pub community_id: CommunityId,
+  /// The post title. The placeholders {date}, {day}, {week}, {month} and {year} are replaced
+  /// with the publish date.
+  pub name_template: String,
+  pub body: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+  pub frequency: RecurringPostFrequency,
+  /// A unix timestamp in seconds, for the first post. Later posts follow at the same time of
+  /// day.
+  pub first_publish_at: i64,
+  /// Feature each new post in the community, and unfeature the previous one.
+  pub feature_latest: Option<bool>,
+  /// Lock the previous post when a new one is published.
+  pub lock_previous: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Change a recurring post. Changes apply from the next post on.
+pub struct EditRecurringPost {
+  pub id: RecurringPostId,
+  pub name_template: Option<String>,
+  /// An empty body clears it.
+  pub body: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+  pub frequency: Option<RecurringPostFrequency>,
+  /// A unix timestamp in seconds.
+  pub next_publish_at: Option<i64>,
+  pub feature_latest: Option<bool>,
+  pub lock_previous: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Stop a recurring post. Posts which were already published are kept.
+pub struct DeleteRecurringPost {
+  pub id: RecurringPostId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the recurring posts of a community. Only for mods.
+pub struct ListRecurringPosts {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RecurringPostResponse {
+  pub recurring_post: RecurringPost,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListRecurringPostsResponse {
+  pub recurring_posts: Vec<RecurringPost>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Create a page in the community wiki. Only for mods.
+pub struct CreateWikiPage {
+  pub community_id: CommunityId,
+  /// The path of the page, with slashes for subpages, like `guides/getting-started`.
+  pub slug: String,
+  pub title: String,
+  pub body: String,
+  /// Who may edit the page, defaults to mods only.
+  pub edit_permission: Option<WikiEditPermission>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edit a wiki page. This saves a new revision.
+pub struct EditWikiPage {
+  pub id: WikiPageId,
+  pub title: Option<String>,
+  pub body: Option<String>,
+  /// Can only be changed by mods.
+  pub edit_permission: Option<WikiEditPermission>,
+  /// A short description of the change, shown in the revision history.
+  pub summary: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete or restore a wiki page. Only for mods.
+pub struct DeleteWikiPage {
+  pub id: WikiPageId,
+  pub deleted: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Get a wiki page by its slug.
+pub struct GetWikiPage {
+  pub community_id: CommunityId,
+  pub slug: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the pages of a community wiki.
+pub struct ListWikiPages {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the revision history of a wiki page, newest first.
+pub struct ListWikiPageRevisions {
+  pub id: WikiPageId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Restore the content of an older revision. This saves a new revision, so it can be undone.
+pub struct RevertWikiPage {
+  pub id: WikiPageId,
+  pub revision_id: WikiPageRevisionId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Allow a user to edit the wiki pages which aren't reserved for mods, or revoke it.
+pub struct AddWikiEditor {
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  pub added: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the approved wiki editors of a community.
+pub struct ListWikiEditors {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct WikiPageResponse {
+  pub wiki_page: WikiPage,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListWikiPagesResponse {
+  pub wiki_pages: Vec<WikiPage>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListWikiPageRevisionsResponse {
+  pub revisions: Vec<WikiPageRevisionView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListWikiEditorsResponse {
+  pub editors: Vec<Person>,
+}
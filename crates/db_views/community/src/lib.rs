@@ -12,6 +12,7 @@ use {
   diesel::{NullableExpressionMethods, Queryable, Selectable},
   lemmy_db_schema::utils::queries::selects::{
     community_post_tags_fragment,
+    community_user_flairs_fragment,
     local_user_community_can_mod,
   },
   lemmy_db_schema_file::schema::multi_community_follow,
@@ -45,6 +46,13 @@ pub struct CommunityView {
     )
   )]
   pub post_tags: TagsView,
+  /// The flairs which users can pick for themselves, or which mods can give them.
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = community_user_flairs_fragment()
+    )
+  )]
+  pub user_flairs: TagsView,
 }
 
 #[skip_serializing_none]
@@ -16,6 +16,7 @@ use lemmy_db_schema::{
 };
 use lemmy_db_schema_file::{
   PersonId,
+  enums::ModeratorPermission,
   schema::{community, community_actions, person},
 };
 use lemmy_diesel_utils::connection::{DbPool, get_conn};
@@ -47,6 +48,32 @@ impl CommunityModeratorView {
     .ok_or(LemmyErrorType::NotAModerator.into())
   }
 
+  /// Checks that the person is a moderator of the community who holds the given permission. If
+  /// no permission is given, the moderator must hold all permissions, which is required for
+  /// managing the mod team itself.
+  pub async fn check_moderator_permission(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+    permission: Option<ModeratorPermission>,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let permissions = community_actions::table
+      .find((person_id, community_id))
+      .filter(community_actions::became_moderator_at.is_not_null())
+      .select(community_actions::moderator_permissions)
+      .first::<Option<Vec<ModeratorPermission>>>(conn)
+      .await
+      .optional()?
+      .ok_or(LemmyErrorType::NotAModerator)?;
+
+    match (permissions, permission) {
+      (None, _) => Ok(()),
+      (Some(permissions), Some(permission)) if permissions.contains(&permission) => Ok(()),
+      _ => Err(LemmyErrorType::MissingModeratorPermission)?,
+    }
+  }
+
   pub async fn is_community_moderator_of_any(
     pool: &mut DbPool<'_>,
     person_id: PersonId,
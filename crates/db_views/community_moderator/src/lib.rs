@@ -1,11 +1,17 @@
-#[cfg(feature = "full")]
-use diesel::{Queryable, Selectable};
 use lemmy_db_schema::source::{community::Community, person::Person};
+use lemmy_db_schema_file::enums::ModeratorPermission;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use {
+  diesel::{Queryable, Selectable},
+  lemmy_db_schema_file::schema::community_actions,
+};
 
 #[cfg(feature = "full")]
 pub mod impls;
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(Queryable, Selectable))]
 #[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
@@ -17,6 +23,9 @@ pub struct CommunityModeratorView {
   pub community: Community,
   #[cfg_attr(feature = "full", diesel(embed))]
   pub moderator: Person,
+  /// The permissions of this moderator. None means that they hold all permissions.
+  #[cfg_attr(feature = "full", diesel(select_expression = community_actions::moderator_permissions))]
+  pub permissions: Option<Vec<ModeratorPermission>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
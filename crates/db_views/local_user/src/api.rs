@@ -1,3 +1,5 @@
+use lemmy_db_schema::newtypes::LocalUserId;
+use lemmy_db_schema_file::PersonId;
 use lemmy_diesel_utils::pagination::PaginationCursor;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -8,6 +10,20 @@ use serde_with::skip_serializing_none;
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
 pub struct AdminListUsers {
   pub banned_only: Option<bool>,
+  /// Only show users who signed up with an invite code of this user.
+  pub invited_by_id: Option<LocalUserId>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Lists the admin audit log. Only available to admins holding all permissions.
+pub struct ListAdminAuditLog {
+  /// Only show calls made by this admin.
+  pub admin_id: Option<PersonId>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
 }
@@ -1,4 +1,4 @@
-use crate::LocalUserView;
+use crate::{AdminAuditLogView, LocalUserView};
 use actix_web::{FromRequest, HttpMessage, HttpRequest, dev::Payload};
 use diesel::{
   BoolExpressionMethods,
@@ -10,18 +10,20 @@ use diesel::{
 use diesel_async::RunQueryDsl;
 use i_love_jesus::SortDirection;
 use lemmy_db_schema::{
-  newtypes::{LocalUserId, OAuthProviderId},
+  newtypes::{AdminAuditLogId, LocalUserId, OAuthProviderId},
   source::{
+    admin_audit_log::{AdminAuditLog, admin_audit_log_keys},
     instance::Instance,
     local_user::{LocalUser, LocalUserInsertForm},
     person::{Person, PersonInsertForm, person_keys},
   },
+  utils::limit_fetch,
 };
 use lemmy_db_schema_file::{
   PersonId,
   aliases::creator_home_instance_actions,
   joins::creator_home_instance_actions_join,
-  schema::{instance_actions, local_user, oauth_account, person},
+  schema::{admin_audit_log, instance_actions, local_user, oauth_account, person},
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -160,6 +162,7 @@ impl LocalUserView {
 #[derive(Default)]
 pub struct LocalUserQuery {
   pub banned_only: Option<bool>,
+  pub invited_by_id: Option<LocalUserId>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
 }
@@ -174,6 +177,10 @@ impl LocalUserQuery {
       .select(LocalUserView::as_select())
       .into_boxed();
 
+    if let Some(invited_by_id) = self.invited_by_id {
+      query = query.filter(local_user::invited_by_id.eq(invited_by_id));
+    }
+
     if self.banned_only.unwrap_or_default() {
       let actions = creator_home_instance_actions;
 
@@ -234,6 +241,58 @@ impl PaginationCursorConversion for LocalUserView {
   }
 }
 
+#[derive(Default)]
+pub struct AdminAuditLogQuery {
+  pub admin_id: Option<PersonId>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+impl AdminAuditLogQuery {
+  pub async fn list(self, pool: &mut DbPool<'_>) -> LemmyResult<PagedResponse<AdminAuditLogView>> {
+    let limit = limit_fetch(self.limit, None)?;
+    let mut query = admin_audit_log::table
+      .inner_join(person::table)
+      .select(AdminAuditLogView::as_select())
+      .limit(limit)
+      .into_boxed();
+
+    if let Some(admin_id) = self.admin_id {
+      query = query.filter(admin_audit_log::admin_id.eq(admin_id));
+    }
+
+    // Newest calls first
+    let paginated_query =
+      AdminAuditLogView::paginate(query, &self.page_cursor, SortDirection::Desc, pool, None)
+        .await?
+        .then_order_by(admin_audit_log_keys::published_at)
+        // Tie breaker
+        .then_order_by(admin_audit_log_keys::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<AdminAuditLogView>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    paginate_response(res, limit, self.page_cursor)
+  }
+}
+
+impl PaginationCursorConversion for AdminAuditLogView {
+  type PaginatedType = AdminAuditLog;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.admin_audit_log.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    AdminAuditLog::read(pool, AdminAuditLogId(cursor.id()?)).await
+  }
+}
+
 #[cfg(test)]
 #[expect(clippy::indexing_slicing)]
 mod tests {
@@ -242,6 +301,7 @@ mod tests {
   use lemmy_db_schema::{
     assert_length,
     source::{
+      admin_audit_log::AdminAuditLogInsertForm,
       instance::{Instance, InstanceActions, InstanceBanForm},
       local_user::{LocalUser, LocalUserInsertForm},
       person::{Person, PersonInsertForm},
@@ -303,4 +363,46 @@ mod tests {
 
     cleanup(data, pool).await
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn list_admin_audit_log() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let edit_site = AdminAuditLogInsertForm::new(data.alice.id, "EditSite".to_string(), None, None);
+    AdminAuditLog::create(pool, &edit_site).await?;
+    let purge_post = AdminAuditLogInsertForm::new(
+      data.alice.id,
+      "PurgePost".to_string(),
+      Some(1),
+      Some(r#"{"post_id":1,"reason":"spam"}"#.to_string()),
+    );
+    AdminAuditLog::create(pool, &purge_post).await?;
+
+    let list = AdminAuditLogQuery {
+      admin_id: Some(data.alice.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert_length!(2, list);
+    // Newest calls come first
+    assert_eq!("PurgePost", list[0].admin_audit_log.action);
+    assert_eq!("EditSite", list[1].admin_audit_log.action);
+    assert_eq!(Some(1), list[0].admin_audit_log.target_id);
+    assert_eq!(None, list[1].admin_audit_log.parameters);
+    assert_eq!(data.alice.id, list[0].admin.id);
+
+    let list = AdminAuditLogQuery {
+      admin_id: Some(PersonId(-1)),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert_length!(0, list);
+
+    cleanup(data, pool).await
+  }
 }
@@ -1,5 +1,9 @@
 use chrono::{DateTime, Utc};
-use lemmy_db_schema::source::{local_user::LocalUser, person::Person};
+use lemmy_db_schema::source::{
+  admin_audit_log::AdminAuditLog,
+  local_user::LocalUser,
+  person::Person,
+};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "full")]
 use {
@@ -35,3 +39,16 @@ pub struct LocalUserView {
   )]
   pub ban_expires_at: Option<DateTime<Utc>>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An entry of the admin audit log, together with the admin who made the call.
+pub struct AdminAuditLogView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub admin_audit_log: AdminAuditLog,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub admin: Person,
+}
@@ -231,11 +231,14 @@ fn map_to_enum(v: NotificationViewInternal, hide_modlog_name: bool) -> Option<No
       post_tags: v.post_tags,
       creator_banned_from_community: v.creator_banned_from_community,
       creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+      creator_flair: v.creator_flair,
+      creator_flair_emoji: v.creator_flair_emoji,
       creator_is_admin: v.creator_is_admin,
       can_mod: v.can_mod,
       creator_banned: v.creator_banned,
       creator_ban_expires_at: v.creator_ban_expires_at,
       creator_is_moderator: v.creator_is_moderator,
+      matched_filters: vec![],
     })
   } else if let (Some(post), Some(community), Some(creator)) =
     (v.post.clone(), v.community.clone(), v.creator.clone())
@@ -245,17 +248,23 @@ fn map_to_enum(v: NotificationViewInternal, hide_modlog_name: bool) -> Option<No
       community,
       creator,
       image_details: v.image_details,
+      event: v.post_event,
       community_actions: v.community_actions,
       post_actions: v.post_actions,
       person_actions: v.person_actions,
       tags: v.post_tags,
+      gallery: v.post_gallery,
+      also_posted_in: Default::default(),
       creator_banned_from_community: v.creator_banned_from_community,
       creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+      creator_flair: v.creator_flair,
+      creator_flair_emoji: v.creator_flair_emoji,
       creator_is_admin: v.creator_is_admin,
       can_mod: v.can_mod,
       creator_banned: v.creator_banned,
       creator_ban_expires_at: v.creator_ban_expires_at,
       creator_is_moderator: v.creator_is_moderator,
+      matched_filters: vec![],
     })
   } else if let (Some(private_message), Some(creator)) =
     (v.private_message.clone(), v.creator.clone())
@@ -10,6 +10,8 @@ use lemmy_db_schema::{
     notification::Notification,
     person::{Person, PersonActions},
     post::{Post, PostActions},
+    post_event::PostEvent,
+    post_gallery::PostGalleryView,
     private_message::PrivateMessage,
     tag::TagsView,
   },
@@ -37,7 +39,10 @@ use {
     utils::queries::selects::{
       creator_ban_expires_from_community,
       creator_banned_from_community,
+      creator_community_flair,
+      creator_community_flair_emoji,
       person1_select,
+      post_gallery_fragment,
       post_tags_fragment,
     },
   },
@@ -76,6 +81,8 @@ struct NotificationViewInternal {
   #[diesel(embed)]
   image_details: Option<ImageDetails>,
   #[diesel(embed)]
+  post_event: Option<PostEvent>,
+  #[diesel(embed)]
   community_actions: Option<CommunityActions>,
   #[diesel(embed)]
   post_actions: Option<PostActions>,
@@ -87,6 +94,8 @@ struct NotificationViewInternal {
   modlog: Option<Modlog>,
   #[diesel(select_expression = post_tags_fragment())]
   post_tags: TagsView,
+  #[diesel(select_expression = post_gallery_fragment())]
+  post_gallery: PostGalleryView,
   #[diesel(select_expression = creator_is_admin())]
   creator_is_admin: bool,
   #[diesel(select_expression = local_user_can_mod())]
@@ -104,6 +113,10 @@ struct NotificationViewInternal {
   creator_banned_from_community: bool,
   #[diesel(select_expression = creator_ban_expires_from_community())]
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  #[diesel(select_expression = creator_community_flair())]
+  pub creator_flair: Option<String>,
+  #[diesel(select_expression = creator_community_flair_emoji())]
+  pub creator_flair_emoji: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -23,6 +23,7 @@ use lemmy_db_schema_file::{
     my_local_user_admin_join,
     my_person_actions_join,
     my_post_actions_join,
+    post_event_join,
   },
   schema::{comment, community, instance, modlog, notification, person, post, private_message},
 };
@@ -108,6 +109,7 @@ pub fn notification_joins(person_id: PersonId, instance_id: InstanceId) -> _ {
     .left_join(item_creator_join)
     .inner_join(recipient_join)
     .left_join(image_details_join())
+    .left_join(post_event_join())
     .left_join(creator_community_actions_join())
     .left_join(creator_local_user_admin_join())
     .left_join(creator_home_instance_actions_join())
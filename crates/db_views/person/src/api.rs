@@ -1,6 +1,6 @@
 use crate::PersonView;
 use lemmy_db_schema::source::site::Site;
-use lemmy_db_schema_file::PersonId;
+use lemmy_db_schema_file::{PersonId, enums::AdminPermission};
 use lemmy_db_views_community::MultiCommunityView;
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use serde::{Deserialize, Serialize};
@@ -23,6 +23,17 @@ pub struct AddAdminResponse {
   pub admins: Vec<PersonView>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Restricts an admin to the given permissions. Only admins holding all permissions can do this.
+pub struct EditAdminPermissions {
+  pub person_id: PersonId,
+  /// The permissions to grant, or none to give the admin all permissions.
+  pub permissions: Option<Vec<AdminPermission>>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -41,6 +52,17 @@ pub struct BanPerson {
   pub expires_at: Option<i64>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Quarantine a person. New posts and comments of a quarantined person are only visible to
+/// themselves and to mods/admins, until they are approved.
+pub struct QuarantinePerson {
+  pub person_id: PersonId,
+  pub quarantine: bool,
+  pub reason: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -8,6 +8,7 @@ use {
     CreatorLocalHomeBanExpiresType,
     creator_local_home_ban_expires,
     creator_local_home_banned,
+    creator_local_quarantined,
   },
   lemmy_db_schema_file::schema::local_user,
   lemmy_diesel_utils::utils::functions::coalesce,
@@ -48,4 +49,11 @@ pub struct PersonView {
      )
   )]
   pub ban_expires_at: Option<DateTime<Utc>>,
+  /// New posts and comments of this person are held back for review.
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = creator_local_quarantined()
+    )
+  )]
+  pub quarantined: bool,
 }
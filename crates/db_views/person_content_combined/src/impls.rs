@@ -4,6 +4,7 @@ use diesel::{
   ExpressionMethods,
   JoinOnDsl,
   NullableExpressionMethods,
+  PgExpressionMethods,
   QueryDsl,
   SelectableHelper,
 };
@@ -34,6 +35,7 @@ use lemmy_db_schema_file::{
     my_local_user_admin_join,
     my_person_actions_join,
     my_post_actions_join,
+    post_event_join,
   },
   schema::{comment, community, community_actions, person, person_content_combined, post},
 };
@@ -145,6 +147,7 @@ impl PersonContentCombinedQuery {
       .inner_join(item_creator_join)
       .inner_join(community_join())
       .left_join(image_details_join())
+      .left_join(post_event_join())
       .left_join(creator_community_actions_join())
       .left_join(creator_local_user_admin_join())
       .left_join(creator_home_instance_actions_join())
@@ -202,6 +205,16 @@ impl PersonContentCombinedQuery {
       );
     }
 
+    // Quarantined content and posts waiting for approval are only shown to the creator, mods and
+    // admins
+    if !my_local_user.is_admin() && my_person_id != Some(self.creator_id) {
+      let is_mod = community_actions::became_moderator_at.is_not_null();
+      query = query
+        .filter(post::quarantined.eq(false).or(is_mod))
+        .filter(post::pending_approval.eq(false).or(is_mod))
+        .filter(comment::quarantined.is_distinct_from(true).or(is_mod));
+    }
+
     // Sorting by published
     let paginated_query = PostCommentCombinedViewWrapper::paginate(
       query,
@@ -244,7 +257,13 @@ mod tests {
   use lemmy_db_schema::{
     source::{
       comment::{Comment, CommentInsertForm},
-      community::{Community, CommunityActions, CommunityFollowerForm, CommunityInsertForm},
+      community::{
+        Community,
+        CommunityActions,
+        CommunityFollowerForm,
+        CommunityInsertForm,
+        CommunityModeratorForm,
+      },
       instance::Instance,
       local_user::{LocalUser, LocalUserInsertForm},
       person::{Person, PersonInsertForm},
@@ -265,6 +284,7 @@ mod tests {
 
   struct Data {
     instance: Instance,
+    community: Community,
     private_community: Community,
     timmy: Person,
     timmy_view: LocalUserView,
@@ -351,6 +371,7 @@ mod tests {
 
     Ok(Data {
       instance,
+      community,
       private_community,
       timmy,
       timmy_view,
@@ -479,4 +500,52 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn quarantined_creator() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let olga_form = PersonInsertForm::test_form(data.instance.id, "olga_pcv");
+    let olga = Person::create(pool, &olga_form).await?;
+    let olga_post_form = PostInsertForm {
+      quarantined: Some(true),
+      ..PostInsertForm::new("olga post pcv".into(), olga.id, data.community.id)
+    };
+    let olga_post = Post::create(pool, &olga_post_form).await?;
+    let olga_comment_form = CommentInsertForm {
+      quarantined: Some(true),
+      ..CommentInsertForm::new(olga.id, olga_post.id, "olga comment pcv".into())
+    };
+    Comment::create(pool, &olga_comment_form, None).await?;
+
+    // A regular user can't see the quarantined content
+    let timmy_sees = PersonContentCombinedQuery::new(olga.id)
+      .list(pool, Some(&data.timmy_view), data.instance.id)
+      .await?;
+    assert_eq!(0, timmy_sees.len());
+
+    // A mod of the community can see it
+    let sara_local_user_form = LocalUserInsertForm::test_form(data.sara.id);
+    let sara_local_user = LocalUser::create(pool, &sara_local_user_form, vec![]).await?;
+    let sara_view = LocalUserView {
+      local_user: sara_local_user,
+      person: data.sara.clone(),
+      banned: false,
+      ban_expires_at: None,
+    };
+    let mod_form = CommunityModeratorForm::new(data.community.id, data.sara.id);
+    CommunityActions::join(pool, &mod_form).await?;
+
+    let sara_sees = PersonContentCombinedQuery::new(olga.id)
+      .list(pool, Some(&sara_view), data.instance.id)
+      .await?;
+    assert_eq!(2, sara_sees.len());
+
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
 }
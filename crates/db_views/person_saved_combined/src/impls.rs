@@ -31,6 +31,7 @@ use lemmy_db_schema_file::{
     my_local_user_admin_join,
     my_person_actions_join,
     my_post_actions_join,
+    post_event_join,
   },
   schema::{comment, person, person_saved_combined, post},
 };
@@ -139,6 +140,7 @@ impl PersonSavedCombinedQuery {
       .inner_join(item_creator_join)
       .inner_join(community_join())
       .left_join(image_details_join())
+      .left_join(post_event_join())
       .left_join(creator_community_actions_join())
       .left_join(creator_local_user_admin_join())
       .left_join(creator_home_instance_actions_join())
@@ -1,9 +1,15 @@
 use crate::PostView;
 use lemmy_db_schema::{
   PostFeatureType,
-  newtypes::{CommunityId, LanguageId, MultiCommunityId, PostId, TagId},
+  newtypes::{CommentId, CommunityId, LanguageId, MultiCommunityId, PostId, TagId},
+  source::revision::{DiffLine, PostRevision},
+};
+use lemmy_db_schema_file::enums::{
+  ListingType,
+  PostEventRsvp,
+  PostNotificationsMode,
+  PostSortType,
 };
-use lemmy_db_schema_file::enums::{ListingType, PostNotificationsMode, PostSortType};
 use lemmy_diesel_utils::{dburl::DbUrl, pagination::PaginationCursor};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -30,6 +36,88 @@ pub struct CreatePost {
   pub tags: Option<Vec<TagId>>,
   /// Time when this post should be scheduled. Null means publish immediately.
   pub scheduled_publish_time_at: Option<i64>,
+  /// Makes this a gallery post with multiple images. If no url is given, the first image is used
+  /// as the post url.
+  pub gallery: Option<Vec<PostGalleryImage>>,
+  /// Makes this an event post which users can RSVP to.
+  pub event: Option<PostEventInput>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Post a copy of an existing post to another community, which links back to the original.
+pub struct CreateCrosspost {
+  pub post_id: PostId,
+  pub community_id: CommunityId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An image of a gallery post, either uploaded to this instance or linked from elsewhere.
+pub struct PostGalleryImage {
+  pub url: String,
+  pub alt_text: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The date, time and place of an event post.
+pub struct PostEventInput {
+  /// Unix timestamp in seconds.
+  pub start_at: i64,
+  /// Unix timestamp in seconds, defaults to the start time.
+  pub end_at: Option<i64>,
+  /// A timezone name like `Europe/Berlin`, defaults to `UTC`.
+  pub timezone: Option<String>,
+  /// The name or address of the place where the event happens.
+  pub location: Option<String>,
+  /// A link to join the event online.
+  pub online_url: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Approve or deny a post which is held for review because its creator is quarantined.
+///
+/// Approving publishes and federates the post, denying removes it.
+pub struct ApproveQuarantinedPost {
+  pub post_id: PostId,
+  pub approve: bool,
+  pub deny_reason: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Approve or deny a post which is waiting in the approval queue of its community.
+///
+/// Approving publishes and federates the post, denying removes it.
+pub struct ApprovePendingPost {
+  pub post_id: PostId,
+  pub approve: bool,
+  pub deny_reason: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the posts which are waiting for approval, oldest first.
+pub struct ListPendingPosts {
+  /// Only show the queue of a single community.
+  pub community_id: Option<CommunityId>,
+  /// Only for admins, show pending posts for communities which you dont moderate
+  pub all_communities: Option<bool>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -71,6 +159,12 @@ pub struct EditPost {
   /// Time when this post should be scheduled. Null means publish immediately.
   pub scheduled_publish_time_at: Option<i64>,
   pub tags: Option<Vec<TagId>>,
+  /// Replaces the images of a gallery post. An empty list turns it into a regular post.
+  pub gallery: Option<Vec<PostGalleryImage>>,
+  /// Replaces the date, time and place of the event.
+  pub event: Option<PostEventInput>,
+  /// Turns an event post back into a regular post.
+  pub remove_event: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -130,6 +224,11 @@ pub struct GetPosts {
   pub mark_as_read: Option<bool>,
   /// If true, then only show posts with no comments
   pub no_comments_only: Option<bool>,
+  /// If true, then only show posts without an accepted answer
+  pub unanswered_only: Option<bool>,
+  /// If true, then only show posts which are held for review because their creator is
+  /// quarantined. Mods see those of their communities, admins see all of them.
+  pub quarantined_only: Option<bool>,
   pub page_cursor: Option<PaginationCursor>,
   /// For backwards compat with API v3 (not available on API v4)
   #[serde(skip)]
@@ -185,6 +284,32 @@ pub struct ListPostLikes {
   pub limit: Option<i64>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the edit history of a post.
+pub struct ListPostRevisions {
+  pub post_id: PostId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A previous version of a post, with the changes made by the edit which replaced it.
+pub struct PostRevisionDiff {
+  pub revision: PostRevision,
+  pub name_diff: Vec<DiffLine>,
+  pub body_diff: Vec<DiffLine>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The edit history of a post, oldest first.
+pub struct ListPostRevisionsResponse {
+  pub revisions: Vec<PostRevisionDiff>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -258,6 +383,27 @@ pub struct SavePost {
   pub save: bool,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Answer an event post. Null removes the answer.
+pub struct RsvpPostEvent {
+  pub post_id: PostId,
+  pub rsvp: Option<PostEventRsvp>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Mark a comment as the accepted answer to a post. Only the post creator and mods can do this.
+/// Null removes the accepted answer.
+pub struct AcceptPostAnswer {
+  pub post_id: PostId,
+  pub comment_id: Option<CommentId>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -3,6 +3,7 @@ use diesel::{
   self,
   BoolExpressionMethods,
   ExpressionMethods,
+  JoinOnDsl,
   NullableExpressionMethods,
   PgTextExpressionMethods,
   QueryDsl,
@@ -13,13 +14,14 @@ use diesel::{
   pg::Pg,
   query_builder::AsQuery,
 };
-use diesel_async::RunQueryDsl;
+use diesel_async::{AsyncPgConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
 use i_love_jesus::{SortDirection, asc_if};
 use lemmy_db_schema::{
   impls::local_user::LocalUserOptionHelper,
-  newtypes::{CommunityId, MultiCommunityId, PostId},
+  newtypes::{CommunityId, ContentFilterId, LocalUserId, MultiCommunityId, PostId},
   source::{
     community::CommunityActions,
+    content_filter::ContentFilter,
     local_user::LocalUser,
     person::Person,
     post::{Post, PostActions, post_actions_keys as pa_key, post_keys as key},
@@ -28,6 +30,7 @@ use lemmy_db_schema::{
   utils::{
     limit_fetch,
     queries::filters::{
+      content_filter_matches_post,
       filter_blocked,
       filter_is_subscribed,
       filter_not_unlisted_or_is_subscribed,
@@ -38,7 +41,13 @@ use lemmy_db_schema::{
 use lemmy_db_schema_file::{
   InstanceId,
   PersonId,
-  enums::{CommunityFollowerState, CommunityVisibility, ListingType, PostSortType},
+  enums::{
+    CommunityFollowerState,
+    CommunityVisibility,
+    ContentFilterAction,
+    ListingType,
+    PostSortType,
+  },
   joins::{
     creator_community_actions_join,
     creator_community_instance_actions_join,
@@ -51,10 +60,12 @@ use lemmy_db_schema_file::{
     my_local_user_admin_join,
     my_person_actions_join,
     my_post_actions_join,
+    post_event_join,
   },
   schema::{
     community,
     community_actions,
+    content_filter,
     local_user_language,
     multi_community_entry,
     person,
@@ -130,6 +141,7 @@ impl PostView {
       .inner_join(person::table)
       .inner_join(community::table)
       .left_join(image_details_join())
+      .left_join(post_event_join())
       .left_join(creator_home_instance_actions_join())
       .left_join(creator_community_instance_actions_join())
       .left_join(creator_local_instance_actions_join)
@@ -188,6 +200,18 @@ impl PostView {
           community::visibility
             .ne(CommunityVisibility::Private)
             .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
+        )
+        // Quarantined posts are only visible to the creator until they are approved
+        .filter(
+          post::quarantined
+            .eq(false)
+            .or(post::creator_id.nullable().eq(my_person_id)),
+        )
+        // Same for posts which are waiting for mod approval
+        .filter(
+          post::pending_approval
+            .eq(false)
+            .or(post::creator_id.nullable().eq(my_person_id)),
         );
     }
 
@@ -280,6 +304,12 @@ pub struct PostQuery<'a> {
   pub show_nsfw: Option<bool>,
   pub hide_media: Option<bool>,
   pub no_comments_only: Option<bool>,
+  /// Only list posts which don't have an accepted answer yet.
+  pub unanswered_only: Option<bool>,
+  /// Only list posts which are held back for review because their creator is quarantined.
+  pub quarantined_only: Option<bool>,
+  /// Only list posts which are waiting for approval by a mod.
+  pub pending_approval_only: Option<bool>,
   pub keyword_blocks: Option<Vec<String>>,
   pub page_cursor: Option<PaginationCursor>,
   /// For backwards compat with API v3 (not available on API v4).
@@ -360,6 +390,11 @@ impl PostQuery<'_> {
 
     let my_person_id = o.local_user.person_id();
     let my_local_user_id = o.local_user.local_user_id();
+    // Matching the content filters is expensive, so it is skipped for users without filters
+    let filter_actions = match my_local_user_id {
+      Some(local_user_id) => ContentFilter::list_active_actions(pool, local_user_id).await?,
+      None => vec![],
+    };
 
     let mut query = PostView::joins(my_person_id, site.instance_id)
       .select(PostView::as_select())
@@ -439,6 +474,10 @@ impl PostQuery<'_> {
       query = query.filter(post::comments.eq(0));
     };
 
+    if o.unanswered_only.unwrap_or_default() {
+      query = query.filter(post::accepted_answer_id.is_null());
+    };
+
     if !o.show_read.unwrap_or(o.local_user.show_read_posts()) {
       query = query.filter(post_actions::read_at.is_null());
     }
@@ -465,8 +504,34 @@ impl PostQuery<'_> {
         .or(post::creator_id.nullable().eq(my_person_id)),
     );
 
+    if o.quarantined_only.unwrap_or_default() {
+      query = query
+        .filter(post::quarantined.eq(true))
+        .filter(post::removed.eq(false));
+    }
+
+    if o.pending_approval_only.unwrap_or_default() {
+      query = query
+        .filter(post::pending_approval.eq(true))
+        .filter(post::removed.eq(false));
+    }
+
     if !o.local_user.is_admin() {
       query = query
+        // quarantined posts are only visible to the creator and to mods
+        .filter(
+          post::quarantined
+            .eq(false)
+            .or(post::creator_id.nullable().eq(my_person_id))
+            .or(community_actions::became_moderator_at.is_not_null()),
+        )
+        // same for posts waiting for approval
+        .filter(
+          post::pending_approval
+            .eq(false)
+            .or(post::creator_id.nullable().eq(my_person_id))
+            .or(community_actions::became_moderator_at.is_not_null()),
+        )
         .filter(
           community::visibility
             .ne(CommunityVisibility::Private)
@@ -507,6 +572,86 @@ impl PostQuery<'_> {
           );
         }
       }
+
+      if let Some(local_user_id) = my_local_user_id
+        && filter_actions.contains(&ContentFilterAction::Hide)
+      {
+        query = query.filter(not(exists(
+          content_filter::table
+            .filter(content_filter::local_user_id.eq(local_user_id))
+            .filter(content_filter::action.eq(ContentFilterAction::Hide))
+            .filter(content_filter_matches_post()),
+        )));
+      }
+    }
+
+    // Show each link only once in the front page, in the community where it was posted first.
+    // Copies only count if they would also be shown in this listing, otherwise the link would
+    // disappear completely.
+    if let Some(person_id) = my_person_id
+      && o.local_user.collapse_crossposts()
+      && o.community_id.is_none()
+      && o.multi_community_id.is_none()
+    {
+      let mut listed_communities = community::table
+        .select(community::id)
+        .filter(community::deleted.eq(false))
+        .filter(community::removed.eq(false))
+        .filter(community::local_removed.eq(false))
+        .into_boxed();
+      let my_community_actions =
+        community_actions::table.filter(community_actions::person_id.eq(person_id));
+      let collapse = match o.listing_type.unwrap_or_default() {
+        ListingType::Subscribed => {
+          let subscribed = my_community_actions
+            .filter(community_actions::follow_state.eq(CommunityFollowerState::Accepted))
+            .select(community_actions::community_id);
+          listed_communities = listed_communities.filter(community::id.eq_any(subscribed));
+          true
+        }
+        ListingType::Local | ListingType::All => {
+          if o.listing_type == Some(ListingType::Local) {
+            listed_communities = listed_communities.filter(community::local.eq(true));
+          }
+          let blocked = my_community_actions
+            .filter(community_actions::blocked_at.is_not_null())
+            .select(community_actions::community_id);
+          listed_communities = listed_communities
+            .filter(community::visibility.eq_any([
+              CommunityVisibility::Public,
+              CommunityVisibility::LocalOnlyPublic,
+              CommunityVisibility::LocalOnlyPrivate,
+            ]))
+            .filter(community::id.ne_all(blocked));
+          true
+        }
+        ListingType::ModeratorView | ListingType::Suggested => false,
+      };
+
+      if collapse {
+        let crosspost = diesel::alias!(post as crosspost);
+        let earlier_copies = crosspost
+          .select(crosspost.field(post::id))
+          .filter(
+            crosspost
+              .field(post::url)
+              .eq(post::url)
+              .or(post::original_post_id.eq(crosspost.field(post::id).nullable())),
+          )
+          .filter(crosspost.field(post::published_at).lt(post::published_at))
+          .filter(crosspost.field(post::deleted).eq(false))
+          .filter(crosspost.field(post::removed).eq(false))
+          .filter(crosspost.field(post::federation_pending).eq(false))
+          .filter(crosspost.field(post::quarantined).eq(false))
+          .filter(crosspost.field(post::pending_approval).eq(false))
+          .filter(crosspost.field(post::scheduled_publish_time_at).is_null())
+          .filter(
+            crosspost
+              .field(post::community_id)
+              .eq_any(listed_communities),
+          );
+        query = query.filter(not(exists(earlier_copies)));
+      }
     }
 
     // Filter by the time range
@@ -568,11 +713,32 @@ impl PostQuery<'_> {
 
     debug!("Post View Query: {:?}", debug_query::<Pg, _>(&query));
     let conn = &mut get_conn(pool).await?;
-    let res = Commented::new(query)
-      .text("PostQuery::list")
-      .load::<PostView>(conn)
-      .await
-      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    let query = Commented::new(query).text("PostQuery::list");
+    let res = if let Some(local_user_id) = my_local_user_id
+      && !filter_actions.is_empty()
+    {
+      conn
+        .run_transaction(|conn| {
+          async move {
+            ContentFilter::set_statement_timeout(conn).await?;
+            let mut res = query
+              .load::<PostView>(conn)
+              .await
+              .with_lemmy_type(LemmyErrorType::NotFound)?;
+            if filter_actions.contains(&ContentFilterAction::Warn) {
+              mark_warn_filters(&mut res, local_user_id, conn).await?;
+            }
+            Ok(res)
+          }
+          .scope_boxed()
+        })
+        .await?
+    } else {
+      query
+        .load::<PostView>(conn)
+        .await
+        .with_lemmy_type(LemmyErrorType::NotFound)?
+    };
     paginate_response(res, limit, o.page_cursor)
   }
 
@@ -589,3 +755,37 @@ impl PostQuery<'_> {
       .await
   }
 }
+
+/// Marks the posts which match any of the user's warn filters.
+async fn mark_warn_filters(
+  res: &mut [PostView],
+  local_user_id: LocalUserId,
+  conn: &mut AsyncPgConnection,
+) -> LemmyResult<()> {
+  if res.is_empty() {
+    return Ok(());
+  }
+  let post_ids: Vec<PostId> = res.iter().map(|pv| pv.post.id).collect();
+  let matched: Vec<(PostId, ContentFilterId)> = post::table
+    .inner_join(person::table)
+    .inner_join(
+      content_filter::table.on(
+        content_filter::local_user_id
+          .eq(local_user_id)
+          .and(content_filter::action.eq(ContentFilterAction::Warn)),
+      ),
+    )
+    .filter(post::id.eq_any(post_ids))
+    .filter(content_filter_matches_post())
+    .select((post::id, content_filter::id))
+    .load(conn)
+    .await?;
+  for pv in res {
+    pv.matched_filters = matched
+      .iter()
+      .filter(|(post_id, _)| *post_id == pv.post.id)
+      .map(|(_, filter_id)| *filter_id)
+      .collect();
+  }
+  Ok(())
+}
@@ -1,10 +1,15 @@
 use chrono::{DateTime, Utc};
-use lemmy_db_schema::source::{
-  community::{Community, CommunityActions},
-  images::ImageDetails,
-  person::{Person, PersonActions},
-  post::{Post, PostActions},
-  tag::TagsView,
+use lemmy_db_schema::{
+  newtypes::ContentFilterId,
+  source::{
+    community::{Community, CommunityActions},
+    images::ImageDetails,
+    person::{Person, PersonActions},
+    post::{Post, PostActions, PostCrosspostsView},
+    post_event::PostEvent,
+    post_gallery::PostGalleryView,
+    tag::TagsView,
+  },
 };
 use serde::{Deserialize, Serialize};
 #[cfg(test)]
@@ -18,13 +23,19 @@ use {
   lemmy_db_schema::utils::queries::selects::post_select_remove_deletes,
   lemmy_db_schema::utils::queries::selects::{
     CreatorLocalHomeBanExpiresType,
+    NoMatchedFiltersType,
     creator_ban_expires_from_community,
     creator_banned_from_community,
+    creator_community_flair,
+    creator_community_flair_emoji,
     creator_is_moderator,
     creator_local_home_ban_expires,
     creator_local_home_community_banned,
     local_user_can_mod_post,
+    no_matched_filters,
     post_creator_is_admin,
+    post_crossposts_fragment,
+    post_gallery_fragment,
     post_tags_fragment,
   },
 };
@@ -53,6 +64,9 @@ pub struct PostView {
   #[cfg_attr(feature = "full", diesel(embed))]
   pub image_details: Option<ImageDetails>,
   #[cfg_attr(feature = "full", diesel(embed))]
+  /// Only set for event posts.
+  pub event: Option<PostEvent>,
+  #[cfg_attr(feature = "full", diesel(embed))]
   pub community_actions: Option<CommunityActions>,
   #[cfg_attr(feature = "full", diesel(embed))]
   pub person_actions: Option<PersonActions>,
@@ -70,6 +84,20 @@ pub struct PostView {
     )
   )]
   pub tags: TagsView,
+  /// The images of a gallery post. Empty for other posts.
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = post_gallery_fragment()
+    )
+  )]
+  pub gallery: PostGalleryView,
+  /// Other communities where the same link or a crosspost of this post was posted.
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = post_crossposts_fragment()
+    )
+  )]
+  pub also_posted_in: PostCrosspostsView,
   #[cfg_attr(feature = "full",
     diesel(
       select_expression = local_user_can_mod_post()
@@ -107,4 +135,25 @@ pub struct PostView {
     )
   )]
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = creator_community_flair()
+    )
+  )]
+  pub creator_flair: Option<String>,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = creator_community_flair_emoji()
+    )
+  )]
+  pub creator_flair_emoji: Option<String>,
+  /// Content filters of the user with the warn action which match this post. The post should
+  /// be collapsed behind a warning.
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression_type = NoMatchedFiltersType,
+      select_expression = no_matched_filters()
+    )
+  )]
+  pub matched_filters: Vec<ContentFilterId>,
 }
@@ -20,6 +20,8 @@ use lemmy_db_schema::{
       CommunityPersonBanForm,
       CommunityUpdateForm,
     },
+    content_filter::{ContentFilter, ContentFilterInsertForm, ContentFilterUpdateForm},
+    images::{ImageDetails, ImageDetailsInsertForm},
     instance::{
       Instance,
       InstanceActions,
@@ -34,6 +36,7 @@ use lemmy_db_schema::{
     multi_community::{MultiCommunity, MultiCommunityInsertForm},
     person::{Person, PersonActions, PersonBlockForm, PersonInsertForm, PersonNoteForm},
     post::{Post, PostActions, PostHideForm, PostInsertForm, PostLikeForm, PostUpdateForm},
+    post_gallery::{PostGalleryItem, PostGalleryItemInsertForm},
     site::Site,
     tag::{PostTag, Tag, TagInsertForm},
   },
@@ -43,12 +46,15 @@ use lemmy_db_schema::{
 use lemmy_db_schema_file::enums::{
   CommunityFollowerState,
   CommunityVisibility,
+  ContentFilterAction,
+  ContentFilterMatch,
   ListingType,
   PostSortType,
 };
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_diesel_utils::{
   connection::{ActualDbPool, DbPool, build_db_pool, get_conn},
+  dburl::DbUrl,
   pagination::PaginationCursor,
   traits::Crud,
 };
@@ -176,6 +182,10 @@ impl Data {
         description: None,
         community_id: community.id,
         deleted: Some(false),
+        color: None,
+        emoji: None,
+        mod_only: None,
+        user_flair: None,
       },
     )
     .await?;
@@ -188,6 +198,10 @@ impl Data {
         description: None,
         community_id: community.id,
         deleted: Some(false),
+        color: None,
+        emoji: None,
+        mod_only: None,
+        user_flair: None,
       },
     )
     .await?;
@@ -972,6 +986,66 @@ async fn post_listings_deleted(data: &mut Data) -> LemmyResult<()> {
   Ok(())
 }
 
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_listings_pending_approval(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  Post::update(
+    pool,
+    data.post.id,
+    &PostUpdateForm {
+      pending_approval: Some(true),
+      ..Default::default()
+    },
+  )
+  .await?;
+
+  // Pending post is only shown to creator (and mods)
+  for (local_user, expect_contains_pending) in [
+    (None, false),
+    (Some(&data.john.local_user), false),
+    (Some(&data.tegan.local_user), true),
+  ] {
+    let contains_pending = PostQuery {
+      local_user,
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?
+    .iter()
+    .any(|p| p.post.id == data.post.id);
+
+    assert_eq!(expect_contains_pending, contains_pending);
+  }
+
+  let read_pending = PostView::read(
+    pool,
+    data.post.id,
+    Some(&data.john.local_user),
+    data.instance.id,
+    false,
+  )
+  .await;
+  assert_eq!(
+    Some(LemmyErrorType::NotFound),
+    read_pending.err().map(|e| e.error_type)
+  );
+
+  // The approval queue only contains the pending post
+  let pending_listing = PostQuery {
+    pending_approval_only: Some(true),
+    ..data.default_post_query()
+  }
+  .list(&data.site, pool)
+  .await?;
+  assert_eq!(vec![POST], names(&pending_listing));
+
+  Ok(())
+}
+
 #[test_context(Data)]
 #[tokio::test]
 #[serial]
@@ -1975,6 +2049,93 @@ async fn post_listings_no_comments_only(data: &mut Data) -> LemmyResult<()> {
   Ok(())
 }
 
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_listings_unanswered_only(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  // Mark a comment as answer to the post
+  let comment_form =
+    CommentInsertForm::new(data.tegan.person.id, data.post.id, "an answer".to_owned());
+  let comment = Comment::create(pool, &comment_form, None).await?;
+  let form = PostUpdateForm {
+    accepted_answer_id: Some(Some(comment.id)),
+    ..Default::default()
+  };
+  Post::update(pool, data.post.id, &form).await?;
+
+  let post_listings_unanswered = PostQuery {
+    sort: Some(PostSortType::New),
+    unanswered_only: Some(true),
+    local_user: Some(&data.tegan.local_user),
+    ..Default::default()
+  }
+  .list(&data.site, pool)
+  .await?;
+
+  assert_eq!(
+    vec![POST_WITH_TAGS, POST_BY_BOT],
+    names(&post_listings_unanswered)
+  );
+
+  // Deleting the answer makes the post unanswered again
+  Comment::delete(pool, comment.id).await?;
+  let post = Post::read(pool, data.post.id).await?;
+  assert_eq!(None, post.accepted_answer_id);
+
+  Ok(())
+}
+
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_listing_creator_flair(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  CommunityActions::update_flair(
+    pool,
+    data.community.id,
+    data.tegan.person.id,
+    Some(("Regular".to_string(), Some("⭐".to_string()))),
+  )
+  .await?;
+  let post_view = PostView::read(pool, data.post.id, None, data.instance.id, false).await?;
+  assert_eq!(
+    (Some("Regular"), Some("⭐")),
+    (
+      post_view.creator_flair.as_deref(),
+      post_view.creator_flair_emoji.as_deref()
+    )
+  );
+
+  // The flair is only shown in its own community
+  let other_community_form = CommunityInsertForm::new(
+    data.instance.id,
+    "test_flair_other".to_string(),
+    "nada".to_owned(),
+    "pubkey".to_string(),
+  );
+  let other_community = Community::create(pool, &other_community_form).await?;
+  let other_post_form = PostInsertForm::new(
+    "post elsewhere".to_string(),
+    data.tegan.person.id,
+    other_community.id,
+  );
+  let other_post = Post::create(pool, &other_post_form).await?;
+  let other_post_view = PostView::read(pool, other_post.id, None, data.instance.id, false).await?;
+  assert_eq!(None, other_post_view.creator_flair);
+
+  CommunityActions::update_flair(pool, data.community.id, data.tegan.person.id, None).await?;
+  let post_view = PostView::read(pool, data.post.id, None, data.instance.id, false).await?;
+  assert_eq!(None, post_view.creator_flair);
+
+  Community::delete(pool, other_community.id).await?;
+  Ok(())
+}
+
 #[test_context(Data)]
 #[tokio::test]
 #[serial]
@@ -2207,6 +2368,56 @@ async fn post_with_blocked_keywords(data: &mut Data) -> LemmyResult<()> {
   assert!(names(&post_listings).contains(&POST));
   Ok(())
 }
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_listing_content_filters(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  let post_listings = data.default_post_query().list(&data.site, pool).await?;
+  assert!(names(&post_listings).contains(&POST_BY_BOT));
+
+  let hide_form = ContentFilterInsertForm::new(
+    data.tegan.local_user.id,
+    "bot".to_string(),
+    ContentFilterMatch::WholeWord,
+    ContentFilter::build_pattern(ContentFilterMatch::WholeWord, "bot"),
+  );
+  ContentFilter::create(pool, &hide_form).await?;
+  let warn_form = ContentFilterInsertForm {
+    action: Some(ContentFilterAction::Warn),
+    ..ContentFilterInsertForm::new(
+      data.tegan.local_user.id,
+      "TAGS".to_string(),
+      ContentFilterMatch::Keyword,
+      ContentFilter::build_pattern(ContentFilterMatch::Keyword, "TAGS"),
+    )
+  };
+  let warn_filter = ContentFilter::create(pool, &warn_form).await?;
+
+  // The bot post is hidden, and the post with tags is marked
+  let post_listings = data.default_post_query().list(&data.site, pool).await?;
+  assert_eq!(vec![POST_WITH_TAGS, POST], names(&post_listings));
+  assert_eq!(vec![warn_filter.id], post_listings[0].matched_filters);
+  assert!(post_listings[1].matched_filters.is_empty());
+
+  // Expired filters are ignored
+  ContentFilter::update(
+    pool,
+    warn_filter.id,
+    &ContentFilterUpdateForm {
+      expires_at: Some(Some(Utc::now() - Duration::from_secs(60))),
+      ..Default::default()
+    },
+  )
+  .await?;
+  let post_listings = data.default_post_query().list(&data.site, pool).await?;
+  assert!(post_listings[0].matched_filters.is_empty());
+
+  Ok(())
+}
+
 #[test_context(Data)]
 #[tokio::test]
 #[serial]
@@ -2235,6 +2446,180 @@ async fn post_tags_present(data: &mut Data) -> LemmyResult<()> {
   Ok(())
 }
 
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_gallery_present(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  let first_url: DbUrl = Url::parse("https://example.com/first.jpg")?.into();
+  let second_url: DbUrl = Url::parse("https://example.com/second.jpg")?.into();
+  ImageDetails::create(
+    pool,
+    &ImageDetailsInsertForm {
+      link: second_url.clone(),
+      width: 800,
+      height: 600,
+      content_type: "image/jpeg".to_string(),
+      blurhash: None,
+    },
+  )
+  .await?;
+  let forms = vec![
+    PostGalleryItemInsertForm::new(data.post.id, first_url.clone(), None, 0),
+    PostGalleryItemInsertForm::new(
+      data.post.id,
+      second_url.clone(),
+      Some("Second".to_string()),
+      1,
+    ),
+  ];
+  PostGalleryItem::replace(pool, data.post.id, &forms).await?;
+
+  let post_view = PostView::read(
+    pool,
+    data.post.id,
+    Some(&data.tegan.local_user),
+    data.instance.id,
+    false,
+  )
+  .await?;
+  let gallery = post_view.gallery.0;
+  assert_eq!(
+    vec![first_url, second_url],
+    gallery
+      .iter()
+      .map(|i| i.item.url.clone())
+      .collect::<Vec<_>>()
+  );
+  assert_eq!(
+    vec![None, Some(800)],
+    gallery
+      .iter()
+      .map(|i| i.image_details.as_ref().map(|d| d.width))
+      .collect::<Vec<_>>()
+  );
+
+  // Other posts have no gallery
+  let all_posts = data.default_post_query().list(&data.site, pool).await?;
+  assert_eq!(
+    vec![0, 0, 2],
+    all_posts
+      .iter()
+      .map(|p| p.gallery.0.len())
+      .collect::<Vec<_>>()
+  );
+
+  Ok(())
+}
+
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_listing_crossposts(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  let form = CommunityInsertForm::new(
+    data.instance.id,
+    "test_community_crossposts".to_string(),
+    "nada".to_owned(),
+    "pubkey".to_string(),
+  );
+  let other_community = Community::create(pool, &form).await?;
+
+  // A link which was posted to both communities
+  let url: DbUrl = Url::parse("https://example.com/article")?.into();
+  let form = PostInsertForm {
+    url: Some(url.clone()),
+    ..PostInsertForm::new(POST.to_string(), data.tegan.person.id, data.community.id)
+  };
+  let link_post = Post::create(pool, &form).await?;
+  let form = PostInsertForm {
+    url: Some(url),
+    ..PostInsertForm::new(POST.to_string(), data.john.person.id, other_community.id)
+  };
+  let same_link_post = Post::create(pool, &form).await?;
+
+  // An explicit crosspost of a text post
+  let form = PostInsertForm {
+    original_post_id: Some(data.post.id),
+    ..PostInsertForm::new(POST.to_string(), data.tegan.person.id, other_community.id)
+  };
+  let crosspost = Post::create(pool, &form).await?;
+
+  let also_posted_in = |post_view: PostView| {
+    post_view
+      .also_posted_in
+      .0
+      .into_iter()
+      .map(|c| (c.post_id, c.community.id))
+      .collect::<Vec<_>>()
+  };
+  let expected = [
+    (link_post.id, vec![(same_link_post.id, other_community.id)]),
+    (data.post.id, vec![(crosspost.id, other_community.id)]),
+    (crosspost.id, vec![(data.post.id, data.community.id)]),
+    (data.bot_post.id, vec![]),
+  ];
+  for (post_id, crossposts) in expected {
+    let post_view = PostView::read(
+      pool,
+      post_id,
+      Some(&data.tegan.local_user),
+      data.instance.id,
+      false,
+    )
+    .await?;
+    assert_eq!(crossposts, also_posted_in(post_view));
+  }
+
+  let all_query = PostQuery {
+    listing_type: Some(ListingType::All),
+    ..data.default_post_query()
+  };
+  let listing = all_query.clone().list(&data.site, pool).await?;
+  assert!(listing.iter().any(|p| p.post.id == same_link_post.id));
+  assert!(listing.iter().any(|p| p.post.id == crosspost.id));
+
+  // With the setting enabled, only the first copy is shown in the front page
+  let local_user_form = LocalUserUpdateForm {
+    collapse_crossposts: Some(true),
+    ..Default::default()
+  };
+  LocalUser::update(pool, data.tegan.local_user.id, &local_user_form).await?;
+  data.tegan.local_user.collapse_crossposts = true;
+  let all_query = PostQuery {
+    listing_type: Some(ListingType::All),
+    ..data.default_post_query()
+  };
+  let listing = all_query.clone().list(&data.site, pool).await?;
+  assert!(listing.iter().any(|p| p.post.id == link_post.id));
+  assert!(listing.iter().any(|p| p.post.id == data.post.id));
+  assert!(!listing.iter().any(|p| p.post.id == same_link_post.id));
+  assert!(!listing.iter().any(|p| p.post.id == crosspost.id));
+
+  // Copies in a blocked community don't hide the other posts
+  let form = CommunityBlockForm::new(data.community.id, data.tegan.person.id);
+  CommunityActions::block(pool, &form).await?;
+  let listing = all_query.list(&data.site, pool).await?;
+  assert!(listing.iter().any(|p| p.post.id == same_link_post.id));
+  assert!(listing.iter().any(|p| p.post.id == crosspost.id));
+  CommunityActions::unblock(pool, &form).await?;
+
+  // Inside a community all posts are listed
+  let listing = PostQuery {
+    community_id: Some(other_community.id),
+    ..data.default_post_query()
+  }
+  .list(&data.site, pool)
+  .await?;
+  assert_eq!(2, listing.len());
+
+  Ok(())
+}
+
 #[test_context(Data)]
 #[tokio::test]
 #[serial]
@@ -5,6 +5,8 @@ use lemmy_db_schema::source::{
   images::ImageDetails,
   person::{Person, PersonActions},
   post::{Post, PostActions},
+  post_event::PostEvent,
+  post_gallery::PostGalleryView,
   tag::TagsView,
 };
 use lemmy_db_views_comment::CommentView;
@@ -18,11 +20,14 @@ use {
     CreatorLocalHomeCommunityBanExpiresType,
     creator_ban_expires_from_community,
     creator_banned_from_community,
+    creator_community_flair,
+    creator_community_flair_emoji,
     creator_is_admin,
     creator_is_moderator,
     creator_local_home_community_ban_expires,
     creator_local_home_community_banned,
     local_user_can_mod,
+    post_gallery_fragment,
     post_tags_fragment,
   },
 };
@@ -50,10 +55,14 @@ pub struct PostCommentCombinedViewInternal {
   pub comment_actions: Option<CommentActions>,
   #[diesel(embed)]
   pub image_details: Option<ImageDetails>,
+  #[diesel(embed)]
+  pub post_event: Option<PostEvent>,
   #[diesel(select_expression = creator_is_admin())]
   pub item_creator_is_admin: bool,
   #[diesel(select_expression = post_tags_fragment())]
   pub post_tags: TagsView,
+  #[diesel(select_expression = post_gallery_fragment())]
+  pub post_gallery: PostGalleryView,
   #[diesel(select_expression = local_user_can_mod())]
   pub can_mod: bool,
   #[diesel(select_expression = creator_local_home_community_banned())]
@@ -69,6 +78,10 @@ pub struct PostCommentCombinedViewInternal {
   pub creator_banned_from_community: bool,
   #[diesel(select_expression = creator_ban_expires_from_community())]
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  #[diesel(select_expression = creator_community_flair())]
+  pub creator_flair: Option<String>,
+  #[diesel(select_expression = creator_community_flair_emoji())]
+  pub creator_flair_emoji: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -105,6 +118,9 @@ impl InternalToCombinedView for PostCommentCombinedViewInternal {
         creator_is_moderator: v.creator_is_moderator,
         creator_banned_from_community: v.creator_banned_from_community,
         creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+        creator_flair: v.creator_flair,
+        creator_flair_emoji: v.creator_flair_emoji,
+        matched_filters: vec![],
       }))
     } else {
       Some(PostCommentCombinedView::Post(PostView {
@@ -112,17 +128,23 @@ impl InternalToCombinedView for PostCommentCombinedViewInternal {
         community: v.community,
         creator: v.item_creator,
         image_details: v.image_details,
+        event: v.post_event,
         community_actions: v.community_actions,
         post_actions: v.post_actions,
         person_actions: v.person_actions,
         creator_is_admin: v.item_creator_is_admin,
         tags: v.post_tags,
+        gallery: v.post_gallery,
+        also_posted_in: Default::default(),
         can_mod: v.can_mod,
         creator_banned: v.creator_banned,
         creator_ban_expires_at: v.creator_ban_expires_at,
         creator_is_moderator: v.creator_is_moderator,
         creator_banned_from_community: v.creator_banned_from_community,
         creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+        creator_flair: v.creator_flair,
+        creator_flair_emoji: v.creator_flair_emoji,
+        matched_filters: vec![],
       }))
     }
   }
@@ -1,5 +1,8 @@
 use crate::PrivateMessageView;
-use lemmy_db_schema::newtypes::PrivateMessageId;
+use lemmy_db_schema::{
+  newtypes::PrivateMessageId,
+  source::revision::{DiffLine, PrivateMessageRevision},
+};
 use lemmy_db_schema_file::PersonId;
 use serde::{Deserialize, Serialize};
 
@@ -37,3 +40,28 @@ pub struct EditPrivateMessage {
 pub struct PrivateMessageResponse {
   pub private_message_view: PrivateMessageView,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the edit history of a private message.
+pub struct ListPrivateMessageRevisions {
+  pub private_message_id: PrivateMessageId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A previous version of a private message, with the changes made by the edit which replaced it.
+pub struct PrivateMessageRevisionDiff {
+  pub revision: PrivateMessageRevision,
+  pub content_diff: Vec<DiffLine>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The edit history of a private message, oldest first.
+pub struct ListPrivateMessageRevisionsResponse {
+  pub revisions: Vec<PrivateMessageRevisionDiff>,
+}
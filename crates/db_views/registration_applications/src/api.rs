@@ -1,6 +1,15 @@
 use crate::RegistrationApplicationView;
-use lemmy_db_schema::newtypes::RegistrationApplicationId;
-use lemmy_db_schema_file::PersonId;
+use lemmy_db_schema::{
+  newtypes::{RegistrationApplicationId, RegistrationQuestionId, RegistrationScreeningRuleId},
+  source::{
+    registration_question::RegistrationQuestion,
+    registration_screening_rule::RegistrationScreeningRule,
+  },
+};
+use lemmy_db_schema_file::{
+  PersonId,
+  enums::{RegistrationQuestionKind, RegistrationScreeningAction, RegistrationScreeningRuleKind},
+};
 use lemmy_diesel_utils::{pagination::PaginationCursor, sensitive::SensitiveString};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -55,10 +64,16 @@ pub struct Register {
   pub captcha_answer: Option<String>,
   /// A form field to trick signup bots. Should be None.
   pub honeypot: Option<String>,
-  /// An answer is mandatory if require application is enabled on the server
+  /// An answer is mandatory if require application is enabled on the server, and the site has no
+  /// registration questions.
   pub answer: Option<String>,
-  /// If this is true the login is valid forever, otherwise it expires after one week.
+  /// The answers to the registration questions of the site.
+  pub answers: Option<Vec<RegistrationQuestionAnswer>>,
+  /// If this is true the session lasts until it is idle for too long, otherwise it ends after one
+  /// week.
   pub stay_logged_in: Option<bool>,
+  /// An invite code is mandatory if the server is invite only.
+  pub invite_code: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -68,3 +83,99 @@ pub struct Register {
 pub struct RegistrationApplicationResponse {
   pub registration_application: RegistrationApplicationView,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An answer to a registration question.
+pub struct RegistrationQuestionAnswer {
+  pub question_id: RegistrationQuestionId,
+  pub answer: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Create a registration question.
+pub struct CreateRegistrationQuestion {
+  pub question: String,
+  pub kind: Option<RegistrationQuestionKind>,
+  /// The possible answers, for choice questions.
+  pub choices: Option<Vec<String>>,
+  pub required: Option<bool>,
+  pub display_order: Option<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edit a registration question.
+pub struct EditRegistrationQuestion {
+  pub id: RegistrationQuestionId,
+  pub question: Option<String>,
+  pub kind: Option<RegistrationQuestionKind>,
+  pub choices: Option<Vec<String>>,
+  pub required: Option<bool>,
+  pub display_order: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a registration question. Answers given to it are kept.
+pub struct DeleteRegistrationQuestion {
+  pub id: RegistrationQuestionId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RegistrationQuestionResponse {
+  pub registration_question: RegistrationQuestion,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListRegistrationQuestionsResponse {
+  pub registration_questions: Vec<RegistrationQuestion>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Create a rule to screen new registration applications automatically.
+pub struct CreateRegistrationScreeningRule {
+  pub kind: RegistrationScreeningRuleKind,
+  /// A domain like `example.com`, a text to search in the answers, or an IP range like
+  /// `10.0.0.0/8`, depending on the kind.
+  pub value: String,
+  pub action: RegistrationScreeningAction,
+  /// Shown to admins in the application.
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a registration screening rule.
+pub struct DeleteRegistrationScreeningRule {
+  pub id: RegistrationScreeningRuleId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RegistrationScreeningRuleResponse {
+  pub registration_screening_rule: RegistrationScreeningRule,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListRegistrationScreeningRulesResponse {
+  pub registration_screening_rules: Vec<RegistrationScreeningRule>,
+}
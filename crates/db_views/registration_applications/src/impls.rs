@@ -169,6 +169,11 @@ mod tests {
       RegistrationApplicationInsertForm,
       RegistrationApplicationUpdateForm,
     },
+    registration_question::{
+      RegistrationAnswer,
+      RegistrationAnswerInsertForm,
+      RegistrationAnswersView,
+    },
   };
   use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
   use lemmy_utils::error::LemmyResult;
@@ -201,10 +206,8 @@ mod tests {
     let sara_local_user = LocalUser::create(pool, &sara_local_user_form, vec![]).await?;
 
     // Sara creates an application
-    let sara_app_form = RegistrationApplicationInsertForm {
-      local_user_id: sara_local_user.id,
-      answer: "LET ME IIIIINN".to_string(),
-    };
+    let sara_app_form =
+      RegistrationApplicationInsertForm::new(sara_local_user.id, "LET ME IIIIINN".to_string());
 
     let sara_app = RegistrationApplication::create(pool, &sara_app_form).await?;
 
@@ -219,13 +222,20 @@ mod tests {
     let jess_local_user = LocalUser::create(pool, &jess_local_user_form, vec![]).await?;
 
     // Sara creates an application
-    let jess_app_form = RegistrationApplicationInsertForm {
-      local_user_id: jess_local_user.id,
-      answer: "LET ME IIIIINN".to_string(),
-    };
+    let jess_app_form =
+      RegistrationApplicationInsertForm::new(jess_local_user.id, "LET ME IIIIINN".to_string());
 
     let jess_app = RegistrationApplication::create(pool, &jess_app_form).await?;
 
+    // Jess also answers the questionnaire
+    let jess_answer_form = RegistrationAnswerInsertForm::new(
+      jess_app.id,
+      None,
+      "Why do you want to join?".to_string(),
+      "To meet people".to_string(),
+    );
+    RegistrationAnswer::create_many(pool, &[jess_answer_form]).await?;
+
     let read_jess_app_view = RegistrationApplicationView::read(pool, jess_app.id).await?;
 
     let mut expected_sara_app_view = RegistrationApplicationView {
@@ -267,6 +277,11 @@ mod tests {
         show_score: sara_local_user.show_score,
         show_upvote_percentage: sara_local_user.show_upvote_percentage,
         show_person_votes: sara_local_user.show_person_votes,
+        admin_permissions: sara_local_user.admin_permissions,
+        invited_by_id: sara_local_user.invited_by_id,
+        deletion_scheduled_at: sara_local_user.deletion_scheduled_at,
+        deletion_delete_content: sara_local_user.deletion_delete_content,
+        collapse_crossposts: sara_local_user.collapse_crossposts,
       },
       creator: Person {
         id: sara_person.id,
@@ -293,9 +308,18 @@ mod tests {
         comment_score: 0,
       },
       admin: None,
+      answers: RegistrationAnswersView::default(),
     };
 
     assert_eq!(read_sara_app_view, expected_sara_app_view);
+    assert_eq!(
+      RegistrationAnswersView(vec![RegistrationAnswer {
+        question_id: None,
+        question: "Why do you want to join?".to_string(),
+        answer: "To meet people".to_string(),
+      }]),
+      read_jess_app_view.answers
+    );
 
     // Do a batch read of the applications
     let apps = RegistrationApplicationQuery {
@@ -2,13 +2,17 @@ use lemmy_db_schema::source::{
   local_user::LocalUser,
   person::Person,
   registration_application::RegistrationApplication,
+  registration_question::RegistrationAnswersView,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
 use {
   diesel::{NullableExpressionMethods, Queryable, Selectable, helper_types::Nullable},
-  lemmy_db_schema::{Person1AliasAllColumnsTuple, utils::queries::selects::person1_select},
+  lemmy_db_schema::{
+    Person1AliasAllColumnsTuple,
+    utils::queries::selects::{person1_select, registration_answers_fragment},
+  },
 };
 
 pub mod api;
@@ -36,4 +40,11 @@ pub struct RegistrationApplicationView {
     )
   )]
   pub admin: Option<Person>,
+  #[cfg_attr(feature = "full",
+    diesel(
+      select_expression = registration_answers_fragment()
+    )
+  )]
+  /// The answers to the registration questionnaire.
+  pub answers: RegistrationAnswersView,
 }
@@ -1,7 +1,14 @@
-use crate::{CommentReportView, CommunityReportView, PostReportView, PrivateMessageReportView};
+use crate::{
+  BanAppealView,
+  CommentReportView,
+  CommunityReportView,
+  PostReportView,
+  PrivateMessageReportView,
+};
 use lemmy_db_schema::{
   ReportType,
   newtypes::{
+    BanAppealId,
     CommentId,
     CommentReportId,
     CommunityId,
@@ -12,6 +19,7 @@ use lemmy_db_schema::{
     PrivateMessageReportId,
   },
 };
+use lemmy_db_schema_file::enums::BanAppealDecision;
 use lemmy_diesel_utils::pagination::PaginationCursor;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -38,6 +46,14 @@ pub struct ListReports {
   pub my_reports_only: Option<bool>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A ban appeal response.
+pub struct BanAppealResponse {
+  pub ban_appeal_view: BanAppealView,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -54,6 +70,17 @@ pub struct CommunityReportResponse {
   pub community_report_view: CommunityReportView,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Appeal a ban. Only one appeal can be submitted per ban.
+pub struct CreateBanAppeal {
+  /// The community you are banned from. If none is given, appeals your site ban.
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -83,6 +110,20 @@ pub struct CreatePostReport {
   pub violates_instance_rules: Option<bool>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Decide on a ban appeal. Community mods handle appeals against community bans, admins handle
+/// appeals against site bans.
+pub struct ResolveBanAppeal {
+  pub appeal_id: BanAppealId,
+  pub decision: BanAppealDecision,
+  /// The new expiry of a shortened ban, in unix epoch seconds. Required for `Shortened`.
+  pub expires_at: Option<i64>,
+  pub reason: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
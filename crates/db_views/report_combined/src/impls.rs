@@ -1,4 +1,5 @@
 use crate::{
+  BanAppealView,
   CommentReportView,
   CommunityReportView,
   LocalUserView,
@@ -11,6 +12,7 @@ use chrono::{DateTime, Days, Utc};
 use diesel::{
   BoolExpressionMethods,
   ExpressionMethods,
+  PgArrayExpressionMethods,
   PgExpressionMethods,
   QueryDsl,
   SelectableHelper,
@@ -20,6 +22,7 @@ use i_love_jesus::asc_if;
 use lemmy_db_schema::{
   ReportType,
   newtypes::{
+    BanAppealId,
     CommentReportId,
     CommunityId,
     CommunityReportId,
@@ -36,7 +39,9 @@ use lemmy_db_schema::{
 };
 use lemmy_db_schema_file::{
   aliases,
+  enums::{AdminPermission, ModeratorPermission},
   schema::{
+    ban_appeal,
     comment_report,
     community,
     community_actions,
@@ -138,6 +143,25 @@ impl ReportCombinedViewInternal {
     Ok(pm)
   }
 
+  pub async fn read_ban_appeal(
+    pool: &mut DbPool<'_>,
+    appeal_id: BanAppealId,
+    my_person: &Person,
+  ) -> LemmyResult<BanAppealView> {
+    let conn = &mut get_conn(pool).await?;
+    let res = report_combined_joins(my_person.id, my_person.instance_id)
+      .filter(report_combined::ban_appeal_id.eq(appeal_id))
+      .select(ReportCombinedViewInternal::as_select())
+      .first(conn)
+      .await?;
+
+    let res = InternalToCombinedView::map_to_enum(res);
+    let Some(ReportCombinedView::BanAppeal(b)) = res else {
+      return Err(LemmyErrorType::NotFound.into());
+    };
+    Ok(b)
+  }
+
   /// returns the current unresolved report count for the communities you mod
   pub async fn get_report_count(pool: &mut DbPool<'_>, user: &LocalUserView) -> LemmyResult<i64> {
     use diesel::dsl::count;
@@ -149,10 +173,32 @@ impl ReportCombinedViewInternal {
       .select(count(report_combined::id))
       .into_boxed();
 
-    if user.local_user.admin {
+    if user
+      .local_user
+      .has_admin_permission(Some(AdminPermission::HandleReports))
+    {
       query = query.filter(filter_admin_reports(Utc::now() - Days::new(3)));
     } else {
-      query = query.filter(filter_mod_reports());
+      query = query
+        .filter(filter_mod_reports())
+        // Moderators with restricted permissions only see reports if they can handle reports,
+        // and ban appeals if they can manage bans.
+        .filter(
+          community_actions::moderator_permissions
+            .is_null()
+            .or(
+              report_combined::ban_appeal_id.is_null().and(
+                community_actions::moderator_permissions
+                  .contains(vec![ModeratorPermission::HandleReports]),
+              ),
+            )
+            .or(
+              report_combined::ban_appeal_id.is_not_null().and(
+                community_actions::moderator_permissions
+                  .contains(vec![ModeratorPermission::ManageBans]),
+              ),
+            ),
+        );
     }
 
     query
@@ -171,6 +217,7 @@ impl PaginationCursorConversion for ReportCombinedView {
       ReportCombinedView::Post(v) => ('P', v.post_report.id.0),
       ReportCombinedView::PrivateMessage(v) => ('M', v.private_message_report.id.0),
       ReportCombinedView::Community(v) => ('Y', v.community_report.id.0),
+      ReportCombinedView::BanAppeal(v) => ('B', v.ban_appeal.id.0),
     };
     CursorData::new_with_prefix(prefix, id)
   }
@@ -191,6 +238,7 @@ impl PaginationCursorConversion for ReportCombinedView {
       'P' => query.filter(report_combined::post_report_id.eq(id)),
       'M' => query.filter(report_combined::private_message_report_id.eq(id)),
       'Y' => query.filter(report_combined::community_report_id.eq(id)),
+      'B' => query.filter(report_combined::ban_appeal_id.eq(id)),
       _ => return Err(LemmyErrorType::CouldntParsePaginationToken.into()),
     };
     let token = query.first(conn).await?;
@@ -235,13 +283,35 @@ impl ReportCombinedQuery {
       );
     }
 
-    if user.local_user.admin {
+    if user
+      .local_user
+      .has_admin_permission(Some(AdminPermission::HandleReports))
+    {
       let show_community_rule_violations = self.show_community_rule_violations.unwrap_or_default();
       if !show_community_rule_violations {
         query = query.filter(filter_admin_reports(Utc::now() - Days::new(3)));
       }
     } else {
-      query = query.filter(filter_mod_reports());
+      query = query
+        .filter(filter_mod_reports())
+        // Moderators with restricted permissions only see reports if they can handle reports,
+        // and ban appeals if they can manage bans.
+        .filter(
+          community_actions::moderator_permissions
+            .is_null()
+            .or(
+              report_combined::ban_appeal_id.is_null().and(
+                community_actions::moderator_permissions
+                  .contains(vec![ModeratorPermission::HandleReports]),
+              ),
+            )
+            .or(
+              report_combined::ban_appeal_id.is_not_null().and(
+                community_actions::moderator_permissions
+                  .contains(vec![ModeratorPermission::ManageBans]),
+              ),
+            ),
+        );
     }
 
     if let Some(post_id) = self.post_id {
@@ -261,6 +331,7 @@ impl ReportCombinedQuery {
           query.filter(report_combined::private_message_report_id.is_not_null())
         }
         ReportType::Communities => query.filter(report_combined::community_report_id.is_not_null()),
+        ReportType::BanAppeals => query.filter(report_combined::ban_appeal_id.is_not_null()),
       }
     }
 
@@ -319,13 +390,19 @@ fn filter_admin_reports(interval: DateTime<Utc>) -> _ {
 }
 
 /// Filter reports which are only for admins (either post/comment report with
-/// `violates_instance_rules=true`, or report on a community/person/private message.
+/// `violates_instance_rules=true`, or report on a community/person/private message, or an appeal
+/// against a site ban.
 #[diesel::dsl::auto_type]
 fn filter_violates_instance_rules() -> _ {
   post_report::violates_instance_rules
     .or(comment_report::violates_instance_rules)
     .or(report_combined::community_report_id.is_not_null())
     .or(report_combined::private_message_report_id.is_not_null())
+    .or(
+      report_combined::ban_appeal_id
+        .is_not_null()
+        .and(ban_appeal::community_id.is_null()),
+    )
 }
 
 #[diesel::dsl::auto_type]
@@ -334,6 +411,7 @@ fn report_is_not_resolved() -> _ {
     .or(comment_report::resolved)
     .or(private_message_report::resolved)
     .or(community_report::resolved)
+    .or(ban_appeal::resolved)
     .is_distinct_from(true)
 }
 
@@ -416,6 +494,19 @@ impl InternalToCombinedView for ReportCombinedViewInternal {
           creator_ban_expires_at: v.creator_ban_expires_at,
         },
       ))
+    } else if let (Some(ban_appeal), Some(ban)) = (v.ban_appeal, v.ban) {
+      Some(ReportCombinedView::BanAppeal(BanAppealView {
+        ban_appeal,
+        ban,
+        community: v.community,
+        creator: v.report_creator,
+        resolver: v.resolver,
+        creator_is_admin: v.creator_is_admin,
+        creator_banned: v.creator_banned,
+        creator_ban_expires_at: v.creator_ban_expires_at,
+        creator_banned_from_community: v.creator_banned_from_community,
+        creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+      }))
     } else if let (Some(community), Some(community_report)) = (v.community, v.community_report) {
       Some(ReportCombinedView::Community(CommunityReportView {
         community_report,
@@ -452,12 +543,20 @@ mod tests {
     ReportType,
     assert_length,
     source::{
+      ban_appeal::{BanAppeal, BanAppealInsertForm},
       comment::{Comment, CommentInsertForm},
       comment_report::{CommentReport, CommentReportForm},
-      community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
+      community::{
+        Community,
+        CommunityActions,
+        CommunityInsertForm,
+        CommunityModeratorForm,
+        CommunityPersonBanForm,
+      },
       community_report::{CommunityReport, CommunityReportForm},
       instance::{Instance, InstanceActions, InstanceBanForm},
       local_user::{LocalUser, LocalUserInsertForm},
+      modlog::{Modlog, ModlogInsertForm},
       person::{Person, PersonInsertForm},
       post::{Post, PostInsertForm},
       post_report::{PostReport, PostReportForm},
@@ -1139,6 +1238,131 @@ mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn ban_appeals() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    // timmy bans sara from his community, admin bans jessica from the site
+    let form = ModlogInsertForm::mod_ban_from_community(
+      data.timmy.id,
+      data.community.id,
+      data.sara.id,
+      true,
+      None,
+      "spam",
+    );
+    Modlog::create(pool, &[form]).await?;
+    let form = ModlogInsertForm::admin_ban(
+      &data.admin_view.person,
+      data.jessica.id,
+      true,
+      None,
+      "troll",
+    );
+    Modlog::create(pool, &[form]).await?;
+    let sara_ban_form = CommunityPersonBanForm::new(data.community.id, data.sara.id);
+    CommunityActions::ban(pool, &sara_ban_form).await?;
+    let jessica_ban_form = InstanceBanForm::new(data.jessica.id, data.instance.id, None);
+    InstanceActions::ban(pool, &jessica_ban_form).await?;
+
+    let sara_ban = Modlog::read_active_ban(
+      pool,
+      data.sara.id,
+      Some(data.community.id),
+      data.instance.id,
+    )
+    .await?;
+    let jessica_ban =
+      Modlog::read_active_ban(pool, data.jessica.id, None, data.instance.id).await?;
+    let (Some(sara_ban), Some(jessica_ban)) = (sara_ban, jessica_ban) else {
+      panic!("missing ban");
+    };
+    // sara is not banned from the site
+    assert!(
+      Modlog::read_active_ban(pool, data.sara.id, None, data.instance.id)
+        .await?
+        .is_none()
+    );
+
+    let form = BanAppealInsertForm::new(
+      data.sara.id,
+      Some(data.community.id),
+      sara_ban.id,
+      "it was a misunderstanding".into(),
+    );
+    let sara_appeal = BanAppeal::create(pool, &form).await?;
+    let form = BanAppealInsertForm::new(data.jessica.id, None, jessica_ban.id, "sorry".into());
+    let jessica_appeal = BanAppeal::create(pool, &form).await?;
+    assert!(BanAppeal::read_for_ban(pool, sara_ban.id).await?.is_some());
+
+    // The community mod only sees the appeal against the community ban
+    let reports = ReportCombinedQuery {
+      type_: Some(ReportType::BanAppeals),
+      ..Default::default()
+    }
+    .list(pool, &data.timmy_view)
+    .await?;
+    assert_length!(1, reports);
+    if let ReportCombinedView::BanAppeal(v) = &reports[0] {
+      assert_eq!(sara_appeal.id, v.ban_appeal.id);
+      assert_eq!(sara_ban.id, v.ban.id);
+      assert_eq!(data.sara.id, v.creator.id);
+      assert_eq!(Some(data.community.id), v.community.as_ref().map(|c| c.id));
+    } else {
+      panic!("wrong type");
+    }
+
+    // The admin sees the appeal against the site ban
+    let reports = ReportCombinedQuery::default()
+      .list(pool, &data.admin_view)
+      .await?;
+    assert_length!(1, reports);
+    if let ReportCombinedView::BanAppeal(v) = &reports[0] {
+      assert_eq!(jessica_appeal.id, v.ban_appeal.id);
+      assert!(v.community.is_none());
+      let read_appeal = ReportCombinedViewInternal::read_ban_appeal(
+        pool,
+        jessica_appeal.id,
+        &data.admin_view.person,
+      )
+      .await?;
+      assert_eq!(&read_appeal, v);
+    } else {
+      panic!("wrong type");
+    }
+    let count = ReportCombinedViewInternal::get_report_count(pool, &data.admin_view).await?;
+    assert_eq!(1, count);
+
+    // After the unban, there is no active ban left to appeal
+    let form = ModlogInsertForm::mod_ban_from_community(
+      data.timmy.id,
+      data.community.id,
+      data.sara.id,
+      false,
+      None,
+      "lifted",
+    );
+    Modlog::create(pool, &[form]).await?;
+    CommunityActions::unban(pool, &sara_ban_form).await?;
+    assert!(
+      Modlog::read_active_ban(
+        pool,
+        data.sara.id,
+        Some(data.community.id),
+        data.instance.id
+      )
+      .await?
+      .is_none()
+    );
+
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn violates_instance_rules() -> LemmyResult<()> {
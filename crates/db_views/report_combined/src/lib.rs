@@ -1,10 +1,12 @@
 use chrono::{DateTime, Utc};
 use lemmy_db_schema::source::{
+  ban_appeal::BanAppeal,
   combined::report::ReportCombined,
   comment::{Comment, CommentActions},
   comment_report::CommentReport,
   community::{Community, CommunityActions},
   community_report::CommunityReport,
+  modlog::Modlog,
   person::{Person, PersonActions},
   post::{Post, PostActions},
   post_report::PostReport,
@@ -50,6 +52,10 @@ pub struct ReportCombinedViewInternal {
   pub private_message_report: Option<PrivateMessageReport>,
   #[diesel(embed)]
   pub community_report: Option<CommunityReport>,
+  #[diesel(embed)]
+  pub ban_appeal: Option<BanAppeal>,
+  #[diesel(embed)]
+  pub ban: Option<Modlog>,
   #[diesel(
     select_expression_type = Person1AliasAllColumnsTuple,
     select_expression = person1_select()
@@ -104,6 +110,7 @@ pub enum ReportCombinedView {
   Comment(CommentReportView),
   PrivateMessage(PrivateMessageReportView),
   Community(CommunityReportView),
+  BanAppeal(BanAppealView),
 }
 
 #[skip_serializing_none]
@@ -186,3 +193,23 @@ pub struct PostReportView {
   pub creator_banned_from_community: bool,
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A ban appeal view.
+pub struct BanAppealView {
+  pub ban_appeal: BanAppeal,
+  /// The modlog entry of the appealed ban.
+  pub ban: Modlog,
+  /// The community of a community ban, none for a site ban.
+  pub community: Option<Community>,
+  pub creator: Person,
+  pub resolver: Option<Person>,
+  pub creator_is_admin: bool,
+  pub creator_banned: bool,
+  pub creator_ban_expires_at: Option<DateTime<Utc>>,
+  pub creator_banned_from_community: bool,
+  pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+}
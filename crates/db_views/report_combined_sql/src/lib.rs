@@ -16,6 +16,7 @@ use lemmy_db_schema_file::{
     creator_local_instance_actions_join,
   },
   schema::{
+    ban_appeal,
     comment,
     comment_actions,
     comment_report,
@@ -23,6 +24,7 @@ use lemmy_db_schema_file::{
     community_actions,
     community_report,
     local_user,
+    modlog,
     person,
     person_actions,
     post,
@@ -47,6 +49,8 @@ pub fn report_combined_joins(my_person_id: PersonId, local_instance_id: Instance
   let private_message_join =
     private_message::table.on(private_message_report::private_message_id.eq(private_message::id));
 
+  let ban_join = modlog::table.on(ban_appeal::ban_id.eq(modlog::id));
+
   let post_join = post::table.on(
     post_report::post_id
       .eq(post::id)
@@ -64,14 +68,16 @@ pub fn report_combined_joins(my_person_id: PersonId, local_instance_id: Instance
       .eq(report_creator)
       .or(comment_report::creator_id.eq(report_creator))
       .or(private_message_report::creator_id.eq(report_creator))
-      .or(community_report::creator_id.eq(report_creator)),
+      .or(community_report::creator_id.eq(report_creator))
+      .or(ban_appeal::creator_id.eq(report_creator)),
   );
 
   let item_creator_join = person::table.on(
     post::creator_id
       .eq(item_creator)
       .or(comment::creator_id.eq(item_creator))
-      .or(private_message::creator_id.eq(item_creator)),
+      .or(private_message::creator_id.eq(item_creator))
+      .or(ban_appeal::creator_id.eq(item_creator)),
   );
 
   let resolver_join = aliases::person2.on(
@@ -79,13 +85,15 @@ pub fn report_combined_joins(my_person_id: PersonId, local_instance_id: Instance
       .eq(resolver)
       .or(post_report::resolver_id.eq(resolver))
       .or(comment_report::resolver_id.eq(resolver))
-      .or(community_report::resolver_id.eq(resolver)),
+      .or(community_report::resolver_id.eq(resolver))
+      .or(ban_appeal::resolver_id.eq(resolver)),
   );
 
   let community_join = community::table.on(
     community_report::community_id
       .eq(community::id)
-      .or(post::community_id.eq(community::id)),
+      .or(post::community_id.eq(community::id))
+      .or(ban_appeal::community_id.eq(community::id.nullable())),
   );
 
   let local_user_join = local_user::table.on(
@@ -97,7 +105,7 @@ pub fn report_combined_joins(my_person_id: PersonId, local_instance_id: Instance
   let creator_community_actions_join = creator_community_actions.on(
     creator_community_actions
       .field(community_actions::community_id)
-      .eq(post::community_id)
+      .eq(community::id)
       .and(
         creator_community_actions
           .field(community_actions::person_id)
@@ -130,6 +138,8 @@ pub fn report_combined_joins(my_person_id: PersonId, local_instance_id: Instance
     .left_join(comment_report::table)
     .left_join(private_message_report::table)
     .left_join(community_report::table)
+    .left_join(ban_appeal::table)
+    .left_join(ban_join)
     .inner_join(report_creator_join)
     .left_join(comment_join)
     .left_join(private_message_join)
@@ -53,6 +53,7 @@ use lemmy_db_schema_file::{
     my_local_user_admin_join,
     my_person_actions_join,
     my_post_actions_join,
+    post_event_join,
   },
   schema::{
     comment,
@@ -114,7 +115,8 @@ impl SearchCombinedViewInternal {
       search_combined::comment_id
         .eq(comment::id.nullable())
         .and(not(comment::removed))
-        .and(not(comment::deleted)),
+        .and(not(comment::deleted))
+        .and(not(comment::quarantined)),
     );
 
     let post_join = post::table.on(
@@ -122,7 +124,9 @@ impl SearchCombinedViewInternal {
         .eq(post::id.nullable())
         .or(comment::post_id.eq(post::id))
         .and(not(post::removed))
-        .and(not(post::deleted)),
+        .and(not(post::deleted))
+        .and(not(post::quarantined))
+        .and(not(post::pending_approval)),
     );
 
     let community_join = community::table.on(
@@ -156,6 +160,7 @@ impl SearchCombinedViewInternal {
       .left_join(item_creator_join)
       .left_join(community_join)
       .left_join(image_details_join())
+      .left_join(post_event_join())
       .left_join(creator_community_actions_join())
       .left_join(creator_local_user_admin_join())
       .left_join(creator_home_instance_actions_join())
@@ -189,6 +194,7 @@ impl PaginationCursorConversion for SearchCombinedView {
       SearchCombinedView::Community(v) => ('O', v.community.id.0),
       SearchCombinedView::Person(v) => ('E', v.person.id.0),
       SearchCombinedView::MultiCommunity(v) => ('M', v.multi.id.0),
+      SearchCombinedView::WikiPage(v) => ('W', v.id.0),
     };
     CursorData::new_with_prefix(prefix, id)
   }
@@ -462,6 +468,9 @@ impl InternalToCombinedView for SearchCombinedViewInternal {
         creator_is_moderator: v.creator_is_moderator,
         creator_banned_from_community: v.creator_banned_from_community,
         creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+        creator_flair: v.creator_flair,
+        creator_flair_emoji: v.creator_flair_emoji,
+        matched_filters: vec![],
       }))
     } else if let (Some(post), Some(creator), Some(community)) =
       (v.post, v.item_creator.clone(), v.community.clone())
@@ -472,16 +481,22 @@ impl InternalToCombinedView for SearchCombinedViewInternal {
         creator,
         creator_is_admin: v.item_creator_is_admin,
         image_details: v.image_details,
+        event: v.post_event,
         community_actions: v.community_actions,
         person_actions: v.person_actions,
         post_actions: v.post_actions,
         tags: v.post_tags,
+        gallery: v.post_gallery,
+        also_posted_in: Default::default(),
         can_mod: v.can_mod,
         creator_banned: v.creator_banned,
         creator_ban_expires_at: v.creator_ban_expires_at,
         creator_is_moderator: v.creator_is_moderator,
         creator_banned_from_community: v.creator_banned_from_community,
         creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+        creator_flair: v.creator_flair,
+        creator_flair_emoji: v.creator_flair_emoji,
+        matched_filters: vec![],
       }))
     } else if let Some(community) = v.community {
       Some(SearchCombinedView::Community(CommunityView {
@@ -489,6 +504,7 @@ impl InternalToCombinedView for SearchCombinedViewInternal {
         community_actions: v.community_actions,
         can_mod: v.can_mod,
         post_tags: v.community_post_tags,
+        user_flairs: v.community_user_flairs,
       }))
     } else if let (Some(multi), Some(creator)) = (v.multi_community, &v.item_creator) {
       Some(SearchCombinedView::MultiCommunity(MultiCommunityView {
@@ -503,6 +519,7 @@ impl InternalToCombinedView for SearchCombinedViewInternal {
         person_actions: v.person_actions,
         banned: v.creator_banned,
         ban_expires_at: v.creator_ban_expires_at,
+        quarantined: v.creator_quarantined,
       }))
     } else {
       None
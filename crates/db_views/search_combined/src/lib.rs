@@ -11,7 +11,10 @@ use lemmy_db_schema::{
     multi_community::MultiCommunity,
     person::{Person, PersonActions},
     post::{Post, PostActions},
+    post_event::PostEvent,
+    post_gallery::PostGalleryView,
     tag::TagsView,
+    wiki::WikiPage,
   },
 };
 use lemmy_db_schema_file::{PersonId, enums::ListingType};
@@ -28,13 +31,18 @@ use {
   lemmy_db_schema::utils::queries::selects::{
     CreatorLocalHomeBanExpiresType,
     community_post_tags_fragment,
+    community_user_flairs_fragment,
     creator_ban_expires_from_community,
     creator_banned_from_community,
+    creator_community_flair,
+    creator_community_flair_emoji,
     creator_is_admin,
     creator_is_moderator,
     creator_local_home_ban_expires,
     creator_local_home_banned,
+    creator_local_quarantined,
     local_user_can_mod,
+    post_gallery_fragment,
     post_tags_fragment,
   },
   lemmy_db_views_local_user::LocalUserView,
@@ -71,6 +79,8 @@ pub(crate) struct SearchCombinedViewInternal {
   pub comment_actions: Option<CommentActions>,
   #[diesel(embed)]
   pub image_details: Option<ImageDetails>,
+  #[diesel(embed)]
+  pub post_event: Option<PostEvent>,
   #[diesel(select_expression = creator_is_admin())]
   pub item_creator_is_admin: bool,
   #[diesel(select_expression = post_tags_fragment())]
@@ -79,6 +89,11 @@ pub(crate) struct SearchCombinedViewInternal {
   #[diesel(select_expression = community_post_tags_fragment())]
   /// available tags in this community
   pub community_post_tags: TagsView,
+  #[diesel(select_expression = community_user_flairs_fragment())]
+  /// available user flairs in this community
+  pub community_user_flairs: TagsView,
+  #[diesel(select_expression = post_gallery_fragment())]
+  pub post_gallery: PostGalleryView,
   #[diesel(select_expression = local_user_can_mod())]
   pub can_mod: bool,
   #[diesel(select_expression = creator_local_home_banned())]
@@ -88,12 +103,18 @@ pub(crate) struct SearchCombinedViewInternal {
     select_expression = creator_local_home_ban_expires()
   )]
   pub creator_ban_expires_at: Option<DateTime<Utc>>,
+  #[diesel(select_expression = creator_local_quarantined())]
+  pub creator_quarantined: bool,
   #[diesel(select_expression = creator_is_moderator())]
   pub creator_is_moderator: bool,
   #[diesel(select_expression = creator_banned_from_community())]
   pub creator_banned_from_community: bool,
   #[diesel(select_expression = creator_ban_expires_from_community())]
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  #[diesel(select_expression = creator_community_flair())]
+  pub creator_flair: Option<String>,
+  #[diesel(select_expression = creator_community_flair_emoji())]
+  pub creator_flair_emoji: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -106,6 +127,8 @@ pub enum SearchCombinedView {
   Community(CommunityView),
   Person(PersonView),
   MultiCommunity(MultiCommunityView),
+  /// Only returned by resolve_object, wiki pages are not included in search results.
+  WikiPage(WikiPage),
 }
 
 #[skip_serializing_none]
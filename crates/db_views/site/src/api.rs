@@ -1,30 +1,64 @@
 use crate::{ReadableFederationState, SiteView};
 use lemmy_db_schema::{
-  newtypes::{LanguageId, MultiCommunityId, OAuthProviderId, TaglineId},
+  newtypes::{
+    CommentId,
+    CommunityId,
+    ContentFilterId,
+    DraftId,
+    InviteCodeId,
+    LanguageId,
+    LoginTokenId,
+    MultiCommunityId,
+    OAuthApplicationId,
+    OAuthProviderId,
+    PersonalAccessTokenId,
+    PostId,
+    PrivateMessageId,
+    TaglineId,
+    UserDataExportId,
+    WebauthnCredentialId,
+  },
   source::{
     comment::Comment,
     community::Community,
+    content_filter::ContentFilter,
+    draft::Draft,
     instance::Instance,
+    invite_code::InviteCode,
     language::Language,
+    local_site_email_domain::LocalSiteEmailDomain,
     local_site_url_blocklist::LocalSiteUrlBlocklist,
     local_user::LocalUser,
     login_token::LoginToken,
+    oauth_application::OAuthApplication,
     oauth_provider::{AdminOAuthProvider, PublicOAuthProvider},
     person::Person,
+    personal_access_token::PersonalAccessToken,
     post::Post,
     private_message::PrivateMessage,
+    registration_question::RegistrationQuestion,
     tagline::Tagline,
+    user_data_export::UserDataExport,
+    user_settings_import::UserSettingsImport,
+    webauthn_credential::WebauthnCredential,
   },
 };
 use lemmy_db_schema_file::{
   InstanceId,
+  PersonId,
   enums::{
+    ApiTokenScope,
     CommentSortType,
+    ContentFilterAction,
+    ContentFilterMatch,
+    DraftKind,
+    EditHistoryVisibility,
     FederationMode,
     ListingType,
     PostListingMode,
     PostSortType,
     RegistrationMode,
+    SettingsImportFormat,
     VoteShow,
   },
 };
@@ -33,10 +67,17 @@ use lemmy_db_views_community_follower::CommunityFollowerView;
 use lemmy_db_views_community_moderator::CommunityModeratorView;
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_person::PersonView;
+use lemmy_db_views_registration_applications::api::RegistrationQuestionAnswer;
 use lemmy_diesel_utils::{pagination::PaginationCursor, sensitive::SensitiveString};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use url::Url;
+use webauthn_rs_proto::{
+  CreationChallengeResponse,
+  PublicKeyCredential,
+  RegisterPublicKeyCredential,
+  RequestChallengeResponse,
+};
 #[cfg(feature = "full")]
 use {extism::FromBytes, extism_convert::Json};
 
@@ -74,11 +115,17 @@ pub struct AuthenticateWithOauth {
   pub show_nsfw: Option<bool>,
   /// Username is mandatory at registration time
   pub username: Option<String>,
-  /// An answer is mandatory if require application is enabled on the server
+  /// An answer is mandatory if require application is enabled on the server, and the site has no
+  /// registration questions.
   pub answer: Option<String>,
+  /// The answers to the registration questions of the site.
+  pub answers: Option<Vec<RegistrationQuestionAnswer>>,
   pub pkce_code_verifier: Option<String>,
-  /// If this is true the login is valid forever, otherwise it expires after one week.
+  /// If this is true the session lasts until it is idle for too long, otherwise it ends after one
+  /// week.
   pub stay_logged_in: Option<bool>,
+  /// An invite code is mandatory at registration time if the server is invite only.
+  pub invite_code: Option<String>,
 }
 
 #[skip_serializing_none]
@@ -123,6 +170,13 @@ pub struct CreateSite {
   pub default_comment_sort_type: Option<CommentSortType>,
   pub legal_information: Option<String>,
   pub application_email_admins: Option<bool>,
+  pub session_idle_timeout_seconds: Option<i32>,
+  pub invite_min_account_age_days: Option<i32>,
+  pub email_domain_allowlist_only: Option<bool>,
+  pub block_disposable_email_domains: Option<bool>,
+  pub account_deletion_grace_period_days: Option<i32>,
+  pub edit_history_visibility: Option<EditHistoryVisibility>,
+  pub edit_history_retention_days: Option<i32>,
   pub discussion_languages: Option<Vec<LanguageId>>,
   pub slur_filter_regex: Option<String>,
   pub rate_limit_message_max_requests: Option<i32>,
@@ -213,6 +267,27 @@ pub struct EditSite {
   pub default_post_time_range_seconds: Option<i32>,
   /// A default fetch limit for number of items returned.
   pub default_items_per_page: Option<i32>,
+  /// Log out sessions which are not refreshed for this many seconds. 0 means no limit.
+  pub session_idle_timeout_seconds: Option<i32>,
+  /// Users whose account is at least this many days old can create invite codes. 0 means only
+  /// admins can.
+  pub invite_min_account_age_days: Option<i32>,
+  /// Only allow email addresses from the allowed email domains.
+  pub email_domain_allowlist_only: Option<bool>,
+  /// Block email addresses from known disposable email providers.
+  pub block_disposable_email_domains: Option<bool>,
+  /// Deleted accounts are only removed after this many days, so users can still cancel the
+  /// deletion. 0 deletes accounts right away.
+  pub account_deletion_grace_period_days: Option<i32>,
+  /// Who can see the previous versions of edited posts, comments and private messages.
+  pub edit_history_visibility: Option<EditHistoryVisibility>,
+  /// Previous versions of edited content are deleted after this many days. 0 keeps them forever.
+  pub edit_history_retention_days: Option<i32>,
+  /// The email domains which can be used when only allowed domains are accepted. Subdomains are
+  /// included.
+  pub allowed_email_domains: Option<Vec<String>>,
+  /// Email domains which can't be used for signups or email changes. Subdomains are included.
+  pub blocked_email_domains: Option<Vec<String>>,
   /// The default comment sort, usually "hot"
   pub default_comment_sort_type: Option<CommentSortType>,
   /// An optional page of legal information
@@ -316,6 +391,8 @@ pub struct GetSiteResponse {
   pub oauth_providers: Vec<PublicOAuthProvider>,
   pub admin_oauth_providers: Vec<AdminOAuthProvider>,
   pub blocked_urls: Vec<LocalSiteUrlBlocklist>,
+  /// The email domains which are allowed or blocked. Blocked domains are only shown to admins.
+  pub email_domains: Vec<LocalSiteEmailDomain>,
   // If true then uploads for post images or markdown images are disabled. Only avatars, icons and
   // banners can be set.
   pub image_upload_disabled: bool,
@@ -324,6 +401,8 @@ pub struct GetSiteResponse {
   ///
   /// Useful for estimating when your application will be approved.
   pub last_application_duration_seconds: Option<i64>,
+  /// The questions which are asked at registration.
+  pub registration_questions: Vec<RegistrationQuestion>,
 }
 
 #[skip_serializing_none]
@@ -367,14 +446,16 @@ pub struct ChangePassword {
   pub new_password: SensitiveString,
   pub new_password_verify: SensitiveString,
   pub old_password: SensitiveString,
-  /// If this is true the login is valid forever, otherwise it expires after one week.
+  /// If this is true the session lasts until it is idle for too long, otherwise it ends after one
+  /// week.
   pub stay_logged_in: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
-/// Delete your account.
+/// Delete your account. If the site has a grace period for deletions, the account is only
+/// removed once it is over, and logging in again allows cancelling the deletion.
 pub struct DeleteAccount {
   pub password: SensitiveString,
   pub delete_content: bool,
@@ -401,13 +482,416 @@ pub struct GenerateTotpSecretResponse {
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
 pub struct ListLoginsResponse {
-  pub logins: Vec<LoginToken>,
+  pub logins: Vec<LoginSession>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct LoginSession {
+  pub login_token: LoginToken,
+  /// A readable name for the device, like "Firefox on Linux".
+  pub device: String,
+  /// True for the session which made this request.
+  pub current: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Get a new jwt for a session, before the current one expires.
+pub struct RefreshLogin {
+  pub refresh_token: SensitiveString,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Log out a single session, eg on a lost device.
+pub struct RevokeLogin {
+  pub id: LoginTokenId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Creates a personal access token for bots and integrations.
+pub struct CreatePersonalAccessToken {
+  pub name: String,
+  pub scopes: Vec<ApiTokenScope>,
+  /// Communities in which the token may take moderator actions.
+  pub moderate_community_ids: Option<Vec<CommunityId>>,
+  /// A unix timestamp in seconds. If this is empty, the token doesn't expire.
+  pub expires_at: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct CreatePersonalAccessTokenResponse {
+  pub personal_access_token: PersonalAccessToken,
+  /// The token to pass as bearer auth. It can't be retrieved again later.
+  pub token: SensitiveString,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListPersonalAccessTokensResponse {
+  pub personal_access_tokens: Vec<PersonalAccessToken>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Revokes one of your personal access tokens.
+pub struct RevokePersonalAccessToken {
+  pub id: PersonalAccessTokenId,
 }
 
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Creates a filter which hides matching posts and comments, or collapses them behind a warning.
+pub struct CreateContentFilter {
+  pub phrase: String,
+  /// Defaults to keyword.
+  pub match_type: Option<ContentFilterMatch>,
+  /// Defaults to hide.
+  pub action: Option<ContentFilterAction>,
+  /// Defaults to true.
+  pub filter_titles: Option<bool>,
+  /// Defaults to true.
+  pub filter_bodies: Option<bool>,
+  /// Defaults to true.
+  pub filter_comments: Option<bool>,
+  /// Defaults to false.
+  pub filter_usernames: Option<bool>,
+  /// Defaults to true.
+  pub filter_urls: Option<bool>,
+  /// Only apply the filter in this community.
+  pub community_id: Option<CommunityId>,
+  /// A unix timestamp in seconds. If this is empty, the filter doesn't expire.
+  pub expires_at: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edits one of your content filters. Setting community_id or expires_at to 0 clears them.
+pub struct EditContentFilter {
+  pub id: ContentFilterId,
+  pub phrase: Option<String>,
+  pub match_type: Option<ContentFilterMatch>,
+  pub action: Option<ContentFilterAction>,
+  pub filter_titles: Option<bool>,
+  pub filter_bodies: Option<bool>,
+  pub filter_comments: Option<bool>,
+  pub filter_usernames: Option<bool>,
+  pub filter_urls: Option<bool>,
+  pub community_id: Option<CommunityId>,
+  pub expires_at: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Deletes one of your content filters.
+pub struct DeleteContentFilter {
+  pub id: ContentFilterId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ContentFilterResponse {
+  pub content_filter: ContentFilter,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListContentFiltersResponse {
+  pub content_filters: Vec<ContentFilter>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Saves an unfinished post, comment or private message on the server.
+///
+/// Post drafts need a community_id, comment drafts a post_id and private message drafts a
+/// recipient_id.
+pub struct CreateDraft {
+  pub kind: DraftKind,
+  pub community_id: Option<CommunityId>,
+  pub post_id: Option<PostId>,
+  /// The comment which this draft replies to.
+  pub parent_id: Option<CommentId>,
+  pub recipient_id: Option<PersonId>,
+  pub name: Option<String>,
+  pub url: Option<String>,
+  pub alt_text: Option<String>,
+  pub custom_thumbnail: Option<String>,
+  pub body: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Saves a new version of one of your drafts. The content is replaced as a whole, so fields which
+/// are left out get cleared. The target can't be changed, except for the community of a post.
+pub struct EditDraft {
+  pub id: DraftId,
+  pub community_id: Option<CommunityId>,
+  pub name: Option<String>,
+  pub url: Option<String>,
+  pub alt_text: Option<String>,
+  pub custom_thumbnail: Option<String>,
+  pub body: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Deletes one of your drafts.
+pub struct DeleteDraft {
+  pub id: DraftId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Publishes one of your drafts, exactly like creating the post, comment or private message
+/// directly. The draft is deleted afterwards.
+pub struct PublishDraft {
+  pub id: DraftId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct DraftResponse {
+  pub draft: Draft,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListDraftsResponse {
+  pub drafts: Vec<Draft>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The id of the published content, depending on the kind of draft.
+pub struct PublishDraftResponse {
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  pub private_message_id: Option<PrivateMessageId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Registers a third party app, which can then ask users for access with OAuth 2.0.
+pub struct CreateOAuthApplication {
+  pub name: String,
+  pub description: Option<String>,
+  pub redirect_uris: Vec<String>,
+  /// Apps which can't keep a secret, like mobile apps, don't get a client secret and need to use
+  /// PKCE instead. Defaults to false.
+  pub public_client: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct CreateOAuthApplicationResponse {
+  pub oauth_application: OAuthApplication,
+  /// It can't be retrieved again later.
+  pub client_secret: Option<SensitiveString>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListOAuthApplicationsResponse {
+  pub oauth_applications: Vec<OAuthApplication>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Deletes one of your apps, and revokes all tokens which were issued to it.
+pub struct DeleteOAuthApplication {
+  pub id: OAuthApplicationId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Creates an invite code, which allows signing up while registration is invite only. Available to
+/// admins, and to users whose account is old enough.
+pub struct CreateInviteCode {
+  /// If this is empty, the code can be used any number of times. Only admins can create codes
+  /// without a limit, for other users it can be at most 10.
+  pub max_uses: Option<i32>,
+  /// A unix timestamp in seconds. If this is empty, the code doesn't expire.
+  pub expires_at: Option<i64>,
+  /// Users who register with the code automatically follow these communities. They need to be
+  /// local and public.
+  pub community_ids: Option<Vec<CommunityId>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct InviteCodeResponse {
+  pub invite_code: InviteCode,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Lists your invite codes.
+pub struct ListInviteCodes {
+  /// List the codes of all users instead. Only available to admins.
+  pub all_users: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListInviteCodesResponse {
+  pub invite_codes: Vec<InviteCode>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Deletes one of your invite codes. Admins can delete the codes of any user.
+pub struct DeleteInviteCode {
+  pub id: InviteCodeId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The parameters of an OAuth 2.0 authorization request, which the frontend passes on unchanged.
+/// Used to show the consent screen, and to approve the request.
+pub struct OAuthAuthorize {
+  /// Must be `code`.
+  pub response_type: String,
+  pub client_id: String,
+  pub redirect_uri: String,
+  /// The requested rights, separated by spaces. Can contain `read`, `post`, `vote`,
+  /// `admin:reports`, `moderate:<community_id>` and `profile`.
+  pub scope: Option<String>,
+  pub state: Option<String>,
+  /// Required for public clients.
+  pub code_challenge: Option<String>,
+  /// Only `S256` is supported.
+  pub code_challenge_method: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Data for the consent screen.
+pub struct OAuthConsentResponse {
+  pub oauth_application: OAuthApplication,
+  /// The app developer.
+  pub creator: Person,
+  pub scopes: Vec<ApiTokenScope>,
+  pub moderate_community_ids: Vec<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct OAuthAuthorizeResponse {
+  /// Where the frontend needs to send the user, with the authorization code.
+  pub redirect_url: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An OAuth 2.0 token request. Apps send it form encoded, as defined in RFC 6749.
+pub struct OAuthToken {
+  /// Either `authorization_code` or `refresh_token`.
+  pub grant_type: String,
+  pub client_id: String,
+  pub client_secret: Option<SensitiveString>,
+  pub code: Option<SensitiveString>,
+  pub redirect_uri: Option<String>,
+  pub code_verifier: Option<String>,
+  pub refresh_token: Option<SensitiveString>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct OAuthTokenResponse {
+  pub access_token: SensitiveString,
+  /// Always `Bearer`.
+  pub token_type: String,
+  /// Lifetime of the access token in seconds.
+  pub expires_in: i64,
+  pub refresh_token: SensitiveString,
+  pub scope: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Revokes an access or refresh token of an app, as defined in RFC 7009.
+pub struct RevokeOAuthToken {
+  pub token: SensitiveString,
+  pub client_id: String,
+  pub client_secret: Option<SensitiveString>,
+  /// Either `access_token` or `refresh_token`. If it is missing, both are tried.
+  pub token_type_hint: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The standard OpenID Connect claims of the user.
+pub struct OAuthUserInfoResponse {
+  /// The actor id of the user.
+  pub sub: String,
+  pub preferred_username: String,
+  pub name: Option<String>,
+  pub picture: Option<String>,
+  pub profile: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
 /// Logging into lemmy.
 ///
 /// Note: Banned users can still log in, to be able to do certain things like delete
@@ -417,7 +901,15 @@ pub struct Login {
   pub password: SensitiveString,
   /// May be required, if totp is enabled for their account.
   pub totp_2fa_token: Option<String>,
-  /// If this is true the login is valid forever, otherwise it expires after one week.
+  /// Returned by `StartWebauthnLogin`, required together with `webauthn_credential` if the
+  /// account has webauthn credentials and doesn't use totp.
+  pub webauthn_challenge_id: Option<String>,
+  #[cfg_attr(feature = "ts-rs", ts(type = "any"))]
+  pub webauthn_credential: Option<PublicKeyCredential>,
+  /// Can be used instead of any second factor. Each code only works once.
+  pub recovery_code: Option<SensitiveString>,
+  /// If this is true the session lasts until it is idle for too long, otherwise it ends after one
+  /// week.
   pub stay_logged_in: Option<bool>,
 }
 
@@ -430,6 +922,8 @@ pub struct LoginResponse {
   /// This is None in response to `Register` if email verification is enabled, or the server
   /// requires registration applications.
   pub jwt: Option<SensitiveString>,
+  /// Use this with `RefreshLogin` to get a new jwt before it expires. It can only be used once.
+  pub refresh_token: Option<SensitiveString>,
   /// If registration applications are required, this will return true for a signup response.
   pub registration_created: bool,
   /// If email verifications are required, this will return true for a signup response.
@@ -549,6 +1043,8 @@ pub struct SaveUserSettings {
   pub hide_media: Option<bool>,
   /// Whether to show vote totals given to others.
   pub show_person_votes: Option<bool>,
+  /// Show crossposts of the same link only once in the front page.
+  pub collapse_crossposts: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
@@ -566,6 +1062,150 @@ pub struct UpdateTotpResponse {
   pub enabled: bool,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Start registering a new passkey or security key. Requires the current password, and the
+/// second factor if one is enabled.
+pub struct StartWebauthnRegistration {
+  /// The current password.
+  pub password: SensitiveString,
+  /// Required if totp is enabled and no other second factor is given.
+  pub totp_2fa_token: Option<String>,
+  /// Returned by `StartWebauthnLogin`, required together with `webauthn_credential` if the
+  /// account has webauthn credentials and doesn't use totp.
+  pub webauthn_challenge_id: Option<String>,
+  #[cfg_attr(feature = "ts-rs", ts(type = "any"))]
+  pub webauthn_credential: Option<PublicKeyCredential>,
+  /// Can be used instead of any second factor. Each code only works once.
+  pub recovery_code: Option<SensitiveString>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The options to pass to `navigator.credentials.create()`.
+pub struct StartWebauthnRegistrationResponse {
+  pub challenge_id: String,
+  #[cfg_attr(feature = "ts-rs", ts(type = "any"))]
+  pub options: CreationChallengeResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Finish registering a new passkey or security key, with the result of
+/// `navigator.credentials.create()`.
+pub struct FinishWebauthnRegistration {
+  pub challenge_id: String,
+  pub name: String,
+  #[cfg_attr(feature = "ts-rs", ts(type = "any"))]
+  pub credential: RegisterPublicKeyCredential,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct WebauthnCredentialResponse {
+  pub webauthn_credential: WebauthnCredential,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListWebauthnCredentialsResponse {
+  pub webauthn_credentials: Vec<WebauthnCredential>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RenameWebauthnCredential {
+  pub id: WebauthnCredentialId,
+  pub name: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a passkey or security key. Requires the current password, and the second factor.
+pub struct DeleteWebauthnCredential {
+  pub id: WebauthnCredentialId,
+  /// The current password.
+  pub password: SensitiveString,
+  /// Required if totp is enabled and no other second factor is given.
+  pub totp_2fa_token: Option<String>,
+  /// Returned by `StartWebauthnLogin`, required together with `webauthn_credential` if the
+  /// account has webauthn credentials and doesn't use totp.
+  pub webauthn_challenge_id: Option<String>,
+  #[cfg_attr(feature = "ts-rs", ts(type = "any"))]
+  pub webauthn_credential: Option<PublicKeyCredential>,
+  /// Can be used instead of any second factor. Each code only works once.
+  pub recovery_code: Option<SensitiveString>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Start a webauthn login, either passwordless or as second factor.
+pub struct StartWebauthnLogin {
+  pub username_or_email: SensitiveString,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The options to pass to `navigator.credentials.get()`.
+pub struct StartWebauthnLoginResponse {
+  pub challenge_id: String,
+  #[cfg_attr(feature = "ts-rs", ts(type = "any"))]
+  pub options: RequestChallengeResponse,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Passwordless login with the result of `navigator.credentials.get()`.
+pub struct WebauthnLogin {
+  pub challenge_id: String,
+  #[cfg_attr(feature = "ts-rs", ts(type = "any"))]
+  pub credential: PublicKeyCredential,
+  /// If this is true the session lasts until it is idle for too long, otherwise it ends after one
+  /// week.
+  pub stay_logged_in: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Generate new recovery codes. Requires the current password, and the second factor if one is
+/// enabled.
+pub struct GenerateRecoveryCodes {
+  /// The current password.
+  pub password: SensitiveString,
+  /// Required if totp is enabled and no other second factor is given.
+  pub totp_2fa_token: Option<String>,
+  /// Returned by `StartWebauthnLogin`, required together with `webauthn_credential` if the
+  /// account has webauthn credentials and doesn't use totp.
+  pub webauthn_challenge_id: Option<String>,
+  #[cfg_attr(feature = "ts-rs", ts(type = "any"))]
+  pub webauthn_credential: Option<PublicKeyCredential>,
+  /// Can be used instead of any second factor. Each code only works once.
+  pub recovery_code: Option<SensitiveString>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// New recovery codes, which replace all previous ones. They are only shown this one time.
+pub struct GenerateRecoveryCodesResponse {
+  pub recovery_codes: Vec<SensitiveString>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -724,13 +1364,69 @@ pub struct UserSettingsBackup {
 /// Your exported data.
 pub struct ExportDataResponse {
   pub notifications: Vec<PostOrCommentOrPrivateMessage>,
+  /// Your posts, comments and sent private messages.
   pub content: Vec<PostOrCommentOrPrivateMessage>,
   pub read_posts: Vec<Url>,
   pub liked: Vec<Url>,
+  #[serde(default)]
+  pub disliked: Vec<Url>,
   pub moderates: Vec<Url>,
+  /// Links to the images you uploaded. The image files themselves are not part of the export.
+  #[serde(default)]
+  pub media: Vec<Url>,
   pub settings: UserSettingsBackup,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Download the archive of a complete data export. It contains your data in the format of
+/// [ExportDataResponse], and your posts, comments and private messages as an ActivityPub outbox.
+/// Uploaded images are only included as links, so download them separately if you need them.
+pub struct DownloadUserDataExport {
+  pub id: UserDataExportId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct UserDataExportResponse {
+  pub export: UserDataExport,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Your data exports, newest first.
+pub struct ListUserDataExportsResponse {
+  pub exports: Vec<UserDataExport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Import follows and blocks from a file exported by Lemmy or other Fediverse software.
+pub struct ImportSettingsFile {
+  pub format: SettingsImportFormat,
+  /// The content of the exported file.
+  pub data: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct UserSettingsImportResponse {
+  pub import: UserSettingsImport,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Your settings imports, newest first.
+pub struct ListUserSettingsImportsResponse {
+  pub imports: Vec<UserSettingsImport>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -396,7 +396,7 @@ CREATE TRIGGER change_values
     EXECUTE FUNCTION r.private_message_change_values ();
 -- Combined tables triggers
 -- These insert (published_at, item_id) into X_combined tables
--- Reports (comment_report, post_report, private_message_report)
+-- Reports (comment_report, post_report, private_message_report, community_report, ban_appeal)
 CREATE PROCEDURE r.create_report_combined_trigger (table_name text)
 LANGUAGE plpgsql
 AS $a$
@@ -423,6 +423,7 @@ CALL r.create_report_combined_trigger ('post_report');
 CALL r.create_report_combined_trigger ('comment_report');
 CALL r.create_report_combined_trigger ('private_message_report');
 CALL r.create_report_combined_trigger ('community_report');
+CALL r.create_report_combined_trigger ('ban_appeal');
 -- person_content (comment, post)
 CREATE PROCEDURE r.create_person_content_combined_trigger (table_name text)
 LANGUAGE plpgsql
@@ -823,3 +824,36 @@ CREATE TRIGGER multi_community_remove_subscribers
     FOR EACH ROW
     WHEN (OLD.follow_state = 'Accepted')
     EXECUTE FUNCTION r.multicommunity_subscribers_decrement ();
+
+-- Count the RSVPs of event posts
+CREATE FUNCTION r.post_event_rsvp_count ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    IF TG_OP IN ('UPDATE', 'DELETE') AND OLD.event_rsvp IS NOT NULL THEN
+        UPDATE
+            post_event
+        SET
+            going_count = going_count - (OLD.event_rsvp = 'Going')::int,
+            interested_count = interested_count - (OLD.event_rsvp = 'Interested')::int
+        WHERE
+            post_id = OLD.post_id;
+    END IF;
+    IF TG_OP IN ('INSERT', 'UPDATE') AND NEW.event_rsvp IS NOT NULL THEN
+        UPDATE
+            post_event
+        SET
+            going_count = going_count + (NEW.event_rsvp = 'Going')::int,
+            interested_count = interested_count + (NEW.event_rsvp = 'Interested')::int
+        WHERE
+            post_id = NEW.post_id;
+    END IF;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER post_event_rsvp_count
+    AFTER INSERT OR DELETE OR UPDATE OF event_rsvp ON post_actions
+    FOR EACH ROW
+    EXECUTE FUNCTION r.post_event_rsvp_count ();
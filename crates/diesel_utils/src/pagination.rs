@@ -159,6 +159,16 @@ impl PaginationCursor {
   pub fn is_back(self) -> LemmyResult<bool> {
     Ok(self.into_internal()?.back)
   }
+
+  /// Cursor for the items following the given one. Useful for lists which are cut off without
+  /// going through [`paginate_response`].
+  pub fn next_after<T: PaginationCursorConversion>(item: &T) -> LemmyResult<Self> {
+    Self::from_internal(PaginationCursorInternal {
+      back: false,
+      data: item.to_cursor(),
+      recovery: false,
+    })
+  }
 }
 
 /// The actual data which is stored inside a cursor, not accessible outside this file.
@@ -58,6 +58,26 @@ where
   }
 }
 
+/// Reverses the order of a boolean key, so that `true` comes first in ascending sorts
+pub struct NotKey<K>(pub K);
+
+impl<K, C> CursorKey<C> for NotKey<K>
+where
+  K: CursorKey<C, SqlType = sql_types::Bool>,
+{
+  type SqlType = sql_types::Bool;
+  type CursorValue = dsl::not<K::CursorValue>;
+  type SqlValue = dsl::not<K::SqlValue>;
+
+  fn get_cursor_value(cursor: &C) -> Self::CursorValue {
+    dsl::not(K::get_cursor_value(cursor))
+  }
+
+  fn get_sql_value() -> Self::SqlValue {
+    dsl::not(K::get_sql_value())
+  }
+}
+
 pub struct CoalesceKey<A, B>(pub A, pub B);
 
 impl<A, B, C> CursorKey<C> for CoalesceKey<A, B>
@@ -251,6 +271,11 @@ pub mod functions {
   define_sql_function!(#[sql_name = "coalesce"] fn coalesce_2_nullable<T: diesel::sql_types::SqlType + diesel::sql_types::SingleValue>(x: diesel::sql_types::Nullable<T>, y: diesel::sql_types::Nullable<T>) -> diesel::sql_types::Nullable<T>);
 
   define_sql_function!(#[sql_name = "coalesce"] fn coalesce_3_nullable<T: diesel::sql_types::SqlType + diesel::sql_types::SingleValue>(x: diesel::sql_types::Nullable<T>, y: diesel::sql_types::Nullable<T>, z: diesel::sql_types::Nullable<T>) -> diesel::sql_types::Nullable<T>);
+
+  // The function behind the case insensitive regex match operator `~*`
+  define_sql_function!(#[sql_name = "texticregexeq"] fn regex_match(x: Text, pattern: Text) -> diesel::sql_types::Bool);
+
+  define_sql_function!(#[sql_name = "texticregexeq"] fn regex_match_nullable(x: diesel::sql_types::Nullable<Text>, pattern: Text) -> diesel::sql_types::Nullable<diesel::sql_types::Bool>);
 }
 
 pub fn now() -> AsExprOf<diesel::dsl::now, diesel::sql_types::Timestamptz> {
@@ -1,5 +1,5 @@
 use actix_web::{Error, HttpRequest, HttpResponse, Result, error::ErrorBadRequest, web};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Days, Utc};
 use lemmy_api_utils::{
   context::LemmyContext,
   utils::{check_private_instance, local_user_view_from_jwt},
@@ -11,6 +11,7 @@ use lemmy_db_schema::{
     multi_community::MultiCommunity,
     notification::Notification,
     person::Person,
+    post_event::PostEvent,
   },
   traits::ApubActor,
 };
@@ -59,6 +60,10 @@ pub fn config(cfg: &mut web::ServiceConfig) {
     web::scope("/feeds")
       .route("/u/{user_name}.xml", web::get().to(get_feed_user))
       .route("/c/{community_name}.xml", web::get().to(get_feed_community))
+      .route(
+        "/c/{community_name}.ics",
+        web::get().to(get_calendar_community),
+      )
       .route(
         "/m/{multi_name}.xml",
         web::get().to(get_feed_multi_community),
@@ -232,6 +237,109 @@ async fn get_feed_community(
   ))
 }
 
+/// How long past events stay in the calendar feed.
+const CALENDAR_PAST_DAYS: u64 = 30;
+
+/// The event posts of a community as iCalendar feed, so that they can be subscribed to in
+/// calendar apps.
+async fn get_calendar_community(
+  name: web::Path<String>,
+  context: web::Data<LemmyContext>,
+) -> Result<HttpResponse, Error> {
+  let (name, domain) = split_name(&name);
+  let community = Community::read_from_name(&mut context.pool(), name, domain, false)
+    .await?
+    .ok_or(ErrorBadRequest("not_found"))?;
+
+  if !community.visibility.can_view_without_login() {
+    return Err(ErrorBadRequest("not_found"));
+  }
+
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  check_private_instance(&None, &site_view.local_site)?;
+
+  let since = Utc::now()
+    .checked_sub_days(Days::new(CALENDAR_PAST_DAYS))
+    .unwrap_or_else(Utc::now);
+  let events = PostEvent::list_for_community(&mut context.pool(), community.id, since).await?;
+
+  let title = format!("{} - {}", site_view.site.name, community.name);
+  let mut lines = vec![
+    "BEGIN:VCALENDAR".to_string(),
+    "VERSION:2.0".to_string(),
+    format!("PRODID:-//Lemmy//{}//EN", context.settings().hostname),
+    "CALSCALE:GREGORIAN".to_string(),
+    format!("X-WR-CALNAME:{}", ical_escape(&title)),
+  ];
+  for (post, event) in events.into_iter().filter(|(p, _)| !p.nsfw) {
+    let post_url = post.local_url(context.settings())?;
+    let location = event
+      .location
+      .or_else(|| event.online_url.map(|u| u.to_string()));
+    lines.extend([
+      "BEGIN:VEVENT".to_string(),
+      format!("UID:{}", post.ap_id),
+      format!(
+        "DTSTAMP:{}",
+        ical_time(post.updated_at.unwrap_or(post.published_at))
+      ),
+      format!("DTSTART:{}", ical_time(event.start_at)),
+      format!("DTEND:{}", ical_time(event.end_at)),
+      format!("SUMMARY:{}", ical_escape(&post.name)),
+      format!("URL:{post_url}"),
+    ]);
+    if let Some(body) = post.body {
+      lines.push(format!("DESCRIPTION:{}", ical_escape(&body)));
+    }
+    if let Some(location) = location {
+      lines.push(format!("LOCATION:{}", ical_escape(&location)));
+    }
+    lines.push("END:VEVENT".to_string());
+  }
+  lines.push("END:VCALENDAR".to_string());
+
+  let body = lines
+    .iter()
+    .map(|l| ical_fold(l))
+    .collect::<Vec<_>>()
+    .join("\r\n");
+  Ok(
+    HttpResponse::Ok()
+      .content_type("text/calendar; charset=utf-8")
+      .body(body + "\r\n"),
+  )
+}
+
+/// Times are always sent in UTC, the timezone of the event is only for display.
+fn ical_time(time: DateTime<Utc>) -> String {
+  time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes a text value, see https://www.rfc-editor.org/rfc/rfc5545#section-3.3.11
+fn ical_escape(text: &str) -> String {
+  text
+    .replace('\\', "\\\\")
+    .replace(';', "\\;")
+    .replace(',', "\\,")
+    .replace("\r\n", "\\n")
+    .replace('\n', "\\n")
+}
+
+/// Splits lines longer than 75 bytes, see https://www.rfc-editor.org/rfc/rfc5545#section-3.1
+fn ical_fold(line: &str) -> String {
+  let mut folded = String::with_capacity(line.len());
+  let mut line_len = 0;
+  for c in line.chars() {
+    if line_len + c.len_utf8() > 75 {
+      folded.push_str("\r\n ");
+      line_len = 1;
+    }
+    folded.push(c);
+    line_len += c.len_utf8();
+  }
+  folded
+}
+
 async fn get_feed_multi_community(
   web::Query(info): web::Query<Params>,
   name: web::Path<String>,
@@ -517,6 +625,20 @@ fn create_modlog_items(modlog: Vec<ModlogView>, settings: &Settings) -> LemmyRes
           ),
           settings,
         ),
+        ModlogKind::AdminQuarantinePerson => build_modlog_item(
+          r,
+          &modlog_url,
+          format!(
+            "{} {}",
+            if r.modlog.is_revert {
+              "Unquarantined"
+            } else {
+              "Quarantined"
+            },
+            &target_person_name
+          ),
+          settings,
+        ),
         ModlogKind::ModBanFromCommunity => build_modlog_item(
           r,
           &modlog_url,
@@ -606,6 +728,51 @@ fn create_modlog_items(modlog: Vec<ModlogView>, settings: &Settings) -> LemmyRes
           ),
           settings,
         ),
+        ModlogKind::ModResolveBanAppeal => build_modlog_item(
+          r,
+          &modlog_url,
+          format!(
+            "{} ban appeal of {}",
+            if r.modlog.is_revert {
+              "Accepted"
+            } else if r.modlog.expires_at.is_some() {
+              "Partially accepted"
+            } else {
+              "Rejected"
+            },
+            &target_person_name
+          ),
+          settings,
+        ),
+        ModlogKind::ModApprovePost => build_modlog_item(
+          r,
+          &modlog_url,
+          format!(
+            "{} post {}",
+            if r.modlog.is_revert {
+              "Denied"
+            } else {
+              "Approved"
+            },
+            &target_post_name
+          ),
+          settings,
+        ),
+        ModlogKind::ModChangeModeratorPermissions => build_modlog_item(
+          r,
+          &modlog_url,
+          format!(
+            "Changed moderator permissions of /u/{} in /c/{}",
+            &&target_person_name, &&target_community_name
+          ),
+          settings,
+        ),
+        ModlogKind::AdminModeratorOverride => build_modlog_item(
+          r,
+          &modlog_url,
+          format!("Acted as moderator in /c/{}", &&target_community_name),
+          settings,
+        ),
         ModlogKind::ModTransferCommunity => build_modlog_item(
           r,
           &modlog_url,
@@ -3,7 +3,7 @@ use actix_web::web::*;
 use lemmy_api_utils::{
   context::LemmyContext,
   request::{delete_image_alias, purge_image_from_pictrs},
-  utils::{is_admin, is_mod_or_admin},
+  utils::{check_admin_action, check_community_mod_action},
 };
 use lemmy_db_schema::source::{
   community::{Community, CommunityUpdateForm},
@@ -11,6 +11,7 @@ use lemmy_db_schema::source::{
   person::{Person, PersonUpdateForm},
   site::{Site, SiteUpdateForm},
 };
+use lemmy_db_schema_file::enums::{AdminPermission, ModeratorPermission};
 use lemmy_db_views_community::api::CommunityIdQuery;
 use lemmy_db_views_local_image::api::DeleteImageParams;
 use lemmy_db_views_local_user::LocalUserView;
@@ -23,7 +24,15 @@ pub async fn delete_site_icon(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
   let site = Site::read_local(&mut context.pool()).await?;
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "DeleteSiteIcon",
+    None,
+    &(),
+    &mut context.pool(),
+  )
+  .await?;
 
   delete_old_image(&site.icon, &context).await?;
 
@@ -40,7 +49,15 @@ pub async fn delete_site_banner(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
   let site = Site::read_local(&mut context.pool()).await?;
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "DeleteSiteBanner",
+    None,
+    &(),
+    &mut context.pool(),
+  )
+  .await?;
 
   delete_old_image(&site.banner, &context).await?;
 
@@ -59,7 +76,14 @@ pub async fn delete_community_icon(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
   let community = Community::read(&mut context.pool(), data.id).await?;
-  is_mod_or_admin(&mut context.pool(), &local_user_view, community.id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
 
   delete_old_image(&community.icon, &context).await?;
 
@@ -78,7 +102,14 @@ pub async fn delete_community_banner(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
   let community = Community::read(&mut context.pool(), data.id).await?;
-  is_mod_or_admin(&mut context.pool(), &local_user_view, community.id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
 
   delete_old_image(&community.icon, &context).await?;
 
@@ -145,7 +176,15 @@ pub async fn delete_image_admin(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<SuccessResponse>> {
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::Purge),
+    "DeleteImage",
+    None,
+    &data,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Use purge, since it should remove any other aliases.
   purge_image_from_pictrs(&data.filename, &context).await?;
@@ -4,7 +4,7 @@ use actix_web::{self, HttpRequest, web::*};
 use lemmy_api_utils::{
   context::LemmyContext,
   request::PictrsResponse,
-  utils::{is_admin, is_mod_or_admin},
+  utils::{check_admin_action, check_community_mod_action},
 };
 use lemmy_db_schema::source::{
   community::{Community, CommunityUpdateForm},
@@ -12,6 +12,7 @@ use lemmy_db_schema::source::{
   person::{Person, PersonUpdateForm},
   site::{Site, SiteUpdateForm},
 };
+use lemmy_db_schema_file::enums::{AdminPermission, ModeratorPermission};
 use lemmy_db_views_community::api::CommunityIdQuery;
 use lemmy_db_views_local_image::api::UploadImageResponse;
 use lemmy_db_views_local_user::LocalUserView;
@@ -85,7 +86,14 @@ pub async fn upload_community_icon(
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<UploadImageResponse>> {
   let community: Community = Community::read(&mut context.pool(), query.id).await?;
-  is_mod_or_admin(&mut context.pool(), &local_user_view, community.id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
 
   let image = do_upload_image(req, body, Avatar, &local_user_view, &context).await?;
   delete_old_image(&community.icon, &context).await?;
@@ -107,7 +115,14 @@ pub async fn upload_community_banner(
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<UploadImageResponse>> {
   let community: Community = Community::read(&mut context.pool(), query.id).await?;
-  is_mod_or_admin(&mut context.pool(), &local_user_view, community.id).await?;
+  check_community_mod_action(
+    &local_user_view,
+    &community,
+    false,
+    Some(ModeratorPermission::EditSettings),
+    &mut context.pool(),
+  )
+  .await?;
 
   let image = do_upload_image(req, body, Banner, &local_user_view, &context).await?;
   delete_old_image(&community.banner, &context).await?;
@@ -127,7 +142,15 @@ pub async fn upload_site_icon(
   local_user_view: LocalUserView,
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<UploadImageResponse>> {
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "UploadSiteIcon",
+    None,
+    &(),
+    &mut context.pool(),
+  )
+  .await?;
   let site = Site::read_local(&mut context.pool()).await?;
 
   let image = do_upload_image(req, body, Avatar, &local_user_view, &context).await?;
@@ -148,7 +171,15 @@ pub async fn upload_site_banner(
   local_user_view: LocalUserView,
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<UploadImageResponse>> {
-  is_admin(&local_user_view)?;
+  check_admin_action(
+    &local_user_view,
+    Some(AdminPermission::EditSite),
+    "UploadSiteBanner",
+    None,
+    &(),
+    &mut context.pool(),
+  )
+  .await?;
   let site = Site::read_local(&mut context.pool()).await?;
 
   let image = do_upload_image(req, body, Banner, &local_user_view, &context).await?;
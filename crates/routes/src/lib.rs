@@ -2,5 +2,6 @@ pub mod feeds;
 pub mod images;
 pub mod middleware;
 pub mod nodeinfo;
+pub mod oauth_metadata;
 pub mod utils;
 pub mod webfinger;
@@ -3,14 +3,24 @@ use actix_web::{
   HttpMessage,
   body::MessageBody,
   dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready},
-  http::header::{CACHE_CONTROL, HeaderValue},
+  http::{
+    Method,
+    header::{CACHE_CONTROL, HeaderValue},
+  },
 };
 use core::future::Ready;
 use futures_util::future::LocalBoxFuture;
 use lemmy_api_utils::{
+  access_token::{check_access_token_scope, with_access_token},
   context::LemmyContext,
-  utils::{local_user_view_from_jwt, read_auth_token},
+  utils::{local_user_view_from_access_token, local_user_view_from_jwt, read_auth_token},
 };
+use lemmy_db_schema::source::personal_access_token::{
+  PERSONAL_ACCESS_TOKEN_PREFIX,
+  PersonalAccessToken,
+};
+use lemmy_db_schema_file::enums::ApiTokenScope;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
 use std::{future::ready, rc::Rc};
 
 #[derive(Clone)]
@@ -66,18 +76,28 @@ where
 
     Box::pin(async move {
       let jwt = read_auth_token(req.request())?;
+      let mut access_token = None;
 
       if let Some(jwt) = &jwt {
-        // Ignore any invalid auth so the site can still be used
-        // This means it is be impossible to get any error message for invalid jwt. Need
-        // to use `/api/v4/account/validate_auth` for that.
-        let local_user_view = local_user_view_from_jwt(jwt, &context).await.ok();
-        if let Some(local_user_view) = local_user_view {
+        if jwt.starts_with(PERSONAL_ACCESS_TOKEN_PREFIX) {
+          // Unlike login jwts, invalid access tokens are rejected so that integrations notice
+          // expired or revoked tokens.
+          let (local_user_view, token) = local_user_view_from_access_token(jwt, &context).await?;
+          check_access_token_route(&token, req.method(), req.path())?;
           req.extensions_mut().insert(local_user_view);
+          access_token = Some(token);
+        } else {
+          // Ignore any invalid auth so the site can still be used
+          // This means it is be impossible to get any error message for invalid jwt. Need
+          // to use `/api/v4/account/validate_auth` for that.
+          let local_user_view = local_user_view_from_jwt(jwt, &context).await.ok();
+          if let Some(local_user_view) = local_user_view {
+            req.extensions_mut().insert(local_user_view);
+          }
         }
       }
 
-      let mut res = svc.call(req).await?;
+      let mut res = with_access_token(access_token, svc.call(req)).await?;
 
       // Add cache-control header if none is present
       if !res.headers().contains_key(CACHE_CONTROL) {
@@ -97,18 +117,120 @@ where
   }
 }
 
+/// Write endpoints which check the moderator or admin rights of an access token themselves, in
+/// `check_community_mod_action` and `check_admin_permission`.
+const MODERATION_ROUTES: &[&str] = &[
+  "/post/remove",
+  "/post/lock",
+  "/post/feature",
+  "/post/mod_update",
+  "/post/approve_quarantined",
+  "/post/pending/approve",
+  "/post/report/resolve",
+  "/comment/remove",
+  "/comment/distinguish",
+  "/comment/lock",
+  "/comment/approve_quarantined",
+  "/comment/report/resolve",
+  "/community/ban_user",
+  "/community/report/resolve",
+  "/private_message/report/resolve",
+  "/report/ban_appeal/resolve",
+];
+
+/// Read only endpoints which may be called with the `Read` scope. Private account data like
+/// settings, logins, tokens, drafts and data exports isn't included.
+const READ_ROUTES: &[&str] = &[
+  "/site",
+  "/modlog",
+  "/search",
+  "/resolve_object",
+  "/federated_instances",
+  "/community",
+  "/community/random",
+  "/community/list",
+  "/community/wiki",
+  "/community/wiki/list",
+  "/community/wiki/revisions",
+  "/multi_community",
+  "/multi_community/list",
+  "/post",
+  "/post/list",
+  "/post/site_metadata",
+  "/post/revisions",
+  "/comment",
+  "/comment/list",
+  "/comment/list/slim",
+  "/comment/tree",
+  "/comment/revisions",
+  "/person",
+  "/person/content",
+  "/custom_emoji/list",
+  "/account/unread_counts",
+  "/account/notification/list",
+  "/account/saved",
+  "/account/read",
+  "/account/hidden",
+  "/account/liked",
+  "/image/proxy",
+];
+
+/// Checks if an access token may call the given endpoint. Only the endpoints which are listed for
+/// a scope are allowed, everything else like account settings or token management is rejected.
+fn check_access_token_route(
+  token: &PersonalAccessToken,
+  method: &Method,
+  path: &str,
+) -> LemmyResult<()> {
+  let Some(route) = path.trim_end_matches('/').strip_prefix("/api/v4") else {
+    return Err(LemmyErrorType::MissingTokenScope.into());
+  };
+  // Only returns public profile data, which OAuth apps need to identify the user
+  if route == "/oauth/userinfo" {
+    return Ok(());
+  }
+  let is_read = method == Method::GET || method == Method::HEAD;
+  let is_image = route
+    .strip_prefix("/image/")
+    .is_some_and(|filename| filename != "list" && filename != "health");
+
+  let scope = match route {
+    r if is_read && (READ_ROUTES.contains(&r) || is_image) => ApiTokenScope::Read,
+    // Reports can contain private messages and content of private communities
+    "/report/list" if is_read => ApiTokenScope::AdminReports,
+    _ if is_read => Err(LemmyErrorType::MissingTokenScope)?,
+    "/post/like" | "/comment/like" => ApiTokenScope::Vote,
+    // Create, edit and delete
+    "/post" | "/comment" | "/private_message" => ApiTokenScope::Post,
+    "/post/crosspost" | "/image" if method == Method::POST => ApiTokenScope::Post,
+    "/post/report" | "/comment/report" | "/private_message/report" | "/community/report" => {
+      ApiTokenScope::Post
+    }
+    r if MODERATION_ROUTES.contains(&r) => return Ok(()),
+    _ => Err(LemmyErrorType::MissingTokenScope)?,
+  };
+  check_access_token_scope(token, scope)
+}
+
 #[cfg(test)]
 mod tests {
 
-  use actix_web::test::TestRequest;
+  use super::check_access_token_route;
+  use actix_web::{http::Method, test::TestRequest};
+  use chrono::Utc;
   use lemmy_api_utils::{claims::Claims, context::LemmyContext};
-  use lemmy_db_schema::source::{
-    instance::Instance,
-    local_user::{LocalUser, LocalUserInsertForm},
-    person::{Person, PersonInsertForm},
+  use lemmy_db_schema::{
+    newtypes::{CommunityId, LocalUserId, PersonalAccessTokenId},
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+      personal_access_token::PersonalAccessToken,
+    },
   };
+  use lemmy_db_schema_file::enums::ApiTokenScope;
   use lemmy_diesel_utils::traits::Crud;
-  use lemmy_utils::error::LemmyResult;
+  use lemmy_utils::error::{LemmyErrorType, LemmyResult};
   use pretty_assertions::assert_eq;
   use serial_test::serial;
 
@@ -129,7 +251,10 @@ mod tests {
       LocalUser::create(&mut context.pool(), &local_user_form, vec![]).await?;
 
     let req = TestRequest::default().to_http_request();
-    let jwt = Claims::generate(inserted_local_user.id, None, req, &context).await?;
+    let jwt = Claims::generate(inserted_local_user.id, None, req, &context)
+      .await?
+      .jwt
+      .ok_or(LemmyErrorType::NotLoggedIn)?;
 
     let valid = Claims::validate(&jwt, &context).await;
     assert!(valid.is_ok());
@@ -139,4 +264,65 @@ mod tests {
 
     Ok(())
   }
+
+  #[test]
+  fn test_access_token_routes() {
+    let token = PersonalAccessToken {
+      id: PersonalAccessTokenId(1),
+      local_user_id: LocalUserId(1),
+      name: "bot".to_string(),
+      token_hash: String::new(),
+      scopes: vec![ApiTokenScope::Read, ApiTokenScope::Vote],
+      moderate_community_ids: vec![CommunityId(1)],
+      expires_at: None,
+      last_used_at: None,
+      published_at: Utc::now(),
+      oauth_application_id: None,
+    };
+
+    assert!(check_access_token_route(&token, &Method::GET, "/api/v4/post/list").is_ok());
+    assert!(check_access_token_route(&token, &Method::POST, "/api/v4/post/like").is_ok());
+    assert!(check_access_token_route(&token, &Method::POST, "/api/v4/post").is_err());
+    // Moderator actions are checked later, against the communities of the token
+    assert!(check_access_token_route(&token, &Method::POST, "/api/v4/post/lock").is_ok());
+    // Tokens can't manage the account or mint new tokens
+    assert!(
+      check_access_token_route(&token, &Method::PUT, "/api/v4/account/settings/save").is_err()
+    );
+    assert!(check_access_token_route(&token, &Method::GET, "/api/v4/account/token/list").is_err());
+    // Private account data can't be read
+    assert!(check_access_token_route(&token, &Method::GET, "/api/v4/account/login/list").is_err());
+    assert!(check_access_token_route(&token, &Method::GET, "/api/v4/account").is_err());
+    assert!(check_access_token_route(&token, &Method::GET, "/api/v3/post/list").is_err());
+    assert!(check_access_token_route(&token, &Method::GET, "/api/v4/report/list").is_err());
+    assert!(check_access_token_route(&token, &Method::DELETE, "/api/v4/account/webauthn").is_err());
+    assert!(
+      check_access_token_route(&token, &Method::GET, "/api/v4/account/data/export/download")
+        .is_err()
+    );
+    // Apps can't approve other apps
+    assert!(check_access_token_route(&token, &Method::POST, "/api/v4/oauth/authorize").is_err());
+    let post_scope = PersonalAccessToken {
+      scopes: vec![ApiTokenScope::Post],
+      ..token.clone()
+    };
+    assert!(check_access_token_route(&post_scope, &Method::POST, "/api/v4/post").is_ok());
+    assert!(check_access_token_route(&post_scope, &Method::DELETE, "/api/v4/post").is_ok());
+    assert!(check_access_token_route(&post_scope, &Method::DELETE, "/api/v4/comment").is_ok());
+    assert!(check_access_token_route(&post_scope, &Method::GET, "/api/v4/post/list").is_err());
+    // Deleting images of other users is an admin action
+    assert!(check_access_token_route(&post_scope, &Method::DELETE, "/api/v4/image").is_err());
+    let admin_reports_scope = PersonalAccessToken {
+      scopes: vec![ApiTokenScope::AdminReports],
+      ..token.clone()
+    };
+    assert!(
+      check_access_token_route(&admin_reports_scope, &Method::GET, "/api/v4/report/list").is_ok()
+    );
+    let no_scopes = PersonalAccessToken {
+      scopes: vec![],
+      ..token
+    };
+    assert!(check_access_token_route(&no_scopes, &Method::GET, "/api/v4/oauth/userinfo").is_ok());
+  }
 }
@@ -45,9 +45,12 @@ async fn node_info_well_known(context: web::Data<LemmyContext>) -> LemmyResult<H
 async fn node_info(context: web::Data<LemmyContext>) -> Result<HttpResponse, Error> {
   let site_view = SiteView::read_local(&mut context.pool()).await?;
 
-  // Since there are 3 registration options,
-  // we need to set open_registrations as true if RegistrationMode is not Closed.
-  let open_registrations = Some(site_view.local_site.registration_mode != RegistrationMode::Closed);
+  // Since there are 4 registration options, we need to set open_registrations as true if anyone
+  // can sign up, even if the registration needs to be approved.
+  let open_registrations = Some(!matches!(
+    site_view.local_site.registration_mode,
+    RegistrationMode::Closed | RegistrationMode::InviteOnly
+  ));
   let json = NodeInfo {
     version: Some("2.1".to_string()),
     software: Some(NodeInfoSoftware {
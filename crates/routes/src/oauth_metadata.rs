@@ -0,0 +1,44 @@
+use actix_web::{HttpResponse, web};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_utils::{cache_header::cache_1hour, error::LemmyResult};
+use serde::Serialize;
+
+/// Metadata for apps which use lemmy as OAuth 2.0 provider.
+pub fn config(cfg: &mut web::ServiceConfig) {
+  cfg.route(
+    "/.well-known/oauth-authorization-server",
+    web::get().to(oauth_metadata).wrap(cache_1hour()),
+  );
+}
+
+async fn oauth_metadata(context: web::Data<LemmyContext>) -> LemmyResult<HttpResponse> {
+  let issuer = context.settings().get_protocol_and_hostname();
+  let api = format!("{issuer}/api/v4/oauth");
+  let metadata = OAuthMetadata {
+    // The consent screen is shown by the frontend
+    authorization_endpoint: format!("{issuer}/oauth/authorize"),
+    token_endpoint: format!("{api}/token"),
+    revocation_endpoint: format!("{api}/revoke"),
+    issuer,
+    scopes_supported: vec!["profile", "read", "post", "vote", "admin:reports"],
+    response_types_supported: vec!["code"],
+    grant_types_supported: vec!["authorization_code", "refresh_token"],
+    code_challenge_methods_supported: vec!["S256"],
+    token_endpoint_auth_methods_supported: vec!["client_secret_post", "none"],
+  };
+  Ok(HttpResponse::Ok().json(metadata))
+}
+
+/// https://www.rfc-editor.org/rfc/rfc8414#section-2
+#[derive(Serialize, Debug)]
+struct OAuthMetadata {
+  issuer: String,
+  authorization_endpoint: String,
+  token_endpoint: String,
+  revocation_endpoint: String,
+  scopes_supported: Vec<&'static str>,
+  response_types_supported: Vec<&'static str>,
+  grant_types_supported: Vec<&'static str>,
+  code_challenge_methods_supported: Vec<&'static str>,
+  token_endpoint_auth_methods_supported: Vec<&'static str>,
+}
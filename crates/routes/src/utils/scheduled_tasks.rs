@@ -1,51 +1,75 @@
 use crate::nodeinfo::{NodeInfo, NodeInfoWellKnown};
 use activitypub_federation::config::Data;
-use chrono::{DateTime, TimeZone, Utc};
+use actix_web::web::Json;
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
 use clokwerk::{AsyncScheduler, TimeUnits as CTimeUnits};
 use diesel::{
   BoolExpressionMethods,
   ExpressionMethods,
   NullableExpressionMethods,
+  OptionalExtension,
   QueryDsl,
   QueryableByName,
   SelectableHelper,
-  dsl::{IntervalDsl, count, exists, not, update},
+  dsl::{IntervalDsl, count, exists, not, select, update},
   query_builder::AsQuery,
   sql_query,
   sql_types::{BigInt, Integer, Timestamptz},
 };
 use diesel_async::{AsyncPgConnection, RunQueryDsl};
 use diesel_uplete::uplete;
+use lemmy_api_crud::post::create::create_post;
 use lemmy_api_utils::{
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::send_webmention,
+  utils::{delete_local_user_account, send_webmention, user_data_export_path},
 };
 use lemmy_db_schema::{
+  newtypes::UserDataExportId,
   source::{
     community::Community,
+    content_filter::ContentFilter,
+    draft::Draft,
     instance::{Instance, InstanceForm},
     local_user::LocalUser,
+    modlog::{Modlog, ModlogInsertForm},
     post::{Post, PostUpdateForm},
+    recurring_post::{RecurringPost, RecurringPostUpdateForm},
   },
   utils::DELETED_REPLACEMENT_TEXT,
 };
-use lemmy_db_schema_file::schema::{
-  captcha_answer,
-  comment,
-  community,
-  community_actions,
-  federation_blocklist,
-  instance,
-  instance_actions,
-  local_site,
-  local_user,
-  person,
-  post,
-  received_activity,
-  sent_activity,
-  site,
+use lemmy_db_schema_file::{
+  enums::{DataExportStatus, ModeratorPermission, SettingsImportStatus},
+  schema::{
+    captcha_answer,
+    comment,
+    comment_revision,
+    community,
+    community_actions,
+    federation_blocklist,
+    instance,
+    instance_actions,
+    local_site,
+    local_user,
+    login_token,
+    oauth_authorization_code,
+    oauth_refresh_token,
+    person,
+    personal_access_token,
+    post,
+    post_revision,
+    private_message_revision,
+    received_activity,
+    sent_activity,
+    site,
+    user_data_export,
+    user_settings_import,
+    webauthn_challenge,
+  },
 };
+use lemmy_db_views_community_moderator::CommunityModeratorView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::api::CreatePost;
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -55,9 +79,11 @@ use lemmy_diesel_utils::{
 use lemmy_utils::{
   DB_BATCH_SIZE,
   error::{LemmyErrorType, LemmyResult},
+  settings::structs::Settings,
 };
 use reqwest_middleware::ClientWithMiddleware;
 use std::time::Duration;
+use tokio::fs;
 use tracing::{info, warn};
 
 /// Schedules various cleanup tasks for lemmy in a background thread
@@ -66,7 +92,8 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   let mut scheduler = AsyncScheduler::with_tz(Utc);
 
   let context_1 = context.clone();
-  // Every 10 minutes update hot ranks, delete expired captchas and publish scheduled posts
+  // Every 10 minutes update hot ranks, delete expired captchas and webauthn challenges, and publish
+  // scheduled and recurring posts
   scheduler.every(CTimeUnits::minutes(10)).run(move || {
     let context = context_1.clone();
 
@@ -79,10 +106,18 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
         .await
         .inspect_err(|e| warn!("Failed to delete expired captcha answers: {e}"))
         .ok();
+      delete_expired_webauthn_challenges(&mut context.pool())
+        .await
+        .inspect_err(|e| warn!("Failed to delete expired webauthn challenges: {e}"))
+        .ok();
       publish_scheduled_posts(&context)
         .await
         .inspect_err(|e| warn!("Failed to publish scheduled posts: {e}"))
         .ok();
+      publish_recurring_posts(&context)
+        .await
+        .inspect_err(|e| warn!("Failed to publish recurring posts: {e}"))
+        .ok();
     }
   });
 
@@ -91,6 +126,12 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   // - Update active daily counts
   // - Expired bans
   // - Expired instance blocks
+  // - Expired OAuth access tokens and codes
+  // - Expired and idle login sessions
+  // - Accounts whose deletion grace period is over
+  // - Old and interrupted data exports
+  // - Old and interrupted settings imports
+  // - Expired content filters
   scheduler.every(CTimeUnits::hour(1)).run(move || {
     let context = context_1.clone();
 
@@ -107,6 +148,31 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
         .await
         .inspect_err(|e| warn!("Failed to delete expired instance bans: {e}"))
         .ok();
+      delete_expired_oauth_tokens(&mut context.pool())
+        .await
+        .inspect_err(|e| warn!("Failed to delete expired oauth tokens: {e}"))
+        .ok();
+      delete_expired_logins(&mut context.pool())
+        .await
+        .inspect_err(|e| warn!("Failed to delete expired logins: {e}"))
+        .ok();
+      delete_scheduled_accounts(&context)
+        .await
+        .inspect_err(|e| warn!("Failed to delete scheduled accounts: {e}"))
+        .ok();
+      clear_old_data_exports(&mut context.pool())
+        .await
+        .inspect_err(|e| warn!("Failed to clear old data exports: {e}"))
+        .ok();
+      clear_old_data_export_files(context.settings()).await;
+      clear_old_settings_imports(&mut context.pool())
+        .await
+        .inspect_err(|e| warn!("Failed to clear old settings imports: {e}"))
+        .ok();
+      ContentFilter::delete_expired(&mut context.pool())
+        .await
+        .inspect_err(|e| warn!("Failed to delete expired content filters: {e}"))
+        .ok();
     }
   });
 
@@ -118,6 +184,8 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   // - Delete old denied users
   // - Update instance software
   // - Delete old outgoing activities
+  // - Delete edit history past the retention period
+  // - Delete drafts which weren't edited for 90 days
   scheduler.every(CTimeUnits::days(1)).run(move || {
     let context = context_1.reset_request_count();
 
@@ -146,6 +214,14 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
         .await
         .inspect_err(|e| warn!("Failed to clear old activities: {e}"))
         .ok();
+      clear_old_revisions(&mut context.pool())
+        .await
+        .inspect_err(|e| warn!("Failed to clear old edit history: {e}"))
+        .ok();
+      Draft::delete_old(&mut context.pool())
+        .await
+        .inspect_err(|e| warn!("Failed to delete old drafts: {e}"))
+        .ok();
     }
   });
 
@@ -294,6 +370,168 @@ async fn delete_expired_captcha_answers(pool: &mut DbPool<'_>) -> LemmyResult<()
   Ok(())
 }
 
+/// Webauthn challenges can only be answered within ten minutes.
+async fn delete_expired_webauthn_challenges(pool: &mut DbPool<'_>) -> LemmyResult<()> {
+  let conn = &mut get_conn(pool).await?;
+
+  diesel::delete(
+    webauthn_challenge::table
+      .filter(webauthn_challenge::published_at.lt(now() - IntervalDsl::minutes(10))),
+  )
+  .execute(conn)
+  .await?;
+
+  Ok(())
+}
+
+/// Access tokens of OAuth apps are short lived, and new ones are issued all the time.
+async fn delete_expired_oauth_tokens(pool: &mut DbPool<'_>) -> LemmyResult<()> {
+  let conn = &mut get_conn(pool).await?;
+
+  diesel::delete(
+    personal_access_token::table
+      .filter(personal_access_token::oauth_application_id.is_not_null())
+      .filter(personal_access_token::expires_at.lt(now().nullable())),
+  )
+  .execute(conn)
+  .await?;
+  diesel::delete(
+    oauth_authorization_code::table.filter(oauth_authorization_code::expires_at.lt(now())),
+  )
+  .execute(conn)
+  .await?;
+  diesel::delete(oauth_refresh_token::table.filter(oauth_refresh_token::expires_at.lt(now())))
+    .execute(conn)
+    .await?;
+
+  Ok(())
+}
+
+/// Sessions end after a week unless `stay_logged_in` was used, and when they are idle for longer
+/// than the admin allows.
+async fn delete_expired_logins(pool: &mut DbPool<'_>) -> LemmyResult<()> {
+  let conn = &mut get_conn(pool).await?;
+
+  diesel::delete(login_token::table.filter(login_token::expires_at.lt(now().nullable())))
+    .execute(conn)
+    .await?;
+  let idle_timeout = local_site::table
+    .select(local_site::session_idle_timeout_seconds)
+    .first::<Option<i32>>(conn)
+    .await
+    .optional()?
+    .flatten();
+  if let Some(idle_timeout) = idle_timeout {
+    diesel::delete(
+      login_token::table
+        .filter(login_token::last_used_at.lt(Utc::now() - TimeDelta::seconds(idle_timeout.into()))),
+    )
+    .execute(conn)
+    .await?;
+  }
+
+  Ok(())
+}
+
+/// Delete accounts whose deletion grace period is over, and federate the deletion.
+async fn delete_scheduled_accounts(context: &Data<LemmyContext>) -> LemmyResult<()> {
+  for local_user in LocalUser::list_due_for_deletion(&mut context.pool()).await? {
+    let local_user_view = LocalUserView::read(&mut context.pool(), local_user.id).await?;
+    info!("Deleting account {}...", local_user_view.person.name);
+    delete_local_user_account(local_user_view, local_user.deletion_delete_content, context)
+      .await
+      .inspect_err(|e| warn!("Failed to delete account: {e}"))
+      .ok();
+  }
+  Ok(())
+}
+
+const DATA_EXPORT_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Export archives are deleted after a week. Exports which are still pending after an hour were
+/// interrupted, for example by a restart, so they are marked as failed.
+async fn clear_old_data_exports(pool: &mut DbPool<'_>) -> LemmyResult<()> {
+  let conn = &mut get_conn(pool).await?;
+
+  diesel::delete(
+    user_data_export::table.filter(user_data_export::published_at.lt(now() - IntervalDsl::days(7))),
+  )
+  .execute(conn)
+  .await?;
+
+  update(
+    user_data_export::table
+      .filter(user_data_export::status.eq(DataExportStatus::Pending))
+      .filter(user_data_export::published_at.lt(now() - IntervalDsl::hours(1))),
+  )
+  .set((
+    user_data_export::status.eq(DataExportStatus::Failed),
+    user_data_export::completed_at.eq(Utc::now()),
+  ))
+  .execute(conn)
+  .await?;
+
+  Ok(())
+}
+
+/// Archive files are removed by their age, so that the archives of deleted accounts are also
+/// removed. Other files in the directory are left alone.
+async fn clear_old_data_export_files(settings: &Settings) {
+  let Ok(mut entries) = fs::read_dir(&settings.data_export_directory).await else {
+    return;
+  };
+  while let Ok(Some(entry)) = entries.next_entry().await {
+    let path = entry.path();
+    let is_archive = path
+      .file_stem()
+      .and_then(|stem| stem.to_str()?.parse().ok())
+      .is_some_and(|id| user_data_export_path(settings, UserDataExportId(id)) == path);
+    if is_archive {
+      remove_expired_data_export(&entry)
+        .await
+        .inspect_err(|e| warn!("Failed to remove data export {}: {e}", path.display()))
+        .ok();
+    }
+  }
+}
+
+/// Removes the archive if it is a regular file which is older than [DATA_EXPORT_MAX_AGE].
+async fn remove_expired_data_export(entry: &fs::DirEntry) -> LemmyResult<()> {
+  let metadata = entry.metadata().await?;
+  let age = metadata.modified()?.elapsed().unwrap_or_default();
+  if metadata.is_file() && age > DATA_EXPORT_MAX_AGE {
+    fs::remove_file(entry.path()).await?;
+  }
+  Ok(())
+}
+
+/// Import reports are deleted after a week. Imports which are still pending after six hours were
+/// interrupted, so they are marked as failed and the user can start a new one.
+async fn clear_old_settings_imports(pool: &mut DbPool<'_>) -> LemmyResult<()> {
+  let conn = &mut get_conn(pool).await?;
+
+  diesel::delete(
+    user_settings_import::table
+      .filter(user_settings_import::published_at.lt(now() - IntervalDsl::days(7))),
+  )
+  .execute(conn)
+  .await?;
+
+  update(
+    user_settings_import::table
+      .filter(user_settings_import::status.eq(SettingsImportStatus::Pending))
+      .filter(user_settings_import::published_at.lt(now() - IntervalDsl::hours(6))),
+  )
+  .set((
+    user_settings_import::status.eq(SettingsImportStatus::Failed),
+    user_settings_import::completed_at.eq(Utc::now()),
+  ))
+  .execute(conn)
+  .await?;
+
+  Ok(())
+}
+
 /// Clear old activities (this table gets very large)
 async fn clear_old_activities(pool: &mut DbPool<'_>) -> LemmyResult<()> {
   info!("Clearing old activities...");
@@ -349,10 +587,63 @@ async fn overwrite_deleted_posts_and_comments(pool: &mut DbPool<'_>) -> LemmyRes
   .set(comment::content.eq(DELETED_REPLACEMENT_TEXT))
   .execute(conn)
   .await?;
+
+  // Otherwise the deleted text would still be readable in the edit history
+  info!("Deleting edit history of overwritten posts and comments...");
+  diesel::delete(
+    post_revision::table.filter(
+      post_revision::post_id.eq_any(
+        post::table
+          .filter(post::deleted.eq(true))
+          .filter(post::body.eq(DELETED_REPLACEMENT_TEXT))
+          .select(post::id),
+      ),
+    ),
+  )
+  .execute(conn)
+  .await?;
+  diesel::delete(
+    comment_revision::table.filter(
+      comment_revision::comment_id.eq_any(
+        comment::table
+          .filter(comment::deleted.eq(true))
+          .filter(comment::content.eq(DELETED_REPLACEMENT_TEXT))
+          .select(comment::id),
+      ),
+    ),
+  )
+  .execute(conn)
+  .await?;
   info!("Done.");
   Ok(())
 }
 
+/// Delete edit history which is older than the retention period configured by the admin.
+async fn clear_old_revisions(pool: &mut DbPool<'_>) -> LemmyResult<()> {
+  let conn = &mut get_conn(pool).await?;
+  let retention_days = local_site::table
+    .select(local_site::edit_history_retention_days)
+    .first::<Option<i32>>(conn)
+    .await?;
+  let Some(days) = retention_days.filter(|d| *d > 0) else {
+    return Ok(());
+  };
+  let cutoff = now() - IntervalDsl::days(days);
+
+  diesel::delete(post_revision::table.filter(post_revision::published_at.lt(cutoff)))
+    .execute(conn)
+    .await?;
+  diesel::delete(comment_revision::table.filter(comment_revision::published_at.lt(cutoff)))
+    .execute(conn)
+    .await?;
+  diesel::delete(
+    private_message_revision::table.filter(private_message_revision::published_at.lt(cutoff)),
+  )
+  .execute(conn)
+  .await?;
+  Ok(())
+}
+
 const ONE_DAY: (&str, &str) = ("1 day", "day");
 const ONE_WEEK: (&str, &str) = ("1 week", "week");
 const ONE_MONTH: (&str, &str) = ("1 month", "month");
@@ -618,6 +909,144 @@ async fn publish_scheduled_posts(context: &Data<LemmyContext>) -> LemmyResult<()
   Ok(())
 }
 
+const RECURRING_POST_LOCK_REASON: &str = "Replaced by a newer recurring post";
+
+/// Publishes the recurring posts which are due.
+async fn publish_recurring_posts(context: &Data<LemmyContext>) -> LemmyResult<()> {
+  for recurring_post in RecurringPost::list_due(&mut context.pool()).await? {
+    publish_recurring_post(recurring_post, context)
+      .await
+      .inspect_err(|e| warn!("Failed to publish recurring post: {e}"))
+      .ok();
+  }
+  Ok(())
+}
+
+async fn publish_recurring_post(
+  recurring_post: RecurringPost,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let pool = &mut context.pool();
+
+  // Move on to the next date first, so that a failing post isn't retried every few minutes
+  let next_publish_at = RecurringPost::next_publish_time(
+    recurring_post.frequency,
+    recurring_post.schedule_start_at,
+    Utc::now(),
+  )
+  .ok_or(LemmyErrorType::InvalidUnixTime)?;
+  let form = RecurringPostUpdateForm {
+    next_publish_at: Some(next_publish_at),
+    ..Default::default()
+  };
+  RecurringPost::update(pool, recurring_post.id, &form).await?;
+
+  // Only publish while the creator is still allowed to manage the recurring post
+  let community = Community::read(pool, recurring_post.community_id).await?;
+  let creator_view = LocalUserView::read_person(pool, recurring_post.creator_id).await?;
+  let creator = creator_view.person.clone();
+  if CommunityModeratorView::check_moderator_permission(
+    pool,
+    community.id,
+    creator.id,
+    Some(ModeratorPermission::EditSettings),
+  )
+  .await
+  .is_err()
+  {
+    return Ok(());
+  }
+
+  // Published like any other post, so that bans, quarantine and approval apply as well
+  let form = CreatePost {
+    name: RecurringPost::render_name(
+      &recurring_post.name_template,
+      recurring_post.next_publish_at,
+    ),
+    community_id: community.id,
+    body: recurring_post.body.clone(),
+    nsfw: Some(recurring_post.nsfw),
+    language_id: recurring_post.language_id,
+    ..Default::default()
+  };
+  let post = create_post(Json(form), context.clone(), creator_view)
+    .await?
+    .0
+    .post_view
+    .post;
+
+  // Featuring and locking need their own permission. Posts which aren't visible yet are left
+  // alone, as the feature would be federated before the post.
+  let can_mod = CommunityModeratorView::check_moderator_permission(
+    pool,
+    community.id,
+    creator.id,
+    Some(ModeratorPermission::RemoveContent),
+  )
+  .await
+  .is_ok()
+    && !post.quarantined
+    && !post.pending_approval;
+  let mut modlog_forms = vec![];
+  if let Some(previous_id) = recurring_post.last_post_id
+    && can_mod
+  {
+    let previous = Post::read(pool, previous_id).await?;
+    if recurring_post.feature_latest && previous.featured_community {
+      let form = PostUpdateForm {
+        featured_community: Some(false),
+        ..Default::default()
+      };
+      let previous = Post::update(pool, previous.id, &form).await?;
+      let form = ModlogInsertForm::mod_feature_post_community(creator.id, &previous, false);
+      modlog_forms.push(form);
+      let send = SendActivityData::FeaturePost(previous, creator.clone(), false);
+      ActivityChannel::submit_activity(send, context)?;
+    }
+    if recurring_post.lock_previous && !previous.locked {
+      let form = PostUpdateForm {
+        locked: Some(true),
+        ..Default::default()
+      };
+      let previous = Post::update(pool, previous.id, &form).await?;
+      modlog_forms.push(ModlogInsertForm::mod_lock_post(
+        creator.id,
+        &previous,
+        true,
+        RECURRING_POST_LOCK_REASON,
+      ));
+      let send = SendActivityData::LockPost(
+        previous,
+        creator.clone(),
+        true,
+        RECURRING_POST_LOCK_REASON.to_string(),
+      );
+      ActivityChannel::submit_activity(send, context)?;
+    }
+  }
+  if recurring_post.feature_latest && can_mod {
+    let form = PostUpdateForm {
+      featured_community: Some(true),
+      ..Default::default()
+    };
+    let post = Post::update(pool, post.id, &form).await?;
+    let form = ModlogInsertForm::mod_feature_post_community(creator.id, &post, true);
+    modlog_forms.push(form);
+    let send = SendActivityData::FeaturePost(post, creator.clone(), true);
+    ActivityChannel::submit_activity(send, context)?;
+  }
+  if !modlog_forms.is_empty() {
+    Modlog::create(pool, &modlog_forms).await?;
+  }
+
+  let form = RecurringPostUpdateForm {
+    last_post_id: Some(Some(post.id)),
+    ..Default::default()
+  };
+  RecurringPost::update(pool, recurring_post.id, &form).await?;
+  Ok(())
+}
+
 /// Updates the instance software and version.
 ///
 /// Does so using the /.well-known/nodeinfo protocol described here:
@@ -787,10 +1216,15 @@ mod tests {
     delete_instance_block_when_expired(pool).await?;
     clear_old_activities(pool).await?;
     overwrite_deleted_posts_and_comments(pool).await?;
+    clear_old_revisions(pool).await?;
     delete_old_denied_users(pool).await?;
     update_instance_software(pool, context.client()).await?;
     delete_expired_captcha_answers(pool).await?;
+    delete_expired_webauthn_challenges(pool).await?;
+    delete_expired_oauth_tokens(pool).await?;
+    delete_expired_logins(pool).await?;
     publish_scheduled_posts(&context).await?;
+    publish_recurring_posts(&context).await?;
 
     let community_after = Community::read(pool, community.id).await?;
     assert_eq!(
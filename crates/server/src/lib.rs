@@ -33,6 +33,7 @@ use lemmy_routes::{
     session::SessionMiddleware,
   },
   nodeinfo,
+  oauth_metadata,
   utils::{
     cors_config,
     prometheus_metrics::{new_prometheus_metrics, serve_prometheus},
@@ -373,6 +374,7 @@ fn create_http_server(
       })
       .configure(feeds::config)
       .configure(nodeinfo::config)
+      .configure(oauth_metadata::config)
       .service(
         scope("/sitemap.xml")
           .wrap(rate_limit.message())
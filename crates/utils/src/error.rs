@@ -107,6 +107,7 @@ pub enum LemmyErrorType {
   InvalidBotAction,
   TagNotInCommunity,
   CantBlockLocalInstance,
+  CantQuarantineRemotePerson,
   Unknown(String),
   UrlLengthOverflow,
   OauthAuthorizationInvalid,
@@ -115,6 +116,7 @@ pub enum LemmyErrorType {
   NotFound,
   PostScheduleTimeMustBeInFuture,
   TooManyScheduledPosts,
+  TooManyGalleryImages,
   CannotCombineFederationBlocklistAndAllowlist,
   CouldntParsePaginationToken,
   PluginError(String),
@@ -125,6 +127,58 @@ pub enum LemmyErrorType {
   MultiCommunityEntryLimitReached,
   TooManyRequests,
   ResolveObjectFailed(String),
+  NotQuarantined,
+  NotPendingApproval,
+  NotBanned,
+  BanAlreadyAppealed,
+  BanAppealAlreadyResolved,
+  ShortenedBanMustExpireEarlier,
+  MissingModeratorPermission,
+  MissingAdminPermission,
+  CantEditOwnAdminPermissions,
+  MissingTokenScope,
+  InvalidAccessTokenName,
+  InvalidOauthApplicationName,
+  InvalidRedirectUri,
+  /// The following errors use the names from the OAuth 2.0 spec, so that apps can handle them.
+  InvalidClient,
+  InvalidGrant,
+  InvalidScope,
+  UnsupportedGrantType,
+  UnsupportedResponseType,
+  MissingWebauthnCredential,
+  IncorrectWebauthnCredential,
+  InvalidWebauthnCredentialName,
+  IncorrectRecoveryCode,
+  InviteCodeRequired,
+  InvalidInviteCode,
+  CantCreateInviteCodes,
+  InvalidInviteCodeMaxUses,
+  TooManyInviteCodes,
+  InvalidInviteCommunity,
+  InvalidRegistrationAnswer,
+  InvalidRegistrationQuestion,
+  InvalidScreeningRule,
+  EmailDomainNotAllowed,
+  InvalidEmailDomain,
+  DataExportAlreadyPending,
+  SettingsImportAlreadyPending,
+  InvalidImportFile,
+  InvalidContentFilter,
+  TooManyContentFilters,
+  InvalidDraft,
+  CannotCrosspostToSameCommunity,
+  InvalidWikiSlug,
+  NoWikiEditAllowed,
+  InvalidEventTime,
+  InvalidTimezone,
+  InvalidEventLocation,
+  PostIsNotAnEvent,
+  InvalidAcceptedAnswer,
+  InvalidTagColor,
+  InvalidFlairEmoji,
+  InvalidUserFlair,
+  TagIsModOnly,
   #[serde(untagged)]
   #[cfg_attr(feature = "ts-rs", ts(skip))]
   UntranslatedError(Option<UntranslatedError>),
@@ -52,6 +52,14 @@ pub struct Settings {
   cors_origin: Vec<String>,
   /// Print logs in JSON format. You can also disable ANSI colors in logs with env var `NO_COLOR`.
   pub json_logging: bool,
+  /// A file with one email domain per line, which replaces the bundled list of disposable email
+  /// providers. Only used if blocking disposable email domains is enabled in the site settings.
+  #[doku(example = "/config/disposable_email_domains.txt")]
+  pub disposable_email_domains_file: Option<String>,
+  /// Directory where the archives of user data exports are stored. They are deleted after a week.
+  #[default("data_exports")]
+  #[doku(example = "/data_exports")]
+  pub data_export_directory: String,
   /// Data for loading Lemmy plugins
   pub plugins: Vec<PluginSettings>,
 }
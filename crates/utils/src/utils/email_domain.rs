@@ -0,0 +1,149 @@
+use crate::{
+  error::{LemmyErrorType, LemmyResult},
+  settings::SETTINGS,
+};
+use itertools::Itertools;
+use std::{collections::HashSet, fs::read_to_string, sync::LazyLock};
+use tracing::warn;
+
+const BUNDLED_DISPOSABLE_EMAIL_DOMAINS: &str = include_str!("disposable_email_domains.txt");
+
+/// Domains of disposable email providers. These are read from the file in the config if one is
+/// set, otherwise the bundled list is used.
+static DISPOSABLE_EMAIL_DOMAINS: LazyLock<HashSet<String>> = LazyLock::new(|| {
+  let file_content = SETTINGS
+    .disposable_email_domains_file
+    .as_ref()
+    .and_then(|file| {
+      read_to_string(file)
+        .inspect_err(|e| warn!("Failed to read disposable email domains from {file}: {e}"))
+        .ok()
+    });
+  parse_domain_list(
+    file_content
+      .as_deref()
+      .unwrap_or(BUNDLED_DISPOSABLE_EMAIL_DOMAINS),
+  )
+});
+
+/// Parses a list with one domain per line. Empty lines and lines starting with `#` are ignored.
+fn parse_domain_list(list: &str) -> HashSet<String> {
+  list
+    .lines()
+    .map(str::trim)
+    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    .map(str::to_lowercase)
+    .collect()
+}
+
+/// Returns the lowercase domain of an email address.
+pub fn email_domain(email: &str) -> Option<String> {
+  email
+    .rsplit_once('@')
+    .map(|(_, domain)| domain.trim().trim_end_matches('.').to_lowercase())
+    .filter(|domain| !domain.is_empty())
+}
+
+/// Checks if the domain is the given domain or one of its subdomains.
+pub fn domain_matches(domain: &str, pattern: &str) -> bool {
+  domain == pattern
+    || domain
+      .strip_suffix(pattern)
+      .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+/// Checks that the email domains are valid, and normalizes them to lowercase without a leading
+/// `@`.
+pub fn check_email_domains_are_valid(domains: &[String]) -> LemmyResult<Vec<String>> {
+  let mut checked = vec![];
+  for domain in domains {
+    let domain = domain.trim().trim_start_matches('@').to_lowercase();
+    if !domain.contains('.')
+      || domain.starts_with('.')
+      || domain.ends_with('.')
+      || domain.contains(|c: char| c.is_whitespace() || c == '@' || c == '/')
+    {
+      Err(LemmyErrorType::InvalidEmailDomain)?
+    }
+    checked.push(domain);
+  }
+
+  Ok(checked.into_iter().unique().collect())
+}
+
+/// Checks if the domain, or any of its parent domains, belongs to a disposable email provider.
+pub fn is_disposable_email_domain(domain: &str) -> bool {
+  is_in_domain_list(domain, &DISPOSABLE_EMAIL_DOMAINS)
+}
+
+fn is_in_domain_list(domain: &str, list: &HashSet<String>) -> bool {
+  let mut current = domain;
+  loop {
+    if list.contains(current) {
+      return true;
+    }
+    match current.split_once('.') {
+      Some((_, parent)) => current = parent,
+      None => return false,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{
+    BUNDLED_DISPOSABLE_EMAIL_DOMAINS,
+    check_email_domains_are_valid,
+    domain_matches,
+    email_domain,
+    is_in_domain_list,
+    parse_domain_list,
+  };
+  use crate::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_email_domain() {
+    assert_eq!(
+      Some("example.com".to_string()),
+      email_domain("User@Example.COM")
+    );
+    assert_eq!(None, email_domain("no-domain@"));
+    assert_eq!(None, email_domain("no-at-sign"));
+  }
+
+  #[test]
+  fn test_domain_matches() {
+    assert!(domain_matches("uni.edu", "uni.edu"));
+    assert!(domain_matches("cs.uni.edu", "uni.edu"));
+    assert!(!domain_matches("notuni.edu", "uni.edu"));
+    assert!(!domain_matches("uni.edu.evil.com", "uni.edu"));
+  }
+
+  #[test]
+  fn test_check_email_domains_are_valid() -> LemmyResult<()> {
+    let domains = vec![
+      "@Uni.edu".to_string(),
+      " uni.edu ".to_string(),
+      "cs.uni.edu".to_string(),
+    ];
+    assert_eq!(
+      vec!["uni.edu".to_string(), "cs.uni.edu".to_string()],
+      check_email_domains_are_valid(&domains)?
+    );
+    assert!(check_email_domains_are_valid(&["localhost".to_string()]).is_err());
+    assert!(check_email_domains_are_valid(&["user@uni.edu".to_string()]).is_err());
+    assert!(check_email_domains_are_valid(&["uni .edu".to_string()]).is_err());
+    Ok(())
+  }
+
+  #[test]
+  fn test_disposable_email_domains() {
+    let list = parse_domain_list(BUNDLED_DISPOSABLE_EMAIL_DOMAINS);
+    assert!(!list.iter().any(|domain| domain.starts_with('#')));
+    assert!(is_in_domain_list("mailinator.com", &list));
+    assert!(is_in_domain_list("abc.mailinator.com", &list));
+    assert!(!is_in_domain_list("gmail.com", &list));
+    assert!(!is_in_domain_list("com", &list));
+  }
+}
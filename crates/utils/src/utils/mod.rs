@@ -1,3 +1,4 @@
+pub mod email_domain;
 pub mod markdown;
 pub mod mention;
 pub mod slurs;
@@ -31,6 +31,18 @@ const MIN_LENGTH_BLOCKING_KEYWORD: usize = 3;
 const MAX_LENGTH_BLOCKING_KEYWORD: usize = 50;
 const ACTOR_NAME_MAX_LENGTH: usize = 20;
 const DISPLAY_NAME_MAX_LENGTH: usize = 50;
+const ACCESS_TOKEN_NAME_MAX_LENGTH: usize = 50;
+const OAUTH_APPLICATION_NAME_MAX_LENGTH: usize = 50;
+const WEBAUTHN_CREDENTIAL_NAME_MAX_LENGTH: usize = 50;
+const CONTENT_FILTER_PHRASE_MAX_LENGTH: usize = 200;
+const CONTENT_FILTER_REGEX_MAX_LENGTH: usize = 100;
+const DRAFT_NAME_MAX_LENGTH: usize = 200;
+const WIKI_SLUG_MAX_LENGTH: usize = 100;
+const EVENT_TIMEZONE_MAX_LENGTH: usize = 50;
+const EVENT_LOCATION_MAX_LENGTH: usize = 500;
+const USER_FLAIR_MAX_LENGTH: usize = 50;
+const FLAIR_EMOJI_MAX_LENGTH: usize = 16;
+pub const POST_GALLERY_MAX_IMAGES: usize = 20;
 
 fn has_newline(name: &str) -> bool {
   name.contains('\n')
@@ -128,6 +140,13 @@ pub fn is_valid_alt_text_field(alt_text: &str) -> LemmyResult<()> {
   Ok(())
 }
 
+pub fn is_valid_post_gallery_size(images: usize) -> LemmyResult<()> {
+  if images > POST_GALLERY_MAX_IMAGES {
+    Err(LemmyErrorType::TooManyGalleryImages)?
+  }
+  Ok(())
+}
+
 /// Checks the site name length, the limit as defined in the DB.
 pub fn site_name_length_check(name: &str) -> LemmyResult<()> {
   min_length_check(name, SITE_NAME_MIN_LENGTH, LemmyErrorType::SiteNameRequired)?;
@@ -138,6 +157,257 @@ pub fn site_name_length_check(name: &str) -> LemmyResult<()> {
   )
 }
 
+/// Checks that a personal access token has a name of reasonable length.
+pub fn access_token_name_check(name: &str) -> LemmyResult<()> {
+  min_length_check(name.trim(), 1, LemmyErrorType::InvalidAccessTokenName)?;
+  max_length_check(
+    name,
+    ACCESS_TOKEN_NAME_MAX_LENGTH,
+    LemmyErrorType::InvalidAccessTokenName,
+  )
+}
+
+/// Checks that a content filter has a phrase of reasonable length.
+pub fn content_filter_phrase_check(phrase: &str) -> LemmyResult<()> {
+  min_length_check(phrase.trim(), 1, LemmyErrorType::InvalidContentFilter)?;
+  max_length_check(
+    phrase,
+    CONTENT_FILTER_PHRASE_MAX_LENGTH,
+    LemmyErrorType::InvalidContentFilter,
+  )
+}
+
+/// Regex filters are matched against every post and comment in a listing, so only simple
+/// patterns are allowed. Backreferences and nested quantifiers such as `(a+)+` can make postgres
+/// take exponential time.
+pub fn content_filter_regex_check(pattern: &str) -> LemmyResult<()> {
+  max_length_check(
+    pattern,
+    CONTENT_FILTER_REGEX_MAX_LENGTH,
+    LemmyErrorType::InvalidContentFilter,
+  )?;
+  let is_quantifier = |c: &char| matches!(c, '*' | '+' | '?' | '{');
+  // For each open group, whether it contains a quantifier
+  let mut groups: Vec<bool> = vec![];
+  let mut chars = pattern.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '\\' => {
+        if chars.next().is_some_and(|c| matches!(c, '1'..='9')) {
+          Err(LemmyErrorType::InvalidContentFilter)?
+        }
+      }
+      '[' => {
+        // Bracket expressions can't contain groups or quantifiers, skip to the end
+        chars.next_if_eq(&'^');
+        chars.next_if_eq(&']');
+        while let Some(c) = chars.next() {
+          match c {
+            '\\' => {
+              chars.next();
+            }
+            ']' => break,
+            _ => {}
+          }
+        }
+      }
+      '(' => {
+        // Skip the `?` of non-capturing groups and lookaheads like `(?:` or `(?=`
+        if chars.next_if_eq(&'?').is_some() {
+          chars.next();
+        }
+        groups.push(false);
+      }
+      ')' => {
+        let inner_quantified = groups.pop().unwrap_or_default();
+        let quantified = chars.peek().is_some_and(is_quantifier);
+        if inner_quantified && quantified {
+          Err(LemmyErrorType::InvalidContentFilter)?
+        }
+        if let Some(outer) = groups.last_mut() {
+          *outer |= inner_quantified || quantified;
+        }
+      }
+      c if is_quantifier(&c) => {
+        if let Some(group) = groups.last_mut() {
+          *group = true;
+        }
+      }
+      _ => {}
+    }
+  }
+  Ok(())
+}
+
+/// Checks the fields of a draft. Unlike for published content these may be empty or incomplete,
+/// so only the length limits apply.
+pub fn draft_fields_check(
+  name: Option<&str>,
+  url: Option<&str>,
+  alt_text: Option<&str>,
+  custom_thumbnail: Option<&str>,
+  body: Option<&str>,
+) -> LemmyResult<()> {
+  if let Some(name) = name {
+    max_length_check(
+      name,
+      DRAFT_NAME_MAX_LENGTH,
+      LemmyErrorType::InvalidPostTitle,
+    )?;
+  }
+  for url in [url, custom_thumbnail].into_iter().flatten() {
+    max_length_check(url, URL_MAX_LENGTH, LemmyErrorType::UrlLengthOverflow)?;
+  }
+  if let Some(alt_text) = alt_text {
+    is_valid_alt_text_field(alt_text)?;
+  }
+  if let Some(body) = body {
+    is_valid_body_field(body, true)?;
+  }
+  Ok(())
+}
+
+/// Checks that an OAuth app has a name of reasonable length.
+pub fn oauth_application_name_check(name: &str) -> LemmyResult<()> {
+  min_length_check(name.trim(), 1, LemmyErrorType::InvalidOauthApplicationName)?;
+  max_length_check(
+    name,
+    OAUTH_APPLICATION_NAME_MAX_LENGTH,
+    LemmyErrorType::InvalidOauthApplicationName,
+  )
+}
+
+/// Checks that a webauthn credential has a name of reasonable length.
+pub fn webauthn_credential_name_check(name: &str) -> LemmyResult<()> {
+  min_length_check(
+    name.trim(),
+    1,
+    LemmyErrorType::InvalidWebauthnCredentialName,
+  )?;
+  max_length_check(
+    name,
+    WEBAUTHN_CREDENTIAL_NAME_MAX_LENGTH,
+    LemmyErrorType::InvalidWebauthnCredentialName,
+  )
+}
+
+/// Checks a redirect uri of an OAuth app. Custom schemes are allowed for native apps, but plain
+/// http only for apps running on localhost.
+pub fn oauth_redirect_uri_check(redirect_uri: &str) -> LemmyResult<()> {
+  let url = Url::parse(redirect_uri).with_lemmy_type(LemmyErrorType::InvalidRedirectUri)?;
+  let is_loopback = matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+  if url.fragment().is_some() || (url.scheme() == "http" && !is_loopback) {
+    Err(LemmyErrorType::InvalidRedirectUri)?
+  }
+  max_length_check(
+    redirect_uri,
+    URL_MAX_LENGTH,
+    LemmyErrorType::InvalidRedirectUri,
+  )
+}
+
+/// Checks the format of a PKCE code verifier, as defined in RFC 7636.
+#[allow(clippy::expect_used)]
+pub fn check_code_verifier(code_verifier: &str) -> LemmyResult<()> {
+  static VALID_CODE_VERIFIER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-zA-Z0-9\-._~]{43,128}$").expect("compile regex"));
+
+  if VALID_CODE_VERIFIER_REGEX.is_match(code_verifier) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidCodeVerifier.into())
+  }
+}
+
+/// Checks the path of a wiki page, which consists of lowercase segments separated by slashes,
+/// like `guides/getting-started`.
+#[allow(clippy::expect_used)]
+pub fn is_valid_wiki_slug(slug: &str) -> LemmyResult<()> {
+  static VALID_WIKI_SLUG_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z0-9_-]+(/[a-z0-9_-]+)*$").expect("compile regex"));
+
+  max_length_check(slug, WIKI_SLUG_MAX_LENGTH, LemmyErrorType::InvalidWikiSlug)?;
+  if VALID_WIKI_SLUG_REGEX.is_match(slug) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidWikiSlug.into())
+  }
+}
+
+/// Checks the timezone of an event post, which is a name from the tz database like
+/// `Europe/Berlin` or `UTC`.
+#[allow(clippy::expect_used)]
+pub fn is_valid_event_timezone(timezone: &str) -> LemmyResult<()> {
+  static VALID_TIMEZONE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[A-Za-z][A-Za-z0-9_+-]*(/[A-Za-z0-9_+-]+)*$").expect("compile regex")
+  });
+
+  max_length_check(
+    timezone,
+    EVENT_TIMEZONE_MAX_LENGTH,
+    LemmyErrorType::InvalidTimezone,
+  )?;
+  if VALID_TIMEZONE_REGEX.is_match(timezone) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidTimezone.into())
+  }
+}
+
+pub fn is_valid_event_location(location: &str) -> LemmyResult<()> {
+  min_length_check(location, 1, LemmyErrorType::InvalidEventLocation)?;
+  max_length_check(
+    location,
+    EVENT_LOCATION_MAX_LENGTH,
+    LemmyErrorType::InvalidEventLocation,
+  )?;
+  if has_newline(location) {
+    Err(LemmyErrorType::InvalidEventLocation.into())
+  } else {
+    Ok(())
+  }
+}
+
+/// Checks the color of a community tag, which must be a hex color like `#ff8800`.
+#[allow(clippy::expect_used)]
+pub fn is_valid_tag_color(color: &str) -> LemmyResult<()> {
+  static VALID_TAG_COLOR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^#[0-9a-fA-F]{6}$").expect("compile regex"));
+
+  if VALID_TAG_COLOR_REGEX.is_match(color) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidTagColor.into())
+  }
+}
+
+/// The emoji of a tag or user flair must be a single (possibly composed) character.
+pub fn is_valid_flair_emoji(emoji: &str) -> LemmyResult<()> {
+  max_length_check(
+    emoji,
+    FLAIR_EMOJI_MAX_LENGTH,
+    LemmyErrorType::InvalidFlairEmoji,
+  )?;
+  if emoji.graphemes(true).count() == 1 && !emoji.trim().is_empty() {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidFlairEmoji.into())
+  }
+}
+
+pub fn is_valid_user_flair(flair: &str) -> LemmyResult<()> {
+  max_length_check(
+    flair,
+    USER_FLAIR_MAX_LENGTH,
+    LemmyErrorType::InvalidUserFlair,
+  )?;
+  if flair.trim().is_empty() || has_newline(flair) {
+    Err(LemmyErrorType::InvalidUserFlair.into())
+  } else {
+    Ok(())
+  }
+}
+
 /// Checks the site / community description length, the limit as defined in the DB.
 pub fn summary_length_check(description: &str) -> LemmyResult<()> {
   max_length_check(
@@ -374,13 +644,20 @@ mod tests {
       check_urls_are_valid,
       clean_url,
       clean_urls_in_text,
+      content_filter_regex_check,
       is_url_blocked,
       is_valid_actor_name,
       is_valid_bio_field,
       is_valid_display_name,
+      is_valid_event_timezone,
+      is_valid_flair_emoji,
       is_valid_matrix_id,
       is_valid_post_title,
+      is_valid_tag_color,
       is_valid_url,
+      is_valid_user_flair,
+      is_valid_wiki_slug,
+      oauth_redirect_uri_check,
       site_name_length_check,
       summary_length_check,
       truncate_for_db,
@@ -489,6 +766,68 @@ Line3",
     assert!(is_valid_post_title("\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}").is_ok());
   }
 
+  #[test]
+  fn test_valid_wiki_slug() {
+    assert!(is_valid_wiki_slug("rules").is_ok());
+    assert!(is_valid_wiki_slug("guides/getting-started").is_ok());
+    assert!(is_valid_wiki_slug("faq/2024/hardware_tips").is_ok());
+    assert!(is_valid_wiki_slug("").is_err());
+    assert!(is_valid_wiki_slug("/rules").is_err());
+    assert!(is_valid_wiki_slug("guides//setup").is_err());
+    assert!(is_valid_wiki_slug("guides/").is_err());
+    assert!(is_valid_wiki_slug("Rules").is_err());
+    assert!(is_valid_wiki_slug("../admin").is_err());
+    assert!(is_valid_wiki_slug(&"a".repeat(101)).is_err());
+  }
+
+  #[test]
+  fn test_valid_event_timezone() {
+    assert!(is_valid_event_timezone("UTC").is_ok());
+    assert!(is_valid_event_timezone("Europe/Berlin").is_ok());
+    assert!(is_valid_event_timezone("America/Argentina/Buenos_Aires").is_ok());
+    assert!(is_valid_event_timezone("Etc/GMT+5").is_ok());
+    assert!(is_valid_event_timezone("").is_err());
+    assert!(is_valid_event_timezone("Europe/").is_err());
+    assert!(is_valid_event_timezone("../etc/passwd").is_err());
+    assert!(is_valid_event_timezone("Europe/Berlin; DROP").is_err());
+    assert!(is_valid_event_timezone(&"A".repeat(51)).is_err());
+  }
+
+  #[test]
+  fn test_valid_flair() {
+    assert!(is_valid_tag_color("#ff8800").is_ok());
+    assert!(is_valid_tag_color("#A1B2C3").is_ok());
+    assert!(is_valid_tag_color("ff8800").is_err());
+    assert!(is_valid_tag_color("#f80").is_err());
+    assert!(is_valid_tag_color("#ff8800; color: red").is_err());
+
+    assert!(is_valid_flair_emoji("🦀").is_ok());
+    assert!(is_valid_flair_emoji("\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}").is_ok());
+    assert!(is_valid_flair_emoji("🦀🦀").is_err());
+    assert!(is_valid_flair_emoji(" ").is_err());
+    assert!(is_valid_flair_emoji("").is_err());
+
+    assert!(is_valid_user_flair("Rust expert").is_ok());
+    assert!(is_valid_user_flair("   ").is_err());
+    assert!(is_valid_user_flair("two\nlines").is_err());
+    assert!(is_valid_user_flair(&"a".repeat(51)).is_err());
+  }
+
+  #[test]
+  fn test_content_filter_regex_check() {
+    assert!(content_filter_regex_check("^rust.*").is_ok());
+    assert!(content_filter_regex_check("\\y(rust|go)\\y").is_ok());
+    assert!(content_filter_regex_check("(ab+c)d").is_ok());
+    assert!(content_filter_regex_check("(?:ab)+").is_ok());
+    assert!(content_filter_regex_check("[(a+)]+").is_ok());
+    assert!(content_filter_regex_check("(a+)+").is_err());
+    assert!(content_filter_regex_check("(?:a*b)*").is_err());
+    assert!(content_filter_regex_check("((a+)b)+").is_err());
+    assert!(content_filter_regex_check("(a|b){2,}x(c?)*").is_err());
+    assert!(content_filter_regex_check("(a)\\1").is_err());
+    assert!(content_filter_regex_check(&"a".repeat(101)).is_err());
+  }
+
   #[test]
   fn test_valid_matrix_id() {
     assert!(is_valid_matrix_id("@dess:matrix.org").is_ok());
@@ -698,4 +1037,14 @@ Line3",
 
     Ok(())
   }
+
+  #[test]
+  fn test_oauth_redirect_uri() {
+    assert!(oauth_redirect_uri_check("https://app.example.com/callback").is_ok());
+    assert!(oauth_redirect_uri_check("http://localhost:8080/callback").is_ok());
+    assert!(oauth_redirect_uri_check("com.example.app:/callback").is_ok());
+    assert!(oauth_redirect_uri_check("http://app.example.com/callback").is_err());
+    assert!(oauth_redirect_uri_check("https://app.example.com/callback#token").is_err());
+    assert!(oauth_redirect_uri_check("not a url").is_err());
+  }
 }
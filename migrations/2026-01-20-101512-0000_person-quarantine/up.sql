@@ -0,0 +1,23 @@
+-- Quarantine is a softer alternative to a site ban. Content from quarantined persons is only
+-- visible to themselves and to mods/admins until it is approved.
+ALTER TABLE instance_actions
+    ADD COLUMN quarantined_at timestamptz;
+
+ALTER TABLE post
+    ADD COLUMN quarantined boolean NOT NULL DEFAULT FALSE;
+
+ALTER TABLE comment
+    ADD COLUMN quarantined boolean NOT NULL DEFAULT FALSE;
+
+-- Only a tiny fraction of rows is ever quarantined, so use partial indexes for the review queue
+CREATE INDEX idx_post_quarantined ON post (published_at DESC)
+WHERE
+    quarantined;
+
+CREATE INDEX idx_comment_quarantined ON comment (published_at DESC)
+WHERE
+    quarantined;
+
+ALTER TYPE modlog_kind
+    ADD value 'AdminQuarantinePerson';
+
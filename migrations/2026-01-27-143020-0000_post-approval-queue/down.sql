@@ -0,0 +1,54 @@
+DROP TABLE pending_post_activity;
+
+ALTER TABLE community
+    ADD COLUMN posting_restricted_to_mods boolean NOT NULL DEFAULT FALSE;
+
+UPDATE
+    community
+SET
+    posting_restricted_to_mods = TRUE
+WHERE
+    posting_mode = 'ModsOnly';
+
+ALTER TABLE community
+    DROP COLUMN posting_mode;
+
+DROP TYPE community_posting_mode_enum;
+
+ALTER TABLE post
+    DROP COLUMN pending_approval;
+
+-- revert change to modlog kind enum
+DELETE FROM modlog
+WHERE kind = 'ModApprovePost';
+
+ALTER TYPE modlog_kind RENAME TO modlog_kind__;
+
+CREATE TYPE modlog_kind AS enum (
+    'AdminAdd',
+    'AdminBan',
+    'AdminAllowInstance',
+    'AdminBlockInstance',
+    'AdminPurgeComment',
+    'AdminPurgeCommunity',
+    'AdminPurgePerson',
+    'AdminPurgePost',
+    'ModAddToCommunity',
+    'ModBanFromCommunity',
+    'ModFeaturePostCommunity',
+    'AdminFeaturePostSite',
+    'ModChangeCommunityVisibility',
+    'ModLockPost',
+    'ModRemoveComment',
+    'AdminRemoveCommunity',
+    'ModRemovePost',
+    'ModTransferCommunity',
+    'ModLockComment',
+    'AdminQuarantinePerson'
+);
+
+ALTER TABLE modlog
+    ALTER COLUMN kind TYPE modlog_kind
+    USING kind::text::modlog_kind;
+
+DROP TYPE modlog_kind__;
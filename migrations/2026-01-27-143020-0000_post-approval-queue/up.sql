@@ -0,0 +1,38 @@
+-- Who can create posts in a community. The new third mode lets anyone submit posts, but they are
+-- only published once a mod approves them.
+CREATE TYPE community_posting_mode_enum AS enum (
+    'Open',
+    'RequireApproval',
+    'ModsOnly'
+);
+
+ALTER TABLE community
+    ADD COLUMN posting_mode community_posting_mode_enum NOT NULL DEFAULT 'Open';
+
+UPDATE
+    community
+SET
+    posting_mode = 'ModsOnly'
+WHERE
+    posting_restricted_to_mods;
+
+ALTER TABLE community
+    DROP COLUMN posting_restricted_to_mods;
+
+ALTER TABLE post
+    ADD COLUMN pending_approval boolean NOT NULL DEFAULT FALSE;
+
+CREATE INDEX idx_post_pending_approval ON post (community_id, published_at DESC)
+WHERE
+    pending_approval;
+
+-- Activities of remote posts which are held back in the approval queue of a local community. They
+-- are announced to the community followers once a mod approves the post.
+CREATE TABLE pending_post_activity (
+    post_id int PRIMARY KEY REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    data json NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+ALTER TYPE modlog_kind
+    ADD value 'ModApprovePost';
@@ -0,0 +1,47 @@
+DELETE FROM report_combined
+WHERE ban_appeal_id IS NOT NULL;
+
+ALTER TABLE report_combined
+    DROP CONSTRAINT report_combined_check,
+    ADD CHECK (num_nonnulls (post_report_id, comment_report_id, private_message_report_id, community_report_id) = 1),
+    DROP COLUMN ban_appeal_id;
+
+DROP TABLE ban_appeal;
+
+DROP TYPE ban_appeal_decision_enum;
+
+-- revert change to modlog kind enum
+DELETE FROM modlog
+WHERE kind = 'ModResolveBanAppeal';
+
+ALTER TYPE modlog_kind RENAME TO modlog_kind__;
+
+CREATE TYPE modlog_kind AS enum (
+    'AdminAdd',
+    'AdminBan',
+    'AdminAllowInstance',
+    'AdminBlockInstance',
+    'AdminPurgeComment',
+    'AdminPurgeCommunity',
+    'AdminPurgePerson',
+    'AdminPurgePost',
+    'ModAddToCommunity',
+    'ModBanFromCommunity',
+    'ModFeaturePostCommunity',
+    'AdminFeaturePostSite',
+    'ModChangeCommunityVisibility',
+    'ModLockPost',
+    'ModRemoveComment',
+    'AdminRemoveCommunity',
+    'ModRemovePost',
+    'ModTransferCommunity',
+    'ModLockComment',
+    'AdminQuarantinePerson',
+    'ModApprovePost'
+);
+
+ALTER TABLE modlog
+    ALTER COLUMN kind TYPE modlog_kind
+    USING kind::text::modlog_kind;
+
+DROP TYPE modlog_kind__;
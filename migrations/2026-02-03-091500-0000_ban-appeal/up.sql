@@ -0,0 +1,31 @@
+-- Structured appeals against site bans and community bans. Each appeal references the modlog
+-- entry of the ban, so that a user can only appeal a given ban once.
+CREATE TYPE ban_appeal_decision_enum AS enum (
+    'Upheld',
+    'Shortened',
+    'Lifted'
+);
+
+CREATE TABLE ban_appeal (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    ban_id int REFERENCES modlog ON UPDATE CASCADE ON DELETE CASCADE NOT NULL UNIQUE,
+    reason text NOT NULL,
+    ap_id text,
+    resolved bool NOT NULL DEFAULT FALSE,
+    resolver_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    decision ban_appeal_decision_enum,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_ban_appeal_published ON ban_appeal (published_at DESC);
+
+ALTER TABLE report_combined
+    ADD COLUMN ban_appeal_id int UNIQUE REFERENCES ban_appeal ON UPDATE CASCADE ON DELETE CASCADE,
+    DROP CONSTRAINT report_combined_check,
+    ADD CHECK (num_nonnulls (post_report_id, comment_report_id, private_message_report_id, community_report_id, ban_appeal_id) = 1);
+
+ALTER TYPE modlog_kind
+    ADD value 'ModResolveBanAppeal';
@@ -0,0 +1,14 @@
+CREATE TYPE moderator_permission_enum AS enum (
+    'HandleReports',
+    'RemoveContent',
+    'ManageBans',
+    'EditSettings',
+    'EditWiki'
+);
+
+-- NULL means the moderator holds all permissions, which keeps existing moderators unchanged.
+ALTER TABLE community_actions
+    ADD COLUMN moderator_permissions moderator_permission_enum[];
+
+ALTER TYPE modlog_kind
+    ADD value 'ModChangeModeratorPermissions';
@@ -0,0 +1,43 @@
+DELETE FROM modlog
+WHERE kind = 'AdminModeratorOverride';
+
+ALTER TYPE modlog_kind RENAME TO modlog_kind__;
+
+CREATE TYPE modlog_kind AS enum (
+    'AdminAdd',
+    'AdminBan',
+    'AdminAllowInstance',
+    'AdminBlockInstance',
+    'AdminPurgeComment',
+    'AdminPurgeCommunity',
+    'AdminPurgePerson',
+    'AdminPurgePost',
+    'ModAddToCommunity',
+    'ModBanFromCommunity',
+    'ModFeaturePostCommunity',
+    'AdminFeaturePostSite',
+    'ModChangeCommunityVisibility',
+    'ModLockPost',
+    'ModRemoveComment',
+    'AdminRemoveCommunity',
+    'ModRemovePost',
+    'ModTransferCommunity',
+    'ModLockComment',
+    'AdminQuarantinePerson',
+    'ModApprovePost',
+    'ModResolveBanAppeal',
+    'ModChangeModeratorPermissions'
+);
+
+ALTER TABLE modlog
+    ALTER COLUMN kind TYPE modlog_kind
+    USING kind::text::modlog_kind;
+
+DROP TYPE modlog_kind__;
+
+DROP TABLE admin_audit_log;
+
+ALTER TABLE local_user
+    DROP COLUMN admin_permissions;
+
+DROP TYPE admin_permission_enum;
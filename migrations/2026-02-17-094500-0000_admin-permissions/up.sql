@@ -0,0 +1,32 @@
+-- Granular admin capabilities. NULL means the admin holds all permissions, which keeps existing
+-- admins unchanged.
+CREATE TYPE admin_permission_enum AS enum (
+    'ReviewRegistrations',
+    'HandleReports',
+    'ManageFederation',
+    'EditSite',
+    'Purge'
+);
+
+ALTER TABLE local_user
+    ADD COLUMN admin_permissions admin_permission_enum[];
+
+-- Every call of an admin API endpoint, including actions which don't show up in the modlog.
+CREATE TABLE admin_audit_log (
+    id serial PRIMARY KEY,
+    admin_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    action text NOT NULL,
+    -- Id of the affected item, its type depends on the action.
+    target_id int,
+    -- Parameters of the API call as JSON, without secrets.
+    parameters text,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_admin_audit_log_admin ON admin_audit_log (admin_id);
+
+CREATE INDEX idx_admin_audit_log_published ON admin_audit_log (published_at DESC);
+
+-- Written when an admin acts as moderator in a community they don't moderate
+ALTER TYPE modlog_kind
+    ADD value 'AdminModeratorOverride';
@@ -0,0 +1,3 @@
+DROP TABLE personal_access_token;
+
+DROP TYPE api_token_scope_enum;
@@ -0,0 +1,24 @@
+-- Scopes which can be granted to a personal access token. Moderation rights are granted per
+-- community with `moderate_community_ids` instead.
+CREATE TYPE api_token_scope_enum AS enum (
+    'Read',
+    'Post',
+    'Vote',
+    'AdminReports'
+);
+
+-- Long lived tokens for bots and integrations, with restricted rights. Tokens are only stored as
+-- SHA-256 hash, and the raw token is only shown once.
+CREATE TABLE personal_access_token (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name text NOT NULL,
+    token_hash text NOT NULL UNIQUE,
+    scopes api_token_scope_enum[] NOT NULL,
+    moderate_community_ids int[] NOT NULL DEFAULT '{}',
+    expires_at timestamptz,
+    last_used_at timestamptz,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_personal_access_token_user ON personal_access_token (local_user_id);
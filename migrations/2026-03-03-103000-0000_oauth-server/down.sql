@@ -0,0 +1,9 @@
+ALTER TABLE personal_access_token
+    DROP COLUMN oauth_application_id;
+
+DROP TABLE oauth_refresh_token;
+
+DROP TABLE oauth_authorization_code;
+
+DROP TABLE oauth_application;
+
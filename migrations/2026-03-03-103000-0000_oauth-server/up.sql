@@ -0,0 +1,44 @@
+-- Third party apps which can ask users for access to their account with OAuth 2.0. Client
+-- secrets, authorization codes and refresh tokens are only stored as SHA-256 hash.
+CREATE TABLE oauth_application (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name text NOT NULL,
+    description text,
+    client_id text NOT NULL UNIQUE,
+    -- Public clients like mobile apps have no secret, and need to use PKCE instead.
+    client_secret_hash text,
+    redirect_uris text[] NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_oauth_application_user ON oauth_application (local_user_id);
+
+-- Short lived codes which are exchanged for tokens after the user approved an app.
+CREATE TABLE oauth_authorization_code (
+    code_hash text PRIMARY KEY,
+    application_id int REFERENCES oauth_application ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    redirect_uri text NOT NULL,
+    scope text NOT NULL,
+    code_challenge text,
+    expires_at timestamptz NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+-- Refresh tokens are replaced by a new one every time they are used.
+CREATE TABLE oauth_refresh_token (
+    token_hash text PRIMARY KEY,
+    application_id int REFERENCES oauth_application ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    scope text NOT NULL,
+    expires_at timestamptz NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_oauth_refresh_token_application ON oauth_refresh_token (application_id);
+
+-- Access tokens for apps are short lived personal access tokens.
+ALTER TABLE personal_access_token
+    ADD COLUMN oauth_application_id int REFERENCES oauth_application ON UPDATE CASCADE ON DELETE CASCADE;
+
@@ -0,0 +1,6 @@
+DROP TABLE recovery_code;
+
+DROP TABLE webauthn_challenge;
+
+DROP TABLE webauthn_credential;
+
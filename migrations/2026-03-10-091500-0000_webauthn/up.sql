@@ -0,0 +1,31 @@
+-- Passkeys and security keys, which can be used as second factor or for passwordless login.
+CREATE TABLE webauthn_credential (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name text NOT NULL,
+    -- Base64 encoded credential id, as sent by the authenticator.
+    credential_id text NOT NULL UNIQUE,
+    -- The serialized public key and signature counter.
+    passkey text NOT NULL,
+    last_used_at timestamptz,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_webauthn_credential_user ON webauthn_credential (local_user_id);
+
+-- Server side state of a registration or authentication ceremony which is in progress.
+CREATE TABLE webauthn_challenge (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    state text NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+-- Single use codes to log in when the second factor is lost. Only a hash is stored.
+CREATE TABLE recovery_code (
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    code_hash text NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (local_user_id, code_hash)
+);
+
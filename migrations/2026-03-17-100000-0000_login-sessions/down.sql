@@ -0,0 +1,8 @@
+ALTER TABLE login_token
+    DROP COLUMN id,
+    DROP COLUMN refresh_token_hash,
+    DROP COLUMN last_used_at,
+    DROP COLUMN expires_at;
+
+ALTER TABLE local_site
+    DROP COLUMN session_idle_timeout_seconds;
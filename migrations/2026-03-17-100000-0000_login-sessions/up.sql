@@ -0,0 +1,19 @@
+-- Sessions get a stable id so that they can be revoked individually, and a refresh token which is
+-- rotated whenever a new access token is issued. Only the SHA-256 hash of the refresh token is
+-- stored.
+ALTER TABLE login_token
+    ADD COLUMN id serial NOT NULL UNIQUE,
+    ADD COLUMN refresh_token_hash text UNIQUE,
+    ADD COLUMN last_used_at timestamptz NOT NULL DEFAULT now(),
+    ADD COLUMN expires_at timestamptz;
+
+-- Sessions which are not used for this long are logged out. Null means no limit.
+ALTER TABLE local_site
+    ADD COLUMN session_idle_timeout_seconds int;
+
+-- Logins from before this change have access tokens which never expire, and no refresh token.
+-- Let them end after one more week, so that clients have time to log in again.
+UPDATE
+    login_token
+SET
+    expires_at = now() + interval '1 week';
@@ -0,0 +1,30 @@
+ALTER TABLE local_site
+    DROP COLUMN invite_min_account_age_days;
+
+ALTER TABLE local_user
+    DROP COLUMN invited_by_id;
+
+DROP TABLE invite_code;
+
+CREATE TYPE registration_mode_enum_tmp AS ENUM (
+    'Closed',
+    'RequireApplication',
+    'Open'
+);
+
+UPDATE
+    local_site
+SET
+    registration_mode = 'Closed'
+WHERE
+    registration_mode = 'InviteOnly';
+
+ALTER TABLE local_site
+    ALTER COLUMN registration_mode DROP DEFAULT,
+    ALTER COLUMN registration_mode TYPE registration_mode_enum_tmp
+    USING (registration_mode::text::registration_mode_enum_tmp),
+    ALTER COLUMN registration_mode SET DEFAULT 'RequireApplication';
+
+DROP TYPE registration_mode_enum;
+
+ALTER TYPE registration_mode_enum_tmp RENAME TO registration_mode_enum;
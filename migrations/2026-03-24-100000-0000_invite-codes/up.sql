@@ -0,0 +1,27 @@
+ALTER TYPE registration_mode_enum
+    ADD VALUE 'InviteOnly';
+
+CREATE TABLE invite_code (
+    id serial PRIMARY KEY,
+    code text NOT NULL UNIQUE,
+    creator_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    -- Null means the code can be used any number of times
+    max_uses int,
+    uses int NOT NULL DEFAULT 0,
+    expires_at timestamptz,
+    -- Users who register with the code automatically follow these communities
+    community_ids int[] NOT NULL DEFAULT '{}',
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_invite_code_creator ON invite_code (creator_id);
+
+-- Keeps track of who invited whom, so that admins can trace invite trees.
+ALTER TABLE local_user
+    ADD COLUMN invited_by_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE SET NULL;
+
+CREATE INDEX idx_local_user_invited_by ON local_user (invited_by_id);
+
+-- Users whose account is at least this old can create invite codes. Null means only admins can.
+ALTER TABLE local_site
+    ADD COLUMN invite_min_account_age_days int;
@@ -0,0 +1,15 @@
+ALTER TABLE registration_application
+    DROP COLUMN screening_action,
+    DROP COLUMN screening_reasons;
+
+DROP TABLE registration_screening_rule;
+
+DROP TYPE registration_screening_action_enum;
+
+DROP TYPE registration_screening_rule_kind_enum;
+
+DROP TABLE registration_answer;
+
+DROP TABLE registration_question;
+
+DROP TYPE registration_question_kind_enum;
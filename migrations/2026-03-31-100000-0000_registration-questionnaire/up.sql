@@ -0,0 +1,55 @@
+CREATE TYPE registration_question_kind_enum AS ENUM (
+    'Text',
+    'Choice'
+);
+
+-- Admin defined questions which are shown in the signup form, in addition to the application
+-- question.
+CREATE TABLE registration_question (
+    id serial PRIMARY KEY,
+    question text NOT NULL,
+    kind registration_question_kind_enum NOT NULL DEFAULT 'Text',
+    -- The possible answers of choice questions
+    choices text[] NOT NULL DEFAULT '{}',
+    required boolean NOT NULL DEFAULT TRUE,
+    display_order int NOT NULL DEFAULT 0,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+-- The question is copied, so that answers stay readable when the question is changed or deleted.
+CREATE TABLE registration_answer (
+    id serial PRIMARY KEY,
+    registration_application_id int REFERENCES registration_application ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    question_id int REFERENCES registration_question ON UPDATE CASCADE ON DELETE SET NULL,
+    question text NOT NULL,
+    answer text NOT NULL
+);
+
+CREATE INDEX idx_registration_answer_application ON registration_answer (registration_application_id);
+
+CREATE TYPE registration_screening_rule_kind_enum AS ENUM (
+    'EmailDomain',
+    'AnswerContains',
+    'IpRange'
+);
+
+CREATE TYPE registration_screening_action_enum AS ENUM (
+    'Approve',
+    'Deny',
+    'Flag'
+);
+
+-- Rules which are checked automatically for new registration applications.
+CREATE TABLE registration_screening_rule (
+    id serial PRIMARY KEY,
+    kind registration_screening_rule_kind_enum NOT NULL,
+    value text NOT NULL,
+    action registration_screening_action_enum NOT NULL,
+    reason text,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+ALTER TABLE registration_application
+    ADD COLUMN screening_action registration_screening_action_enum,
+    ADD COLUMN screening_reasons text[] NOT NULL DEFAULT '{}';
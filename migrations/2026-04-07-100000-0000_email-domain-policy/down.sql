@@ -0,0 +1,5 @@
+ALTER TABLE local_site
+    DROP COLUMN email_domain_allowlist_only,
+    DROP COLUMN block_disposable_email_domains;
+
+DROP TABLE local_site_email_domain;
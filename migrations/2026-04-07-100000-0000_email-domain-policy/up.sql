@@ -0,0 +1,13 @@
+-- Email domains which admins allow or block for signups and email changes. Subdomains are
+-- included.
+CREATE TABLE local_site_email_domain (
+    id serial PRIMARY KEY,
+    domain text NOT NULL,
+    allowed boolean NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    UNIQUE (domain, allowed)
+);
+
+ALTER TABLE local_site
+    ADD COLUMN email_domain_allowlist_only boolean NOT NULL DEFAULT FALSE,
+    ADD COLUMN block_disposable_email_domains boolean NOT NULL DEFAULT FALSE;
@@ -0,0 +1,10 @@
+DROP TABLE user_data_export;
+
+DROP TYPE data_export_status_enum;
+
+ALTER TABLE local_user
+    DROP COLUMN deletion_scheduled_at,
+    DROP COLUMN deletion_delete_content;
+
+ALTER TABLE local_site
+    DROP COLUMN account_deletion_grace_period_days;
@@ -0,0 +1,28 @@
+-- Null means accounts are deleted right away
+ALTER TABLE local_site
+    ADD COLUMN account_deletion_grace_period_days int;
+
+ALTER TABLE local_user
+    ADD COLUMN deletion_scheduled_at timestamptz,
+    ADD COLUMN deletion_delete_content boolean NOT NULL DEFAULT FALSE;
+
+CREATE INDEX idx_local_user_deletion_scheduled_at ON local_user (deletion_scheduled_at)
+WHERE
+    deletion_scheduled_at IS NOT NULL;
+
+CREATE TYPE data_export_status_enum AS ENUM (
+    'Pending',
+    'Complete',
+    'Failed'
+);
+
+CREATE TABLE user_data_export (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    -- The finished archive is stored as a file in the data export directory
+    status data_export_status_enum NOT NULL DEFAULT 'Pending',
+    published_at timestamptz NOT NULL DEFAULT now(),
+    completed_at timestamptz
+);
+
+CREATE INDEX idx_user_data_export_local_user ON user_data_export (local_user_id);
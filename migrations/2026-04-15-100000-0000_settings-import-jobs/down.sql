@@ -0,0 +1,5 @@
+DROP TABLE user_settings_import;
+
+DROP TYPE settings_import_status_enum;
+
+DROP TYPE settings_import_format_enum;
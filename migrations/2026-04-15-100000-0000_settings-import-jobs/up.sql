@@ -0,0 +1,29 @@
+CREATE TYPE settings_import_format_enum AS ENUM (
+    'Lemmy',
+    'MastodonFollows',
+    'MastodonBlocks',
+    'MastodonDomainBlocks',
+    'Mbin',
+    'Piefed'
+);
+
+CREATE TYPE settings_import_status_enum AS ENUM (
+    'Pending',
+    'Complete',
+    'Failed'
+);
+
+CREATE TABLE user_settings_import (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    format settings_import_format_enum NOT NULL,
+    status settings_import_status_enum NOT NULL DEFAULT 'Pending',
+    total_items int NOT NULL DEFAULT 0,
+    processed_items int NOT NULL DEFAULT 0,
+    -- Urls or names of the items which couldn't be imported
+    failed_items text[] NOT NULL DEFAULT '{}',
+    published_at timestamptz NOT NULL DEFAULT now(),
+    completed_at timestamptz
+);
+
+CREATE INDEX idx_user_settings_import_local_user ON user_settings_import (local_user_id);
@@ -0,0 +1,5 @@
+DROP TABLE content_filter;
+
+DROP TYPE content_filter_action_enum;
+
+DROP TYPE content_filter_match_enum;
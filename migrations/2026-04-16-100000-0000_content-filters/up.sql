@@ -0,0 +1,32 @@
+CREATE TYPE content_filter_match_enum AS ENUM (
+    'Keyword',
+    'WholeWord',
+    'Regex'
+);
+
+CREATE TYPE content_filter_action_enum AS ENUM (
+    'Hide',
+    'Warn'
+);
+
+CREATE TABLE content_filter (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    phrase varchar(200) NOT NULL,
+    match_type content_filter_match_enum NOT NULL DEFAULT 'Keyword',
+    -- Case insensitive Postgres regex built from the phrase and match type
+    pattern text NOT NULL,
+    action content_filter_action_enum NOT NULL DEFAULT 'Hide',
+    filter_titles boolean NOT NULL DEFAULT TRUE,
+    filter_bodies boolean NOT NULL DEFAULT TRUE,
+    filter_comments boolean NOT NULL DEFAULT TRUE,
+    filter_usernames boolean NOT NULL DEFAULT FALSE,
+    filter_urls boolean NOT NULL DEFAULT TRUE,
+    -- Null means the filter applies everywhere
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    expires_at timestamptz,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_content_filter_local_user ON content_filter (local_user_id);
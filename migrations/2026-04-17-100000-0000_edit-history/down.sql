@@ -0,0 +1,7 @@
+DROP TABLE post_revision, comment_revision, private_message_revision;
+
+ALTER TABLE local_site
+    DROP COLUMN edit_history_visibility,
+    DROP COLUMN edit_history_retention_days;
+
+DROP TYPE edit_history_visibility_enum;
@@ -0,0 +1,45 @@
+CREATE TYPE edit_history_visibility_enum AS ENUM (
+    'Public',
+    'ModsAndAdmins'
+);
+
+ALTER TABLE local_site
+    ADD COLUMN edit_history_visibility edit_history_visibility_enum NOT NULL DEFAULT 'ModsAndAdmins',
+    -- Null means revisions are kept forever
+    ADD COLUMN edit_history_retention_days int DEFAULT 90;
+
+-- Each row holds a previous version of the content, which was replaced at published_at
+CREATE TABLE post_revision (
+    id serial PRIMARY KEY,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name text NOT NULL,
+    body text,
+    url text,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_post_revision_post ON post_revision (post_id);
+
+CREATE INDEX idx_post_revision_published ON post_revision (published_at);
+
+CREATE TABLE comment_revision (
+    id serial PRIMARY KEY,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    content text NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_comment_revision_comment ON comment_revision (comment_id);
+
+CREATE INDEX idx_comment_revision_published ON comment_revision (published_at);
+
+CREATE TABLE private_message_revision (
+    id serial PRIMARY KEY,
+    private_message_id int REFERENCES private_message ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    content text NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_private_message_revision_private_message ON private_message_revision (private_message_id);
+
+CREATE INDEX idx_private_message_revision_published ON private_message_revision (published_at);
@@ -0,0 +1,11 @@
+-- The images of a gallery post, in the order in which they are shown. The first image is also
+-- stored as post.url, for clients and instances which only know about a single image.
+CREATE TABLE post_gallery_item (
+    id serial PRIMARY KEY,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    url text NOT NULL,
+    alt_text text,
+    position int NOT NULL
+);
+
+CREATE INDEX idx_post_gallery_item_post ON post_gallery_item (post_id, position);
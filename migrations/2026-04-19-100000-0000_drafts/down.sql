@@ -0,0 +1,3 @@
+DROP TABLE draft;
+
+DROP TYPE draft_kind_enum;
@@ -0,0 +1,30 @@
+CREATE TYPE draft_kind_enum AS ENUM (
+    'Post',
+    'Comment',
+    'PrivateMessage'
+);
+
+CREATE TABLE draft (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    kind draft_kind_enum NOT NULL,
+    -- Where the draft will be published, depending on its kind
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    parent_comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    recipient_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    name text,
+    url text,
+    alt_text text,
+    custom_thumbnail text,
+    body text,
+    nsfw boolean,
+    language_id int REFERENCES
+    LANGUAGE ON UPDATE CASCADE ON DELETE SET NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_draft_local_user ON draft (local_user_id);
+
+CREATE INDEX idx_draft_updated ON draft (coalesce(updated_at, published_at));
@@ -0,0 +1,3 @@
+DROP TABLE recurring_post;
+
+DROP TYPE recurring_post_frequency_enum;
@@ -0,0 +1,31 @@
+CREATE TYPE recurring_post_frequency_enum AS ENUM (
+    'Daily',
+    'Weekly',
+    'Monthly'
+);
+
+CREATE TABLE recurring_post (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    -- The moderator who set up the schedule, posts are published in their name
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    -- Title with placeholders like {date}, which are filled in for each post
+    name_template varchar(200) NOT NULL,
+    body text,
+    nsfw boolean NOT NULL DEFAULT FALSE,
+    language_id int REFERENCES
+    LANGUAGE ON UPDATE CASCADE ON DELETE SET NULL,
+    frequency recurring_post_frequency_enum NOT NULL,
+    -- Later posts are counted from here, so that monthly schedules don't drift after short months
+    schedule_start_at timestamptz NOT NULL,
+    next_publish_at timestamptz NOT NULL,
+    feature_latest boolean NOT NULL DEFAULT FALSE,
+    lock_previous boolean NOT NULL DEFAULT FALSE,
+    last_post_id int REFERENCES post ON UPDATE CASCADE ON DELETE SET NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_recurring_post_community ON recurring_post (community_id);
+
+CREATE INDEX idx_recurring_post_next_publish ON recurring_post (next_publish_at);
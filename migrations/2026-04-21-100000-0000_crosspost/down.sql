@@ -0,0 +1,5 @@
+ALTER TABLE local_user
+    DROP COLUMN collapse_crossposts;
+
+ALTER TABLE post
+    DROP COLUMN original_post_id;
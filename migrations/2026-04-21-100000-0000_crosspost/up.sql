@@ -0,0 +1,12 @@
+-- A crosspost links back to the post it was copied from. Chains of crossposts always point to the
+-- first post.
+ALTER TABLE post
+    ADD COLUMN original_post_id int REFERENCES post ON UPDATE CASCADE ON DELETE SET NULL;
+
+CREATE INDEX idx_post_original_post ON post (original_post_id)
+WHERE
+    original_post_id IS NOT NULL;
+
+-- Show posts of the same link only once in the front page
+ALTER TABLE local_user
+    ADD COLUMN collapse_crossposts boolean NOT NULL DEFAULT FALSE;
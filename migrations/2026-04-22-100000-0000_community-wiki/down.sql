@@ -0,0 +1,7 @@
+DROP TABLE wiki_editor;
+
+DROP TABLE wiki_page_revision;
+
+DROP TABLE wiki_page;
+
+DROP TYPE wiki_edit_permission_enum;
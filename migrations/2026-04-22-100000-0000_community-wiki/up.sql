@@ -0,0 +1,42 @@
+CREATE TYPE wiki_edit_permission_enum AS ENUM (
+    'Moderators',
+    'ApprovedEditors'
+);
+
+CREATE TABLE wiki_page (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    -- Path of the page within the community wiki, like "guides/getting-started"
+    slug varchar(100) NOT NULL,
+    title varchar(200) NOT NULL,
+    body text NOT NULL,
+    edit_permission wiki_edit_permission_enum NOT NULL DEFAULT 'Moderators',
+    ap_id text NOT NULL UNIQUE,
+    local boolean NOT NULL DEFAULT TRUE,
+    deleted boolean NOT NULL DEFAULT FALSE,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    UNIQUE (community_id, slug)
+);
+
+-- Every saved version of a wiki page, including the current one
+CREATE TABLE wiki_page_revision (
+    id serial PRIMARY KEY,
+    wiki_page_id int REFERENCES wiki_page ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    title varchar(200) NOT NULL,
+    body text NOT NULL,
+    -- A short description of the change
+    summary varchar(200),
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_wiki_page_revision_page ON wiki_page_revision (wiki_page_id, published_at DESC);
+
+-- Users who are allowed to edit the wiki pages which aren't reserved for mods
+CREATE TABLE wiki_editor (
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (community_id, person_id)
+);
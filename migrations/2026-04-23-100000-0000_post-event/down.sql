@@ -0,0 +1,7 @@
+ALTER TABLE post_actions
+    DROP COLUMN event_rsvp,
+    DROP COLUMN event_rsvp_at;
+
+DROP TABLE post_event;
+
+DROP TYPE post_event_rsvp_enum;
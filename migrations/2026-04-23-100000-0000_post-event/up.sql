@@ -0,0 +1,24 @@
+CREATE TYPE post_event_rsvp_enum AS enum (
+    'Going',
+    'Interested'
+);
+
+-- Date, time and place of an event post. The RSVP counts are updated by triggers on post_actions.
+CREATE TABLE post_event (
+    post_id int PRIMARY KEY REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    start_at timestamptz NOT NULL,
+    end_at timestamptz NOT NULL,
+    timezone varchar(50) NOT NULL,
+    location text,
+    online_url text,
+    going_count int NOT NULL DEFAULT 0,
+    interested_count int NOT NULL DEFAULT 0,
+    CHECK (end_at >= start_at)
+);
+
+CREATE INDEX idx_post_event_end ON post_event (end_at);
+
+ALTER TABLE post_actions
+    ADD COLUMN event_rsvp post_event_rsvp_enum,
+    ADD COLUMN event_rsvp_at timestamptz,
+    ADD CONSTRAINT post_actions_check_event_rsvp CHECK ((event_rsvp IS NULL) = (event_rsvp_at IS NULL));
@@ -0,0 +1,5 @@
+ALTER TABLE post
+    DROP COLUMN accepted_answer_id;
+
+ALTER TABLE comment
+    DROP COLUMN pinned;
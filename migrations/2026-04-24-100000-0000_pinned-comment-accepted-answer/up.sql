@@ -0,0 +1,11 @@
+-- Pinned comments are sorted before their siblings, regardless of the sort type
+ALTER TABLE comment
+    ADD COLUMN pinned boolean NOT NULL DEFAULT FALSE;
+
+-- The comment which the post author or a mod marked as answer to the post
+ALTER TABLE post
+    ADD COLUMN accepted_answer_id int REFERENCES comment ON UPDATE CASCADE ON DELETE SET NULL;
+
+CREATE INDEX idx_post_accepted_answer ON post (accepted_answer_id)
+WHERE
+    accepted_answer_id IS NOT NULL;
@@ -0,0 +1,9 @@
+ALTER TABLE community_actions
+    DROP COLUMN flair,
+    DROP COLUMN flair_emoji;
+
+ALTER TABLE tag
+    DROP COLUMN color,
+    DROP COLUMN emoji,
+    DROP COLUMN mod_only,
+    DROP COLUMN user_flair;
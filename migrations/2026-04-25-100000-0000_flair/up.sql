@@ -0,0 +1,12 @@
+-- Styling for community tags. Tags with user_flair set aren't used for posts, but are the
+-- options which users can pick as their flair in the community.
+ALTER TABLE tag
+    ADD COLUMN color varchar(7),
+    ADD COLUMN emoji varchar(16),
+    ADD COLUMN mod_only boolean NOT NULL DEFAULT FALSE,
+    ADD COLUMN user_flair boolean NOT NULL DEFAULT FALSE;
+
+-- The flair of a user in a community, shown next to their name
+ALTER TABLE community_actions
+    ADD COLUMN flair varchar(50),
+    ADD COLUMN flair_emoji varchar(16);